@@ -0,0 +1,153 @@
+#!/usr/bin/env python3
+"""Downloads the Unicode Character Database files that
+scripts/generate_unicode_data.py reads into data/unicode, checking each one
+against data/unicode/SHA256SUMS
+
+A file without a pinned checksum is only written with --pin, which adds its
+checksum to SHA256SUMS, so pinning a new file is a deliberate, reviewable
+change. A download that doesn't match its pinned checksum is an error and
+leaves the committed file alone
+
+Usage: scripts/fetch_unicode_data.py [--pin] [version ...]
+"""
+
+import argparse
+import hashlib
+import os
+import sys
+import urllib.request
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+DATA = os.path.join(ROOT, "data", "unicode")
+CHECKSUMS = os.path.join(DATA, "SHA256SUMS")
+
+BASE_URL = "https://www.unicode.org/Public"
+
+# The Emoji version that goes with each Unicode version; emoji-data.txt only
+# moved into the UCD with 13.0.0
+EMOJI_VERSIONS = {
+    "10.0.0": "5.0",
+    "11.0.0": "11.0",
+    "12.1.0": "12.1",
+    "13.0.0": "13.0",
+    "14.0.0": "14.0",
+    "15.0.0": "15.0",
+    "15.1.0": "15.1",
+    "16.0.0": "16.0",
+}
+
+# Properties of strings came with the v flag, in ECMAScript 2024
+FIRST_SEQUENCE_VERSION = "15.1.0"
+
+
+def version_key(version):
+    return tuple(map(int, version.split(".")))
+
+
+def files(version, latest):
+    """(name, URL) of each file the generator reads for a version"""
+    ucd = "%s/%s/ucd" % (BASE_URL, version)
+    emoji = "%s/emoji/%s" % (BASE_URL, EMOJI_VERSIONS[version])
+    result = [
+        ("DerivedGeneralCategory.txt", ucd + "/extracted/DerivedGeneralCategory.txt"),
+        ("Scripts.txt", ucd + "/Scripts.txt"),
+        ("ScriptExtensions.txt", ucd + "/ScriptExtensions.txt"),
+        ("PropList.txt", ucd + "/PropList.txt"),
+        ("DerivedCoreProperties.txt", ucd + "/DerivedCoreProperties.txt"),
+        ("DerivedBinaryProperties.txt", ucd + "/extracted/DerivedBinaryProperties.txt"),
+        ("DerivedNormalizationProps.txt", ucd + "/DerivedNormalizationProps.txt"),
+        (
+            "emoji-data.txt",
+            ucd + "/emoji/emoji-data.txt"
+            if version_key(version) >= (13, 0, 0)
+            else emoji + "/emoji-data.txt",
+        ),
+    ]
+    if version_key(version) >= version_key(FIRST_SEQUENCE_VERSION):
+        result += [
+            ("emoji-sequences.txt", emoji + "/emoji-sequences.txt"),
+            ("emoji-zwj-sequences.txt", emoji + "/emoji-zwj-sequences.txt"),
+        ]
+    if latest:
+        result += [
+            (name, "%s/%s" % (ucd, name))
+            for name in [
+                "PropertyAliases.txt",
+                "PropertyValueAliases.txt",
+                "CaseFolding.txt",
+                "SpecialCasing.txt",
+                "UnicodeData.txt",
+            ]
+        ]
+    return result
+
+
+def read_checksums():
+    checksums = {}
+    if os.path.exists(CHECKSUMS):
+        with open(CHECKSUMS, encoding="utf-8") as f:
+            for line in f:
+                checksum, _, path = line.strip().partition("  ")
+                checksums[path] = checksum
+    return checksums
+
+
+def write_checksums(checksums):
+    with open(CHECKSUMS, "w", encoding="utf-8") as f:
+        for path in sorted(
+            checksums,
+            key=lambda path: (version_key(path.split("/")[0]), path),
+        ):
+            f.write("%s  %s\n" % (checksums[path], path))
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n\n")[0])
+    parser.add_argument(
+        "--pin",
+        action="store_true",
+        help="write files that have no checksum yet, and pin their checksums",
+    )
+    parser.add_argument(
+        "versions",
+        nargs="*",
+        help="the versions to fetch (default: all of them)",
+    )
+    args = parser.parse_args()
+
+    latest = max(EMOJI_VERSIONS, key=version_key)
+    versions = args.versions or sorted(EMOJI_VERSIONS, key=version_key)
+    checksums = read_checksums()
+    failed = False
+    for version in versions:
+        if version not in EMOJI_VERSIONS:
+            sys.exit("unknown Unicode version %s" % version)
+        for name, url in files(version, version == latest):
+            path = "%s/%s" % (version, name)
+            with urllib.request.urlopen(url) as response:
+                contents = response.read()
+            checksum = hashlib.sha256(contents).hexdigest()
+            if path in checksums:
+                if checksums[path] != checksum:
+                    print("%s: checksum mismatch for %s" % (path, url), file=sys.stderr)
+                    failed = True
+                    continue
+            elif args.pin:
+                checksums[path] = checksum
+                print("%s: pinned %s" % (path, checksum))
+            else:
+                print("%s: not pinned, rerun with --pin to add it" % path, file=sys.stderr)
+                failed = True
+                continue
+            os.makedirs(os.path.join(DATA, version), exist_ok=True)
+            with open(os.path.join(DATA, version, name), "wb") as f:
+                f.write(contents)
+
+    if args.pin:
+        write_checksums(checksums)
+    if failed:
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
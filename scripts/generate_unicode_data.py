@@ -0,0 +1,433 @@
+#!/usr/bin/env python3
+"""Generates src/unicode/property_data.rs and src/unicode/case_folding_data.rs
+from the Unicode Character Database files in data/unicode
+
+Every directory in data/unicode is a Unicode version, and the latest one's
+PropertyAliases.txt and PropertyValueAliases.txt name the properties. Lines
+for other properties are skipped, so the full UCD files that
+scripts/fetch_unicode_data.py downloads can be used as they are. A missing
+file is an error, except for the emoji sequences of versions without
+properties of strings. The case folding tables only use the latest version
+
+Usage: scripts/generate_unicode_data.py
+"""
+
+import os
+import re
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+DATA = os.path.join(ROOT, "data", "unicode")
+OUTPUT = os.path.join(ROOT, "src", "unicode", "property_data.rs")
+CASE_FOLDING_OUTPUT = os.path.join(ROOT, "src", "unicode", "case_folding_data.rs")
+
+MAX_CODE_POINT = 0x10FFFF
+
+BINARY_PROPERTY_FILES = [
+    "PropList.txt",
+    "DerivedCoreProperties.txt",
+    "DerivedBinaryProperties.txt",
+    "DerivedNormalizationProps.txt",
+    "emoji-data.txt",
+]
+SEQUENCE_FILES = ["emoji-sequences.txt", "emoji-zwj-sequences.txt"]
+STRING_PROPERTIES = [
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+]
+
+
+# Code point sets are sorted lists of disjoint, non-adjacent inclusive ranges
+
+
+def normalize(ranges):
+    merged = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+        else:
+            merged.append((start, end))
+    return merged
+
+
+def union(*sets):
+    return normalize([r for s in sets for r in s])
+
+
+def complement(ranges):
+    result = []
+    next_start = 0
+    for start, end in ranges:
+        if start > next_start:
+            result.append((next_start, start - 1))
+        next_start = end + 1
+    if next_start <= MAX_CODE_POINT:
+        result.append((next_start, MAX_CODE_POINT))
+    return result
+
+
+def difference(a, b):
+    return complement(union(complement(a), b))
+
+
+def symmetric_difference(a, b):
+    return union(difference(a, b), difference(b, a))
+
+
+def read_ucd_file(path):
+    """(code points, fields, comment) for each data line of a UCD file"""
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            data, _, comment = line.partition("#")
+            if not data.strip():
+                continue
+            code_points, *fields = [field.strip() for field in data.split(";")]
+            yield code_points, fields, comment.strip()
+
+
+def parse_range(code_points):
+    start, _, end = code_points.partition("..")
+    return int(start, 16), int(end or start, 16)
+
+
+def read_aliases(directory):
+    binary = [
+        [code_points] + fields
+        for code_points, fields, _ in read_ucd_file(
+            os.path.join(directory, "PropertyAliases.txt")
+        )
+    ]
+    values = {"gc": [], "sc": []}
+    groups = {}
+    for prop, fields, comment in read_ucd_file(
+        os.path.join(directory, "PropertyValueAliases.txt")
+    ):
+        if prop in values:
+            values[prop].append(fields)
+        if prop == "gc" and comment:
+            groups[fields[0]] = [value.strip() for value in comment.split("|")]
+    return binary, values, groups
+
+
+def read_version(directory, binary_properties):
+    general_category = {}
+    for code_points, fields, _ in read_ucd_file(
+        os.path.join(directory, "DerivedGeneralCategory.txt")
+    ):
+        general_category.setdefault(fields[0], []).append(parse_range(code_points))
+    general_category = {k: normalize(v) for k, v in general_category.items()}
+    general_category["Cn"] = union(
+        general_category.get("Cn", []),
+        complement(union(*general_category.values())),
+    )
+
+    script = {}
+    for code_points, fields, _ in read_ucd_file(
+        os.path.join(directory, "Scripts.txt")
+    ):
+        script.setdefault(fields[0], []).append(parse_range(code_points))
+    script = {k: normalize(v) for k, v in script.items()}
+    script["Unknown"] = union(
+        script.get("Unknown", []), complement(union(*script.values()))
+    )
+
+    extensions = {}
+    for code_points, fields, _ in read_ucd_file(
+        os.path.join(directory, "ScriptExtensions.txt")
+    ):
+        extensions[parse_range(code_points)] = fields[0].split()
+
+    binary = {}
+    for name in BINARY_PROPERTY_FILES:
+        for code_points, fields, _ in read_ucd_file(os.path.join(directory, name)):
+            if len(fields) == 1 and fields[0] in binary_properties:
+                binary.setdefault(fields[0], []).append(parse_range(code_points))
+    binary = {k: normalize(v) for k, v in binary.items()}
+
+    sequences = {}
+    for name in SEQUENCE_FILES:
+        # Only the versions with properties of strings have these
+        if not os.path.exists(os.path.join(directory, name)):
+            continue
+        for code_points, fields, _ in read_ucd_file(os.path.join(directory, name)):
+            if fields[0] in STRING_PROPERTIES and " " in code_points:
+                sequences.setdefault(fields[0], set()).add(
+                    tuple(int(cp, 16) for cp in code_points.split())
+                )
+
+    return general_category, script, extensions, binary, sequences
+
+
+def script_extensions(script, extensions, short_names):
+    """Script_Extensions by short name: the Script unless listed otherwise"""
+    listed = normalize(extensions.keys())
+    result = {
+        short_names[long]: difference(ranges, listed)
+        for long, ranges in script.items()
+    }
+    for code_points, values in extensions.items():
+        for value in values:
+            result[value] = union(result.get(value, []), [code_points])
+    return result
+
+
+def radix36(value):
+    if value < 0:
+        return "-" + radix36(-value)
+    digits = "0123456789abcdefghijklmnopqrstuvwxyz"
+    result = ""
+    while True:
+        value, digit = divmod(value, 36)
+        result = digits[digit] + result
+        if not value:
+            return result
+
+
+def encode_ranges(ranges):
+    """Each range start and end as the difference from the previous value, as
+    `ids.rs` restores them"""
+    result = []
+    last = 0
+    for start, end in ranges:
+        for value in (start, end):
+            result.append(radix36(value - last))
+            last = value
+    return " ".join(result)
+
+
+def versioned(sets):
+    """The latest version's code points, preceded by the ones each earlier
+    version differs from the next one in"""
+    return [
+        encode_ranges(symmetric_difference(ranges, next_ranges))
+        for ranges, next_ranges in zip(sets, sets[1:])
+    ] + [encode_ranges(sets[-1])]
+
+
+def case_classes(mapping):
+    """The groups of code points that the mapping sends to the same code
+    point, which is put first"""
+    classes = {}
+    for cp, target in mapping.items():
+        if cp != target:
+            classes.setdefault(target, set()).add(cp)
+    for target in classes:
+        assert mapping.get(target, target) == target, "%04X isn't fixed" % target
+    return sorted([target] + sorted(members) for target, members in classes.items())
+
+
+def encode_classes(classes):
+    """Each class's first code point as the difference from the previous
+    class's first, and the rest as the difference from their own, as
+    `case_folding.rs` restores them"""
+    result = []
+    last = 0
+    for first, *rest in classes:
+        result.append(
+            " ".join([radix36(first - last)] + [radix36(cp - first) for cp in rest])
+        )
+        last = first
+    return ",".join(result)
+
+
+def read_case_foldings(directory):
+    """Simple case folding, and the legacy (non-unicode mode) `Canonicalize`:
+    full upper-casing, unless that would make a code unit longer or take it
+    out of (or into) ASCII"""
+    simple = {}
+    for code_point, (status, mapping, *_), _ in read_ucd_file(
+        os.path.join(directory, "CaseFolding.txt")
+    ):
+        if status in ("C", "S"):
+            simple[int(code_point, 16)] = int(mapping, 16)
+
+    upper = {}
+    for code_point, fields, _ in read_ucd_file(
+        os.path.join(directory, "UnicodeData.txt")
+    ):
+        if fields[11]:
+            upper[int(code_point, 16)] = [int(fields[11], 16)]
+    for code_point, (_, _, mapping, *condition), _ in read_ucd_file(
+        os.path.join(directory, "SpecialCasing.txt")
+    ):
+        if not any(condition):
+            upper[int(code_point, 16)] = [int(cp, 16) for cp in mapping.split()]
+    legacy = {}
+    for cp, mapping in upper.items():
+        if cp > 0xFFFF or len(mapping) != 1:
+            continue
+        (target,) = mapping
+        if target > 0xFFFF or cp >= 128 and target < 128:
+            continue
+        legacy[cp] = target
+    return simple, legacy
+
+
+def write_table(out, name, entries):
+    grouped = {}
+    for names, data in entries:
+        grouped.setdefault(tuple(data), []).extend(names)
+    rows = sorted((" ".join(sorted(names)), data) for data, names in grouped.items())
+    out.append("pub(super) static %s: &[(&str, [&str; %d])] = &[" % (name, len(rows[0][1])))
+    for names, data in rows:
+        out.append("    (")
+        out.append('        "%s",' % names)
+        out.append("        [")
+        for ranges in data:
+            out.append('            "%s",' % ranges)
+        out.append("        ],")
+        out.append("    ),")
+    out.append("];")
+    out.append("")
+
+
+def main():
+    versions = sorted(
+        [
+            entry
+            for entry in os.listdir(DATA)
+            if os.path.isdir(os.path.join(DATA, entry))
+        ],
+        key=lambda version: tuple(map(int, version.split("."))),
+    )
+    binary_aliases, value_aliases, groups = read_aliases(
+        os.path.join(DATA, versions[-1])
+    )
+    binary_properties = {names[1] for names in binary_aliases}
+    script_short_names = {names[1]: names[0] for names in value_aliases["sc"]}
+    data = [
+        read_version(os.path.join(DATA, version), binary_properties)
+        for version in versions
+    ]
+
+    general_category_entries = []
+    for names in value_aliases["gc"]:
+        value = names[0]
+        general_category_entries.append(
+            (
+                names,
+                versioned(
+                    [
+                        union(
+                            *[
+                                general_category.get(leaf, [])
+                                for leaf in groups.get(value, [value])
+                            ]
+                        )
+                        for general_category, *_ in data
+                    ]
+                ),
+            )
+        )
+
+    script_entries = []
+    script_extensions_entries = []
+    all_extensions = [
+        script_extensions(script, extensions, script_short_names)
+        for _, script, extensions, _, _ in data
+    ]
+    for names in value_aliases["sc"]:
+        short, long = names[0], names[1]
+        script_entries.append(
+            (names, versioned([script.get(long, []) for _, script, *_ in data]))
+        )
+        script_extensions_entries.append(
+            (
+                names,
+                versioned([extensions.get(short, []) for extensions in all_extensions]),
+            )
+        )
+
+    binary_entries = [
+        (names, versioned([binary.get(names[1], []) for *_, binary, _ in data]))
+        for names in binary_aliases
+    ]
+
+    sequence_versions = [
+        (version, sequences)
+        for version, (*_, sequences) in zip(versions, data)
+        if sequences
+    ]
+
+    out = [
+        "// Generated by scripts/generate_unicode_data.py from the Unicode Character",
+        "// Database files in data/unicode; don't edit by hand",
+        "//",
+        "// Each entry has the code points of the names for every version in",
+        "// `UNICODE_VERSIONS`: the last string has the ones for the latest version, and",
+        "// each of the others those whose membership differs from the next version.",
+        "// Each string is a list of inclusive ranges encoded the same way as in",
+        "// `ids.rs`; names with identical code points in every version share an entry",
+        "",
+        "pub(super) static UNICODE_VERSIONS: [&str; %d] = [" % len(versions),
+        "    %s," % ", ".join('"%s"' % version for version in versions),
+        "];",
+        "",
+    ]
+    write_table(out, "GENERAL_CATEGORY_RANGES", general_category_entries)
+    write_table(out, "SCRIPT_RANGES", script_entries)
+    write_table(out, "SCRIPT_EXTENSIONS_RANGES", script_extensions_entries)
+    write_table(out, "BINARY_PROPERTY_RANGES", binary_entries)
+
+    out += [
+        "// The multi-code-point sequences of each property of strings, for the last",
+        "// %d versions in `UNICODE_VERSIONS`: first those in the earliest, then those"
+        % len(sequence_versions),
+        "// added in each later one. Each sequence is its code points in base 36,",
+        "// separated by dots. Basic_Emoji's single code points aren't listed, as",
+        "// they're Emoji_Presentation minus Regional_Indicator",
+        "pub(super) static STRING_PROPERTY_SEQUENCES: &[(&str, [&str; %d])] = &["
+        % len(sequence_versions),
+    ]
+    for name in STRING_PROPERTIES:
+        out.append("    (")
+        out.append('        "%s",' % name)
+        out.append("        [")
+        seen = set()
+        for _, sequences in sequence_versions:
+            current = sequences.get(name, set())
+            assert seen <= current, "%s sequences were removed" % name
+            out.append(
+                '            "%s",'
+                % " ".join(
+                    ".".join(radix36(cp) for cp in sequence)
+                    for sequence in sorted(current - seen)
+                )
+            )
+            seen = current
+        out.append("        ],")
+        out.append("    ),")
+    out.append("];")
+
+    with open(OUTPUT, "w", encoding="utf-8") as f:
+        f.write("\n".join(out) + "\n")
+
+    simple, legacy = read_case_foldings(os.path.join(DATA, versions[-1]))
+    out = [
+        "// Generated by scripts/generate_unicode_data.py from the Unicode %s"
+        % versions[-1],
+        "// CaseFolding.txt, UnicodeData.txt and SpecialCasing.txt in data/unicode;",
+        "// don't edit by hand",
+        "//",
+        "// Each is a list of the groups of code points that a `Canonicalize` maps to",
+        "// the same code point, which is the first in each group, encoded as",
+        "// `restore_classes` in `case_folding.rs` expects",
+        "",
+        "/// Simple case folding, statuses C and S of CaseFolding.txt",
+        'pub(super) static SIMPLE_CASE_FOLDING_CLASS_DATA: &str = "%s";'
+        % encode_classes(case_classes(simple)),
+        "",
+        "/// Upper-casing, for the code units it maps to another single code unit on",
+        "/// the same side of ASCII",
+        'pub(super) static LEGACY_CASE_CLASS_DATA: &str = "%s";'
+        % encode_classes(case_classes(legacy)),
+    ]
+    with open(CASE_FOLDING_OUTPUT, "w", encoding="utf-8") as f:
+        f.write("\n".join(out) + "\n")
+
+
+if __name__ == "__main__":
+    main()
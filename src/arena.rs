@@ -1,8 +1,11 @@
+use std::iter;
+
 use debug_cell::{Ref, RefCell, RefMut};
 
 use id_arena::{Arena, Id};
+use serde::de;
 
-use crate::ast::{Node, NodeInterface};
+use crate::ast::{load_node_unresolved, Node, NodeInterface, NodeUnresolved};
 
 #[derive(Default)]
 pub struct AllArenas {
@@ -16,11 +19,171 @@ impl AllArenas {
         id
     }
 
-    pub fn node(&self, node: Id<Node>) -> Ref<Node> {
+    /// Accepts an `Id<Node>` or any of the typed handles in
+    /// [`node_ids`](crate::node_ids)
+    pub fn node(&self, node: impl Into<Id<Node>>) -> Ref<Node> {
+        let node = node.into();
         Ref::map(self.nodes.borrow(), |nodes| &nodes[node])
     }
 
-    pub fn node_mut(&self, node: Id<Node>) -> RefMut<Node> {
+    pub fn node_mut(&self, node: impl Into<Id<Node>>) -> RefMut<Node> {
+        let node = node.into();
         RefMut::map(self.nodes.borrow_mut(), |nodes| &mut nodes[node])
     }
+
+    /// The node's parent, its parent's parent and so on up to the root
+    pub fn ancestors(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        ancestors(self, node.into())
+    }
+
+    /// Everything below the node (not including the node itself), in
+    /// pre-order
+    pub fn descendants(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        self.preorder(node).skip(1)
+    }
+
+    /// Walks the tree below (and including) the node, yielding an
+    /// [`Event::Enter`] for each node before any of its children and an
+    /// [`Event::Leave`] after all of them, the same order in which a
+    /// [`RegExpVisitor`](crate::visitor::RegExpVisitor) calls its handlers
+    pub fn traverse(&self, node: impl Into<Id<Node>>) -> Traversal<'_> {
+        Traversal::new(self, node.into())
+    }
+
+    /// The node and everything below it, each before its children: the nodes
+    /// of [`traverse`](Self::traverse)'s [`Event::Enter`]s
+    pub fn preorder(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        preorder(self, node.into())
+    }
+
+    /// The node and everything below it, each after its children: the nodes
+    /// of [`traverse`](Self::traverse)'s [`Event::Leave`]s
+    pub fn postorder(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        self.traverse(node)
+            .filter_map(|(node, event)| (event == Event::Leave).then_some(node))
+    }
+
+    /// Gives up mutability, e.g. once parsing is done, so that the trees
+    /// can be read from several threads at once
+    pub fn freeze(self) -> FrozenArena {
+        FrozenArena {
+            nodes: self.nodes.into_inner(),
+        }
+    }
+
+    /// Loads a tree from the JSON produced by
+    /// [`SerializableNode`](crate::SerializableNode) (or by regexpp),
+    /// returning the id of its root node
+    pub fn load_json(&self, json: &str) -> serde_json::Result<Id<Node>> {
+        let node: NodeUnresolved = serde_json::from_str(json)?;
+        load_node_unresolved(self, node).map_err(de::Error::custom)
+    }
+}
+
+/// A read-only [`AllArenas`], which (unlike `AllArenas`) is `Send` and
+/// `Sync`. Trees parsed in different arenas can be combined by thawing them
+/// and folding each into one arena with
+/// [`fold_reg_exp_ast`](crate::fold_reg_exp_ast)
+pub struct FrozenArena {
+    nodes: Arena<Node>,
+}
+
+impl FrozenArena {
+    pub fn node(&self, node: impl Into<Id<Node>>) -> &Node {
+        &self.nodes[node.into()]
+    }
+
+    /// The node's parent, its parent's parent and so on up to the root
+    pub fn ancestors(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        ancestors(self, node.into())
+    }
+
+    /// The node and everything below it, each before its children
+    pub fn preorder(&self, node: impl Into<Id<Node>>) -> impl Iterator<Item = Id<Node>> + '_ {
+        preorder(self, node.into())
+    }
+
+    /// Makes the arena mutable again, keeping all of the ids into it valid
+    pub fn thaw(self) -> AllArenas {
+        AllArenas {
+            nodes: RefCell::new(self.nodes),
+        }
+    }
+}
+
+/// The tree links that traversals follow, so that [`AllArenas`] and
+/// [`FrozenArena`] can share them
+pub trait Tree {
+    fn parent_of(&self, node: Id<Node>) -> Option<Id<Node>>;
+    fn children_of(&self, node: Id<Node>) -> Vec<Id<Node>>;
+}
+
+impl Tree for AllArenas {
+    fn parent_of(&self, node: Id<Node>) -> Option<Id<Node>> {
+        self.node(node).maybe_parent()
+    }
+
+    fn children_of(&self, node: Id<Node>) -> Vec<Id<Node>> {
+        self.node(node).children().collect()
+    }
+}
+
+impl Tree for FrozenArena {
+    fn parent_of(&self, node: Id<Node>) -> Option<Id<Node>> {
+        self.node(node).maybe_parent()
+    }
+
+    fn children_of(&self, node: Id<Node>) -> Vec<Id<Node>> {
+        self.node(node).children().collect()
+    }
+}
+
+fn ancestors(tree: &impl Tree, node: Id<Node>) -> impl Iterator<Item = Id<Node>> + '_ {
+    iter::successors(tree.parent_of(node), |&node| tree.parent_of(node))
+}
+
+fn preorder<T: Tree>(tree: &T, node: Id<Node>) -> impl Iterator<Item = Id<Node>> + '_ {
+    Traversal::new(tree, node).filter_map(|(node, event)| (event == Event::Enter).then_some(node))
+}
+
+/// Whether a [`Traversal`] is arriving at a node or done with it
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Event {
+    Enter,
+    Leave,
+}
+
+/// Returned by [`AllArenas::traverse`]. Each node's children are looked up
+/// as it's entered, so the arena isn't borrowed between calls to `next()`
+pub struct Traversal<'a, T = AllArenas> {
+    _arena: &'a T,
+    _pending: Vec<(Id<Node>, Event)>,
+}
+
+impl<'a, T: Tree> Traversal<'a, T> {
+    fn new(arena: &'a T, node: Id<Node>) -> Self {
+        Self {
+            _arena: arena,
+            _pending: vec![(node, Event::Enter)],
+        }
+    }
+}
+
+impl<'a, T: Tree> Iterator for Traversal<'a, T> {
+    type Item = (Id<Node>, Event);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, event) = self._pending.pop()?;
+        if event == Event::Enter {
+            self._pending.push((node, Event::Leave));
+            let children = self._arena.children_of(node);
+            self._pending.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, Event::Enter)),
+            );
+        }
+        Some((node, event))
+    }
 }
@@ -1,18 +1,18 @@
-#[cfg(test)]
-use std::collections::HashMap;
+use std::{collections::HashMap, iter};
 
 use id_arena::Id;
-#[cfg(test)]
 use pathdiff::diff_paths;
-#[cfg(test)]
-use serde::{Deserialize, Deserializer};
-
-#[cfg(test)]
-use crate::AllArenas;
+use serde::{de, Deserialize, Deserializer};
 
 use crate::{
+    node_ids::{
+        new_unchecked, AlternativeId, BackreferenceId, CapturingGroupId, CharacterClassElementId,
+        CharacterId, ClassIntersectionLeftId, ClassSetExpressionId, ClassSetOperandId,
+        ClassSubtractionLeftId, ElementId, FlagsId, ModifierFlagsId, ModifiersId, NodeId,
+        PatternId, QuantifiableElementId, StringAlternativeId,
+    },
     validator::{AssertionKind, CapturingGroupKey, CharacterKind},
-    CodePoint, Wtf16,
+    AllArenas, CodePoint, RegExpSyntaxErrorKind, Wtf16,
 };
 
 #[derive(Clone)]
@@ -26,6 +26,8 @@ pub enum Node {
     ClassSubtraction(ClassSubtraction),
     ExpressionCharacterClass(ExpressionCharacterClass),
     Group(Group),
+    Modifiers(Modifiers),
+    ModifierFlags(ModifierFlags),
     Assertion(Assertion),
     Pattern(Pattern),
     Quantifier(Quantifier),
@@ -35,9 +37,187 @@ pub enum Node {
     Character(Character),
     CharacterSet(CharacterSet),
     Flags(Flags),
+    Error(Error),
+}
+
+/// Which variant of [`Node`] a node is, without its contents
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Alternative,
+    CapturingGroup,
+    CharacterClass,
+    CharacterClassRange,
+    ClassIntersection,
+    ClassStringDisjunction,
+    ClassSubtraction,
+    ExpressionCharacterClass,
+    Group,
+    Modifiers,
+    ModifierFlags,
+    Assertion,
+    Pattern,
+    Quantifier,
+    RegExpLiteral,
+    StringAlternative,
+    Backreference,
+    Character,
+    CharacterSet,
+    Flags,
+    Error,
+}
+
+impl NodeKind {
+    /// The `type` that regexpp gives nodes of this kind
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Alternative => "Alternative",
+            Self::CapturingGroup => "CapturingGroup",
+            Self::CharacterClass => "CharacterClass",
+            Self::CharacterClassRange => "CharacterClassRange",
+            Self::ClassIntersection => "ClassIntersection",
+            Self::ClassStringDisjunction => "ClassStringDisjunction",
+            Self::ClassSubtraction => "ClassSubtraction",
+            Self::ExpressionCharacterClass => "ExpressionCharacterClass",
+            Self::Group => "Group",
+            Self::Modifiers => "Modifiers",
+            Self::ModifierFlags => "ModifierFlags",
+            Self::Assertion => "Assertion",
+            Self::Pattern => "Pattern",
+            Self::Quantifier => "Quantifier",
+            Self::RegExpLiteral => "RegExpLiteral",
+            Self::StringAlternative => "StringAlternative",
+            Self::Backreference => "Backreference",
+            Self::Character => "Character",
+            Self::CharacterSet => "CharacterSet",
+            Self::Flags => "Flags",
+            Self::Error => "Error",
+        }
+    }
+}
+
+/// The field of its parent that a node hangs off, with its index for the
+/// fields that hold a list
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChildField {
+    Pattern,
+    Flags,
+    Alternatives(usize),
+    Elements(usize),
+    Modifiers,
+    Add,
+    Remove,
+    Element,
+    Min,
+    Max,
+    Left,
+    Right,
+    Expression,
 }
 
 impl Node {
+    pub fn kind(&self) -> NodeKind {
+        match self {
+            Node::Alternative(_) => NodeKind::Alternative,
+            Node::CapturingGroup(_) => NodeKind::CapturingGroup,
+            Node::CharacterClass(_) => NodeKind::CharacterClass,
+            Node::CharacterClassRange(_) => NodeKind::CharacterClassRange,
+            Node::ClassIntersection(_) => NodeKind::ClassIntersection,
+            Node::ClassStringDisjunction(_) => NodeKind::ClassStringDisjunction,
+            Node::ClassSubtraction(_) => NodeKind::ClassSubtraction,
+            Node::ExpressionCharacterClass(_) => NodeKind::ExpressionCharacterClass,
+            Node::Group(_) => NodeKind::Group,
+            Node::Modifiers(_) => NodeKind::Modifiers,
+            Node::ModifierFlags(_) => NodeKind::ModifierFlags,
+            Node::Assertion(_) => NodeKind::Assertion,
+            Node::Pattern(_) => NodeKind::Pattern,
+            Node::Quantifier(_) => NodeKind::Quantifier,
+            Node::RegExpLiteral(_) => NodeKind::RegExpLiteral,
+            Node::StringAlternative(_) => NodeKind::StringAlternative,
+            Node::Backreference(_) => NodeKind::Backreference,
+            Node::Character(_) => NodeKind::Character,
+            Node::CharacterSet(_) => NodeKind::CharacterSet,
+            Node::Flags(_) => NodeKind::Flags,
+            Node::Error(_) => NodeKind::Error,
+        }
+    }
+
+    /// The node's children in source order. A backreference's `resolved`
+    /// groups are links elsewhere in the tree rather than children, so
+    /// they aren't included
+    ///
+    /// The ids are collected up front, so the iterator doesn't hold a
+    /// borrow of the node
+    pub fn children(&self) -> impl Iterator<Item = Id<Node>> {
+        self.children_with_fields().map(|(_, child)| child)
+    }
+
+    /// Like [`children`](Self::children), but also says which field each
+    /// child is in
+    pub fn children_with_fields(&self) -> impl Iterator<Item = (ChildField, Id<Node>)> {
+        fn indexed(
+            field: fn(usize) -> ChildField,
+            nodes: &[impl Into<Id<Node>> + Copy],
+        ) -> Vec<(ChildField, Id<Node>)> {
+            nodes
+                .iter()
+                .enumerate()
+                .map(|(index, &node)| (field(index), node.into()))
+                .collect()
+        }
+
+        match self {
+            Node::RegExpLiteral(node) => vec![
+                (ChildField::Pattern, node.pattern.id()),
+                (ChildField::Flags, node.flags.id()),
+            ],
+            Node::Pattern(node) => indexed(ChildField::Alternatives, &node.alternatives),
+            Node::Alternative(node) => indexed(ChildField::Elements, &node.elements),
+            Node::Group(node) => node
+                .modifiers
+                .map(|modifiers| (ChildField::Modifiers, modifiers.id()))
+                .into_iter()
+                .chain(indexed(ChildField::Alternatives, &node.alternatives))
+                .collect(),
+            Node::CapturingGroup(node) => indexed(ChildField::Alternatives, &node.alternatives),
+            Node::Modifiers(node) => iter::once((ChildField::Add, node.add.id()))
+                .chain(node.remove.map(|remove| (ChildField::Remove, remove.id())))
+                .collect(),
+            Node::Assertion(node) => node
+                .alternatives
+                .as_deref()
+                .map(|alternatives| indexed(ChildField::Alternatives, alternatives))
+                .unwrap_or_default(),
+            Node::Quantifier(node) => vec![(ChildField::Element, node.element.id())],
+            Node::CharacterClass(node) => indexed(ChildField::Elements, &node.elements),
+            Node::CharacterClassRange(node) => vec![
+                (ChildField::Min, node.min.id()),
+                (ChildField::Max, node.max.id()),
+            ],
+            Node::ClassIntersection(node) => vec![
+                (ChildField::Left, node.left.id()),
+                (ChildField::Right, node.right.id()),
+            ],
+            Node::ClassSubtraction(node) => vec![
+                (ChildField::Left, node.left.id()),
+                (ChildField::Right, node.right.id()),
+            ],
+            Node::ExpressionCharacterClass(node) => {
+                vec![(ChildField::Expression, node.expression.id())]
+            }
+            Node::ClassStringDisjunction(node) => {
+                indexed(ChildField::Alternatives, &node.alternatives)
+            }
+            Node::StringAlternative(node) => indexed(ChildField::Elements, &node.elements),
+            Node::ModifierFlags(_)
+            | Node::Backreference(_)
+            | Node::Character(_)
+            | Node::CharacterSet(_)
+            | Node::Flags(_)
+            | Node::Error(_) => Default::default(),
+        }
+        .into_iter()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn new_flags(
         parent: Option<Id<Node>>,
@@ -60,6 +240,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             dot_all,
             global,
@@ -77,7 +258,7 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        alternatives: Vec<Id<Node>>,
+        alternatives: Vec<AlternativeId>,
     ) -> Self {
         Self::Pattern(Pattern {
             _base: NodeBase {
@@ -86,6 +267,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             alternatives,
         })
@@ -96,7 +278,7 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        elements: Vec<Id<Node>>,
+        elements: Vec<ElementId>,
     ) -> Self {
         Self::Alternative(Alternative {
             _base: NodeBase {
@@ -105,6 +287,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             elements,
         })
@@ -115,7 +298,8 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        alternatives: Vec<Id<Node>>,
+        modifiers: Option<ModifiersId>,
+        alternatives: Vec<AlternativeId>,
     ) -> Self {
         Self::Group(Group {
             _base: NodeBase {
@@ -124,19 +308,68 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
+            modifiers,
             alternatives,
         })
     }
 
+    pub fn new_modifiers(
+        parent: Option<Id<Node>>,
+        start: usize,
+        end: usize,
+        raw: Wtf16,
+        add: ModifierFlagsId,
+        remove: Option<ModifierFlagsId>,
+    ) -> Self {
+        Self::Modifiers(Modifiers {
+            _base: NodeBase {
+                _arena_id: Default::default(),
+                parent,
+                start,
+                end,
+                raw,
+                synthesized: false,
+            },
+            add,
+            remove,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_modifier_flags(
+        parent: Option<Id<Node>>,
+        start: usize,
+        end: usize,
+        raw: Wtf16,
+        dot_all: bool,
+        ignore_case: bool,
+        multiline: bool,
+    ) -> Self {
+        Self::ModifierFlags(ModifierFlags {
+            _base: NodeBase {
+                _arena_id: Default::default(),
+                parent,
+                start,
+                end,
+                raw,
+                synthesized: false,
+            },
+            dot_all,
+            ignore_case,
+            multiline,
+        })
+    }
+
     pub fn new_capturing_group(
         parent: Option<Id<Node>>,
         start: usize,
         end: usize,
         raw: Wtf16,
         name: Option<Wtf16>,
-        alternatives: Vec<Id<Node>>,
-        references: Vec<Id<Node>>,
+        alternatives: Vec<AlternativeId>,
+        references: Vec<BackreferenceId>,
     ) -> Self {
         Self::CapturingGroup(CapturingGroup {
             _base: NodeBase {
@@ -145,6 +378,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             name,
             alternatives,
@@ -161,7 +395,7 @@ impl Node {
         min: u32,
         max: u32,
         greedy: bool,
-        element: Id<Node>,
+        element: QuantifiableElementId,
     ) -> Self {
         Self::Quantifier(Quantifier {
             _base: NodeBase {
@@ -170,6 +404,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             min,
             max,
@@ -185,7 +420,7 @@ impl Node {
         raw: Wtf16,
         kind: AssertionKind,
         negate: Option<bool>,
-        alternatives: Option<Vec<Id<Node>>>,
+        alternatives: Option<Vec<AlternativeId>>,
     ) -> Self {
         Self::Assertion(Assertion {
             _base: NodeBase {
@@ -194,6 +429,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             kind,
             negate,
@@ -220,6 +456,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             kind,
             strings,
@@ -243,18 +480,41 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             value,
         })
     }
 
+    pub fn new_error(
+        parent: Option<Id<Node>>,
+        start: usize,
+        end: usize,
+        raw: Wtf16,
+        kind: RegExpSyntaxErrorKind,
+        message: String,
+    ) -> Self {
+        Self::Error(Error {
+            _base: NodeBase {
+                _arena_id: Default::default(),
+                parent,
+                start,
+                end,
+                raw,
+                synthesized: false,
+            },
+            kind,
+            message,
+        })
+    }
+
     pub fn new_backreference(
         parent: Option<Id<Node>>,
         start: usize,
         end: usize,
         raw: Wtf16,
         ref_: CapturingGroupKey,
-        resolved: Option<Id<Node>>,
+        resolved: Vec<CapturingGroupId>,
     ) -> Self {
         Self::Backreference(Backreference {
             _base: NodeBase {
@@ -263,6 +523,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             ref_,
             resolved,
@@ -276,7 +537,7 @@ impl Node {
         raw: Wtf16,
         unicode_sets: bool,
         negate: bool,
-        elements: Vec<Id<Node> /*CharacterClassElement*/>,
+        elements: Vec<CharacterClassElementId>,
     ) -> Self {
         Self::CharacterClass(CharacterClass {
             _base: NodeBase {
@@ -285,6 +546,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             unicode_sets,
             negate,
@@ -298,7 +560,7 @@ impl Node {
         end: usize,
         raw: Wtf16,
         negate: bool,
-        expression: Id<Node>,
+        expression: ClassSetExpressionId,
     ) -> Self {
         Self::ExpressionCharacterClass(ExpressionCharacterClass {
             _base: NodeBase {
@@ -307,6 +569,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             negate,
             expression,
@@ -318,8 +581,8 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        min: Id<Node>,
-        max: Id<Node>,
+        min: CharacterId,
+        max: CharacterId,
     ) -> Self {
         Self::CharacterClassRange(CharacterClassRange {
             _base: NodeBase {
@@ -328,6 +591,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             min,
             max,
@@ -339,8 +603,8 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        left: Id<Node>,
-        right: Id<Node>,
+        left: ClassIntersectionLeftId,
+        right: ClassSetOperandId,
     ) -> Self {
         Self::ClassIntersection(ClassIntersection {
             _base: NodeBase {
@@ -349,6 +613,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             left,
             right,
@@ -360,8 +625,8 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        left: Id<Node>,
-        right: Id<Node>,
+        left: ClassSubtractionLeftId,
+        right: ClassSetOperandId,
     ) -> Self {
         Self::ClassSubtraction(ClassSubtraction {
             _base: NodeBase {
@@ -370,6 +635,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             left,
             right,
@@ -381,7 +647,7 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        alternatives: Vec<Id<Node>>,
+        alternatives: Vec<StringAlternativeId>,
     ) -> Self {
         Self::ClassStringDisjunction(ClassStringDisjunction {
             _base: NodeBase {
@@ -390,6 +656,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             alternatives,
         })
@@ -400,7 +667,7 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        elements: Vec<Id<Node>>,
+        elements: Vec<CharacterId>,
     ) -> Self {
         Self::StringAlternative(StringAlternative {
             _base: NodeBase {
@@ -409,6 +676,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             elements,
         })
@@ -419,8 +687,8 @@ impl Node {
         start: usize,
         end: usize,
         raw: Wtf16,
-        pattern: Id<Node>,
-        flags: Id<Node>,
+        pattern: PatternId,
+        flags: FlagsId,
     ) -> Self {
         Self::RegExpLiteral(RegExpLiteral {
             _base: NodeBase {
@@ -429,6 +697,7 @@ impl Node {
                 start,
                 end,
                 raw,
+                synthesized: false,
             },
             pattern,
             flags,
@@ -477,6 +746,20 @@ impl Node {
         }
     }
 
+    pub fn as_character(&self) -> &Character {
+        match self {
+            Self::Character(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_character_mut(&mut self) -> &mut Character {
+        match self {
+            Self::Character(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
     pub fn as_character_class(&self) -> &CharacterClass {
         match self {
             Self::CharacterClass(value) => value,
@@ -547,6 +830,41 @@ impl Node {
         }
     }
 
+    pub fn as_group_mut(&mut self) -> &mut Group {
+        match self {
+            Self::Group(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_modifiers(&self) -> &Modifiers {
+        match self {
+            Self::Modifiers(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_modifiers_mut(&mut self) -> &mut Modifiers {
+        match self {
+            Self::Modifiers(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_modifier_flags(&self) -> &ModifierFlags {
+        match self {
+            Self::ModifierFlags(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_modifier_flags_mut(&mut self) -> &mut ModifierFlags {
+        match self {
+            Self::ModifierFlags(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
     pub fn as_pattern(&self) -> &Pattern {
         match self {
             Self::Pattern(value) => value,
@@ -554,6 +872,13 @@ impl Node {
         }
     }
 
+    pub fn as_pattern_mut(&mut self) -> &mut Pattern {
+        match self {
+            Self::Pattern(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
     pub fn as_quantifier(&self) -> &Quantifier {
         match self {
             Self::Quantifier(value) => value,
@@ -574,9 +899,29 @@ impl Node {
             _ => unreachable!(),
         }
     }
+
+    pub fn as_string_alternative_mut(&mut self) -> &mut StringAlternative {
+        match self {
+            Self::StringAlternative(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_flags(&self) -> &Flags {
+        match self {
+            Self::Flags(value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_flags_mut(&mut self) -> &mut Flags {
+        match self {
+            Self::Flags(value) => value,
+            _ => unreachable!(),
+        }
+    }
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum NodeUnresolved {
@@ -589,6 +934,8 @@ pub enum NodeUnresolved {
     ClassSubtraction(Box<ClassSubtractionUnresolved>),
     ExpressionCharacterClass(Box<ExpressionCharacterClassUnresolved>),
     Group(Box<GroupUnresolved>),
+    Modifiers(Box<ModifiersUnresolved>),
+    ModifierFlags(Box<ModifierFlagsUnresolved>),
     Assertion(Box<AssertionUnresolved>),
     Pattern(Box<PatternUnresolved>),
     Quantifier(Box<QuantifierUnresolved>),
@@ -598,6 +945,7 @@ pub enum NodeUnresolved {
     Character(Box<CharacterUnresolved>),
     CharacterSet(Box<CharacterSetUnresolved>),
     Flags(Box<FlagsUnresolved>),
+    Error(Box<ErrorUnresolved>),
 }
 
 pub trait NodeInterface {
@@ -606,10 +954,15 @@ pub trait NodeInterface {
     fn set_parent(&mut self, parent: Option<Id<Node>>);
     fn parent(&self) -> Id<Node>;
     fn start(&self) -> usize;
+    fn set_start(&mut self, start: usize);
     fn end(&self) -> usize;
     fn set_end(&mut self, end: usize);
     fn raw(&self) -> &[u16];
     fn set_raw(&mut self, raw: Wtf16);
+    /// Whether `start`/`end`/`raw` were made up (by an edit or a builder)
+    /// rather than coming from parsed source
+    fn is_synthesized(&self) -> bool;
+    fn set_synthesized(&mut self, synthesized: bool);
 }
 
 impl NodeInterface for Node {
@@ -624,6 +977,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.set_arena_id(id),
             Node::ExpressionCharacterClass(node) => node._base.set_arena_id(id),
             Node::Group(node) => node._base.set_arena_id(id),
+            Node::Modifiers(node) => node._base.set_arena_id(id),
+            Node::ModifierFlags(node) => node._base.set_arena_id(id),
             Node::Assertion(node) => node._base.set_arena_id(id),
             Node::Pattern(node) => node._base.set_arena_id(id),
             Node::Quantifier(node) => node._base.set_arena_id(id),
@@ -633,6 +988,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.set_arena_id(id),
             Node::CharacterSet(node) => node._base.set_arena_id(id),
             Node::Flags(node) => node._base.set_arena_id(id),
+            Node::Error(node) => node._base.set_arena_id(id),
         }
     }
 
@@ -647,6 +1003,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.maybe_parent(),
             Node::ExpressionCharacterClass(node) => node._base.maybe_parent(),
             Node::Group(node) => node._base.maybe_parent(),
+            Node::Modifiers(node) => node._base.maybe_parent(),
+            Node::ModifierFlags(node) => node._base.maybe_parent(),
             Node::Assertion(node) => node._base.maybe_parent(),
             Node::Pattern(node) => node._base.maybe_parent(),
             Node::Quantifier(node) => node._base.maybe_parent(),
@@ -656,6 +1014,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.maybe_parent(),
             Node::CharacterSet(node) => node._base.maybe_parent(),
             Node::Flags(node) => node._base.maybe_parent(),
+            Node::Error(node) => node._base.maybe_parent(),
         }
     }
 
@@ -670,6 +1029,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.set_parent(parent),
             Node::ExpressionCharacterClass(node) => node._base.set_parent(parent),
             Node::Group(node) => node._base.set_parent(parent),
+            Node::Modifiers(node) => node._base.set_parent(parent),
+            Node::ModifierFlags(node) => node._base.set_parent(parent),
             Node::Assertion(node) => node._base.set_parent(parent),
             Node::Pattern(node) => node._base.set_parent(parent),
             Node::Quantifier(node) => node._base.set_parent(parent),
@@ -679,6 +1040,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.set_parent(parent),
             Node::CharacterSet(node) => node._base.set_parent(parent),
             Node::Flags(node) => node._base.set_parent(parent),
+            Node::Error(node) => node._base.set_parent(parent),
         }
     }
 
@@ -693,6 +1055,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.parent(),
             Node::ExpressionCharacterClass(node) => node._base.parent(),
             Node::Group(node) => node._base.parent(),
+            Node::Modifiers(node) => node._base.parent(),
+            Node::ModifierFlags(node) => node._base.parent(),
             Node::Assertion(node) => node._base.parent(),
             Node::Pattern(node) => node._base.parent(),
             Node::Quantifier(node) => node._base.parent(),
@@ -702,6 +1066,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.parent(),
             Node::CharacterSet(node) => node._base.parent(),
             Node::Flags(node) => node._base.parent(),
+            Node::Error(node) => node._base.parent(),
         }
     }
 
@@ -716,6 +1081,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.start(),
             Node::ExpressionCharacterClass(node) => node._base.start(),
             Node::Group(node) => node._base.start(),
+            Node::Modifiers(node) => node._base.start(),
+            Node::ModifierFlags(node) => node._base.start(),
             Node::Assertion(node) => node._base.start(),
             Node::Pattern(node) => node._base.start(),
             Node::Quantifier(node) => node._base.start(),
@@ -725,6 +1092,33 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.start(),
             Node::CharacterSet(node) => node._base.start(),
             Node::Flags(node) => node._base.start(),
+            Node::Error(node) => node._base.start(),
+        }
+    }
+
+    fn set_start(&mut self, start: usize) {
+        match self {
+            Node::Alternative(node) => node._base.set_start(start),
+            Node::CapturingGroup(node) => node._base.set_start(start),
+            Node::CharacterClass(node) => node._base.set_start(start),
+            Node::CharacterClassRange(node) => node._base.set_start(start),
+            Node::ClassIntersection(node) => node._base.set_start(start),
+            Node::ClassStringDisjunction(node) => node._base.set_start(start),
+            Node::ClassSubtraction(node) => node._base.set_start(start),
+            Node::ExpressionCharacterClass(node) => node._base.set_start(start),
+            Node::Group(node) => node._base.set_start(start),
+            Node::Modifiers(node) => node._base.set_start(start),
+            Node::ModifierFlags(node) => node._base.set_start(start),
+            Node::Assertion(node) => node._base.set_start(start),
+            Node::Pattern(node) => node._base.set_start(start),
+            Node::Quantifier(node) => node._base.set_start(start),
+            Node::RegExpLiteral(node) => node._base.set_start(start),
+            Node::StringAlternative(node) => node._base.set_start(start),
+            Node::Backreference(node) => node._base.set_start(start),
+            Node::Character(node) => node._base.set_start(start),
+            Node::CharacterSet(node) => node._base.set_start(start),
+            Node::Flags(node) => node._base.set_start(start),
+            Node::Error(node) => node._base.set_start(start),
         }
     }
 
@@ -739,6 +1133,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.end(),
             Node::ExpressionCharacterClass(node) => node._base.end(),
             Node::Group(node) => node._base.end(),
+            Node::Modifiers(node) => node._base.end(),
+            Node::ModifierFlags(node) => node._base.end(),
             Node::Assertion(node) => node._base.end(),
             Node::Pattern(node) => node._base.end(),
             Node::Quantifier(node) => node._base.end(),
@@ -748,6 +1144,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.end(),
             Node::CharacterSet(node) => node._base.end(),
             Node::Flags(node) => node._base.end(),
+            Node::Error(node) => node._base.end(),
         }
     }
 
@@ -762,6 +1159,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.set_end(end),
             Node::ExpressionCharacterClass(node) => node._base.set_end(end),
             Node::Group(node) => node._base.set_end(end),
+            Node::Modifiers(node) => node._base.set_end(end),
+            Node::ModifierFlags(node) => node._base.set_end(end),
             Node::Assertion(node) => node._base.set_end(end),
             Node::Pattern(node) => node._base.set_end(end),
             Node::Quantifier(node) => node._base.set_end(end),
@@ -771,6 +1170,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.set_end(end),
             Node::CharacterSet(node) => node._base.set_end(end),
             Node::Flags(node) => node._base.set_end(end),
+            Node::Error(node) => node._base.set_end(end),
         }
     }
 
@@ -785,6 +1185,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.raw(),
             Node::ExpressionCharacterClass(node) => node._base.raw(),
             Node::Group(node) => node._base.raw(),
+            Node::Modifiers(node) => node._base.raw(),
+            Node::ModifierFlags(node) => node._base.raw(),
             Node::Assertion(node) => node._base.raw(),
             Node::Pattern(node) => node._base.raw(),
             Node::Quantifier(node) => node._base.raw(),
@@ -794,6 +1196,7 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.raw(),
             Node::CharacterSet(node) => node._base.raw(),
             Node::Flags(node) => node._base.raw(),
+            Node::Error(node) => node._base.raw(),
         }
     }
 
@@ -808,6 +1211,8 @@ impl NodeInterface for Node {
             Node::ClassSubtraction(node) => node._base.set_raw(raw),
             Node::ExpressionCharacterClass(node) => node._base.set_raw(raw),
             Node::Group(node) => node._base.set_raw(raw),
+            Node::Modifiers(node) => node._base.set_raw(raw),
+            Node::ModifierFlags(node) => node._base.set_raw(raw),
             Node::Assertion(node) => node._base.set_raw(raw),
             Node::Pattern(node) => node._base.set_raw(raw),
             Node::Quantifier(node) => node._base.set_raw(raw),
@@ -817,14 +1222,66 @@ impl NodeInterface for Node {
             Node::Character(node) => node._base.set_raw(raw),
             Node::CharacterSet(node) => node._base.set_raw(raw),
             Node::Flags(node) => node._base.set_raw(raw),
+            Node::Error(node) => node._base.set_raw(raw),
+        }
+    }
+
+    fn is_synthesized(&self) -> bool {
+        match self {
+            Node::Alternative(node) => node._base.is_synthesized(),
+            Node::CapturingGroup(node) => node._base.is_synthesized(),
+            Node::CharacterClass(node) => node._base.is_synthesized(),
+            Node::CharacterClassRange(node) => node._base.is_synthesized(),
+            Node::ClassIntersection(node) => node._base.is_synthesized(),
+            Node::ClassStringDisjunction(node) => node._base.is_synthesized(),
+            Node::ClassSubtraction(node) => node._base.is_synthesized(),
+            Node::ExpressionCharacterClass(node) => node._base.is_synthesized(),
+            Node::Group(node) => node._base.is_synthesized(),
+            Node::Modifiers(node) => node._base.is_synthesized(),
+            Node::ModifierFlags(node) => node._base.is_synthesized(),
+            Node::Assertion(node) => node._base.is_synthesized(),
+            Node::Pattern(node) => node._base.is_synthesized(),
+            Node::Quantifier(node) => node._base.is_synthesized(),
+            Node::RegExpLiteral(node) => node._base.is_synthesized(),
+            Node::StringAlternative(node) => node._base.is_synthesized(),
+            Node::Backreference(node) => node._base.is_synthesized(),
+            Node::Character(node) => node._base.is_synthesized(),
+            Node::CharacterSet(node) => node._base.is_synthesized(),
+            Node::Flags(node) => node._base.is_synthesized(),
+            Node::Error(node) => node._base.is_synthesized(),
+        }
+    }
+
+    fn set_synthesized(&mut self, synthesized: bool) {
+        match self {
+            Node::Alternative(node) => node._base.set_synthesized(synthesized),
+            Node::CapturingGroup(node) => node._base.set_synthesized(synthesized),
+            Node::CharacterClass(node) => node._base.set_synthesized(synthesized),
+            Node::CharacterClassRange(node) => node._base.set_synthesized(synthesized),
+            Node::ClassIntersection(node) => node._base.set_synthesized(synthesized),
+            Node::ClassStringDisjunction(node) => node._base.set_synthesized(synthesized),
+            Node::ClassSubtraction(node) => node._base.set_synthesized(synthesized),
+            Node::ExpressionCharacterClass(node) => node._base.set_synthesized(synthesized),
+            Node::Group(node) => node._base.set_synthesized(synthesized),
+            Node::Modifiers(node) => node._base.set_synthesized(synthesized),
+            Node::ModifierFlags(node) => node._base.set_synthesized(synthesized),
+            Node::Assertion(node) => node._base.set_synthesized(synthesized),
+            Node::Pattern(node) => node._base.set_synthesized(synthesized),
+            Node::Quantifier(node) => node._base.set_synthesized(synthesized),
+            Node::RegExpLiteral(node) => node._base.set_synthesized(synthesized),
+            Node::StringAlternative(node) => node._base.set_synthesized(synthesized),
+            Node::Backreference(node) => node._base.set_synthesized(synthesized),
+            Node::Character(node) => node._base.set_synthesized(synthesized),
+            Node::CharacterSet(node) => node._base.set_synthesized(synthesized),
+            Node::Flags(node) => node._base.set_synthesized(synthesized),
+            Node::Error(node) => node._base.set_synthesized(synthesized),
         }
     }
 }
 
-#[cfg(test)]
 fn resolve_location_vec(
     arena: &AllArenas,
-    nodes: &[Id<Node>],
+    nodes: &[impl Into<Id<Node>> + Copy],
     vec_property_name: &str,
     path: &mut Vec<String>,
     path_map: &mut HashMap<Id<Node>, String>,
@@ -838,13 +1295,13 @@ fn resolve_location_vec(
     path.pop();
 }
 
-#[cfg(test)]
 pub fn resolve_location(
     arena: &AllArenas,
-    node: Id<Node>,
+    node: impl Into<Id<Node>>,
     path: &mut Vec<String>,
     path_map: &mut HashMap<Id<Node>, String>,
 ) {
+    let node = node.into();
     path_map.insert(node, format!("/{}", path.join("/")));
     match &*arena.node(node) {
         Node::Alternative(node) => {
@@ -892,8 +1349,24 @@ pub fn resolve_location(
             path.pop();
         }
         Node::Group(node) => {
+            if let Some(modifiers) = node.modifiers {
+                path.push("modifiers".to_owned());
+                resolve_location(arena, modifiers, path, path_map);
+                path.pop();
+            }
             resolve_location_vec(arena, &node.alternatives, "alternatives", path, path_map);
         }
+        Node::Modifiers(node) => {
+            path.push("add".to_owned());
+            resolve_location(arena, node.add, path, path_map);
+            path.pop();
+
+            if let Some(remove) = node.remove {
+                path.push("remove".to_owned());
+                resolve_location(arena, remove, path, path_map);
+                path.pop();
+            }
+        }
         Node::Assertion(node) => {
             if let Some(alternatives) = node.alternatives.as_ref() {
                 resolve_location_vec(arena, alternatives, "alternatives", path, path_map);
@@ -931,6 +1404,7 @@ pub struct NodeBase {
     start: usize,
     end: usize,
     raw: Wtf16,
+    synthesized: bool,
 }
 
 impl NodeInterface for NodeBase {
@@ -954,6 +1428,10 @@ impl NodeInterface for NodeBase {
         self.start
     }
 
+    fn set_start(&mut self, start: usize) {
+        self.start = start;
+    }
+
     fn end(&self) -> usize {
         self.end
     }
@@ -969,20 +1447,440 @@ impl NodeInterface for NodeBase {
     fn set_raw(&mut self, raw: Wtf16) {
         self.raw = raw;
     }
+
+    fn is_synthesized(&self) -> bool {
+        self.synthesized
+    }
+
+    fn set_synthesized(&mut self, synthesized: bool) {
+        self.synthesized = synthesized;
+    }
 }
 
-#[cfg(test)]
-fn get_relative_path(from: Id<Node>, to: Id<Node>, path_map: &HashMap<Id<Node>, String>) -> String {
+pub fn get_relative_path(
+    from: Id<Node>,
+    to: impl Into<Id<Node>>,
+    path_map: &HashMap<Id<Node>, String>,
+) -> String {
     let from_path = &path_map[&from];
-    let to_path = &path_map[&to];
+    let to_path = &path_map[&to.into()];
     let relative = diff_paths(to_path, from_path).unwrap();
     let relative = relative.to_str().unwrap().replace('\\', "/");
     format!("♻️{}", relative.strip_suffix('/').unwrap_or(&relative),)
 }
 
+fn resolve_relative_path(from_path: &str, relative: &str) -> Option<String> {
+    let mut path = from_path
+        .split('/')
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<_>>();
+    for component in relative.strip_prefix("♻️")?.split('/') {
+        match component {
+            "" | "." => (),
+            ".." => {
+                path.pop()?;
+            }
+            component => path.push(component),
+        }
+    }
+    Some(format!("/{}", path.join("/")))
+}
+
+#[derive(Default)]
+struct PendingLinks {
+    from_path: String,
+    relative_paths: Vec<String>,
+}
+
+/// Rebuilds a tree from its [`NodeUnresolved`] form, remembering the
+/// path-based links so that they can be resolved once every node exists
+struct NodeLoader<'a> {
+    arena: &'a AllArenas,
+    path: Vec<String>,
+    path_map: HashMap<String, Id<Node>>,
+    links: Vec<(Id<Node>, PendingLinks)>,
+    kind_error: Option<String>,
+}
+
+impl<'a> NodeLoader<'a> {
+    fn new(arena: &'a AllArenas) -> Self {
+        Self {
+            arena,
+            path: Default::default(),
+            path_map: Default::default(),
+            links: Default::default(),
+            kind_error: Default::default(),
+        }
+    }
+
+    fn current_path(&self) -> String {
+        format!("/{}", self.path.join("/"))
+    }
+
+    fn load_child<T: NodeId>(&mut self, node: NodeUnresolved, property_name: &str) -> T {
+        self.path.push(property_name.to_owned());
+        let node = self.load(node);
+        let typed = T::from_id(self.arena, node).unwrap_or_else(|| {
+            let path = self.current_path();
+            self.kind_error
+                .get_or_insert_with(|| format!("unexpected node type at {path:?}"));
+            new_unchecked(node)
+        });
+        self.path.pop();
+        typed
+    }
+
+    fn load_maybe_child<T: NodeId>(
+        &mut self,
+        node: Option<NodeUnresolved>,
+        property_name: &str,
+    ) -> Option<T> {
+        node.map(|node| self.load_child(node, property_name))
+    }
+
+    fn load_children<T: NodeId>(
+        &mut self,
+        nodes: Vec<NodeUnresolved>,
+        property_name: &str,
+    ) -> Vec<T> {
+        self.path.push(property_name.to_owned());
+        let nodes = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(index, node)| self.load_child(node, &index.to_string()))
+            .collect();
+        self.path.pop();
+        nodes
+    }
+
+    fn alloc(&mut self, node: Node, children: &[impl Into<Id<Node>> + Copy]) -> Id<Node> {
+        let node = self.arena.alloc_node(node);
+        for &child in children {
+            self.arena.node_mut(child).set_parent(Some(node));
+        }
+        self.path_map.insert(self.current_path(), node);
+        node
+    }
+
+    fn alloc_leaf(&mut self, node: Node) -> Id<Node> {
+        self.alloc(node, &[] as &[Id<Node>])
+    }
+
+    fn defer_links(&mut self, node: Id<Node>, relative_paths: Vec<String>) {
+        self.links.push((
+            node,
+            PendingLinks {
+                from_path: self.current_path(),
+                relative_paths,
+            },
+        ));
+    }
+
+    fn load(&mut self, node: NodeUnresolved) -> Id<Node> {
+        match node {
+            NodeUnresolved::Alternative(node) => {
+                let elements = self.load_children(node.elements, "elements");
+                self.alloc(
+                    Node::new_alternative(None, node.start, node.end, node.raw, elements.clone()),
+                    &elements,
+                )
+            }
+            NodeUnresolved::CapturingGroup(node) => {
+                let alternatives = self.load_children(node.alternatives, "alternatives");
+                let id = self.alloc(
+                    Node::new_capturing_group(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        node.name,
+                        alternatives.clone(),
+                        Default::default(),
+                    ),
+                    &alternatives,
+                );
+                self.defer_links(id, node.references);
+                id
+            }
+            NodeUnresolved::CharacterClass(node) => {
+                let elements = self.load_children(node.elements, "elements");
+                self.alloc(
+                    Node::new_character_class(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        node.unicode_sets,
+                        node.negate,
+                        elements.clone(),
+                    ),
+                    &elements,
+                )
+            }
+            NodeUnresolved::CharacterClassRange(node) => {
+                let min = self.load_child(node.min, "min");
+                let max = self.load_child(node.max, "max");
+                self.alloc(
+                    Node::new_character_class_range(None, node.start, node.end, node.raw, min, max),
+                    &[min, max],
+                )
+            }
+            NodeUnresolved::ClassIntersection(node) => {
+                let left = self.load_child(node.left, "left");
+                let right = self.load_child(node.right, "right");
+                self.alloc(
+                    Node::new_class_intersection(None, node.start, node.end, node.raw, left, right),
+                    &[left.id(), right.id()],
+                )
+            }
+            NodeUnresolved::ClassStringDisjunction(node) => {
+                let alternatives = self.load_children(node.alternatives, "alternatives");
+                self.alloc(
+                    Node::new_class_string_disjunction(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        alternatives.clone(),
+                    ),
+                    &alternatives,
+                )
+            }
+            NodeUnresolved::ClassSubtraction(node) => {
+                let left = self.load_child(node.left, "left");
+                let right = self.load_child(node.right, "right");
+                self.alloc(
+                    Node::new_class_subtraction(None, node.start, node.end, node.raw, left, right),
+                    &[left.id(), right.id()],
+                )
+            }
+            NodeUnresolved::ExpressionCharacterClass(node) => {
+                let expression = self.load_child(node.expression, "expression");
+                self.alloc(
+                    Node::new_expression_character_class(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        node.negate,
+                        expression,
+                    ),
+                    &[expression],
+                )
+            }
+            NodeUnresolved::Group(node) => {
+                let modifiers = self.load_maybe_child(node.modifiers, "modifiers");
+                let alternatives = self.load_children(node.alternatives, "alternatives");
+                self.alloc(
+                    Node::new_group(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        modifiers,
+                        alternatives.clone(),
+                    ),
+                    &modifiers
+                        .map(ModifiersId::id)
+                        .into_iter()
+                        .chain(alternatives.into_iter().map(AlternativeId::id))
+                        .collect::<Vec<_>>(),
+                )
+            }
+            NodeUnresolved::Modifiers(node) => {
+                let add = self.load_child(node.add, "add");
+                let remove = self.load_maybe_child(node.remove, "remove");
+                self.alloc(
+                    Node::new_modifiers(None, node.start, node.end, node.raw, add, remove),
+                    &[add].into_iter().chain(remove).collect::<Vec<_>>(),
+                )
+            }
+            NodeUnresolved::ModifierFlags(node) => self.alloc_leaf(Node::new_modifier_flags(
+                None,
+                node.start,
+                node.end,
+                node.raw,
+                node.dot_all,
+                node.ignore_case,
+                node.multiline,
+            )),
+            NodeUnresolved::Assertion(node) => {
+                let alternatives = node
+                    .alternatives
+                    .map(|alternatives| self.load_children(alternatives, "alternatives"));
+                self.alloc(
+                    Node::new_assertion(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        node.kind,
+                        node.negate,
+                        alternatives.clone(),
+                    ),
+                    alternatives.as_deref().unwrap_or_default(),
+                )
+            }
+            NodeUnresolved::Pattern(node) => {
+                let alternatives = self.load_children(node.alternatives, "alternatives");
+                self.alloc(
+                    Node::new_pattern(None, node.start, node.end, node.raw, alternatives.clone()),
+                    &alternatives,
+                )
+            }
+            NodeUnresolved::Quantifier(node) => {
+                let element = self.load_child(node.element, "element");
+                self.alloc(
+                    Node::new_quantifier(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        node.min,
+                        node.max,
+                        node.greedy,
+                        element,
+                    ),
+                    &[element],
+                )
+            }
+            NodeUnresolved::RegExpLiteral(node) => {
+                let pattern = self.load_child(node.pattern, "pattern");
+                let flags = self.load_child(node.flags, "flags");
+                self.alloc(
+                    Node::new_reg_exp_literal(None, node.start, node.end, node.raw, pattern, flags),
+                    &[pattern.id(), flags.id()],
+                )
+            }
+            NodeUnresolved::StringAlternative(node) => {
+                let elements = self.load_children(node.elements, "elements");
+                self.alloc(
+                    Node::new_string_alternative(
+                        None,
+                        node.start,
+                        node.end,
+                        node.raw,
+                        elements.clone(),
+                    ),
+                    &elements,
+                )
+            }
+            NodeUnresolved::Backreference(node) => {
+                let id = self.alloc_leaf(Node::new_backreference(
+                    None,
+                    node.start,
+                    node.end,
+                    node.raw,
+                    node.ref_,
+                    Default::default(),
+                ));
+                self.defer_links(
+                    id,
+                    match node.resolved {
+                        BackreferenceResolvedUnresolved::Single(resolved) => vec![resolved],
+                        BackreferenceResolvedUnresolved::Multiple(resolved) => resolved,
+                    },
+                );
+                id
+            }
+            NodeUnresolved::Character(node) => self.alloc_leaf(Node::new_character(
+                None, node.start, node.end, node.raw, node.value,
+            )),
+            NodeUnresolved::CharacterSet(node) => self.alloc_leaf(Node::new_character_set(
+                None,
+                node.start,
+                node.end,
+                node.raw,
+                node.kind,
+                node.strings,
+                node.key,
+                node.value,
+                node.negate,
+            )),
+            NodeUnresolved::Flags(node) => self.alloc_leaf(Node::new_flags(
+                None,
+                node.start,
+                node.end,
+                node.raw,
+                node.dot_all,
+                node.global,
+                node.has_indices,
+                node.ignore_case,
+                node.multiline,
+                node.sticky,
+                node.unicode,
+                node.unicode_sets,
+            )),
+            NodeUnresolved::Error(node) => self.alloc_leaf(Node::new_error(
+                None,
+                node.start,
+                node.end,
+                node.raw,
+                node.kind,
+                node.message,
+            )),
+        }
+    }
+
+    fn resolve_links(self) -> Result<(), String> {
+        if let Some(kind_error) = self.kind_error {
+            return Err(kind_error);
+        }
+        for (node, links) in self.links {
+            let targets = links
+                .relative_paths
+                .iter()
+                .map(|relative| {
+                    resolve_relative_path(&links.from_path, relative)
+                        .and_then(|path| self.path_map.get(&path).copied())
+                        .ok_or_else(|| {
+                            format!(
+                                "couldn't resolve {relative:?} relative to {:?}",
+                                links.from_path
+                            )
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let is_capturing_group = matches!(&*self.arena.node(node), Node::CapturingGroup(_));
+            if is_capturing_group {
+                let targets = typed_links(self.arena, targets, &links.from_path)?;
+                self.arena
+                    .node_mut(node)
+                    .as_capturing_group_mut()
+                    .references = targets;
+            } else {
+                let targets = typed_links(self.arena, targets, &links.from_path)?;
+                self.arena.node_mut(node).as_backreference_mut().resolved = targets;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn typed_links<T: NodeId>(
+    arena: &AllArenas,
+    targets: Vec<Id<Node>>,
+    from_path: &str,
+) -> Result<Vec<T>, String> {
+    targets
+        .into_iter()
+        .map(|target| {
+            T::from_id(arena, target)
+                .ok_or_else(|| format!("unexpected node type linked from {from_path:?}"))
+        })
+        .collect()
+}
+
+pub fn load_node_unresolved(arena: &AllArenas, node: NodeUnresolved) -> Result<Id<Node>, String> {
+    let mut loader = NodeLoader::new(arena);
+    let node = loader.load(node);
+    loader.resolve_links()?;
+    Ok(node)
+}
+
 #[cfg(test)]
 pub fn to_node_unresolved(
-    id: Id<Node>,
+    id: impl Into<Id<Node>>,
     arena: &AllArenas,
     path_map: &HashMap<Id<Node>, String>,
 ) -> NodeUnresolved {
@@ -1112,12 +2010,41 @@ pub fn to_node_unresolved(
             start: node._base.start,
             end: node._base.end,
             raw: node._base.raw.to_owned(),
+            modifiers: node
+                .modifiers
+                .map(|modifiers| to_node_unresolved(modifiers, arena, path_map)),
             alternatives: node
                 .alternatives
                 .iter()
                 .map(|&node| to_node_unresolved(node, arena, path_map))
                 .collect(),
         })),
+        Node::Modifiers(node) => NodeUnresolved::Modifiers(Box::new(ModifiersUnresolved {
+            parent: node
+                ._base
+                .parent
+                .map(|parent| get_relative_path(node._base._arena_id.unwrap(), parent, path_map)),
+            start: node._base.start,
+            end: node._base.end,
+            raw: node._base.raw.to_owned(),
+            add: to_node_unresolved(node.add, arena, path_map),
+            remove: node
+                .remove
+                .map(|remove| to_node_unresolved(remove, arena, path_map)),
+        })),
+        Node::ModifierFlags(node) => {
+            NodeUnresolved::ModifierFlags(Box::new(ModifierFlagsUnresolved {
+                parent: node._base.parent.map(|parent| {
+                    get_relative_path(node._base._arena_id.unwrap(), parent, path_map)
+                }),
+                start: node._base.start,
+                end: node._base.end,
+                raw: node._base.raw.to_owned(),
+                dot_all: node.dot_all,
+                ignore_case: node.ignore_case,
+                multiline: node.multiline,
+            }))
+        }
         Node::Assertion(node) => NodeUnresolved::Assertion(Box::new(AssertionUnresolved {
             parent: node
                 ._base
@@ -1198,11 +2125,22 @@ pub fn to_node_unresolved(
                 end: node._base.end,
                 raw: node._base.raw.to_owned(),
                 ref_: node.ref_.clone(),
-                resolved: get_relative_path(
-                    node._base._arena_id.unwrap(),
-                    node.resolved.unwrap(),
-                    path_map,
-                ),
+                ambiguous: node.resolved.len() != 1,
+                resolved: match &*node.resolved {
+                    [resolved] => BackreferenceResolvedUnresolved::Single(get_relative_path(
+                        node._base._arena_id.unwrap(),
+                        *resolved,
+                        path_map,
+                    )),
+                    resolved => BackreferenceResolvedUnresolved::Multiple(
+                        resolved
+                            .iter()
+                            .map(|&resolved| {
+                                get_relative_path(node._base._arena_id.unwrap(), resolved, path_map)
+                            })
+                            .collect(),
+                    ),
+                },
             }))
         }
         Node::Character(node) => NodeUnresolved::Character(Box::new(CharacterUnresolved {
@@ -1215,6 +2153,17 @@ pub fn to_node_unresolved(
             raw: node._base.raw.to_owned(),
             value: node.value,
         })),
+        Node::Error(node) => NodeUnresolved::Error(Box::new(ErrorUnresolved {
+            parent: node
+                ._base
+                .parent
+                .map(|parent| get_relative_path(node._base._arena_id.unwrap(), parent, path_map)),
+            start: node._base.start,
+            end: node._base.end,
+            raw: node._base.raw.to_owned(),
+            kind: node.kind.clone(),
+            message: node.message.clone(),
+        })),
         Node::CharacterSet(node) => {
             NodeUnresolved::CharacterSet(Box::new(CharacterSetUnresolved {
                 parent: node._base.parent.map(|parent| {
@@ -1253,11 +2202,10 @@ pub fn to_node_unresolved(
 #[derive(Clone)]
 pub struct RegExpLiteral {
     _base: NodeBase,
-    pub pattern: Id<Node>, /*Pattern*/
-    pub flags: Id<Node>,   /*Flags*/
+    pub pattern: PatternId,
+    pub flags: FlagsId,
 }
 
-#[cfg(test)]
 fn deserialize_possibly_infinity_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
 where
     D: Deserializer<'de>,
@@ -1271,15 +2219,18 @@ where
     let string_or_u32 = StringOrU32::deserialize(deserializer)?;
     Ok(match string_or_u32 {
         StringOrU32::String(value) => {
-            // TODO: should handle this better?
-            assert!(value == "$$Infinity");
+            if value != "$$Infinity" {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Str(&value),
+                    &"a number or \"$$Infinity\"",
+                ));
+            }
             u32::MAX
         }
         StringOrU32::U32(value) => value,
     })
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct RegExpLiteralUnresolved {
     pub parent: Option<String>,
@@ -1293,10 +2244,9 @@ pub struct RegExpLiteralUnresolved {
 #[derive(Clone)]
 pub struct Pattern {
     _base: NodeBase,
-    pub alternatives: Vec<Id<Node> /*Alternative*/>,
+    pub alternatives: Vec<AlternativeId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct PatternUnresolved {
     pub parent: Option<String>,
@@ -1309,10 +2259,9 @@ pub struct PatternUnresolved {
 #[derive(Clone)]
 pub struct Alternative {
     _base: NodeBase,
-    pub elements: Vec<Id<Node> /*Element*/>,
+    pub elements: Vec<ElementId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct AlternativeUnresolved {
     pub parent: Option<String>,
@@ -1325,28 +2274,65 @@ pub struct AlternativeUnresolved {
 #[derive(Clone)]
 pub struct Group {
     _base: NodeBase,
-    pub alternatives: Vec<Id<Node> /*Alternative*/>,
+    pub modifiers: Option<ModifiersId>,
+    pub alternatives: Vec<AlternativeId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct GroupUnresolved {
     pub parent: Option<String>,
     pub start: usize,
     pub end: usize,
     pub raw: Wtf16,
+    pub modifiers: Option<NodeUnresolved>,
     pub alternatives: Vec<NodeUnresolved>,
 }
 
+#[derive(Clone)]
+pub struct Modifiers {
+    _base: NodeBase,
+    pub add: ModifierFlagsId,
+    pub remove: Option<ModifierFlagsId>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct ModifiersUnresolved {
+    pub parent: Option<String>,
+    pub start: usize,
+    pub end: usize,
+    pub raw: Wtf16,
+    pub add: NodeUnresolved,
+    pub remove: Option<NodeUnresolved>,
+}
+
+#[derive(Clone)]
+pub struct ModifierFlags {
+    _base: NodeBase,
+    pub dot_all: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifierFlagsUnresolved {
+    pub parent: Option<String>,
+    pub start: usize,
+    pub end: usize,
+    pub raw: Wtf16,
+    pub dot_all: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+}
+
 #[derive(Clone)]
 pub struct CapturingGroup {
     _base: NodeBase,
     pub name: Option<Wtf16>,
-    pub alternatives: Vec<Id<Node> /*Alternative*/>,
-    pub references: Vec<Id<Node> /*Backreference*/>,
+    pub alternatives: Vec<AlternativeId>,
+    pub references: Vec<BackreferenceId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct CapturingGroupUnresolved {
     pub parent: Option<String>,
@@ -1363,10 +2349,9 @@ pub struct Assertion {
     _base: NodeBase,
     pub kind: AssertionKind,
     pub negate: Option<bool>,
-    pub alternatives: Option<Vec<Id<Node> /*Alternative*/>>,
+    pub alternatives: Option<Vec<AlternativeId>>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct AssertionUnresolved {
     pub parent: Option<String>,
@@ -1384,10 +2369,9 @@ pub struct Quantifier {
     pub min: u32,
     pub max: u32,
     pub greedy: bool,
-    pub element: Id<Node /*QuantifiableElement*/>,
+    pub element: QuantifiableElementId,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct QuantifierUnresolved {
     pub parent: Option<String>,
@@ -1406,10 +2390,9 @@ pub struct CharacterClass {
     _base: NodeBase,
     pub unicode_sets: bool,
     pub negate: bool,
-    pub elements: Vec<Id<Node> /*CharacterClassElement*/>,
+    pub elements: Vec<CharacterClassElementId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct CharacterClassUnresolved {
@@ -1425,11 +2408,10 @@ pub struct CharacterClassUnresolved {
 #[derive(Clone)]
 pub struct CharacterClassRange {
     _base: NodeBase,
-    pub min: Id<Node /*Character*/>,
-    pub max: Id<Node /*Character*/>,
+    pub min: CharacterId,
+    pub max: CharacterId,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct CharacterClassRangeUnresolved {
     pub parent: Option<String>,
@@ -1450,7 +2432,6 @@ pub struct CharacterSet {
     pub negate: Option<bool>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct CharacterSetUnresolved {
     pub parent: Option<String>,
@@ -1468,10 +2449,9 @@ pub struct CharacterSetUnresolved {
 pub struct ExpressionCharacterClass {
     _base: NodeBase,
     pub negate: bool,
-    pub expression: Id<Node /*ClassIntersection | ClassSubtraction*/>,
+    pub expression: ClassSetExpressionId,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct ExpressionCharacterClassUnresolved {
     pub parent: Option<String>,
@@ -1485,11 +2465,10 @@ pub struct ExpressionCharacterClassUnresolved {
 #[derive(Clone)]
 pub struct ClassIntersection {
     _base: NodeBase,
-    pub left: Id<Node /*ClassIntersection | ClassSetOperand*/>,
-    pub right: Id<Node /*ClassSetOperand*/>,
+    pub left: ClassIntersectionLeftId,
+    pub right: ClassSetOperandId,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct ClassIntersectionUnresolved {
     pub parent: Option<String>,
@@ -1503,11 +2482,10 @@ pub struct ClassIntersectionUnresolved {
 #[derive(Clone)]
 pub struct ClassSubtraction {
     _base: NodeBase,
-    pub left: Id<Node /*ClassSetOperand | ClassSubtraction*/>,
-    pub right: Id<Node /*ClassSetOperand*/>,
+    pub left: ClassSubtractionLeftId,
+    pub right: ClassSetOperandId,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct ClassSubtractionUnresolved {
     pub parent: Option<String>,
@@ -1521,10 +2499,9 @@ pub struct ClassSubtractionUnresolved {
 #[derive(Clone)]
 pub struct ClassStringDisjunction {
     _base: NodeBase,
-    pub alternatives: Vec<Id<Node> /*StringAlternative*/>,
+    pub alternatives: Vec<StringAlternativeId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct ClassStringDisjunctionUnresolved {
     pub parent: Option<String>,
@@ -1537,10 +2514,9 @@ pub struct ClassStringDisjunctionUnresolved {
 #[derive(Clone)]
 pub struct StringAlternative {
     _base: NodeBase,
-    pub elements: Vec<Id<Node> /*Character*/>,
+    pub elements: Vec<CharacterId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct StringAlternativeUnresolved {
     pub parent: Option<String>,
@@ -1556,7 +2532,25 @@ pub struct Character {
     pub value: CodePoint,
 }
 
-#[cfg(test)]
+/// A region of the source that couldn't be parsed, produced when parsing
+/// in tolerant mode
+#[derive(Clone)]
+pub struct Error {
+    _base: NodeBase,
+    pub kind: RegExpSyntaxErrorKind,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct ErrorUnresolved {
+    pub parent: Option<String>,
+    pub start: usize,
+    pub end: usize,
+    pub raw: Wtf16,
+    pub kind: RegExpSyntaxErrorKind,
+    pub message: String,
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct CharacterUnresolved {
     pub parent: Option<String>,
@@ -1570,10 +2564,9 @@ pub struct CharacterUnresolved {
 pub struct Backreference {
     _base: NodeBase,
     pub ref_: CapturingGroupKey,
-    pub resolved: Option<Id<Node /*CapturingGroup*/>>,
+    pub resolved: Vec<CapturingGroupId>,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct BackreferenceUnresolved {
     pub parent: Option<String>,
@@ -1582,7 +2575,17 @@ pub struct BackreferenceUnresolved {
     pub raw: Wtf16,
     #[serde(rename = "ref")]
     pub ref_: CapturingGroupKey,
-    pub resolved: String,
+    /// Absent from the JSON of older regexpp versions
+    #[serde(default)]
+    pub ambiguous: bool,
+    pub resolved: BackreferenceResolvedUnresolved,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum BackreferenceResolvedUnresolved {
+    Single(String),
+    Multiple(Vec<String>),
 }
 
 #[derive(Clone)]
@@ -1598,7 +2601,6 @@ pub struct Flags {
     pub unicode_sets: bool,
 }
 
-#[cfg(test)]
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct FlagsUnresolved {
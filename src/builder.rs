@@ -0,0 +1,896 @@
+use id_arena::Id;
+
+use crate::{
+    ast::{Node, NodeInterface},
+    node_ids::{
+        new_unchecked, private::Sealed, AlternativeId, BackreferenceId, CapturingGroupId,
+        CharacterClassElementId, CharacterId, ElementId, FlagsId, PatternId, QuantifiableElementId,
+        StringAlternativeId,
+    },
+    printer::RegExpPrinter,
+    validator::{AssertionKind, CapturingGroupKey, CharacterKind, ValidatePatternFlags},
+    AllArenas, CodePoint, RegExpSyntaxErrorKind, RegExpValidator, Wtf16,
+};
+
+/// What a builder found out about its subtree that can only be acted on
+/// once the whole tree exists
+#[derive(Default)]
+struct BuilderState {
+    error: Option<RegExpSyntaxErrorKind>,
+    capturing_groups: Vec<CapturingGroupId>,
+    backreferences: Vec<BackreferenceId>,
+    character_classes: Vec<Id<Node>>,
+    requires_unicode: bool,
+    requires_unicode_sets: bool,
+}
+
+impl BuilderState {
+    /// Only the first error is reported
+    fn fail(&mut self, kind: RegExpSyntaxErrorKind) {
+        if self.error.is_none() {
+            self.error = Some(kind);
+        }
+    }
+
+    fn merge(&mut self, other: BuilderState) {
+        if let Some(error) = other.error {
+            self.fail(error);
+        }
+        self.capturing_groups.extend(other.capturing_groups);
+        self.backreferences.extend(other.backreferences);
+        self.character_classes.extend(other.character_classes);
+        self.requires_unicode |= other.requires_unicode;
+        self.requires_unicode_sets |= other.requires_unicode_sets;
+    }
+}
+
+fn alloc(arena: &AllArenas, node: Node, children: &[impl Into<Id<Node>> + Copy]) -> Id<Node> {
+    let node = arena.alloc_node(node);
+    for &child in children {
+        arena.node_mut(child).set_parent(Some(node));
+    }
+    node
+}
+
+fn character(arena: &AllArenas, value: CodePoint) -> CharacterId {
+    CharacterId::new_unchecked(arena.alloc_node(Node::new_character(
+        None,
+        0,
+        0,
+        Default::default(),
+        value,
+    )))
+}
+
+fn any_character_set(arena: &AllArenas) -> Id<Node> {
+    arena.alloc_node(Node::new_character_set(
+        None,
+        0,
+        0,
+        Default::default(),
+        CharacterKind::Any,
+        None,
+        None,
+        None,
+        None,
+    ))
+}
+
+fn escape_character_set(arena: &AllArenas, kind: CharacterKind, negate: bool) -> Id<Node> {
+    arena.alloc_node(Node::new_character_set(
+        None,
+        0,
+        0,
+        Default::default(),
+        kind,
+        None,
+        None,
+        None,
+        Some(negate),
+    ))
+}
+
+fn property_character_set(
+    arena: &AllArenas,
+    key: &str,
+    value: Option<&str>,
+    negate: bool,
+    state: &mut BuilderState,
+) -> Id<Node> {
+    state.requires_unicode = true;
+    arena.alloc_node(Node::new_character_set(
+        None,
+        0,
+        0,
+        Default::default(),
+        CharacterKind::Property,
+        Some(false),
+        Some(key.into()),
+        value.map(Into::into),
+        Some(negate),
+    ))
+}
+
+fn class_string_disjunction(
+    arena: &AllArenas,
+    strings: &[&str],
+    state: &mut BuilderState,
+) -> Id<Node> {
+    state.requires_unicode_sets = true;
+    let alternatives = strings
+        .iter()
+        .map(|string| {
+            let elements = string
+                .chars()
+                .map(|value| character(arena, value.into()))
+                .collect::<Vec<_>>();
+            StringAlternativeId::new_unchecked(alloc(
+                arena,
+                Node::new_string_alternative(None, 0, 0, Default::default(), elements.clone()),
+                &elements,
+            ))
+        })
+        .collect::<Vec<_>>();
+    alloc(
+        arena,
+        Node::new_class_string_disjunction(None, 0, 0, Default::default(), alternatives.clone()),
+        &alternatives,
+    )
+}
+
+fn expression_character_class<'a>(
+    arena: &'a AllArenas,
+    negate: bool,
+    build: impl FnOnce(ClassSetOperands<'a>) -> ClassSetOperand<'a>,
+    state: &mut BuilderState,
+) -> Id<Node> {
+    let operand = build(ClassSetOperands { _arena: arena });
+    state.merge(operand._state);
+    state.requires_unicode_sets = true;
+    let expression = operand._node;
+    if operand._operator.is_none() {
+        // There's nothing to intersect or subtract, so this is just `[...]`
+        let node = alloc(
+            arena,
+            Node::new_character_class(
+                None,
+                0,
+                0,
+                Default::default(),
+                true,
+                negate,
+                vec![new_unchecked(expression)],
+            ),
+            &[expression],
+        );
+        state.character_classes.push(node);
+        return node;
+    }
+    alloc(
+        arena,
+        Node::new_expression_character_class(
+            None,
+            0,
+            0,
+            Default::default(),
+            negate,
+            new_unchecked(expression),
+        ),
+        &[expression],
+    )
+}
+
+/// Builds a tree in code, e.g.
+/// `PatternBuilder::new(&arena).alt(|a| a.char('a').quantified(1, None, true))`
+///
+/// Parents are linked up as nodes are created. Once the tree is complete,
+/// [`build`](Self::build) prints it to synthesize `start`/`end`/`raw` for
+/// every node, checks the result with the validator and resolves
+/// backreferences. Shape rules that the node types can't express on their
+/// own (e.g. set operations only appearing inside of an expression class)
+/// are enforced by what each builder allows
+pub struct PatternBuilder<'a> {
+    _disjunction: DisjunctionBuilder<'a>,
+    _flags: ValidatePatternFlags,
+}
+
+impl<'a> PatternBuilder<'a> {
+    pub fn new(arena: &'a AllArenas) -> Self {
+        Self {
+            _disjunction: DisjunctionBuilder::new(arena),
+            _flags: Default::default(),
+        }
+    }
+
+    /// The mode that [`build`](Self::build) validates the pattern in
+    pub fn with_flags(mut self, flags: ValidatePatternFlags) -> Self {
+        self._flags = flags;
+        self
+    }
+
+    pub fn alt(
+        mut self,
+        build: impl FnOnce(AlternativeBuilder<'a>) -> AlternativeBuilder<'a>,
+    ) -> Self {
+        self._disjunction = self._disjunction.alt(build);
+        self
+    }
+
+    pub fn build(self) -> Result<PatternId, RegExpSyntaxErrorKind> {
+        let arena = self._disjunction._arena;
+        let unicode_sets = self._flags.unicode_sets.unwrap_or_default();
+        let unicode = unicode_sets || self._flags.unicode.unwrap_or_default();
+        let (alternatives, state) = self._disjunction.finish();
+        let pattern = alloc(
+            arena,
+            Node::new_pattern(None, 0, 0, Default::default(), alternatives.clone()),
+            &alternatives,
+        );
+        finish(arena, pattern, state, unicode, unicode_sets, false).map(PatternId::new_unchecked)
+    }
+
+    /// Wraps the pattern in a `RegExpLiteral` with the given flags (e.g.
+    /// `"gu"`)
+    pub fn build_literal(
+        self,
+        flags: &str,
+    ) -> Result<Id<Node> /*RegExpLiteral*/, RegExpSyntaxErrorKind> {
+        let arena = self._disjunction._arena;
+        RegExpValidator::new(None)
+            .validate_flags(&Wtf16::from(flags), None, None)
+            .map_err(|error| error.kind)?;
+        let has_flag = |flag: char| flags.contains(flag);
+        let flags = FlagsId::new_unchecked(arena.alloc_node(Node::new_flags(
+            None,
+            0,
+            0,
+            Default::default(),
+            has_flag('s'),
+            has_flag('g'),
+            has_flag('d'),
+            has_flag('i'),
+            has_flag('m'),
+            has_flag('y'),
+            has_flag('u'),
+            has_flag('v'),
+        )));
+        let (alternatives, state) = self._disjunction.finish();
+        let pattern = PatternId::new_unchecked(alloc(
+            arena,
+            Node::new_pattern(None, 0, 0, Default::default(), alternatives.clone()),
+            &alternatives,
+        ));
+        let literal = alloc(
+            arena,
+            Node::new_reg_exp_literal(None, 0, 0, Default::default(), pattern, flags),
+            &[pattern.id(), flags.id()],
+        );
+        finish(
+            arena,
+            literal,
+            state,
+            has_flag('u') || has_flag('v'),
+            has_flag('v'),
+            true,
+        )
+    }
+}
+
+fn finish(
+    arena: &AllArenas,
+    root: Id<Node>,
+    state: BuilderState,
+    unicode: bool,
+    unicode_sets: bool,
+    is_literal: bool,
+) -> Result<Id<Node>, RegExpSyntaxErrorKind> {
+    if let Some(error) = state.error {
+        return Err(error);
+    }
+    if state.requires_unicode_sets && !unicode_sets {
+        return Err(RegExpSyntaxErrorKind::InvalidCharacterClass);
+    }
+    if state.requires_unicode && !unicode {
+        return Err(RegExpSyntaxErrorKind::InvalidEscape);
+    }
+    for &class in &state.character_classes {
+        arena.node_mut(class).as_character_class_mut().unicode_sets = unicode_sets;
+    }
+
+    let flags = ValidatePatternFlags {
+        unicode: Some(unicode),
+        unicode_sets: Some(unicode_sets),
+    };
+    let (source, spans) = RegExpPrinter::new(arena, Some(flags)).print_with_spans(root);
+    let mut validator = RegExpValidator::new(None);
+    if is_literal {
+        validator.validate_literal(&source, None, None)
+    } else {
+        validator.validate_pattern(
+            &source,
+            None,
+            None,
+            Some(ValidatePatternFlags {
+                unicode: Some(unicode),
+                unicode_sets: Some(unicode_sets),
+            }),
+        )
+    }
+    .map_err(|error| error.kind)?;
+    for (node, start, end) in spans {
+        let mut node = arena.node_mut(node);
+        node.set_start(start);
+        node.set_end(end);
+        node.set_raw(source[start..end].into());
+        node.set_synthesized(true);
+    }
+
+    let mut capturing_groups = state.capturing_groups;
+    capturing_groups.sort_by_key(|&group| arena.node(group).start());
+    for backreference in state.backreferences {
+        let ref_ = arena.node(backreference).as_backreference().ref_.clone();
+        let resolved = match &ref_ {
+            CapturingGroupKey::Index(index) => index
+                .checked_sub(1)
+                .and_then(|index| capturing_groups.get(index))
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            CapturingGroupKey::Name(name) => capturing_groups
+                .iter()
+                .copied()
+                .filter(|&group| arena.node(group).as_capturing_group().name.as_ref() == Some(name))
+                .collect(),
+        };
+        if resolved.is_empty() {
+            return Err(match ref_ {
+                CapturingGroupKey::Index(_) => RegExpSyntaxErrorKind::InvalidEscape,
+                CapturingGroupKey::Name(name) => {
+                    RegExpSyntaxErrorKind::InvalidNamedCaptureReferenced { name }
+                }
+            });
+        }
+        for &group in &resolved {
+            arena
+                .node_mut(group)
+                .as_capturing_group_mut()
+                .references
+                .push(backreference);
+        }
+        arena
+            .node_mut(backreference)
+            .as_backreference_mut()
+            .resolved = resolved;
+    }
+    Ok(root)
+}
+
+/// The alternatives of a pattern, group or lookaround assertion
+pub struct DisjunctionBuilder<'a> {
+    _arena: &'a AllArenas,
+    _alternatives: Vec<AlternativeId>,
+    _state: BuilderState,
+}
+
+impl<'a> DisjunctionBuilder<'a> {
+    fn new(arena: &'a AllArenas) -> Self {
+        Self {
+            _arena: arena,
+            _alternatives: Default::default(),
+            _state: Default::default(),
+        }
+    }
+
+    pub fn alt(
+        mut self,
+        build: impl FnOnce(AlternativeBuilder<'a>) -> AlternativeBuilder<'a>,
+    ) -> Self {
+        let (alternative, state) = build(AlternativeBuilder::new(self._arena)).finish();
+        self._alternatives.push(alternative);
+        self._state.merge(state);
+        self
+    }
+
+    /// A disjunction with no alternatives gets a single empty one, like
+    /// `()` does when parsed
+    fn finish(mut self) -> (Vec<AlternativeId>, BuilderState) {
+        if self._alternatives.is_empty() {
+            self = self.alt(|alternative| alternative);
+        }
+        (self._alternatives, self._state)
+    }
+}
+
+pub struct AlternativeBuilder<'a> {
+    _arena: &'a AllArenas,
+    _elements: Vec<ElementId>,
+    _state: BuilderState,
+}
+
+impl<'a> AlternativeBuilder<'a> {
+    fn new(arena: &'a AllArenas) -> Self {
+        Self {
+            _arena: arena,
+            _elements: Default::default(),
+            _state: Default::default(),
+        }
+    }
+
+    fn finish(self) -> (AlternativeId, BuilderState) {
+        let alternative = AlternativeId::new_unchecked(alloc(
+            self._arena,
+            Node::new_alternative(None, 0, 0, Default::default(), self._elements.clone()),
+            &self._elements,
+        ));
+        (alternative, self._state)
+    }
+
+    fn push(mut self, element: impl Into<Id<Node>>) -> Self {
+        self._elements.push(new_unchecked(element.into()));
+        self
+    }
+
+    fn disjunction(
+        &mut self,
+        build: impl FnOnce(DisjunctionBuilder<'a>) -> DisjunctionBuilder<'a>,
+    ) -> Vec<AlternativeId> {
+        let (alternatives, state) = build(DisjunctionBuilder::new(self._arena)).finish();
+        self._state.merge(state);
+        alternatives
+    }
+
+    /// Accepts a `char` or, for lone surrogates, a raw [`CodePoint`]
+    pub fn char(self, value: impl Into<CodePoint>) -> Self {
+        let node = character(self._arena, value.into());
+        self.push(node)
+    }
+
+    pub fn chars(self, value: &str) -> Self {
+        value
+            .chars()
+            .fold(self, |builder, value| builder.char(value))
+    }
+
+    pub fn any(self) -> Self {
+        let node = any_character_set(self._arena);
+        self.push(node)
+    }
+
+    pub fn digit(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Digit, negate);
+        self.push(node)
+    }
+
+    pub fn space(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Space, negate);
+        self.push(node)
+    }
+
+    pub fn word(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Word, negate);
+        self.push(node)
+    }
+
+    /// `\p{key=value}`, or `\p{key}` when there's no value. Only available
+    /// in `u`/`v` mode
+    pub fn property(mut self, key: &str, value: Option<&str>, negate: bool) -> Self {
+        let node = property_character_set(self._arena, key, value, negate, &mut self._state);
+        self.push(node)
+    }
+
+    pub fn start(self) -> Self {
+        self.assertion(AssertionKind::Start, None, None)
+    }
+
+    pub fn end(self) -> Self {
+        self.assertion(AssertionKind::End, None, None)
+    }
+
+    pub fn word_boundary(self, negate: bool) -> Self {
+        self.assertion(AssertionKind::Word, Some(negate), None)
+    }
+
+    pub fn lookahead(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(DisjunctionBuilder<'a>) -> DisjunctionBuilder<'a>,
+    ) -> Self {
+        let alternatives = self.disjunction(build);
+        self.assertion(AssertionKind::Lookahead, Some(negate), Some(alternatives))
+    }
+
+    pub fn lookbehind(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(DisjunctionBuilder<'a>) -> DisjunctionBuilder<'a>,
+    ) -> Self {
+        let alternatives = self.disjunction(build);
+        self.assertion(AssertionKind::Lookbehind, Some(negate), Some(alternatives))
+    }
+
+    fn assertion(
+        self,
+        kind: AssertionKind,
+        negate: Option<bool>,
+        alternatives: Option<Vec<AlternativeId>>,
+    ) -> Self {
+        let node = alloc(
+            self._arena,
+            Node::new_assertion(
+                None,
+                0,
+                0,
+                Default::default(),
+                kind,
+                negate,
+                alternatives.clone(),
+            ),
+            alternatives.as_deref().unwrap_or_default(),
+        );
+        self.push(node)
+    }
+
+    /// A non-capturing group
+    pub fn group(
+        mut self,
+        build: impl FnOnce(DisjunctionBuilder<'a>) -> DisjunctionBuilder<'a>,
+    ) -> Self {
+        let alternatives = self.disjunction(build);
+        let node = alloc(
+            self._arena,
+            Node::new_group(None, 0, 0, Default::default(), None, alternatives.clone()),
+            &alternatives,
+        );
+        self.push(node)
+    }
+
+    pub fn capturing_group(
+        mut self,
+        name: Option<&str>,
+        build: impl FnOnce(DisjunctionBuilder<'a>) -> DisjunctionBuilder<'a>,
+    ) -> Self {
+        let alternatives = self.disjunction(build);
+        let node = alloc(
+            self._arena,
+            Node::new_capturing_group(
+                None,
+                0,
+                0,
+                Default::default(),
+                name.map(Into::into),
+                alternatives.clone(),
+                Default::default(),
+            ),
+            &alternatives,
+        );
+        self._state
+            .capturing_groups
+            .push(CapturingGroupId::new_unchecked(node));
+        self.push(node)
+    }
+
+    /// `\1`-style reference to the capturing group with the given 1-based
+    /// index
+    pub fn backreference(self, index: usize) -> Self {
+        self.backreference_to(CapturingGroupKey::Index(index))
+    }
+
+    pub fn named_backreference(self, name: &str) -> Self {
+        self.backreference_to(CapturingGroupKey::Name(name.into()))
+    }
+
+    fn backreference_to(mut self, ref_: CapturingGroupKey) -> Self {
+        let node = BackreferenceId::new_unchecked(self._arena.alloc_node(Node::new_backreference(
+            None,
+            0,
+            0,
+            Default::default(),
+            ref_,
+            Default::default(),
+        )));
+        self._state.backreferences.push(node);
+        self.push(node)
+    }
+
+    /// Wraps the preceding element in a quantifier. A `max` of `None` means
+    /// unbounded
+    pub fn quantified(mut self, min: u32, max: Option<u32>, greedy: bool) -> Self {
+        let max = max.unwrap_or(u32::MAX);
+        let element = match self._elements.last() {
+            Some(&element)
+                if !matches!(
+                    &*self._arena.node(element),
+                    Node::Assertion(_) | Node::Quantifier(_)
+                ) =>
+            {
+                QuantifiableElementId::new_unchecked(element.id())
+            }
+            _ => {
+                self._state.fail(RegExpSyntaxErrorKind::NothingToRepeat);
+                return self;
+            }
+        };
+        if min > max {
+            self._state
+                .fail(RegExpSyntaxErrorKind::NumbersOutOfOrderInQuantifier);
+        }
+        self._elements.pop();
+        let node = alloc(
+            self._arena,
+            Node::new_quantifier(None, 0, 0, Default::default(), min, max, greedy, element),
+            &[element],
+        );
+        self.push(node)
+    }
+
+    /// `[...]`
+    pub fn class(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(ClassBuilder<'a>) -> ClassBuilder<'a>,
+    ) -> Self {
+        let (node, state) = build(ClassBuilder::new(self._arena)).finish(negate);
+        self._state.merge(state);
+        self.push(node)
+    }
+
+    /// A `v`-mode class made of set operations, e.g. `[\w--\d]`
+    pub fn expression_class(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(ClassSetOperands<'a>) -> ClassSetOperand<'a>,
+    ) -> Self {
+        let node = expression_character_class(self._arena, negate, build, &mut self._state);
+        self.push(node)
+    }
+}
+
+/// The elements of a `[...]` class
+pub struct ClassBuilder<'a> {
+    _arena: &'a AllArenas,
+    _elements: Vec<CharacterClassElementId>,
+    _state: BuilderState,
+}
+
+impl<'a> ClassBuilder<'a> {
+    fn new(arena: &'a AllArenas) -> Self {
+        Self {
+            _arena: arena,
+            _elements: Default::default(),
+            _state: Default::default(),
+        }
+    }
+
+    fn finish(mut self, negate: bool) -> (Id<Node>, BuilderState) {
+        // `unicode_sets` is filled in once the mode is known
+        let class = alloc(
+            self._arena,
+            Node::new_character_class(
+                None,
+                0,
+                0,
+                Default::default(),
+                false,
+                negate,
+                self._elements.clone(),
+            ),
+            &self._elements,
+        );
+        self._state.character_classes.push(class);
+        (class, self._state)
+    }
+
+    fn push(mut self, element: impl Into<Id<Node>>) -> Self {
+        self._elements.push(new_unchecked(element.into()));
+        self
+    }
+
+    pub fn char(self, value: impl Into<CodePoint>) -> Self {
+        let node = character(self._arena, value.into());
+        self.push(node)
+    }
+
+    pub fn chars(self, value: &str) -> Self {
+        value
+            .chars()
+            .fold(self, |builder, value| builder.char(value))
+    }
+
+    /// Taking characters rather than nodes means a range can't be given
+    /// anything but `Character` endpoints
+    pub fn range(mut self, min: impl Into<CodePoint>, max: impl Into<CodePoint>) -> Self {
+        let (min, max) = (min.into(), max.into());
+        if min > max {
+            self._state
+                .fail(RegExpSyntaxErrorKind::RangeOutOfOrderInCharacterClass);
+        }
+        let min = character(self._arena, min);
+        let max = character(self._arena, max);
+        let node = alloc(
+            self._arena,
+            Node::new_character_class_range(None, 0, 0, Default::default(), min, max),
+            &[min, max],
+        );
+        self.push(node)
+    }
+
+    pub fn digit(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Digit, negate);
+        self.push(node)
+    }
+
+    pub fn space(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Space, negate);
+        self.push(node)
+    }
+
+    pub fn word(self, negate: bool) -> Self {
+        let node = escape_character_set(self._arena, CharacterKind::Word, negate);
+        self.push(node)
+    }
+
+    pub fn property(mut self, key: &str, value: Option<&str>, negate: bool) -> Self {
+        let node = property_character_set(self._arena, key, value, negate, &mut self._state);
+        self.push(node)
+    }
+
+    /// `\q{...}`. Only available in `v` mode
+    pub fn string_disjunction(mut self, strings: &[&str]) -> Self {
+        let node = class_string_disjunction(self._arena, strings, &mut self._state);
+        self.push(node)
+    }
+
+    /// A nested `[...]`. Only available in `v` mode
+    pub fn class(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(ClassBuilder<'a>) -> ClassBuilder<'a>,
+    ) -> Self {
+        let (node, state) = build(ClassBuilder::new(self._arena)).finish(negate);
+        self._state.merge(state);
+        self._state.requires_unicode_sets = true;
+        self.push(node)
+    }
+
+    /// A nested class made of set operations. Only available in `v` mode
+    pub fn expression_class(
+        mut self,
+        negate: bool,
+        build: impl FnOnce(ClassSetOperands<'a>) -> ClassSetOperand<'a>,
+    ) -> Self {
+        let node = expression_character_class(self._arena, negate, build, &mut self._state);
+        self.push(node)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum SetOperator {
+    Intersection,
+    Subtraction,
+}
+
+/// Creates the operands of an expression class, which are then combined
+/// with [`ClassSetOperand::intersect`] and [`ClassSetOperand::subtract`]
+#[derive(Copy, Clone)]
+pub struct ClassSetOperands<'a> {
+    _arena: &'a AllArenas,
+}
+
+impl<'a> ClassSetOperands<'a> {
+    fn operand(self, node: impl Into<Id<Node>>, state: BuilderState) -> ClassSetOperand<'a> {
+        ClassSetOperand {
+            _arena: self._arena,
+            _node: node.into(),
+            _operator: None,
+            _state: state,
+        }
+    }
+
+    pub fn char(self, value: impl Into<CodePoint>) -> ClassSetOperand<'a> {
+        self.operand(character(self._arena, value.into()), Default::default())
+    }
+
+    pub fn digit(self, negate: bool) -> ClassSetOperand<'a> {
+        let node = escape_character_set(self._arena, CharacterKind::Digit, negate);
+        self.operand(node, Default::default())
+    }
+
+    pub fn space(self, negate: bool) -> ClassSetOperand<'a> {
+        let node = escape_character_set(self._arena, CharacterKind::Space, negate);
+        self.operand(node, Default::default())
+    }
+
+    pub fn word(self, negate: bool) -> ClassSetOperand<'a> {
+        let node = escape_character_set(self._arena, CharacterKind::Word, negate);
+        self.operand(node, Default::default())
+    }
+
+    pub fn property(self, key: &str, value: Option<&str>, negate: bool) -> ClassSetOperand<'a> {
+        let mut state = BuilderState::default();
+        let node = property_character_set(self._arena, key, value, negate, &mut state);
+        self.operand(node, state)
+    }
+
+    pub fn string_disjunction(self, strings: &[&str]) -> ClassSetOperand<'a> {
+        let mut state = BuilderState::default();
+        let node = class_string_disjunction(self._arena, strings, &mut state);
+        self.operand(node, state)
+    }
+
+    pub fn class(
+        self,
+        negate: bool,
+        build: impl FnOnce(ClassBuilder<'a>) -> ClassBuilder<'a>,
+    ) -> ClassSetOperand<'a> {
+        let (node, state) = build(ClassBuilder::new(self._arena)).finish(negate);
+        self.operand(node, state)
+    }
+
+    pub fn expression_class(
+        self,
+        negate: bool,
+        build: impl FnOnce(ClassSetOperands<'a>) -> ClassSetOperand<'a>,
+    ) -> ClassSetOperand<'a> {
+        let mut state = BuilderState::default();
+        let node = expression_character_class(self._arena, negate, build, &mut state);
+        self.operand(node, state)
+    }
+}
+
+/// An operand of an expression class, or the intersection/subtraction
+/// built up from operands so far
+pub struct ClassSetOperand<'a> {
+    _arena: &'a AllArenas,
+    _node: Id<Node>,
+    _operator: Option<SetOperator>,
+    _state: BuilderState,
+}
+
+impl<'a> ClassSetOperand<'a> {
+    /// `self&&right`
+    pub fn intersect(self, right: ClassSetOperand<'a>) -> Self {
+        self.combine(SetOperator::Intersection, right)
+    }
+
+    /// `self--right`
+    pub fn subtract(self, right: ClassSetOperand<'a>) -> Self {
+        self.combine(SetOperator::Subtraction, right)
+    }
+
+    /// Operations are left-associative and can't be mixed without nesting,
+    /// so the right-hand side has to be a single operand and the operator
+    /// has to match any that's already been applied
+    fn combine(mut self, operator: SetOperator, right: ClassSetOperand<'a>) -> Self {
+        if right._operator.is_some() || self._operator.is_some() && self._operator != Some(operator)
+        {
+            self._state
+                .fail(RegExpSyntaxErrorKind::InvalidSetOperationInCharacterClass);
+        }
+        self._state.merge(right._state);
+        let (left, right) = (self._node, right._node);
+        let node = match operator {
+            SetOperator::Intersection => Node::new_class_intersection(
+                None,
+                0,
+                0,
+                Default::default(),
+                new_unchecked(left),
+                new_unchecked(right),
+            ),
+            SetOperator::Subtraction => Node::new_class_subtraction(
+                None,
+                0,
+                0,
+                Default::default(),
+                new_unchecked(left),
+                new_unchecked(right),
+            ),
+        };
+        Self {
+            _arena: self._arena,
+            _node: alloc(self._arena, node, &[left, right]),
+            _operator: Some(operator),
+            _state: self._state,
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use std::{fmt, ops::RangeInclusive};
+
+use id_arena::Id;
+
+use crate::{
+    ast::Node,
+    ecma_versions::{EcmaVersion, LATEST_ECMA_VERSION},
+    unicode::{
+        close_over_case, unicode_property_char_set, CARRIAGE_RETURN, DIGIT_NINE, DIGIT_ZERO,
+        LATIN_CAPITAL_LETTER_A, LATIN_CAPITAL_LETTER_Z, LATIN_SMALL_LETTER_A, LATIN_SMALL_LETTER_Z,
+        LINE_FEED, LINE_SEPARATOR, LOW_LINE, MAX_CODE_POINT, PARAGRAPH_SEPARATOR,
+    },
+    validator::CharacterKind,
+    AllArenas, CodePoint, StringSet,
+};
+
+/// A set of code points, stored as sorted ranges that neither overlap nor
+/// touch, so that equal sets compare equal
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    _ranges: Vec<(CodePoint, CodePoint)>,
+}
+
+impl CharSet {
+    pub fn empty() -> Self {
+        Default::default()
+    }
+
+    /// Every code point, `0..=0x10FFFF`
+    pub fn all() -> Self {
+        Self {
+            _ranges: vec![(0, MAX_CODE_POINT)],
+        }
+    }
+
+    pub fn from_code_point(cp: CodePoint) -> Self {
+        Self {
+            _ranges: vec![(cp, cp)],
+        }
+    }
+
+    /// The ranges can be in any order, and can overlap
+    pub fn from_ranges(ranges: impl IntoIterator<Item = RangeInclusive<CodePoint>>) -> Self {
+        let mut ranges = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| (*range.start(), *range.end()))
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut merged: Vec<(CodePoint, CodePoint)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        Self { _ranges: merged }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<CodePoint>> + '_ {
+        self._ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self._ranges.is_empty()
+    }
+
+    /// The number of code points in the set
+    pub fn len(&self) -> usize {
+        self._ranges
+            .iter()
+            .map(|&(start, end)| (end - start) as usize + 1)
+            .sum()
+    }
+
+    pub fn contains(&self, cp: CodePoint) -> bool {
+        let index = self._ranges.partition_point(|&(_, end)| end < cp);
+        self._ranges
+            .get(index)
+            .is_some_and(|&(start, _)| start <= cp)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_ranges(self.ranges().chain(other.ranges()))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(start, end)), Some(&(other_start, other_end))) =
+            (self._ranges.get(i), other._ranges.get(j))
+        {
+            let (start, end) = (start.max(other_start), end.min(other_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if self._ranges[i].1 < other_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { _ranges: ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Everything in `0..=0x10FFFF` that isn't in the set
+    pub fn complement(&self) -> Self {
+        let mut ranges = vec![];
+        let mut next = 0;
+        for &(start, end) in &self._ranges {
+            if start > next {
+                ranges.push((next, start - 1));
+            }
+            next = end + 1;
+        }
+        if next <= MAX_CODE_POINT {
+            ranges.push((next, MAX_CODE_POINT));
+        }
+        Self { _ranges: ranges }
+    }
+
+    /// The code points with a Unicode property, as `\p{key=value}` (or
+    /// `\p{key}` for a binary property) matches them in the ECMAScript
+    /// version, or `None` if the version doesn't have the property
+    pub fn from_property(version: EcmaVersion, key: &str, value: Option<&str>) -> Option<Self> {
+        unicode_property_char_set(version, key, value)
+    }
+
+    /// What the node matches, which has to be a `Character`,
+    /// `CharacterSet`, `CharacterClass`, `CharacterClassRange`,
+    /// `ClassIntersection`, `ClassSubtraction`, `ClassStringDisjunction`
+    /// or `ExpressionCharacterClass`
+    ///
+    /// With the `i` flag, the set includes everything that matches
+    /// case-insensitively. Outside of unicode mode, where a pattern matches
+    /// code units rather than code points, nothing above `0xFFFF` is ever
+    /// included. A class that contains strings is a [`CharSetError::Strings`]
+    /// (see [`StringSet::from_node`] for those), but one that only can, like
+    /// `[\q{a|bc}--\q{bc}]`, is fine
+    pub fn from_node(
+        arena: &AllArenas,
+        node: impl Into<Id<Node>>,
+        flags: CharSetFlags,
+    ) -> Result<Self, CharSetError> {
+        let id = node.into();
+        let node = arena.node(id);
+        let closed = |set: Self| {
+            if flags.ignore_case {
+                close_over_case(&set, flags.unicode_mode())
+            } else {
+                set
+            }
+        };
+        let negated = |set: Self, negate: bool| {
+            if negate {
+                flags.all().difference(&set)
+            } else {
+                set
+            }
+        };
+        Ok(match &*node {
+            Node::Character(node) => closed(Self::from_code_point(node.value)),
+            Node::CharacterClassRange(node) => {
+                let min = node.min.get(arena).value;
+                let max = node.max.get(arena).value;
+                closed(Self::from_ranges([min..=max]))
+            }
+            Node::CharacterSet(node) if node.strings != Some(true) => match node.kind {
+                CharacterKind::Any if flags.dot_all => flags.all(),
+                CharacterKind::Any => flags.all().difference(&Self::from_ranges(
+                    [
+                        LINE_FEED,
+                        CARRIAGE_RETURN,
+                        LINE_SEPARATOR,
+                        PARAGRAPH_SEPARATOR,
+                    ]
+                    .map(|cp| cp..=cp),
+                )),
+                CharacterKind::Digit => negated(
+                    Self::from_ranges([DIGIT_ZERO..=DIGIT_NINE]),
+                    node.negate == Some(true),
+                ),
+                CharacterKind::Space => negated(white_space(), node.negate == Some(true)),
+                CharacterKind::Word => negated(
+                    closed(Self::from_ranges([
+                        DIGIT_ZERO..=DIGIT_NINE,
+                        LATIN_CAPITAL_LETTER_A..=LATIN_CAPITAL_LETTER_Z,
+                        LOW_LINE..=LOW_LINE,
+                        LATIN_SMALL_LETTER_A..=LATIN_SMALL_LETTER_Z,
+                    ])),
+                    node.negate == Some(true),
+                ),
+                CharacterKind::Property => {
+                    let key = String::try_from(node.key.as_ref().unwrap()).unwrap();
+                    let value = node
+                        .value
+                        .as_ref()
+                        .map(|value| String::try_from(value).unwrap());
+                    let set = Self::from_property(flags.ecma_version, &key, value.as_deref())
+                        .ok_or(CharSetError::UnknownProperty)?;
+                    // Only `v` mode case folds `\P{..}`'s property before
+                    // complementing it, so eg `/\P{Lu}/iu` matches "A" (as "a"
+                    // isn't `Lu`) but `/\P{Lu}/iv` doesn't
+                    if flags.unicode_sets {
+                        negated(closed(set), node.negate == Some(true))
+                    } else {
+                        closed(negated(set, node.negate == Some(true)))
+                    }
+                }
+            },
+            Node::CharacterSet(_)
+            | Node::CharacterClass(_)
+            | Node::ClassStringDisjunction(_)
+            | Node::ClassIntersection(_)
+            | Node::ClassSubtraction(_)
+            | Node::ExpressionCharacterClass(_) => {
+                StringSet::from_node(arena, id, flags)?.try_into()?
+            }
+            _ => return Err(CharSetError::NotACharacterClass),
+        })
+    }
+}
+
+/// `WhiteSpace` and `LineTerminator`, which is what `\s` matches
+fn white_space() -> CharSet {
+    CharSet::from_ranges([
+        0x09..=0x0d,
+        0x20..=0x20,
+        0xa0..=0xa0,
+        0x1680..=0x1680,
+        0x2000..=0x200a,
+        LINE_SEPARATOR..=PARAGRAPH_SEPARATOR,
+        0x202f..=0x202f,
+        0x205f..=0x205f,
+        0x3000..=0x3000,
+        0xfeff..=0xfeff,
+    ])
+}
+
+/// The flags that change what a character class matches, along with the
+/// ECMAScript version whose Unicode data properties come from
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CharSetFlags {
+    pub ignore_case: bool,
+    pub dot_all: bool,
+    pub unicode: bool,
+    pub unicode_sets: bool,
+    pub ecma_version: EcmaVersion,
+}
+
+impl Default for CharSetFlags {
+    fn default() -> Self {
+        Self {
+            ignore_case: Default::default(),
+            dot_all: Default::default(),
+            unicode: Default::default(),
+            unicode_sets: Default::default(),
+            ecma_version: LATEST_ECMA_VERSION,
+        }
+    }
+}
+
+impl CharSetFlags {
+    /// The flags in effect at the node: those of the `RegExpLiteral` it's
+    /// in (if any), as changed by the modifiers of the groups it's inside
+    pub fn at(arena: &AllArenas, node: impl Into<Id<Node>>) -> Self {
+        let node = node.into();
+        let mut flags = Self::default();
+        let ancestors = arena.ancestors(node).collect::<Vec<_>>();
+        if let Some(Node::RegExpLiteral(literal)) =
+            ancestors.last().map(|&root| arena.node(root)).as_deref()
+        {
+            let literal_flags = literal.flags.get(arena);
+            flags.ignore_case = literal_flags.ignore_case;
+            flags.dot_all = literal_flags.dot_all;
+            flags.unicode = literal_flags.unicode;
+            flags.unicode_sets = literal_flags.unicode_sets;
+        }
+        // Outermost first, so that inner groups' modifiers win
+        for &ancestor in ancestors.iter().rev() {
+            let Node::Group(group) = &*arena.node(ancestor) else {
+                continue;
+            };
+            let Some(modifiers) = group.modifiers else {
+                continue;
+            };
+            let modifiers = modifiers.get(arena);
+            let add = modifiers.add.get(arena);
+            flags.ignore_case |= add.ignore_case;
+            flags.dot_all |= add.dot_all;
+            if let Some(remove) = modifiers.remove {
+                let remove = remove.get(arena);
+                flags.ignore_case &= !remove.ignore_case;
+                flags.dot_all &= !remove.dot_all;
+            }
+        }
+        flags
+    }
+
+    pub(crate) fn unicode_mode(self) -> bool {
+        self.unicode || self.unicode_sets
+    }
+
+    /// Everything that can be matched: code points in unicode mode, code
+    /// units otherwise
+    pub(crate) fn all(self) -> CharSet {
+        if self.unicode_mode() {
+            CharSet::all()
+        } else {
+            CharSet::from_ranges([0..=0xffff])
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CharSetError {
+    /// The node isn't something that matches a single character
+    NotACharacterClass,
+    /// The class can match strings of more (or fewer) than one character
+    Strings,
+    /// The `\p{..}` property isn't one the ECMAScript version has
+    UnknownProperty,
+}
+
+impl fmt::Display for CharSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotACharacterClass => write!(f, "Node isn't a character class"),
+            Self::Strings => write!(f, "Character class can match strings"),
+            Self::UnknownProperty => write!(f, "Unknown property"),
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub enum EcmaVersion {
     _2022,
     _2023,
     _2024,
+    _2025,
 }
 
 impl TryFrom<u32> for EcmaVersion {
@@ -31,6 +32,7 @@ impl TryFrom<u32> for EcmaVersion {
             2022 => EcmaVersion::_2022,
             2023 => EcmaVersion::_2023,
             2024 => EcmaVersion::_2024,
+            2025 => EcmaVersion::_2025,
             _ => return Err(format!("'{}' is not a valid ECMA version", value)),
         })
     }
@@ -46,4 +48,4 @@ impl<'de> Deserialize<'de> for EcmaVersion {
     }
 }
 
-pub const LATEST_ECMA_VERSION: EcmaVersion = EcmaVersion::_2024;
+pub const LATEST_ECMA_VERSION: EcmaVersion = EcmaVersion::_2025;
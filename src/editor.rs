@@ -0,0 +1,665 @@
+use std::{fmt, iter};
+
+use id_arena::Id;
+use itertools::Itertools;
+
+use crate::{
+    ast::{Node, NodeInterface},
+    node_ids::{
+        is_character_class_element, is_class_intersection_left, is_class_set_expression,
+        is_class_set_operand, is_class_subtraction_left, is_element, is_quantifiable_element,
+        new_unchecked, private::Sealed, AlternativeId, BackreferenceId, CapturingGroupId,
+        ElementId, NodeId,
+    },
+    validator::{CapturingGroupKey, CharacterKind},
+    AllArenas,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The node has no parent to be edited within
+    Detached,
+    /// The node can't be put where it was asked to go
+    InvalidPosition,
+    /// The edit would leave a parent without a child it requires
+    RequiredChild,
+    /// The node would end up inside of itself
+    Cycle,
+    /// Unwrapping the group would change what the pattern matches
+    NotUnwrappable,
+    /// The edit would leave a backreference without any of the capturing
+    /// groups it refers to
+    DanglingBackreference,
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Detached => write!(f, "Node has no parent"),
+            Self::InvalidPosition => write!(f, "Node can't be placed there"),
+            Self::RequiredChild => write!(f, "Parent requires this child"),
+            Self::Cycle => write!(f, "Node can't be placed inside of itself"),
+            Self::NotUnwrappable => write!(f, "Group can't be unwrapped"),
+            Self::DanglingBackreference => {
+                write!(f, "Backreference would be left without its capturing group")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Field {
+    Pattern,
+    Flags,
+    Modifiers,
+    Add,
+    Remove,
+    Element,
+    Min,
+    Max,
+    Left,
+    Right,
+    Expression,
+}
+
+/// Where a node sits within its parent
+#[derive(Copy, Clone)]
+enum Slot {
+    Index(usize),
+    Field(Field),
+}
+
+fn child_list(node: &Node) -> Option<Vec<Id<Node>>> {
+    fn ids<T: NodeId>(list: &[T]) -> Option<Vec<Id<Node>>> {
+        Some(list.iter().map(|&id| id.into()).collect())
+    }
+
+    match node {
+        Node::Pattern(node) => ids(&node.alternatives),
+        Node::Alternative(node) => ids(&node.elements),
+        Node::Group(node) => ids(&node.alternatives),
+        Node::CapturingGroup(node) => ids(&node.alternatives),
+        Node::Assertion(node) => node.alternatives.as_deref().and_then(ids),
+        Node::CharacterClass(node) => ids(&node.elements),
+        Node::ClassStringDisjunction(node) => ids(&node.alternatives),
+        Node::StringAlternative(node) => ids(&node.elements),
+        _ => None,
+    }
+}
+
+/// Edits a node's list of children as plain ids. Callers have already
+/// checked with [`fits`] that anything they add is the right kind for the
+/// list
+fn update_child_list(node: &mut Node, update: impl FnOnce(&mut Vec<Id<Node>>)) {
+    fn update_ids<T: NodeId>(list: &mut Vec<T>, update: impl FnOnce(&mut Vec<Id<Node>>)) {
+        let mut ids = list.iter().map(|&id| id.into()).collect();
+        update(&mut ids);
+        *list = ids.into_iter().map(new_unchecked).collect();
+    }
+
+    match node {
+        Node::Pattern(node) => update_ids(&mut node.alternatives, update),
+        Node::Alternative(node) => update_ids(&mut node.elements, update),
+        Node::Group(node) => update_ids(&mut node.alternatives, update),
+        Node::CapturingGroup(node) => update_ids(&mut node.alternatives, update),
+        Node::Assertion(node) => update_ids(node.alternatives.as_mut().unwrap(), update),
+        Node::CharacterClass(node) => update_ids(&mut node.elements, update),
+        Node::ClassStringDisjunction(node) => update_ids(&mut node.alternatives, update),
+        Node::StringAlternative(node) => update_ids(&mut node.elements, update),
+        _ => unreachable!(),
+    }
+}
+
+fn child_fields(node: &Node) -> Vec<(Field, Id<Node>)> {
+    match node {
+        Node::RegExpLiteral(node) => {
+            vec![
+                (Field::Pattern, node.pattern.id()),
+                (Field::Flags, node.flags.id()),
+            ]
+        }
+        Node::Group(node) => node
+            .modifiers
+            .map(|modifiers| (Field::Modifiers, modifiers.id()))
+            .into_iter()
+            .collect(),
+        Node::Modifiers(node) => iter::once((Field::Add, node.add.id()))
+            .chain(node.remove.map(|remove| (Field::Remove, remove.id())))
+            .collect(),
+        Node::Quantifier(node) => vec![(Field::Element, node.element.id())],
+        Node::CharacterClassRange(node) => {
+            vec![(Field::Min, node.min.id()), (Field::Max, node.max.id())]
+        }
+        Node::ClassIntersection(node) => {
+            vec![
+                (Field::Left, node.left.id()),
+                (Field::Right, node.right.id()),
+            ]
+        }
+        Node::ClassSubtraction(node) => {
+            vec![
+                (Field::Left, node.left.id()),
+                (Field::Right, node.right.id()),
+            ]
+        }
+        Node::ExpressionCharacterClass(node) => vec![(Field::Expression, node.expression.id())],
+        _ => Default::default(),
+    }
+}
+
+fn set_child_field(
+    node: &mut Node,
+    field: Field,
+    child: Option<Id<Node>>,
+) -> Result<(), EditError> {
+    match (node, field, child) {
+        (Node::Group(node), Field::Modifiers, child) => node.modifiers = child.map(new_unchecked),
+        (Node::Modifiers(node), Field::Remove, child) => node.remove = child.map(new_unchecked),
+        (_, _, None) => return Err(EditError::RequiredChild),
+        (Node::RegExpLiteral(node), Field::Pattern, Some(child)) => {
+            node.pattern = new_unchecked(child)
+        }
+        (Node::RegExpLiteral(node), Field::Flags, Some(child)) => node.flags = new_unchecked(child),
+        (Node::Modifiers(node), Field::Add, Some(child)) => node.add = new_unchecked(child),
+        (Node::Quantifier(node), Field::Element, Some(child)) => {
+            node.element = new_unchecked(child)
+        }
+        (Node::CharacterClassRange(node), Field::Min, Some(child)) => {
+            node.min = new_unchecked(child)
+        }
+        (Node::CharacterClassRange(node), Field::Max, Some(child)) => {
+            node.max = new_unchecked(child)
+        }
+        (Node::ClassIntersection(node), Field::Left, Some(child)) => {
+            node.left = new_unchecked(child)
+        }
+        (Node::ClassIntersection(node), Field::Right, Some(child)) => {
+            node.right = new_unchecked(child)
+        }
+        (Node::ClassSubtraction(node), Field::Left, Some(child)) => {
+            node.left = new_unchecked(child)
+        }
+        (Node::ClassSubtraction(node), Field::Right, Some(child)) => {
+            node.right = new_unchecked(child)
+        }
+        (Node::ExpressionCharacterClass(node), Field::Expression, Some(child)) => {
+            node.expression = new_unchecked(child)
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+/// Whether `node` can sit at `slot` within `parent`
+fn fits(parent: &Node, slot: Slot, node: &Node) -> bool {
+    match (parent, slot) {
+        (
+            Node::Pattern(_) | Node::Group(_) | Node::CapturingGroup(_) | Node::Assertion(_),
+            Slot::Index(_),
+        ) => matches!(node, Node::Alternative(_)),
+        (Node::Alternative(_), Slot::Index(_)) => is_element(node),
+        (Node::ClassStringDisjunction(_), Slot::Index(_)) => {
+            matches!(node, Node::StringAlternative(_))
+        }
+        (Node::StringAlternative(_), Slot::Index(_)) => matches!(node, Node::Character(_)),
+        (Node::CharacterClass(parent), Slot::Index(_)) if parent.unicode_sets => {
+            is_character_class_element(node)
+        }
+        (Node::CharacterClass(_), Slot::Index(_)) => match node {
+            Node::Character(_) | Node::CharacterClassRange(_) => true,
+            Node::CharacterSet(node) => {
+                node.kind != CharacterKind::Any && !node.strings.unwrap_or_default()
+            }
+            _ => false,
+        },
+        (_, Slot::Field(Field::Pattern)) => matches!(node, Node::Pattern(_)),
+        (_, Slot::Field(Field::Flags)) => matches!(node, Node::Flags(_)),
+        (_, Slot::Field(Field::Modifiers)) => matches!(node, Node::Modifiers(_)),
+        (_, Slot::Field(Field::Add | Field::Remove)) => matches!(node, Node::ModifierFlags(_)),
+        (_, Slot::Field(Field::Element)) => is_quantifiable_element(node),
+        (_, Slot::Field(Field::Min | Field::Max)) => matches!(node, Node::Character(_)),
+        // `a&&b&&c` nests to the left, so a left operand may be another
+        // operation of the same kind
+        (Node::ClassIntersection(_), Slot::Field(Field::Left)) => is_class_intersection_left(node),
+        (Node::ClassSubtraction(_), Slot::Field(Field::Left)) => is_class_subtraction_left(node),
+        (_, Slot::Field(Field::Right)) => is_class_set_operand(node),
+        (_, Slot::Field(Field::Expression)) => is_class_set_expression(node),
+        _ => false,
+    }
+}
+
+/// Rewrites trees in place while keeping them consistent: parent links are
+/// updated, backreferences keep referring to the same capturing groups
+/// (numbered ones are renumbered if their group moves), and the edited
+/// node's ancestors (along with anything moved into the tree) are marked as
+/// [synthesized](NodeInterface::is_synthesized), since their `start`/`end`/
+/// `raw` no longer describe the source they came from
+///
+/// A node that's moved into a new position is detached from wherever it
+/// was first. An edit that would leave a backreference without any of its
+/// capturing groups fails with [`EditError::DanglingBackreference`] and
+/// leaves the trees as they were
+pub struct RegExpEditor<'a> {
+    _arena: &'a AllArenas,
+}
+
+impl<'a> RegExpEditor<'a> {
+    pub fn new(arena: &'a AllArenas) -> Self {
+        Self { _arena: arena }
+    }
+
+    pub fn replace(
+        &self,
+        node: impl Into<Id<Node>>,
+        new: impl Into<Id<Node>>,
+    ) -> Result<(), EditError> {
+        let (node, new) = (node.into(), new.into());
+        if node == new {
+            return Ok(());
+        }
+        self.transaction(&[node, new], || {
+            let parent = self.parent(node)?;
+            self.check_placement(parent, self.slot(parent, node), new)?;
+            let previous_parent = self.detach(new)?;
+            let slot = self.slot(parent, node);
+            self.put(parent, slot, new);
+            self._arena.node_mut(node).set_parent(None);
+            self.mark_subtree_synthesized(new);
+            self.finish_edit(parent, Some(node));
+            if let Some(previous_parent) = previous_parent {
+                self.finish_edit(previous_parent, None);
+            }
+            Ok(())
+        })
+    }
+
+    pub fn remove(&self, node: impl Into<Id<Node>>) -> Result<(), EditError> {
+        let node = node.into();
+        self.transaction(&[node], || {
+            let parent = self.cut(node)?;
+            self.finish_edit(parent, Some(node));
+            Ok(())
+        })
+    }
+
+    /// Takes a node out of its parent, returning the parent
+    fn cut(&self, node: Id<Node>) -> Result<Id<Node>, EditError> {
+        let parent = self.parent(node)?;
+        match self.slot(parent, node) {
+            Slot::Index(index) => {
+                let mut parent = self._arena.node_mut(parent);
+                let requires_alternative = matches!(
+                    &*parent,
+                    Node::Pattern(_)
+                        | Node::Group(_)
+                        | Node::CapturingGroup(_)
+                        | Node::Assertion(_)
+                        | Node::ClassStringDisjunction(_)
+                );
+                if requires_alternative && child_list(&parent).unwrap().len() == 1 {
+                    return Err(EditError::RequiredChild);
+                }
+                update_child_list(&mut parent, |list| {
+                    list.remove(index);
+                });
+            }
+            Slot::Field(field) => {
+                set_child_field(&mut self._arena.node_mut(parent), field, None)?;
+            }
+        }
+        self._arena.node_mut(node).set_parent(None);
+        Ok(parent)
+    }
+
+    pub fn insert_before(
+        &self,
+        node: impl Into<Id<Node>>,
+        new: impl Into<Id<Node>>,
+    ) -> Result<(), EditError> {
+        self.insert(node.into(), new.into(), 0)
+    }
+
+    pub fn insert_after(
+        &self,
+        node: impl Into<Id<Node>>,
+        new: impl Into<Id<Node>>,
+    ) -> Result<(), EditError> {
+        self.insert(node.into(), new.into(), 1)
+    }
+
+    fn insert(&self, node: Id<Node>, new: Id<Node>, offset: usize) -> Result<(), EditError> {
+        self.transaction(&[node, new], || {
+            let parent = self.parent(node)?;
+            if node == new {
+                return Err(EditError::Cycle);
+            }
+            self.check_placement(parent, self.slot(parent, node), new)?;
+            let previous_parent = self.detach(new)?;
+            let index = match self.slot(parent, node) {
+                Slot::Index(index) => index,
+                Slot::Field(_) => return Err(EditError::InvalidPosition),
+            };
+            update_child_list(&mut self._arena.node_mut(parent), |list| {
+                list.insert(index + offset, new)
+            });
+            self._arena.node_mut(new).set_parent(Some(parent));
+            self.mark_subtree_synthesized(new);
+            self.finish_edit(parent, None);
+            if let Some(previous_parent) = previous_parent {
+                self.finish_edit(previous_parent, None);
+            }
+            Ok(())
+        })
+    }
+
+    /// Wraps an element in a non-capturing group, returning the group
+    pub fn wrap_in_group(
+        &self,
+        node: impl Into<Id<Node>>,
+    ) -> Result<Id<Node> /*Group*/, EditError> {
+        let node = node.into();
+        let parent = self.parent(node)?;
+        let slot = self.slot(parent, node);
+        if !matches!(
+            (&*self._arena.node(parent), slot),
+            (Node::Alternative(_), Slot::Index(_)) | (Node::Quantifier(_), Slot::Field(_))
+        ) {
+            return Err(EditError::InvalidPosition);
+        }
+
+        let (start, end, raw) = {
+            let node = self._arena.node(node);
+            (node.start(), node.end(), node.raw().to_owned())
+        };
+        let alternative = self._arena.alloc_node(Node::new_alternative(
+            None,
+            start,
+            end,
+            raw.clone().into(),
+            vec![ElementId::new_unchecked(node)],
+        ));
+        let group = self._arena.alloc_node(Node::new_group(
+            None,
+            start,
+            end,
+            "(?:"
+                .encode_utf16()
+                .chain(raw)
+                .chain(")".encode_utf16())
+                .collect::<Vec<_>>()
+                .into(),
+            None,
+            vec![AlternativeId::new_unchecked(alternative)],
+        ));
+        self.put(parent, slot, group);
+        self._arena.node_mut(node).set_parent(Some(alternative));
+        self._arena.node_mut(alternative).set_parent(Some(group));
+        self._arena.node_mut(alternative).set_synthesized(true);
+        self.finish_edit(group, None);
+        Ok(group)
+    }
+
+    /// Replaces a non-capturing group with its contents. Only a group
+    /// without modifiers and with a single alternative can be unwrapped,
+    /// and inside of a quantifier that alternative has to be a single
+    /// quantifiable element
+    pub fn unwrap_group(&self, group: impl Into<Id<Node>>) -> Result<(), EditError> {
+        let group = group.into();
+        let parent = self.parent(group)?;
+        let alternative = match &*self._arena.node(group) {
+            Node::Group(node) if node.modifiers.is_none() && node.alternatives.len() == 1 => {
+                node.alternatives[0]
+            }
+            _ => return Err(EditError::NotUnwrappable),
+        };
+        let elements = alternative
+            .get(self._arena)
+            .elements
+            .iter()
+            .map(|&element| element.id())
+            .collect::<Vec<_>>();
+        match self.slot(parent, group) {
+            Slot::Index(index) if matches!(&*self._arena.node(parent), Node::Alternative(_)) => {
+                update_child_list(&mut self._arena.node_mut(parent), |list| {
+                    list.splice(index..=index, elements.iter().copied());
+                });
+            }
+            slot @ Slot::Field(Field::Element) => match &*elements {
+                [element] if is_quantifiable_element(&self._arena.node(*element)) => {
+                    self.put(parent, slot, *element);
+                }
+                _ => return Err(EditError::NotUnwrappable),
+            },
+            _ => return Err(EditError::NotUnwrappable),
+        }
+        for &element in &elements {
+            self._arena.node_mut(element).set_parent(Some(parent));
+        }
+        self._arena.node_mut(group).set_parent(None);
+        self.finish_edit(parent, None);
+        Ok(())
+    }
+
+    fn parent(&self, node: Id<Node>) -> Result<Id<Node>, EditError> {
+        self._arena
+            .node(node)
+            .maybe_parent()
+            .ok_or(EditError::Detached)
+    }
+
+    fn slot(&self, parent: Id<Node>, child: Id<Node>) -> Slot {
+        let parent = self._arena.node(parent);
+        if let Some(index) =
+            child_list(&parent).and_then(|list| list.iter().position(|&node| node == child))
+        {
+            return Slot::Index(index);
+        }
+        child_fields(&parent)
+            .into_iter()
+            .find(|&(_, node)| node == child)
+            .map(|(field, _)| Slot::Field(field))
+            .unwrap()
+    }
+
+    fn check_placement(
+        &self,
+        parent: Id<Node>,
+        slot: Slot,
+        new: Id<Node>,
+    ) -> Result<(), EditError> {
+        if self
+            .self_and_ancestors(parent)
+            .any(|ancestor| ancestor == new)
+        {
+            return Err(EditError::Cycle);
+        }
+        if !fits(&self._arena.node(parent), slot, &self._arena.node(new)) {
+            return Err(EditError::InvalidPosition);
+        }
+        Ok(())
+    }
+
+    /// Removes a node that's about to be moved from wherever it currently
+    /// is, returning its previous parent
+    fn detach(&self, node: Id<Node>) -> Result<Option<Id<Node>>, EditError> {
+        if self._arena.node(node).maybe_parent().is_none() {
+            return Ok(None);
+        }
+        self.cut(node).map(Some)
+    }
+
+    fn put(&self, parent: Id<Node>, slot: Slot, child: Id<Node>) {
+        match slot {
+            Slot::Index(index) => {
+                update_child_list(&mut self._arena.node_mut(parent), |list| {
+                    list[index] = child
+                });
+            }
+            Slot::Field(field) => {
+                set_child_field(&mut self._arena.node_mut(parent), field, Some(child)).unwrap();
+            }
+        }
+        self._arena.node_mut(child).set_parent(Some(parent));
+    }
+
+    fn self_and_ancestors(&self, node: Id<Node>) -> impl Iterator<Item = Id<Node>> + '_ {
+        iter::once(node).chain(self._arena.ancestors(node))
+    }
+
+    fn self_and_descendants(&self, node: Id<Node>) -> impl Iterator<Item = Id<Node>> + '_ {
+        iter::once(node).chain(self._arena.descendants(node))
+    }
+
+    fn mark_subtree_synthesized(&self, node: Id<Node>) {
+        for node in self.self_and_descendants(node) {
+            self._arena.node_mut(node).set_synthesized(true);
+        }
+    }
+
+    /// Marks everything from `edited` up as synthesized and re-links
+    /// backreferences in the subtree (if any) that was cut out of the tree
+    fn finish_edit(&self, edited: Id<Node>, removed: Option<Id<Node>>) {
+        for ancestor in self.self_and_ancestors(edited) {
+            self._arena.node_mut(ancestor).set_synthesized(true);
+        }
+        if let Some(removed) = removed {
+            relink_backreferences(self._arena, removed, false).unwrap();
+        }
+    }
+
+    fn root(&self, node: Id<Node>) -> Id<Node> {
+        self.self_and_ancestors(node).last().unwrap()
+    }
+
+    /// Runs an edit of the trees that `nodes` are in and then re-links
+    /// their backreferences, putting every node in those trees back the way
+    /// it was if either fails
+    fn transaction<T>(
+        &self,
+        nodes: &[Id<Node>],
+        edit: impl FnOnce() -> Result<T, EditError>,
+    ) -> Result<T, EditError> {
+        let roots = nodes
+            .iter()
+            .map(|&node| self.root(node))
+            .unique()
+            .collect::<Vec<_>>();
+        let snapshot = roots
+            .iter()
+            .flat_map(|&root| self.self_and_descendants(root))
+            .map(|node| (node, self._arena.node(node).clone()))
+            .collect::<Vec<_>>();
+        let result = edit().and_then(|value| {
+            for &root in &roots {
+                if self._arena.node(root).maybe_parent().is_none() {
+                    relink_backreferences(self._arena, root, true)?;
+                }
+            }
+            Ok(value)
+        });
+        if result.is_err() {
+            for (node, saved) in snapshot {
+                *self._arena.node_mut(node) = saved;
+            }
+        }
+        result
+    }
+}
+
+/// The tree's capturing groups (in source order, with their references
+/// cleared) and backreferences
+fn links(arena: &AllArenas, root: Id<Node>) -> (Vec<CapturingGroupId>, Vec<BackreferenceId>) {
+    let mut capturing_groups: Vec<CapturingGroupId> = Default::default();
+    let mut backreferences: Vec<BackreferenceId> = Default::default();
+    for node in iter::once(root).chain(arena.descendants(root)) {
+        match &mut *arena.node_mut(node) {
+            Node::CapturingGroup(group) => {
+                group.references.clear();
+                capturing_groups.push(CapturingGroupId::new_unchecked(node));
+            }
+            Node::Backreference(_) => backreferences.push(BackreferenceId::new_unchecked(node)),
+            _ => (),
+        }
+    }
+    (capturing_groups, backreferences)
+}
+
+fn resolve(
+    arena: &AllArenas,
+    capturing_groups: &[CapturingGroupId],
+    ref_: &CapturingGroupKey,
+) -> Vec<CapturingGroupId> {
+    match ref_ {
+        CapturingGroupKey::Index(ref_) => ref_
+            .checked_sub(1)
+            .and_then(|index| capturing_groups.get(index))
+            .copied()
+            .into_iter()
+            .collect(),
+        CapturingGroupKey::Name(ref_) => capturing_groups
+            .iter()
+            .filter(|&&group| group.get(arena).name.as_ref() == Some(ref_))
+            .copied()
+            .collect(),
+    }
+}
+
+/// Links backreferences to capturing groups the same way the parser
+/// does, by index or by name
+pub(crate) fn resolve_backreferences(arena: &AllArenas, root: Id<Node>) {
+    let (capturing_groups, backreferences) = links(arena, root);
+    for reference in backreferences {
+        let groups = resolve(arena, &capturing_groups, &reference.get(arena).ref_);
+        for &group in &groups {
+            group.get_mut(arena).references.push(reference);
+        }
+        reference.get_mut(arena).resolved = groups;
+    }
+}
+
+/// Links backreferences to capturing groups after an edit. A numbered
+/// backreference keeps its links to groups that are still in the tree and
+/// is renumbered to match where they now are, a named one is resolved by
+/// name, and one that hasn't been linked yet is resolved like the parser
+/// does
+///
+/// A backreference that loses all of its groups is an error if `strict`,
+/// otherwise (in a subtree that was cut out of its tree) it keeps its
+/// links to groups outside of the subtree
+fn relink_backreferences(arena: &AllArenas, root: Id<Node>, strict: bool) -> Result<(), EditError> {
+    let (capturing_groups, backreferences) = links(arena, root);
+    for reference in backreferences {
+        let (ref_, previous) = {
+            let reference = reference.get(arena);
+            (reference.ref_.clone(), reference.resolved.clone())
+        };
+        let groups = match &ref_ {
+            CapturingGroupKey::Index(_) if !previous.is_empty() => previous
+                .iter()
+                .filter(|group| capturing_groups.contains(group))
+                .copied()
+                .collect(),
+            ref_ => resolve(arena, &capturing_groups, ref_),
+        };
+        if groups.is_empty() && !previous.is_empty() {
+            if strict {
+                return Err(EditError::DanglingBackreference);
+            }
+            continue;
+        }
+        for &group in &groups {
+            group.get_mut(arena).references.push(reference);
+        }
+        if let (CapturingGroupKey::Index(index), Some(group)) = (&ref_, groups.first()) {
+            let renumbered = capturing_groups.iter().position(|g| g == group).unwrap() + 1;
+            if renumbered != *index {
+                reference.get_mut(arena).ref_ = CapturingGroupKey::Index(renumbered);
+                for node in iter::once(reference.id()).chain(arena.ancestors(reference)) {
+                    arena.node_mut(node).set_synthesized(true);
+                }
+            }
+        }
+        reference.get_mut(arena).resolved = groups;
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use id_arena::Id;
+
+use crate::{
+    ast::{Node, NodeInterface, NodeKind},
+    editor::resolve_backreferences,
+    node_ids::NodeId,
+    AllArenas,
+};
+
+/// Copies a tree bottom-up into a target arena (which may be the one the
+/// tree is already in), giving a [`Fold`] the chance to replace each node
+/// along the way. The source tree is left as it was
+pub struct RegExpFolder<'a> {
+    _source: &'a AllArenas,
+    _target: &'a AllArenas,
+}
+
+impl<'a> RegExpFolder<'a> {
+    pub fn new(source: &'a AllArenas, target: &'a AllArenas) -> Self {
+        Self {
+            _source: source,
+            _target: target,
+        }
+    }
+
+    /// Returns the root of the new tree. Backreferences in it are resolved
+    /// against its capturing groups once it's complete
+    ///
+    /// Panics if a fold method returns a node that can't go where the node
+    /// it replaces was, e.g. a `Character` in place of an `Alternative`
+    pub fn fold(&self, node: Id<Node>, folder: &mut impl Fold) -> Id<Node> {
+        let (root, _) = self.fold_node(node, folder);
+        resolve_backreferences(self._target, root);
+        root
+    }
+
+    /// Also returns whether anything in the subtree was replaced
+    fn fold_node(&self, node: Id<Node>, folder: &mut impl Fold) -> (Id<Node>, bool) {
+        let children = self._source.node(node).children();
+        let mut changed = false;
+        let children = children
+            .map(|child| {
+                let (child, child_changed) = self.fold_node(child, folder);
+                changed |= child_changed;
+                child
+            })
+            .collect::<Vec<_>>();
+
+        let copy = with_children(self._target, self._source.node(node).clone(), &children);
+        let copy = self._target.alloc_node(copy);
+        for &child in &children {
+            self._target.node_mut(child).set_parent(Some(copy));
+        }
+        if changed {
+            // Its `raw` still has the children it was parsed with
+            self._target.node_mut(copy).set_synthesized(true);
+        }
+
+        let kind = self._target.node(copy).kind();
+        let folded = fold_kind(folder, kind, copy, self._target);
+        self._target.node_mut(folded).set_parent(None);
+        (folded, changed || folded != copy)
+    }
+}
+
+pub(crate) fn typed<T: NodeId>(arena: &AllArenas, id: Id<Node>, parent: NodeKind) -> T {
+    T::from_id(arena, id).unwrap_or_else(|| {
+        panic!(
+            "a {:?} can't be a child of a {:?} there",
+            arena.node(id).kind(),
+            parent
+        )
+    })
+}
+
+/// Swaps in new children, in the order that [`Node::children`] lists them.
+/// Links to other parts of the tree are dropped, to be resolved again
+/// later
+fn with_children(arena: &AllArenas, mut node: Node, children: &[Id<Node>]) -> Node {
+    let kind = node.kind();
+    let mut children = children.iter().copied();
+    let mut next = || children.next().unwrap();
+    node.set_parent(None);
+    match &mut node {
+        Node::RegExpLiteral(node) => {
+            node.pattern = typed(arena, next(), kind);
+            node.flags = typed(arena, next(), kind);
+        }
+        Node::Pattern(node) => {
+            node.alternatives = node
+                .alternatives
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::Alternative(node) => {
+            node.elements = node
+                .elements
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::Group(node) => {
+            node.modifiers = node.modifiers.map(|_| typed(arena, next(), kind));
+            node.alternatives = node
+                .alternatives
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::CapturingGroup(node) => {
+            node.alternatives = node
+                .alternatives
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+            node.references.clear();
+        }
+        Node::Modifiers(node) => {
+            node.add = typed(arena, next(), kind);
+            node.remove = node.remove.map(|_| typed(arena, next(), kind));
+        }
+        Node::Assertion(node) => {
+            node.alternatives = node.alternatives.as_ref().map(|alternatives| {
+                alternatives
+                    .iter()
+                    .map(|_| typed(arena, next(), kind))
+                    .collect()
+            });
+        }
+        Node::Quantifier(node) => {
+            node.element = typed(arena, next(), kind);
+        }
+        Node::CharacterClass(node) => {
+            node.elements = node
+                .elements
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::CharacterClassRange(node) => {
+            node.min = typed(arena, next(), kind);
+            node.max = typed(arena, next(), kind);
+        }
+        Node::ClassIntersection(node) => {
+            node.left = typed(arena, next(), kind);
+            node.right = typed(arena, next(), kind);
+        }
+        Node::ClassSubtraction(node) => {
+            node.left = typed(arena, next(), kind);
+            node.right = typed(arena, next(), kind);
+        }
+        Node::ExpressionCharacterClass(node) => {
+            node.expression = typed(arena, next(), kind);
+        }
+        Node::ClassStringDisjunction(node) => {
+            node.alternatives = node
+                .alternatives
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::StringAlternative(node) => {
+            node.elements = node
+                .elements
+                .iter()
+                .map(|_| typed(arena, next(), kind))
+                .collect();
+        }
+        Node::Backreference(node) => {
+            node.resolved.clear();
+        }
+        Node::ModifierFlags(_)
+        | Node::Character(_)
+        | Node::CharacterSet(_)
+        | Node::Flags(_)
+        | Node::Error(_) => (),
+    }
+    node
+}
+
+macro_rules! fold_methods {
+    ($($kind:ident => $fold:ident;)+) => {
+        /// Each method is called with a copy of the node (already in the
+        /// target arena, with its children folded) and returns the node to
+        /// use in its place, which by default is the copy itself. A
+        /// replacement has to be a node in the target arena
+        #[allow(unused_variables)]
+        pub trait Fold {
+            $(
+                fn $fold(&mut self, node: Id<Node>, arena: &AllArenas) -> Id<Node> {
+                    node
+                }
+            )+
+        }
+
+        fn fold_kind(
+            folder: &mut impl Fold,
+            kind: NodeKind,
+            node: Id<Node>,
+            arena: &AllArenas,
+        ) -> Id<Node> {
+            match kind {
+                $(NodeKind::$kind => folder.$fold(node, arena),)+
+            }
+        }
+    };
+}
+
+fold_methods! {
+    Alternative => fold_alternative;
+    Assertion => fold_assertion;
+    Backreference => fold_backreference;
+    CapturingGroup => fold_capturing_group;
+    Character => fold_character;
+    CharacterClass => fold_character_class;
+    CharacterClassRange => fold_character_class_range;
+    CharacterSet => fold_character_set;
+    ClassIntersection => fold_class_intersection;
+    ClassStringDisjunction => fold_class_string_disjunction;
+    ClassSubtraction => fold_class_subtraction;
+    Error => fold_error;
+    ExpressionCharacterClass => fold_expression_character_class;
+    Flags => fold_flags;
+    Group => fold_group;
+    Modifiers => fold_modifiers;
+    ModifierFlags => fold_modifier_flags;
+    Pattern => fold_pattern;
+    Quantifier => fold_quantifier;
+    RegExpLiteral => fold_reg_exp_literal;
+    StringAlternative => fold_string_alternative;
+}
@@ -0,0 +1,40 @@
+use derive_builder::Builder;
+use serde::Deserialize;
+
+/// How characters that can't be written literally (control characters,
+/// line terminators, lone surrogates) are escaped
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EscapeStyle {
+    /// `\xHH` or `\uHHHH`
+    #[default]
+    Hex,
+    /// `\u{H...}`, falling back to [`EscapeStyle::Hex`] outside of
+    /// `u`/`v` mode where braced escapes aren't available
+    Braced,
+}
+
+#[derive(Builder, Copy, Clone, Default, Deserialize)]
+#[builder(default, setter(strip_option))]
+#[serde(rename_all = "camelCase")]
+pub struct FormatOptions {
+    escape_style: Option<EscapeStyle>,
+    uppercase_hex: Option<bool>,
+    sort_class_ranges: Option<bool>,
+}
+
+impl FormatOptions {
+    pub fn escape_style(&self) -> EscapeStyle {
+        self.escape_style.unwrap_or_default()
+    }
+
+    pub fn uppercase_hex(&self) -> bool {
+        self.uppercase_hex.unwrap_or_default()
+    }
+
+    /// Whether the characters and ranges of a character class get sorted,
+    /// with overlapping and adjacent ones merged
+    pub fn sort_class_ranges(&self) -> bool {
+        self.sort_class_ranges.unwrap_or(true)
+    }
+}
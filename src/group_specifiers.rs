@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+use squalid::OptionExt;
+
+use crate::Wtf16;
+
+pub trait GroupSpecifiers {
+    fn clear(&mut self);
+    fn is_empty(&self) -> bool;
+    fn has_in_pattern(&self, name: &Wtf16) -> bool;
+    fn has_in_scope(&self, name: &Wtf16) -> bool;
+    fn add_to_scope(&mut self, name: Wtf16);
+    fn enter_disjunction(&mut self);
+    fn enter_alternative(&mut self, index: usize);
+    fn leave_disjunction(&mut self);
+    fn current_branch(&self) -> usize;
+    fn restore_branch(&mut self, branch: usize);
+}
+
+#[derive(Default)]
+pub struct GroupSpecifiersAsES2018 {
+    group_name: HashSet<Wtf16>,
+}
+
+impl GroupSpecifiers for GroupSpecifiersAsES2018 {
+    fn clear(&mut self) {
+        self.group_name.clear();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.group_name.is_empty()
+    }
+
+    fn has_in_pattern(&self, name: &Wtf16) -> bool {
+        self.group_name.contains(name)
+    }
+
+    fn has_in_scope(&self, name: &Wtf16) -> bool {
+        self.has_in_pattern(name)
+    }
+
+    fn add_to_scope(&mut self, name: Wtf16) {
+        self.group_name.insert(name);
+    }
+
+    fn enter_disjunction(&mut self) {}
+
+    fn enter_alternative(&mut self, _index: usize) {}
+
+    fn leave_disjunction(&mut self) {}
+
+    fn current_branch(&self) -> usize {
+        0
+    }
+
+    fn restore_branch(&mut self, _branch: usize) {}
+}
+
+#[derive(Copy, Clone)]
+struct BranchId {
+    parent: Option<usize>,
+    base: usize,
+}
+
+/// Tracks the alternative each group name was declared in, so that the
+/// same name may be reused in alternatives that can never both participate
+/// in a match
+pub struct GroupSpecifiersAsES2025 {
+    branches: Vec<BranchId>,
+    branch_id: usize,
+    group_names: HashMap<Wtf16, Vec<usize>>,
+}
+
+impl Default for GroupSpecifiersAsES2025 {
+    fn default() -> Self {
+        Self {
+            branches: vec![BranchId {
+                parent: None,
+                base: 0,
+            }],
+            branch_id: 0,
+            group_names: Default::default(),
+        }
+    }
+}
+
+impl GroupSpecifiersAsES2025 {
+    fn separated_from(&self, branch_id: usize, other: usize) -> bool {
+        let branch = self.branches[branch_id];
+        let other_branch = self.branches[other];
+        if branch.base == other_branch.base && branch_id != other {
+            return true;
+        }
+        if other_branch
+            .parent
+            .matches(|other_parent| self.separated_from(branch_id, other_parent))
+        {
+            return true;
+        }
+        branch
+            .parent
+            .matches(|parent| self.separated_from(parent, other))
+    }
+
+    fn child(&mut self) -> usize {
+        let id = self.branches.len();
+        self.branches.push(BranchId {
+            parent: Some(self.branch_id),
+            base: id,
+        });
+        id
+    }
+
+    fn sibling(&mut self) -> usize {
+        let branch = self.branches[self.branch_id];
+        self.branches.push(BranchId {
+            parent: branch.parent,
+            base: branch.base,
+        });
+        self.branches.len() - 1
+    }
+}
+
+impl GroupSpecifiers for GroupSpecifiersAsES2025 {
+    fn clear(&mut self) {
+        *self = Default::default();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.group_names.is_empty()
+    }
+
+    fn has_in_pattern(&self, name: &Wtf16) -> bool {
+        self.group_names.contains_key(name)
+    }
+
+    fn has_in_scope(&self, name: &Wtf16) -> bool {
+        self.group_names.get(name).matches(|branches| {
+            branches
+                .iter()
+                .any(|&branch| !self.separated_from(branch, self.branch_id))
+        })
+    }
+
+    fn add_to_scope(&mut self, name: Wtf16) {
+        self.group_names
+            .entry(name)
+            .or_default()
+            .push(self.branch_id);
+    }
+
+    fn enter_disjunction(&mut self) {
+        self.branch_id = self.child();
+    }
+
+    fn enter_alternative(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+        self.branch_id = self.sibling();
+    }
+
+    fn leave_disjunction(&mut self) {
+        self.branch_id = self.branches[self.branch_id].parent.unwrap();
+    }
+
+    fn current_branch(&self) -> usize {
+        self.branch_id
+    }
+
+    fn restore_branch(&mut self, branch: usize) {
+        self.branch_id = branch;
+    }
+}
@@ -3,26 +3,55 @@
 /// Derived from [regexpp](https://github.com/eslint-community/regexpp)
 mod arena;
 mod ast;
+mod builder;
+mod char_set;
 mod ecma_versions;
+mod editor;
+pub mod fold;
+mod formatter;
+mod group_specifiers;
+mod matcher;
+pub mod node_ids;
+pub mod owned;
 mod parser;
+mod printer;
 mod reader;
 mod regexp_syntax_error;
+mod selector;
+mod serialize;
+mod string_set;
 #[cfg(test)]
 mod test;
-mod unicode;
+pub mod unicode;
 pub mod validator;
 pub mod visitor;
 mod wtf16;
 
-pub use arena::AllArenas;
-pub use ast::{Node, NodeInterface};
+use std::rc::Rc;
+
+pub use arena::{AllArenas, Event, FrozenArena, Traversal};
+pub use ast::{ChildField, Node, NodeInterface, NodeKind};
+pub use builder::{
+    AlternativeBuilder, ClassBuilder, ClassSetOperand, ClassSetOperands, DisjunctionBuilder,
+    PatternBuilder,
+};
+pub use char_set::{CharSet, CharSetError, CharSetFlags};
 pub use ecma_versions::EcmaVersion;
+pub use editor::{EditError, RegExpEditor};
+use fold::RegExpFolder;
+pub use formatter::{EscapeStyle, FormatOptions, FormatOptionsBuilder};
 use id_arena::Id;
-pub use parser::RegExpParser;
+pub use matcher::{MatchIndices, MatchResult, Matcher, MatcherFlags};
+pub use owned::OwnedNode;
+pub use parser::{RegExpParser, TolerantParseResult};
+pub use printer::RegExpPrinter;
 pub use reader::{CodePoint, Reader};
-pub use regexp_syntax_error::RegExpSyntaxError;
+pub use regexp_syntax_error::{RegExpSyntaxError, RegExpSyntaxErrorKind};
+pub use selector::{Selector, SelectorError};
+pub use serialize::SerializableNode;
+pub use string_set::StringSet;
 pub use validator::{RegExpValidator, ValidatePatternFlags};
-use visitor::RegExpVisitor;
+use visitor::{ContextRegExpVisitor, ControlledRegExpVisitor, RegExpVisitor, VisitControl};
 pub use wtf16::Wtf16;
 
 pub extern crate id_arena;
@@ -37,14 +66,112 @@ pub fn parse_reg_exp_literal(
     RegExpParser::new(arena, options).parse_literal(source, None, None)
 }
 
+/// Parses a literal and prints it back out in a canonical style. The result
+/// is re-validated under the same options, so it's guaranteed to still be a
+/// valid literal with the same flags; that it matches the same strings isn't
+/// checked
+pub fn format_reg_exp_literal(
+    source: &[u16],
+    options: Option<parser::Options>,
+    format_options: Option<FormatOptions>,
+) -> Result<Wtf16> {
+    let arena = AllArenas::default();
+    let ast = parse_reg_exp_literal(source, options, &arena)?;
+    let formatted = RegExpPrinter::new(&arena, None)
+        .with_format_options(format_options.unwrap_or_default())
+        .print(ast);
+    RegExpValidator::new(Some(Rc::new(options.unwrap_or_default())))
+        .validate_literal(&formatted, None, None)?;
+    Ok(formatted)
+}
+
+pub fn print_reg_exp_ast(node: Id<Node>, arena: &AllArenas) -> Wtf16 {
+    RegExpPrinter::new(arena, None).print(node)
+}
+
 pub fn visit_reg_exp_ast(node: Id<Node>, handlers: &impl visitor::Handlers, arena: &AllArenas) {
     RegExpVisitor::new(arena, handlers).visit(node);
 }
 
+/// Returns [`VisitControl::Break`] if one of the handlers stopped the
+/// traversal early
+pub fn visit_reg_exp_ast_with_control(
+    node: Id<Node>,
+    handlers: &impl visitor::ControlHandlers,
+    arena: &AllArenas,
+) -> VisitControl {
+    ControlledRegExpVisitor::new(arena, handlers).visit(node)
+}
+
+/// Like [`visit_reg_exp_ast_with_control`], but the handlers are also given
+/// each node's ancestors, depth and the field of its parent it's in
+pub fn visit_reg_exp_ast_with_context(
+    node: Id<Node>,
+    handlers: &impl visitor::ContextHandlers,
+    arena: &AllArenas,
+) -> VisitControl {
+    ContextRegExpVisitor::new(arena, handlers).visit(node)
+}
+
+/// The nodes at or below `node` that match an esquery-style
+/// [`Selector`], in pre-order
+pub fn query_reg_exp_ast(
+    node: Id<Node>,
+    selector: &str,
+    arena: &AllArenas,
+) -> std::result::Result<Vec<Id<Node>>, SelectorError> {
+    Ok(Selector::parse(selector)?.query(node, arena))
+}
+
+/// The code points a character class (or a character, escape or range)
+/// matches, under the flags in effect where it is
+pub fn compute_char_set(
+    node: Id<Node>,
+    arena: &AllArenas,
+) -> std::result::Result<CharSet, CharSetError> {
+    CharSet::from_node(arena, node, CharSetFlags::at(arena, node))
+}
+
+/// What a character class matches when it can contain strings, as in
+/// `v`-flag mode, under the flags in effect where it is
+pub fn compute_string_set(
+    node: Id<Node>,
+    arena: &AllArenas,
+) -> std::result::Result<StringSet, CharSetError> {
+    StringSet::from_node(arena, node, CharSetFlags::at(arena, node))
+}
+
+/// Whether a character class can match strings (other than single code
+/// points), going by its syntax alone, which is what makes it invalid to
+/// negate
+pub fn may_contain_strings(node: Id<Node>, arena: &AllArenas) -> bool {
+    string_set::may_contain_strings(arena, node)
+}
+
+/// Rebuilds the tree in `target_arena` (which can be the same as `arena`),
+/// replacing nodes wherever the folder asks to
+pub fn fold_reg_exp_ast(
+    node: Id<Node>,
+    folder: &mut impl fold::Fold,
+    arena: &AllArenas,
+    target_arena: &AllArenas,
+) -> Id<Node> {
+    RegExpFolder::new(arena, target_arena).fold(node, folder)
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{cell::RefCell, collections::HashMap, rc::Rc};
+    use std::{
+        cell::RefCell,
+        collections::{HashMap, HashSet},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc, Arc,
+        },
+        thread,
+    };
 
+    use indexmap::IndexMap;
     use itertools::Itertools;
     use regex::Captures;
     use speculoos::prelude::*;
@@ -54,15 +181,16 @@ mod tests {
 
     use crate::{
         ast::{resolve_location, to_node_unresolved, NodeUnresolved},
+        node_ids::{AlternativeId, BackreferenceId, CapturingGroupId, CharacterId, ElementId},
         test::fixtures::{
             self,
             parser::literal::{self, AstOrError},
         },
         unicode::{
-            is_line_terminator, ASTERISK, LEFT_SQUARE_BRACKET, REVERSE_SOLIDUS,
+            case_folding, is_line_terminator, ASTERISK, LEFT_SQUARE_BRACKET, REVERSE_SOLIDUS,
             RIGHT_SQUARE_BRACKET, SOLIDUS,
         },
-        validator::{bmp_char_to_utf_16, ValidatePatternFlags},
+        validator::{bmp_char_to_utf_16, AssertionKind, ValidatePatternFlags},
     };
 
     #[test]
@@ -98,11 +226,83 @@ mod tests {
                     AstOrError::Ast(expected) => {
                         let actual = generate_ast(&source, options, &arena);
                         assert_that!(&actual).is_equal_to(expected);
+
+                        // Only lossless for lone surrogates with the feature
+                        #[cfg(feature = "serde_json")]
+                        {
+                            let ast =
+                                parse_reg_exp_literal(&source, Some(options), &arena).unwrap();
+                            let serialized =
+                                serde_json::to_string(&SerializableNode::new(&arena, ast)).unwrap();
+                            let actual: NodeUnresolved = serde_json::from_str(&serialized).unwrap();
+                            assert_that!(&actual).is_equal_to(expected);
+
+                            let loaded = arena.load_json(&serialized).unwrap();
+                            let mut path: Vec<String> = Default::default();
+                            let mut path_map: HashMap<Id<Node>, String> = Default::default();
+                            resolve_location(&arena, loaded, &mut path, &mut path_map);
+                            assert_that!(&to_node_unresolved(loaded, &arena, &path_map))
+                                .is_equal_to(expected);
+                        }
+
+                        let ast = parse_reg_exp_literal(&source, Some(options), &arena).unwrap();
+                        struct Identity;
+                        impl fold::Fold for Identity {}
+                        let folded_arena = AllArenas::default();
+                        let folded = fold_reg_exp_ast(ast, &mut Identity, &arena, &folded_arena);
+                        let mut path: Vec<String> = Default::default();
+                        let mut path_map: HashMap<Id<Node>, String> = Default::default();
+                        resolve_location(&folded_arena, folded, &mut path, &mut path_map);
+                        assert_that!(&to_node_unresolved(folded, &folded_arena, &path_map))
+                            .is_equal_to(expected);
+
+                        let owned = OwnedNode::new(&arena, ast);
+                        let owned_arena = AllArenas::default();
+                        let allocated = owned.alloc(&owned_arena);
+                        let mut path: Vec<String> = Default::default();
+                        let mut path_map: HashMap<Id<Node>, String> = Default::default();
+                        resolve_location(&owned_arena, allocated, &mut path, &mut path_map);
+                        assert_that!(&to_node_unresolved(allocated, &owned_arena, &path_map))
+                            .is_equal_to(expected);
+                        assert_that!(&OwnedNode::new(&owned_arena, allocated)).is_equal_to(owned);
+
+                        let printed = print_reg_exp_ast(ast, &arena);
+                        let reparsed = parse_reg_exp_literal(&printed, Some(options), &arena)
+                            .unwrap_or_else(|error| {
+                                panic!(
+                                    "{:?} printed as unparseable {:?}: {}",
+                                    String::from_utf16_lossy(&source),
+                                    String::from_utf16_lossy(&printed),
+                                    error.message
+                                )
+                            });
+                        assert_that!(&to_structure(reparsed, &arena))
+                            .is_equal_to(to_structure(ast, &arena));
+
+                        let formatted = format_reg_exp_literal(
+                            &source,
+                            Some(options),
+                            Some(
+                                FormatOptionsBuilder::default()
+                                    .sort_class_ranges(false)
+                                    .build()
+                                    .unwrap(),
+                            ),
+                        )
+                        .unwrap();
+                        let reparsed =
+                            parse_reg_exp_literal(&formatted, Some(options), &arena).unwrap();
+                        assert_that!(&to_structure(reparsed, &arena))
+                            .is_equal_to(to_structure(ast, &arena));
+                        format_reg_exp_literal(&source, Some(options), None).unwrap();
                     }
                     AstOrError::Error(expected) => {
                         let err =
                             parse_reg_exp_literal(&source, Some(options), &arena).unwrap_err();
-                        assert_that!(&err).is_equal_to(expected);
+                        assert_that!(&err.message).is_equal_to(&expected.message);
+                        assert_that!(&err.index).is_equal_to(expected.index);
+                        assert_that!(&err.message.ends_with(&err.kind.to_string())).is_true();
+                        assert_that!(&(err.span.is_empty() && !source.is_empty())).is_false();
 
                         assert_that!(&&expected.message[..27])
                             .is_equal_to(&"Invalid regular expression:");
@@ -143,6 +343,25 @@ mod tests {
         }
     }
 
+    fn to_structure(node: Id<Node>, arena: &AllArenas) -> serde_json::Value {
+        fn strip_positions(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(object) => {
+                    for key in ["start", "end", "raw"] {
+                        object.remove(key);
+                    }
+                    object.values_mut().for_each(strip_positions);
+                }
+                serde_json::Value::Array(array) => array.iter_mut().for_each(strip_positions),
+                _ => (),
+            }
+        }
+
+        let mut value = serde_json::to_value(SerializableNode::new(arena, node)).unwrap();
+        strip_positions(&mut value);
+        value
+    }
+
     struct PatternAndFlags {
         pattern: Wtf16,
         flags: Wtf16,
@@ -225,29 +444,6 @@ mod tests {
                 let arena = AllArenas::default();
                 let ast = generate_ast(&source, options, &arena);
 
-                fn get_node_type(node: &Node) -> &'static str {
-                    match node {
-                        Node::Alternative(_) => "Alternative",
-                        Node::CapturingGroup(_) => "CapturingGroup",
-                        Node::CharacterClass(_) => "CharacterClass",
-                        Node::CharacterClassRange(_) => "CharacterClassRange",
-                        Node::ClassIntersection(_) => "ClassIntersection",
-                        Node::ClassStringDisjunction(_) => "ClassStringDisjunction",
-                        Node::ClassSubtraction(_) => "ClassSubtraction",
-                        Node::ExpressionCharacterClass(_) => "ExpressionCharacterClass",
-                        Node::Group(_) => "Group",
-                        Node::Assertion(_) => "Assertion",
-                        Node::Pattern(_) => "Pattern",
-                        Node::Quantifier(_) => "Quantifier",
-                        Node::RegExpLiteral(_) => "RegExpLiteral",
-                        Node::StringAlternative(_) => "StringAlternative",
-                        Node::Backreference(_) => "Backreference",
-                        Node::Character(_) => "Character",
-                        Node::CharacterSet(_) => "CharacterSet",
-                        Node::Flags(_) => "Flags",
-                    }
-                }
-
                 struct HistoryRecorder<'a> {
                     arena: &'a AllArenas,
                     history: RefCell<Vec<Wtf16>>,
@@ -265,7 +461,7 @@ mod tests {
                         let mut event: Wtf16 = (&*format!("{event_type}:")).into();
                         let node_ref = self.arena.node(node);
                         event.extend(
-                            Wtf16::from(&*format!("{}:", get_node_type(&node_ref)))
+                            Wtf16::from(&*format!("{}:", node_ref.kind().as_str()))
                                 .iter()
                                 .copied(),
                         );
@@ -383,6 +579,14 @@ mod tests {
                         self.leave(node);
                     }
 
+                    fn on_error_enter(&self, node: Id<Node /*Error*/>) {
+                        self.enter(node);
+                    }
+
+                    fn on_error_leave(&self, node: Id<Node /*Error*/>) {
+                        self.leave(node);
+                    }
+
                     fn on_expression_character_class_enter(
                         &self,
                         node: Id<Node /*ExpressionCharacterClass*/>,
@@ -413,6 +617,22 @@ mod tests {
                         self.leave(node);
                     }
 
+                    fn on_modifiers_enter(&self, node: Id<Node /*Modifiers*/>) {
+                        self.enter(node);
+                    }
+
+                    fn on_modifiers_leave(&self, node: Id<Node /*Modifiers*/>) {
+                        self.leave(node);
+                    }
+
+                    fn on_modifier_flags_enter(&self, node: Id<Node /*ModifierFlags*/>) {
+                        self.enter(node);
+                    }
+
+                    fn on_modifier_flags_leave(&self, node: Id<Node /*ModifierFlags*/>) {
+                        self.leave(node);
+                    }
+
                     fn on_pattern_enter(&self, node: Id<Node /*Pattern*/>) {
                         self.enter(node);
                     }
@@ -453,4 +673,1371 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_visit_reg_exp_ast_with_control() {
+        struct FindCharacter<'a> {
+            arena: &'a AllArenas,
+            target: &'static str,
+            history: RefCell<Vec<String>>,
+        }
+
+        impl<'a> visitor::ControlHandlers for FindCharacter<'a> {
+            fn on_assertion_enter(&self, _node: Id<Node>) -> VisitControl {
+                VisitControl::SkipChildren
+            }
+
+            fn on_assertion_leave(&self, _node: Id<Node>) -> VisitControl {
+                self.history.borrow_mut().push("assertion".to_owned());
+                VisitControl::Continue
+            }
+
+            fn on_character_enter(&self, node: Id<Node>) -> VisitControl {
+                let raw = String::from_utf16_lossy(self.arena.node(node).raw());
+                self.history.borrow_mut().push(raw.clone());
+                if raw == self.target {
+                    VisitControl::Break
+                } else {
+                    VisitControl::Continue
+                }
+            }
+
+            fn on_pattern_leave(&self, _node: Id<Node>) -> VisitControl {
+                self.history.borrow_mut().push("pattern".to_owned());
+                VisitControl::Continue
+            }
+        }
+
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from("/a(?=b)cd/"), None, &arena).unwrap();
+        for (target, expected_control, expected_history) in [
+            ("c", VisitControl::Break, vec!["a", "assertion", "c"]),
+            (
+                "x",
+                VisitControl::Continue,
+                vec!["a", "assertion", "c", "d", "pattern"],
+            ),
+        ] {
+            let handlers = FindCharacter {
+                arena: &arena,
+                target,
+                history: Default::default(),
+            };
+            assert_that!(&visit_reg_exp_ast_with_control(ast, &handlers, &arena))
+                .is_equal_to(expected_control);
+            assert_that!(&handlers.history.into_inner()).is_equal_to(
+                expected_history
+                    .into_iter()
+                    .map(ToOwned::to_owned)
+                    .collect_vec(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_visit_reg_exp_ast_with_context() {
+        struct DescribeCharacters<'a> {
+            arena: &'a AllArenas,
+            history: RefCell<Vec<String>>,
+        }
+
+        impl<'a> visitor::ContextHandlers for DescribeCharacters<'a> {
+            fn on_character_enter(
+                &self,
+                node: Id<Node>,
+                context: &visitor::VisitContext,
+            ) -> VisitControl {
+                let in_negated_lookbehind = context.ancestors().iter().any(|&ancestor| {
+                    matches!(
+                        &*self.arena.node(ancestor),
+                        Node::Assertion(assertion)
+                            if assertion.kind == AssertionKind::Lookbehind
+                                && assertion.negate == Some(true)
+                    )
+                });
+                let parent = self.arena.node(context.parent().unwrap()).kind();
+                self.history.borrow_mut().push(format!(
+                    "{} {} {:?} {:?}{}",
+                    String::from_utf16_lossy(self.arena.node(node).raw()),
+                    context.depth(),
+                    parent,
+                    context.field().unwrap(),
+                    if in_negated_lookbehind {
+                        " negated"
+                    } else {
+                        ""
+                    }
+                ));
+                VisitControl::Continue
+            }
+
+            fn on_string_alternative_leave(
+                &self,
+                _node: Id<Node>,
+                context: &visitor::VisitContext,
+            ) -> VisitControl {
+                assert_that!(&context.fields().len()).is_equal_to(context.depth());
+                VisitControl::Continue
+            }
+        }
+
+        let arena = AllArenas::default();
+        let ast =
+            parse_reg_exp_literal(&Wtf16::from("/(?<!a)[b-c\\q{de}]/v"), None, &arena).unwrap();
+        let handlers = DescribeCharacters {
+            arena: &arena,
+            history: Default::default(),
+        };
+        assert_that!(&visit_reg_exp_ast_with_context(ast, &handlers, &arena))
+            .is_equal_to(VisitControl::Continue);
+        assert_that!(&handlers.history.into_inner()).is_equal_to(
+            [
+                "a 5 Alternative Elements(0) negated",
+                "b 5 CharacterClassRange Min",
+                "c 5 CharacterClassRange Max",
+                "d 6 StringAlternative Elements(0)",
+                "e 6 StringAlternative Elements(1)",
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect_vec(),
+        );
+    }
+
+    #[test]
+    fn test_fold_reg_exp_ast() {
+        #[derive(Default)]
+        struct ReplaceA {
+            characters: usize,
+        }
+
+        impl fold::Fold for ReplaceA {
+            fn fold_character(&mut self, node: Id<Node>, arena: &AllArenas) -> Id<Node> {
+                self.characters += 1;
+                if arena.node(node).as_character().value != CodePoint::from('a') {
+                    return node;
+                }
+                arena.alloc_node(Node::new_character(
+                    None,
+                    0,
+                    0,
+                    "b".into(),
+                    CodePoint::from('b'),
+                ))
+            }
+        }
+
+        let arena = AllArenas::default();
+        let ast =
+            parse_reg_exp_literal(&Wtf16::from(r"/(a)[a-c](?<x>c)\1\k<x>/"), None, &arena).unwrap();
+        for target_arena in [&arena, &AllArenas::default()] {
+            let mut folder = ReplaceA::default();
+            let folded = fold_reg_exp_ast(ast, &mut folder, &arena, target_arena);
+            assert_that!(&folder.characters).is_equal_to(4);
+            assert_that!(&String::from_utf16_lossy(&print_reg_exp_ast(
+                folded,
+                target_arena
+            )))
+            .is_equal_to(r"/(b)[b-c](?<x>c)\1\k<x>/".to_owned());
+            assert_that!(&String::from_utf16_lossy(&print_reg_exp_ast(ast, &arena)))
+                .is_equal_to(r"/(a)[a-c](?<x>c)\1\k<x>/".to_owned());
+
+            let pattern = target_arena.node(folded).as_reg_exp_literal().pattern;
+            let elements = pattern.get(target_arena).alternatives[0]
+                .get(target_arena)
+                .elements
+                .clone();
+            assert_that!(&target_arena.node(folded).is_synthesized()).is_true();
+            assert_that!(&target_arena.node(elements[2]).is_synthesized()).is_false();
+            for (reference, group) in [(elements[3], elements[0]), (elements[4], elements[2])] {
+                assert_that!(&target_arena.node(reference).as_backreference().resolved[0].id())
+                    .is_equal_to(group.id());
+                assert_that!(&target_arena.node(group).parent())
+                    .is_equal_to(pattern.get(target_arena).alternatives[0].id());
+            }
+        }
+    }
+
+    #[test]
+    fn test_print_reg_exp_ast() {
+        for (source, expected) in [
+            ("/(?:)/", "/(?:)/"),
+            ("/a\\/b{2,2}?/gimsuyd", "/a\\/b{2}?/dgimsuy"),
+            ("/(a)\\1\\x30/", "/(a)\\1\\x30/"),
+            ("/[\\]\\-^]\\t/", "/[\\]\\-\\^]\\t/"),
+            ("/[\\q{abc|d}&&\\w]--[&]/v", "/[\\q{abc|d}&&\\w]--[\\&]/v"),
+            ("/\\u{1F600}\\ud800/u", "/\u{1F600}\\u{d800}/u"),
+            (
+                "/(?<a>.)\\k<a>(?i-m:\\p{Lu})/u",
+                "/(?<a>.)\\k<a>(?i-m:\\p{General_Category=Lu})/u",
+            ),
+        ] {
+            let arena = AllArenas::default();
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap();
+            assert_that!(&String::from_utf16_lossy(&print_reg_exp_ast(ast, &arena)))
+                .is_equal_to(expected.to_owned());
+        }
+    }
+
+    #[test]
+    fn test_format_reg_exp_literal() {
+        let braced = FormatOptionsBuilder::default()
+            .escape_style(EscapeStyle::Braced)
+            .build()
+            .unwrap();
+        let uppercase = FormatOptionsBuilder::default()
+            .uppercase_hex(true)
+            .build()
+            .unwrap();
+        let unsorted = FormatOptionsBuilder::default()
+            .sort_class_ranges(false)
+            .build()
+            .unwrap();
+        for (source, format_options, expected) in [
+            (r"/a{0,}\-\a/", None, r"/a*-a/"),
+            (r"/[\.c-eab\-]/", None, r"/[-.a-e]/"),
+            (r"/[\.c-eab\-]/", Some(unsorted), r"/[.c-eab-]/"),
+            (r"/[\^a][^\^]/", None, r"/[\^a][^^]/"),
+            (r"/\x01\u2028/", None, r"/\x01\u2028/"),
+            (r"/\x01\u2028/u", Some(braced), r"/\u{1}\u{2028}/u"),
+            (r"/\x01/", Some(braced), r"/\x01/"),
+            (r"/\x1b\ud800/", Some(uppercase), r"/\x1B\uD800/"),
+            (r"/\ud800/u", None, r"/\u{d800}/u"),
+            (r"/[\&\&\!a]/v", None, r"/[!&a]/v"),
+            (r"/[\&&&\&]/v", None, r"/[\&&&\&]/v"),
+        ] {
+            assert_that!(&String::from_utf16_lossy(
+                &format_reg_exp_literal(&Wtf16::from(source), None, format_options).unwrap()
+            ))
+            .is_equal_to(expected.to_owned());
+        }
+    }
+
+    #[test]
+    fn test_pattern_builder() {
+        let arena = AllArenas::default();
+        let built = [
+            PatternBuilder::new(&arena)
+                .alt(|a| {
+                    a.char('a')
+                        .quantified(1, None, true)
+                        .capturing_group(Some("x"), |g| g.alt(|a| a.chars("b/").any()).alt(|a| a))
+                        .named_backreference("x")
+                        .class(true, |c| c.range('a', 'z').char('-').digit(false))
+                })
+                .alt(|a| {
+                    a.start()
+                        .lookbehind(true, |g| g.alt(|a| a.word(false)))
+                        .end()
+                })
+                .build_literal("gi")
+                .unwrap(),
+            PatternBuilder::new(&arena)
+                .alt(|a| {
+                    a.expression_class(false, |o| {
+                        o.class(false, |c| c.range('a', 'z'))
+                            .subtract(o.string_disjunction(&["ab", "c"]))
+                            .subtract(o.char('&'))
+                    })
+                    .property("General_Category", Some("Lu"), false)
+                })
+                .build_literal("v")
+                .unwrap(),
+        ];
+        for ast in built {
+            let source = print_reg_exp_ast(ast, &arena);
+            let parsed = parse_reg_exp_literal(&source, None, &arena).unwrap();
+            assert_that!(&serde_json::to_value(SerializableNode::new(&arena, ast)).unwrap())
+                .is_equal_to(serde_json::to_value(SerializableNode::new(&arena, parsed)).unwrap());
+        }
+
+        let empty = PatternBuilder::new(&arena).build_literal("").unwrap();
+        assert_that!(&String::from_utf16_lossy(arena.node(empty).raw()))
+            .is_equal_to("/(?:)/".to_owned());
+
+        let pattern = PatternBuilder::new(&arena)
+            .alt(|a| a.capturing_group(None, |g| g).char('0').backreference(1))
+            .build()
+            .unwrap();
+        assert_that!(&String::from_utf16_lossy(arena.node(pattern).raw()))
+            .is_equal_to(r"()0\1".to_owned());
+
+        for (builder, expected) in [
+            (
+                PatternBuilder::new(&arena).alt(|a| a.start().quantified(0, Some(1), true)),
+                RegExpSyntaxErrorKind::NothingToRepeat,
+            ),
+            (
+                PatternBuilder::new(&arena).alt(|a| a.char('a').quantified(2, Some(1), true)),
+                RegExpSyntaxErrorKind::NumbersOutOfOrderInQuantifier,
+            ),
+            (
+                PatternBuilder::new(&arena).alt(|a| a.class(false, |c| c.range('z', 'a'))),
+                RegExpSyntaxErrorKind::RangeOutOfOrderInCharacterClass,
+            ),
+            (
+                PatternBuilder::new(&arena)
+                    .alt(|a| a.class(false, |c| c.string_disjunction(&["ab"]))),
+                RegExpSyntaxErrorKind::InvalidCharacterClass,
+            ),
+            (
+                PatternBuilder::new(&arena).alt(|a| {
+                    a.expression_class(false, |o| {
+                        o.word(false)
+                            .intersect(o.digit(false))
+                            .subtract(o.char('a'))
+                    })
+                }),
+                RegExpSyntaxErrorKind::InvalidSetOperationInCharacterClass,
+            ),
+            (
+                PatternBuilder::new(&arena).alt(|a| a.named_backreference("y")),
+                RegExpSyntaxErrorKind::InvalidNamedCaptureReferenced { name: "y".into() },
+            ),
+        ] {
+            assert_that!(&builder
+                .with_flags(ValidatePatternFlags {
+                    unicode: Some(true),
+                    unicode_sets: None,
+                })
+                .build()
+                .unwrap_err())
+            .is_equal_to(expected);
+        }
+    }
+
+    #[test]
+    fn test_reg_exp_editor() {
+        let arena = AllArenas::default();
+        let editor = RegExpEditor::new(&arena);
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/(a)b\1/"), None, &arena).unwrap();
+        let pattern = arena.node(ast).as_reg_exp_literal().pattern;
+        let alternative = arena.node(pattern).as_pattern().alternatives[0];
+        fn ids(ids: &[impl Into<Id<Node>> + Copy]) -> Vec<Id<Node>> {
+            ids.iter().map(|&id| id.into()).collect()
+        }
+        let elements = || ids(&alternative.get(&arena).elements);
+        let printed = || String::from_utf16_lossy(&print_reg_exp_ast(ast, &arena));
+        let resolved =
+            |reference: Id<Node>| ids(&arena.node(reference).as_backreference().resolved);
+
+        let [group_a, b, reference] = elements()[..] else {
+            unreachable!()
+        };
+        assert_that!(&arena.node(ast).is_synthesized()).is_false();
+        let group = editor.wrap_in_group(b).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(a)(?:b)\1/".to_owned());
+        assert_that!(&String::from_utf16_lossy(arena.node(group).raw()))
+            .is_equal_to("(?:b)".to_owned());
+        assert_that!(&arena.node(ast).is_synthesized()).is_true();
+        assert_that!(&arena.node(b).is_synthesized()).is_false();
+        editor.unwrap_group(group).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(a)b\1/".to_owned());
+        assert_that!(&arena.node(b).parent()).is_equal_to(alternative.id());
+
+        let built = PatternBuilder::new(&arena)
+            .alt(|a| {
+                a.capturing_group(None, |g| g.alt(|a| a.char('x')))
+                    .word_boundary(false)
+            })
+            .build()
+            .unwrap();
+        let built_alternative = arena.node(built).as_pattern().alternatives[0];
+        let [group_x, boundary] = ids(&built_alternative.get(&arena).elements)[..] else {
+            unreachable!()
+        };
+        editor.insert_before(group_a, group_x).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(x)(a)b\2/".to_owned());
+        assert_that!(&resolved(reference)).is_equal_to(vec![group_a]);
+        assert_that!(&arena.node(group_x).as_capturing_group().references).has_length(0);
+        assert_that!(&arena.node(reference).is_synthesized()).is_true();
+        assert_that!(&ids(&built_alternative.get(&arena).elements)).is_equal_to(vec![boundary]);
+        editor.replace(b, boundary).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(x)(a)\b\2/".to_owned());
+        assert_that!(&arena.node(b).maybe_parent()).is_none();
+        editor.remove(group_x).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(a)\b\1/".to_owned());
+        assert_that!(&resolved(reference)).is_equal_to(vec![group_a]);
+        assert_that!(&ids(&arena.node(group_a).as_capturing_group().references))
+            .is_equal_to(vec![reference]);
+        assert_that!(&arena.node(group_x).as_capturing_group().references).has_length(0);
+
+        let group_a_alternative = arena.node(group_a).as_capturing_group().alternatives[0];
+        for (result, expected) in [
+            (editor.replace(group_a, alternative), EditError::Cycle),
+            (
+                editor.replace(reference, group_a_alternative),
+                EditError::InvalidPosition,
+            ),
+            (editor.remove(group_a_alternative), EditError::RequiredChild),
+            (editor.remove(ast), EditError::Detached),
+            (editor.unwrap_group(group_a), EditError::NotUnwrappable),
+            (editor.remove(group_a), EditError::DanglingBackreference),
+            (
+                editor.replace(group_a, group_x),
+                EditError::DanglingBackreference,
+            ),
+        ] {
+            assert_that!(&result.unwrap_err()).is_equal_to(expected);
+        }
+        assert_that!(&printed()).is_equal_to(r"/(a)\b\1/".to_owned());
+        assert_that!(&arena.node(group_a).parent()).is_equal_to(alternative.id());
+        assert_that!(&resolved(reference)).is_equal_to(vec![group_a]);
+        assert_that!(&ids(&arena.node(group_a).as_capturing_group().references))
+            .is_equal_to(vec![reference]);
+    }
+
+    #[test]
+    fn test_reg_exp_editor_keeps_backreference_links() {
+        let arena = AllArenas::default();
+        let editor = RegExpEditor::new(&arena);
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/(a)(b)(c)\2/"), None, &arena).unwrap();
+        let pattern = arena.node(ast).as_reg_exp_literal().pattern;
+        let alternative = arena.node(pattern).as_pattern().alternatives[0];
+        let [group_a, group_b, group_c, reference] = alternative.get(&arena).elements[..] else {
+            unreachable!()
+        };
+        let printed = || String::from_utf16_lossy(&print_reg_exp_ast(ast, &arena));
+        let resolved = || {
+            arena
+                .node(reference)
+                .as_backreference()
+                .resolved
+                .iter()
+                .map(|&group| group.id())
+                .collect_vec()
+        };
+
+        editor.remove(group_a).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(b)(c)\1/".to_owned());
+        assert_that!(&resolved()).is_equal_to(vec![group_b.id()]);
+
+        editor.insert_after(group_c, group_b).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(c)(b)\2/".to_owned());
+        assert_that!(&resolved()).is_equal_to(vec![group_b.id()]);
+
+        assert_that!(&editor.remove(group_b).unwrap_err())
+            .is_equal_to(EditError::DanglingBackreference);
+        assert_that!(&printed()).is_equal_to(r"/(c)(b)\2/".to_owned());
+        editor.remove(reference).unwrap();
+        editor.remove(group_b).unwrap();
+        assert_that!(&printed()).is_equal_to(r"/(c)/".to_owned());
+    }
+
+    #[test]
+    fn test_node_ids() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/a(b)\1/"), None, &arena).unwrap();
+        let pattern = arena.node(ast).as_reg_exp_literal().pattern;
+        let alternative = pattern.get(&arena).alternatives[0];
+        let [a, group, reference] = alternative.get(&arena).elements[..] else {
+            unreachable!()
+        };
+
+        let character = CharacterId::new(&arena, a.id()).unwrap();
+        assert_that!(&character.get(&arena).value).is_equal_to(CodePoint::from('a'));
+        assert_that!(&ElementId::from(character)).is_equal_to(a);
+        assert_that!(&CharacterId::new(&arena, group.id())).is_none();
+        assert_that!(&AlternativeId::new(&arena, a.id())).is_none();
+        assert_that!(&ElementId::new(&arena, alternative.id())).is_none();
+
+        let group = CapturingGroupId::new(&arena, group.id()).unwrap();
+        let reference = BackreferenceId::new(&arena, reference.id()).unwrap();
+        assert_that!(&reference.get(&arena).resolved).is_equal_to(vec![group]);
+        assert_that!(&group.get(&arena).references).is_equal_to(vec![reference]);
+        assert_that!(&arena.node(group).parent()).is_equal_to(alternative.id());
+    }
+
+    #[test]
+    fn test_node_children_and_ancestors() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/(?i:a|b)[c-d]/g"), None, &arena).unwrap();
+        assert_that!(&arena
+            .descendants(ast)
+            .map(|node| arena.node(node).kind())
+            .collect_vec())
+        .is_equal_to(vec![
+            NodeKind::Pattern,
+            NodeKind::Alternative,
+            NodeKind::Group,
+            NodeKind::Modifiers,
+            NodeKind::ModifierFlags,
+            NodeKind::Alternative,
+            NodeKind::Character,
+            NodeKind::Alternative,
+            NodeKind::Character,
+            NodeKind::CharacterClass,
+            NodeKind::CharacterClassRange,
+            NodeKind::Character,
+            NodeKind::Character,
+            NodeKind::Flags,
+        ]);
+
+        let pattern = arena.node(ast).as_reg_exp_literal().pattern;
+        let alternative = pattern.get(&arena).alternatives[0];
+        let [group, class] = alternative.get(&arena).elements[..] else {
+            unreachable!()
+        };
+        assert_that!(&arena.node(group).children().count()).is_equal_to(3);
+        let range = arena.node(class).children().next().unwrap();
+        let min = arena.node(range).children().next().unwrap();
+        assert_that!(&arena.node(min).children().count()).is_equal_to(0);
+        assert_that!(&arena.ancestors(min).collect_vec()).is_equal_to(vec![
+            range,
+            class.id(),
+            alternative.id(),
+            pattern.id(),
+            ast,
+        ]);
+        assert_that!(&arena.ancestors(ast).count()).is_equal_to(0);
+    }
+
+    #[test]
+    fn test_traverse() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/a[b-c]/"), None, &arena).unwrap();
+        let describe = |node: Id<Node>| {
+            let node = arena.node(node);
+            format!(
+                "{} {}",
+                node.kind().as_str(),
+                String::from_utf16_lossy(node.raw())
+            )
+        };
+        assert_that!(&arena
+            .traverse(ast)
+            .map(|(node, event)| format!("{event:?} {}", describe(node)))
+            .collect_vec())
+        .is_equal_to(
+            [
+                "Enter RegExpLiteral /a[b-c]/",
+                "Enter Pattern a[b-c]",
+                "Enter Alternative a[b-c]",
+                "Enter Character a",
+                "Leave Character a",
+                "Enter CharacterClass [b-c]",
+                "Enter CharacterClassRange b-c",
+                "Enter Character b",
+                "Leave Character b",
+                "Enter Character c",
+                "Leave Character c",
+                "Leave CharacterClassRange b-c",
+                "Leave CharacterClass [b-c]",
+                "Leave Alternative a[b-c]",
+                "Leave Pattern a[b-c]",
+                "Enter Flags ",
+                "Leave Flags ",
+                "Leave RegExpLiteral /a[b-c]/",
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect_vec(),
+        );
+
+        assert_that!(&arena.preorder(ast).map(describe).take(3).collect_vec()).is_equal_to(
+            [
+                "RegExpLiteral /a[b-c]/",
+                "Pattern a[b-c]",
+                "Alternative a[b-c]",
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect_vec(),
+        );
+        assert_that!(&arena.postorder(ast).map(describe).take(4).collect_vec()).is_equal_to(
+            [
+                "Character a",
+                "Character b",
+                "Character c",
+                "CharacterClassRange b-c",
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect_vec(),
+        );
+        let class = arena
+            .preorder(ast)
+            .find(|&node| arena.node(node).kind() == NodeKind::CharacterClass)
+            .unwrap();
+        assert_that!(&arena
+            .postorder(class)
+            .filter(|&node| arena.node(node).kind() == NodeKind::Character)
+            .count())
+        .is_equal_to(2);
+    }
+
+    #[test]
+    fn test_query_reg_exp_ast() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(
+            &Wtf16::from(r"/(?:(a)+)*\p{Script=Greek}(?<!(b)\1)[^c-d]x{2,3}/u"),
+            None,
+            &arena,
+        )
+        .unwrap();
+        let query = |selector: &str| {
+            query_reg_exp_ast(ast, selector, &arena)
+                .unwrap()
+                .into_iter()
+                .map(|node| String::from_utf16_lossy(arena.node(node).raw()))
+                .collect_vec()
+        };
+        for (selector, expected) in [
+            ("Quantifier > Group CapturingGroup", vec!["(a)"]),
+            ("Quantifier > CapturingGroup", vec!["(a)"]),
+            ("Quantifier > CapturingGroup Quantifier", vec![]),
+            (
+                r#"CharacterSet[kind=property][key="Script"]"#,
+                vec![r"\p{Script=Greek}"],
+            ),
+            (
+                "Assertion[kind=lookbehind][negate=true] Backreference",
+                vec![r"\1"],
+            ),
+            ("Assertion[negate=false] Backreference", vec![]),
+            ("characterclass[negate=true] > *", vec!["c-d"]),
+            ("CharacterClassRange > :first-child", vec!["c"]),
+            ("Quantifier[min>=2][max<5]", vec!["x{2,3}"]),
+            ("Quantifier[max=Infinity]", vec!["(?:(a)+)*", "(a)+"]),
+            ("Quantifier:not(:has(CapturingGroup))", vec!["x{2,3}"]),
+            (
+                "Assertion + CharacterClass, Character[value=120]",
+                vec!["[^c-d]", "x"],
+            ),
+            ("CapturingGroup ~ Backreference", vec![r"\1"]),
+        ] {
+            assert_that!(&query(selector))
+                .is_equal_to(expected.into_iter().map(ToOwned::to_owned).collect_vec());
+        }
+
+        for (selector, expected) in [
+            ("Quantifier >", SelectorError::UnexpectedEnd),
+            ("Quantifier[min", SelectorError::UnexpectedEnd),
+            ("Quantifier]", SelectorError::UnexpectedCharacter(10)),
+            (
+                ":first",
+                SelectorError::UnknownPseudoClass("first".to_owned()),
+            ),
+        ] {
+            assert_that!(&query_reg_exp_ast(ast, selector, &arena).unwrap_err())
+                .is_equal_to(expected);
+        }
+    }
+
+    #[test]
+    fn test_compute_char_set() {
+        let char_set = |source: &str| {
+            let arena = AllArenas::default();
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap();
+            let innermost = *query_reg_exp_ast(ast, "Alternative > *", &arena)
+                .unwrap()
+                .last()
+                .unwrap();
+            compute_char_set(innermost, &arena)
+        };
+        let ranges = |source: &str| {
+            char_set(source)
+                .unwrap()
+                .ranges()
+                .map(|range| (*range.start(), *range.end()))
+                .collect_vec()
+        };
+
+        assert_that!(&ranges(r"/[a-f\d_]/")).is_equal_to(vec![
+            (0x30, 0x39),
+            (0x5f, 0x5f),
+            (0x61, 0x66),
+        ]);
+        assert_that!(&ranges(r"/[^\0-\uffff]/")).is_equal_to(vec![]);
+        assert_that!(&ranges(r"/[^\0-\uffff]/u")).is_equal_to(vec![(0x10000, 0x10ffff)]);
+        assert_that!(&ranges(r"/[a-c]/i")).is_equal_to(vec![(0x41, 0x43), (0x61, 0x63)]);
+        assert_that!(&ranges(r"/(?i:[a-c])/")).is_equal_to(vec![(0x41, 0x43), (0x61, 0x63)]);
+        assert_that!(&ranges(r"/(?-i:[a-c])/i")).is_equal_to(vec![(0x61, 0x63)]);
+        assert_that!(&ranges(r"/k/i")).is_equal_to(vec![(0x4b, 0x4b), (0x6b, 0x6b)]);
+        assert_that!(&ranges(r"/k/iu")).is_equal_to(vec![
+            (0x4b, 0x4b),
+            (0x6b, 0x6b),
+            (0x212a, 0x212a),
+        ]);
+        assert_that!(&ranges(r"/\W/iu").contains(&(0x212a, 0x212a))).is_false();
+        assert_that!(&ranges(r"/./")).is_equal_to(vec![
+            (0, 0x09),
+            (0x0b, 0x0c),
+            (0x0e, 0x2027),
+            (0x202a, 0xffff),
+        ]);
+        assert_that!(&ranges(r"/./su")).is_equal_to(vec![(0, 0x10ffff)]);
+        assert_that!(&ranges(r"/[\w--\d]/v")).is_equal_to(vec![
+            (0x41, 0x5a),
+            (0x5f, 0x5f),
+            (0x61, 0x7a),
+        ]);
+        assert_that!(&ranges(r"/[[a-z]&&[^c-x]]/v")).is_equal_to(vec![(0x61, 0x62), (0x79, 0x7a)]);
+        assert_that!(&ranges(r"/[\q{a|b}]/v")).is_equal_to(vec![(0x61, 0x62)]);
+        assert_that!(&char_set(r"/[\q{a|bc}]/v")).is_equal_to(Err(CharSetError::Strings));
+        assert_that!(&char_set(r"/[\p{RGI_Emoji}]/v")).is_equal_to(Err(CharSetError::Strings));
+        let not_digit_or_upper = char_set(r"/[^\d\p{Lu}]/u").unwrap();
+        assert_that!(&not_digit_or_upper.contains(0x61)).is_true();
+        assert_that!(&not_digit_or_upper.contains(0x41)).is_false();
+        assert_that!(&not_digit_or_upper.contains(0x391)).is_false();
+        assert_that!(&not_digit_or_upper.contains(0x35)).is_false();
+        assert_that!(&char_set(r"/\p{Lu}/iu").unwrap().contains(0x3b1)).is_true();
+        assert_that!(&char_set(r"/\P{Script=Greek}/u").unwrap().contains(0x3b1)).is_false();
+        assert_that!(&char_set(r"/\P{Lu}/iu").unwrap().contains(0x41)).is_true();
+        assert_that!(&char_set(r"/\P{Lu}/iv").unwrap().contains(0x41)).is_false();
+        assert_that!(&char_set(r"/[^\P{Lu}]/iv").unwrap().contains(0x61)).is_true();
+        assert_that!(&char_set(r"/a+/")).is_equal_to(Err(CharSetError::NotACharacterClass));
+
+        let lower = CharSet::from_ranges([0x61..=0x7a]);
+        let vowels = CharSet::from_ranges([0x61..=0x61, 0x65..=0x65, 0x69..=0x69]);
+        assert_that!(&lower.contains(0x65)).is_true();
+        assert_that!(&lower.difference(&vowels).len()).is_equal_to(23);
+        assert_that!(&lower.intersection(&vowels)).is_equal_to(vowels.clone());
+        assert_that!(&vowels.is_subset(&lower)).is_true();
+        assert_that!(&lower.complement().complement()).is_equal_to(lower.clone());
+        assert_that!(&lower.union(&lower.complement())).is_equal_to(CharSet::all());
+    }
+
+    #[test]
+    fn test_unicode_property_char_set() {
+        let property = |version, key, value| CharSet::from_property(version, key, value);
+
+        let greek = property(EcmaVersion::_2025, "Script", Some("Greek")).unwrap();
+        assert_that!(&greek.contains(0x3b1)).is_true();
+        assert_that!(&greek.contains(0x61)).is_false();
+        assert_that!(&property(EcmaVersion::_2025, "sc", Some("Grek")))
+            .is_equal_to(Some(greek.clone()));
+        assert_that!(&greek.is_subset(&property(EcmaVersion::_2025, "scx", Some("Greek")).unwrap()))
+            .is_true();
+
+        assert_that!(&property(EcmaVersion::_2017, "Script", Some("Greek"))).is_none();
+        assert_that!(&property(EcmaVersion::_2022, "Script", Some("Kawi"))).is_none();
+        assert_that!(&property(EcmaVersion::_2023, "Script", Some("Kawi"))).is_some();
+        assert_that!(&property(EcmaVersion::_2025, "Script", Some("Nope"))).is_none();
+        assert_that!(&property(EcmaVersion::_2018, "Extended_Pictographic", None)).is_none();
+
+        // U+1F97A was assigned in Unicode 11, U+2EBF0 in Unicode 15.1
+        let assigned = |version| property(version, "Assigned", None).unwrap();
+        assert_that!(&assigned(EcmaVersion::_2018).contains(0x1f97a)).is_false();
+        assert_that!(&assigned(EcmaVersion::_2019).contains(0x1f97a)).is_true();
+        let unassigned = |version| property(version, "General_Category", Some("Cn")).unwrap();
+        assert_that!(&unassigned(EcmaVersion::_2018).contains(0x1f97a)).is_true();
+        assert_that!(&unassigned(EcmaVersion::_2018))
+            .is_equal_to(assigned(EcmaVersion::_2018).complement());
+        let new_letters = property(EcmaVersion::_2024, "gc", Some("Lo"))
+            .unwrap()
+            .difference(&property(EcmaVersion::_2023, "gc", Some("Lo")).unwrap());
+        assert_that!(&new_letters.contains(0x2ebf0)).is_true();
+        assert_that!(&new_letters.contains(0x4e00)).is_false();
+
+        // U+0589 changed from Common to Armenian in Unicode 13, and Garay was
+        // added (with case pairs) in Unicode 16
+        let armenian = |version| property(version, "sc", Some("Armenian")).unwrap();
+        assert_that!(&armenian(EcmaVersion::_2020).contains(0x589)).is_false();
+        assert_that!(&armenian(EcmaVersion::_2021).contains(0x589)).is_true();
+        assert_that!(&property(EcmaVersion::_2024, "sc", Some("Garay"))).is_none();
+        let garay = property(EcmaVersion::_2025, "sc", Some("Gara")).unwrap();
+        assert_that!(&garay.contains(0x10d40)).is_true();
+        let unknown = |version| property(version, "Script", Some("Unknown")).unwrap();
+        assert_that!(&unknown(EcmaVersion::_2024).contains(0x10d40)).is_true();
+        assert_that!(&unknown(EcmaVersion::_2025).is_subset(&garay.complement())).is_true();
+        let cwkcf = |version| property(version, "CWKCF", None).unwrap();
+        assert_that!(&cwkcf(EcmaVersion::_2024).contains(0x10d50)).is_false();
+        assert_that!(&cwkcf(EcmaVersion::_2025).contains(0x10d50)).is_true();
+        assert_that!(&cwkcf(EcmaVersion::_2018).contains(0x41)).is_true();
+
+        assert_that!(&property(EcmaVersion::_2025, "ASCII", None))
+            .is_equal_to(Some(CharSet::from_ranges([0..=0x7f])));
+        assert_that!(&property(EcmaVersion::_2025, "Any", None)).is_equal_to(Some(CharSet::all()));
+        assert_that!(&property(EcmaVersion::_2025, "L", None)).is_none();
+        let letters = property(EcmaVersion::_2025, "General_Category", Some("L")).unwrap();
+        assert_that!(&letters.contains(0x41)).is_true();
+        assert_that!(&letters
+            .is_subset(&property(EcmaVersion::_2025, "Alphabetic", None).unwrap()))
+        .is_true();
+        assert_that!(&property(EcmaVersion::_2025, "gc", Some("Cs")))
+            .is_equal_to(Some(CharSet::from_ranges([0xd800..=0xdfff])));
+    }
+
+    #[test]
+    fn test_compute_string_set() {
+        fn parse(source: &str, arena: &AllArenas) -> Id<Node> {
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, arena).unwrap();
+            *query_reg_exp_ast(ast, "Alternative > *", arena)
+                .unwrap()
+                .last()
+                .unwrap()
+        }
+        let string_set = |source: &str| {
+            let arena = AllArenas::default();
+            compute_string_set(parse(source, &arena), &arena)
+        };
+        let string = |string: &str| string.chars().map(CodePoint::from).collect_vec();
+
+        let abc_d = string_set(r"/[\q{abc|d}--\q{abc}]/v").unwrap();
+        assert_that!(&abc_d).is_equal_to(StringSet::from_strings([string("d")]));
+        let arena = AllArenas::default();
+        assert_that!(&compute_char_set(
+            parse(r"/[\q{abc|d}--\q{abc}]/v", &arena),
+            &arena
+        ))
+        .is_equal_to(Ok(CharSet::from_code_point(0x64)));
+
+        let flags =
+            string_set(r"/[\p{RGI_Emoji}&&[\q{🇫🇷|x}\p{RGI_Emoji_Flag_Sequence}]]/v").unwrap();
+        assert_that!(&flags.contains(&string("🇫🇷"))).is_true();
+        assert_that!(&flags.contains(&string("🇩🇪"))).is_true();
+        assert_that!(&flags.contains(&string("x"))).is_false();
+        assert_that!(&flags.chars().is_empty()).is_true();
+        let not_france = string_set(r"/[\p{RGI_Emoji_Flag_Sequence}--\q{🇫🇷}]/v").unwrap();
+        assert_that!(&not_france.contains(&string("🇫🇷"))).is_false();
+        assert_that!(&not_france.contains(&string("🇩🇪"))).is_true();
+
+        let ignoring_case = string_set(r"/[\q{AB|c}]/vi").unwrap();
+        assert_that!(&ignoring_case.chars())
+            .is_equal_to(&CharSet::from_ranges([0x43..=0x43, 0x63..=0x63]));
+        assert_that!(&ignoring_case.strings().count()).is_equal_to(1);
+        assert_that!(&string_set(r"/[\q{ab}--\q{AB}]/vi").unwrap().is_empty()).is_true();
+        assert_that!(&string_set(r"/[\q{ab}--\q{AB}]/v").unwrap().is_empty()).is_false();
+
+        let rgi_emoji = StringSet::from_property(EcmaVersion::_2025, "RGI_Emoji").unwrap();
+        for emoji in ["😀", "#️⃣", "👍🏽", "👩‍💻", "🏴󠁧󠁢󠁳󠁣󠁴󠁿", "🇨🇶"]
+        {
+            assert_that!(&rgi_emoji.contains(&string(emoji))).is_true();
+        }
+        assert_that!(&rgi_emoji.contains(&string("a"))).is_false();
+        assert_that!(&rgi_emoji.contains(&string("🇦🇦"))).is_false();
+        let sark = string("🇨🇶");
+        assert_that!(
+            &StringSet::from_property(EcmaVersion::_2024, "RGI_Emoji_Flag_Sequence")
+                .unwrap()
+                .contains(&sark)
+        )
+        .is_false();
+        assert_that!(&StringSet::from_property(EcmaVersion::_2023, "RGI_Emoji")).is_none();
+        assert_that!(&StringSet::from_property(EcmaVersion::_2025, "Emoji")).is_none();
+
+        for (source, expected) in [
+            (r"/[\q{a|bc}]/v", true),
+            (r"/[\q{a|b}]/v", false),
+            (r"/[\q{}]/v", true),
+            (r"/\p{RGI_Emoji}/v", true),
+            (r"/[\p{RGI_Emoji}--\q{x}]/v", true),
+            (r"/[a&&\p{RGI_Emoji}]/v", false),
+            (r"/[[\q{ab}]&&[\q{ab}]]/v", true),
+            (r"/[^[a-z]]/v", false),
+        ] {
+            let arena = AllArenas::default();
+            assert_that!(&may_contain_strings(parse(source, &arena), &arena)).is_equal_to(expected);
+        }
+    }
+
+    #[test]
+    fn test_case_folding() {
+        for (cp, unicode_mode, expected) in [
+            ('A', true, 'a'),
+            ('\u{212a}', true, 'k'),
+            ('\u{17f}', true, 's'),
+            ('\u{3c2}', true, '\u{3c3}'),
+            ('\u{1e9e}', true, '\u{df}'),
+            ('\u{ab70}', true, '\u{13a0}'),
+            ('\u{1fd3}', true, '\u{390}'),
+            ('\u{10400}', true, '\u{10428}'),
+            ('a', false, 'A'),
+            ('\u{212a}', false, '\u{212a}'),
+            ('\u{17f}', false, '\u{17f}'),
+            ('\u{df}', false, '\u{df}'),
+            ('\u{3c2}', false, '\u{3a3}'),
+            ('\u{1f80}', false, '\u{1f80}'),
+            ('\u{a7d3}', false, '\u{a7d3}'),
+            ('\u{10428}', false, '\u{10428}'),
+        ] {
+            assert_that!(&case_folding::canonicalize(cp.into(), unicode_mode))
+                .is_equal_to(CodePoint::from(expected));
+        }
+
+        assert_that!(&case_folding::case_equivalents(0x6b, true)).is_equal_to(
+            CharSet::from_ranges([0x4b..=0x4b, 0x6b..=0x6b, 0x212a..=0x212a]),
+        );
+        assert_that!(&case_folding::case_equivalents(0x6b, false))
+            .is_equal_to(CharSet::from_ranges([0x4b..=0x4b, 0x6b..=0x6b]));
+        assert_that!(&case_folding::case_equivalents(0x3c3, false).len()).is_equal_to(3);
+        assert_that!(&case_folding::case_equivalents(0x31, true))
+            .is_equal_to(CharSet::from_code_point(0x31));
+        assert_that!(&case_folding::close_over_case(
+            &CharSet::from_ranges([0x61..=0x63]),
+            false
+        ))
+        .is_equal_to(CharSet::from_ranges([0x41..=0x43, 0x61..=0x63]));
+
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/[\q{AB|Ꭰꭰ}]/vi"), None, &arena).unwrap();
+        let class = query_reg_exp_ast(ast, "CharacterClass", &arena).unwrap()[0];
+        assert_that!(&compute_string_set(class, &arena)
+            .unwrap()
+            .strings()
+            .map(ToOwned::to_owned)
+            .collect_vec())
+        .is_equal_to(vec![vec![0x61, 0x62], vec![0x13a0, 0x13a0]]);
+    }
+
+    #[test]
+    fn test_matcher() {
+        fn exec(source: &str, input: &str) -> Option<Vec<Option<String>>> {
+            let arena = AllArenas::default();
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap();
+            let result = Matcher::new(&arena, ast).exec(&Wtf16::from(input))?;
+            Some(
+                result
+                    .captures
+                    .iter()
+                    .map(|capture| {
+                        capture
+                            .as_ref()
+                            .map(|capture| String::try_from(capture).unwrap())
+                    })
+                    .collect(),
+            )
+        }
+
+        for (source, input, expected) in [
+            ("/a+?b*/", "xaabb", Some(vec![Some("a")])),
+            ("/a{2,}?/", "aaaa", Some(vec![Some("aa")])),
+            ("/a??b/", "ab", Some(vec![Some("ab")])),
+            ("/(a)|b/", "b", Some(vec![Some("b"), None])),
+            (
+                "/((a)|(ab))((c)|(bc))/",
+                "abc",
+                Some(vec![
+                    Some("abc"),
+                    Some("a"),
+                    Some("a"),
+                    None,
+                    Some("bc"),
+                    None,
+                    Some("bc"),
+                ]),
+            ),
+            ("/(a*)*/", "b", Some(vec![Some(""), None])),
+            ("/(a*)b\\1+/", "baaaac", Some(vec![Some("b"), Some("")])),
+            ("/(?:a|())*?b/", "ab", Some(vec![Some("ab"), None])),
+            (
+                "/(z)((a+)?(b+)?(c))*/",
+                "zaacbbbcac",
+                Some(vec![
+                    Some("zaacbbbcac"),
+                    Some("z"),
+                    Some("ac"),
+                    Some("a"),
+                    None,
+                    Some("c"),
+                ]),
+            ),
+            ("/(a)\\1/i", "aA", Some(vec![Some("aA"), Some("a")])),
+            ("/\\1(a)/", "a", Some(vec![Some("a"), Some("a")])),
+            (
+                "/(?=(a+))a*b\\1/",
+                "baaabac",
+                Some(vec![Some("aba"), Some("a")]),
+            ),
+            (
+                "/(.*?)a(?!(a+)b\\2c)\\2(.*)/",
+                "baaabaac",
+                Some(vec![Some("baaabaac"), Some("ba"), None, Some("abaac")]),
+            ),
+            (
+                "/(?<=\\$)\\d+(\\.\\d*)?/",
+                "cost $10.53",
+                Some(vec![Some("10.53"), Some(".53")]),
+            ),
+            (
+                "/(?<=(\\d+)(\\d+))$/",
+                "1053",
+                Some(vec![Some(""), Some("1"), Some("053")]),
+            ),
+            ("/(?<=\\1(a))b/", "aab", Some(vec![Some("b"), Some("a")])),
+            ("/(?<!a)b/", "abcb", Some(vec![Some("b")])),
+            ("/^b/", "a\nb", None),
+            ("/^b/m", "a\nb", Some(vec![Some("b")])),
+            ("/a$/m", "a\nb", Some(vec![Some("a")])),
+            ("/a.c/", "a\nc", None),
+            ("/a.c/s", "a\nc", Some(vec![Some("a\nc")])),
+            ("/\\bb\\B./", "ab bc", Some(vec![Some("bc")])),
+            ("/(?i:a)b/", "AB", None),
+            ("/(?i:a)b/", "Ab", Some(vec![Some("Ab")])),
+            ("/(?-i:a)b/i", "aB", Some(vec![Some("aB")])),
+            ("/^.$/", "😀", None),
+            ("/^.$/u", "😀", Some(vec![Some("😀")])),
+            ("/\\u{1F600}+/u", "😀😀", Some(vec![Some("😀😀")])),
+            ("/k/i", "\u{212a}", None),
+            ("/k/iu", "\u{212a}", Some(vec![Some("\u{212a}")])),
+            ("/\\w/iu", "\u{17f}", Some(vec![Some("\u{17f}")])),
+            ("/\\W/iu", "\u{17f}", None),
+            ("/\\P{Lu}/iu", "A", Some(vec![Some("A")])),
+            ("/\\P{Lu}/iv", "A", None),
+            ("/[\\q{abc|a}b]/v", "abc", Some(vec![Some("abc")])),
+            ("/[\\q{abc|a}b]/v", "ab", Some(vec![Some("a")])),
+            ("/[\\q{AB}]/vi", "ab", Some(vec![Some("ab")])),
+            ("/^\\p{RGI_Emoji}$/v", "👍🏽", Some(vec![Some("👍🏽")])),
+        ] {
+            let expected = expected.map(|captures| {
+                captures
+                    .into_iter()
+                    .map(|capture| capture.map(ToOwned::to_owned))
+                    .collect()
+            });
+            assert_that!(&(source, exec(source, input))).is_equal_to((source, expected));
+        }
+
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from("/a/g"), None, &arena).unwrap();
+        let mut matcher = Matcher::new(&arena, ast);
+        let input = Wtf16::from("aba");
+        assert_that!(&matcher.exec(&input).map(|result| result.index)).is_equal_to(Some(0));
+        assert_that!(&matcher.last_index()).is_equal_to(1);
+        assert_that!(&matcher.exec(&input).map(|result| result.index)).is_equal_to(Some(2));
+        assert_that!(&matcher.test(&input)).is_false();
+        assert_that!(&matcher.last_index()).is_equal_to(0);
+
+        let ast = parse_reg_exp_literal(&Wtf16::from("/a/y"), None, &arena).unwrap();
+        let mut matcher = Matcher::new(&arena, ast);
+        assert_that!(&matcher.test(&input)).is_true();
+        assert_that!(&matcher.test(&input)).is_false();
+        matcher.set_last_index(2);
+        assert_that!(&matcher.test(&input)).is_true();
+        assert_that!(&matcher.last_index()).is_equal_to(3);
+
+        let input = Wtf16::from("😀");
+        for (source, expected) in [("/\\ude00/g", Some(1)), ("/\\ude00/gu", None)] {
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap();
+            let result = Matcher::new(&arena, ast).exec(&input);
+            assert_that!(&result.map(|result| result.index)).is_equal_to(expected);
+        }
+
+        let ast = parse_reg_exp_literal(
+            &Wtf16::from("/(?<year>\\d{4})-(?:(?<day>\\d\\d)|(?<month>\\w+)(?<day>x)?)/d"),
+            None,
+            &arena,
+        )
+        .unwrap();
+        let result = Matcher::new(&arena, ast)
+            .exec(&Wtf16::from("x2024-10"))
+            .unwrap();
+        assert_that!(&result.index).is_equal_to(1);
+        assert_that!(&result.groups).is_equal_to(Some(IndexMap::from([
+            ("year".to_owned(), Some(Wtf16::from("2024"))),
+            ("day".to_owned(), Some(Wtf16::from("10"))),
+            ("month".to_owned(), None),
+        ])));
+        let indices = result.indices.unwrap();
+        assert_that!(&indices.captures).is_equal_to(vec![
+            Some(1..8),
+            Some(1..5),
+            Some(6..8),
+            None,
+            None,
+        ]);
+        assert_that!(&indices.groups.unwrap()["day"]).is_equal_to(Some(6..8));
+
+        let pattern = RegExpParser::new(&arena, None)
+            .parse_pattern(&Wtf16::from("(a)"), None, None, None)
+            .unwrap();
+        let flags = MatcherFlags {
+            ignore_case: true,
+            ..Default::default()
+        };
+        let result = Matcher::with_flags(&arena, pattern, flags)
+            .exec(&Wtf16::from("bA"))
+            .unwrap();
+        assert_that!(&result.index).is_equal_to(1);
+        assert_that!(&result.groups).is_none();
+        assert_that!(&result.indices).is_none();
+
+        let (a, ab) = ("a".repeat(200_000), "ab".repeat(100_000));
+        for (source, input, expected) in [
+            ("/(a)*/", &a, 200_000),
+            ("/(?:ab)*/", &ab, 200_000),
+            ("/(a|b)*/", &ab, 200_000),
+            ("/(?:ab)*?$/", &ab, 200_000),
+            ("/(?:ab)*c|b/", &ab, 1),
+        ] {
+            let ast = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap();
+            let result = Matcher::new(&arena, ast)
+                .exec(&Wtf16::from(input.as_str()))
+                .unwrap();
+            assert_that!(&(source, result.captures[0].as_ref().unwrap().len()))
+                .is_equal_to((source, expected));
+        }
+    }
+
+    #[test]
+    fn test_frozen_arena() {
+        struct Identity;
+        impl fold::Fold for Identity {}
+
+        #[derive(Default)]
+        struct CountingOptions {
+            literals: AtomicUsize,
+        }
+        impl validator::Options for CountingOptions {
+            fn strict(&self) -> Option<bool> {
+                None
+            }
+
+            fn ecma_version(&self) -> Option<EcmaVersion> {
+                None
+            }
+
+            fn on_literal_enter(&self, _start: usize) {
+                self.literals.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let counting = Arc::new(CountingOptions::default());
+        let options: Arc<dyn validator::Options + Send + Sync> = counting.clone();
+        let sources = [r"/a(b)\1/", r"/[c-d]+/v"];
+        let parsed = thread::scope(|scope| {
+            sources
+                .map(|source| {
+                    let options = options.clone();
+                    scope.spawn(move || {
+                        let source = Wtf16::from(source);
+                        RegExpValidator::new_shared(options)
+                            .validate_literal(&source, None, None)
+                            .unwrap();
+                        // The parser validates with its own state, so the
+                        // shared options don't see this second pass
+                        let arena = AllArenas::default();
+                        let ast = parse_reg_exp_literal(&source, None, &arena).unwrap();
+                        (arena.freeze(), ast)
+                    })
+                })
+                .map(|handle| handle.join().unwrap())
+        });
+
+        assert_that!(&counting.literals.load(Ordering::Relaxed)).is_equal_to(2);
+
+        let (arena, ast) = &parsed[0];
+        let counts = thread::scope(|scope| {
+            [NodeKind::Character, NodeKind::Backreference]
+                .map(|kind| {
+                    scope.spawn(move || {
+                        arena
+                            .preorder(*ast)
+                            .filter(|&node| arena.node(node).kind() == kind)
+                            .count()
+                    })
+                })
+                .map(|handle| handle.join().unwrap())
+        });
+        assert_that!(&counts).is_equal_to([2, 1]);
+        let reference = arena
+            .preorder(*ast)
+            .find(|&node| arena.node(node).kind() == NodeKind::Backreference)
+            .unwrap();
+        assert_that!(&arena.ancestors(reference).last()).is_equal_to(Some(*ast));
+
+        let merged = AllArenas::default();
+        for ((arena, ast), source) in parsed.into_iter().zip(sources) {
+            let arena = arena.thaw();
+            let copy = fold_reg_exp_ast(ast, &mut Identity, &arena, &merged);
+            assert_that!(&print_reg_exp_ast(copy, &merged)).is_equal_to(Wtf16::from(source));
+        }
+    }
+
+    #[test]
+    fn test_owned_node() {
+        let arena = AllArenas::default();
+        let ast =
+            parse_reg_exp_literal(&Wtf16::from(r"/(?<a>b)\k<a>[c-d]/"), None, &arena).unwrap();
+        let owned = OwnedNode::new(&arena, ast);
+        let OwnedNode::RegExpLiteral(literal) = &owned else {
+            unreachable!()
+        };
+        let elements = &literal.pattern.alternatives[0].elements;
+        assert_that!(&elements.len()).is_equal_to(3);
+        let OwnedNode::CharacterClass(class) = &elements[2] else {
+            unreachable!()
+        };
+        let OwnedNode::CharacterClassRange(range) = &class.elements[0] else {
+            unreachable!()
+        };
+        assert_that!(&range.min.value).is_equal_to(CodePoint::from('c'));
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn({
+            let owned = owned.clone();
+            move || sender.send(owned).unwrap()
+        });
+        let received = receiver.recv().unwrap();
+        assert_that!(&HashSet::from([owned.clone(), received]).len()).is_equal_to(1);
+
+        let other_arena = AllArenas::default();
+        let allocated = owned.alloc(&other_arena);
+        assert_that!(&print_reg_exp_ast(allocated, &other_arena))
+            .is_equal_to(Wtf16::from(r"/(?<a>b)\k<a>[c-d]/"));
+        let reference = other_arena
+            .preorder(allocated)
+            .find(|&node| other_arena.node(node).kind() == NodeKind::Backreference)
+            .unwrap();
+        let group = other_arena
+            .preorder(allocated)
+            .find(|&node| other_arena.node(node).kind() == NodeKind::CapturingGroup)
+            .unwrap();
+        assert_that!(&other_arena.node(reference).as_backreference().resolved)
+            .is_equal_to(vec![CapturingGroupId::new(&other_arena, group).unwrap()]);
+
+        let mut edited = owned;
+        let OwnedNode::RegExpLiteral(literal) = &mut edited else {
+            unreachable!()
+        };
+        literal.flags.global = true;
+        assert_that!(&OwnedNode::new(&arena, ast)).is_not_equal_to(edited);
+    }
+
+    #[test]
+    fn test_load_json_unresolvable_reference() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/(a)\1/"), None, &arena).unwrap();
+        let serialized = serde_json::to_string(&SerializableNode::new(&arena, ast)).unwrap();
+        arena.load_json(&serialized).unwrap();
+        assert_that!(&arena
+            .load_json(&serialized.replace(r#""resolved":"♻️"#, r#""resolved":"♻️../../.."#))
+            .unwrap_err()
+            .to_string())
+        .contains("couldn't resolve");
+        assert_that!(&arena
+            .load_json(&serialized.replace(r#""resolved":"♻️../0""#, r#""resolved":"♻️../..""#))
+            .unwrap_err()
+            .to_string())
+        .contains("unexpected node type");
+    }
+
+    #[test]
+    fn test_serialize_subtree() {
+        let arena = AllArenas::default();
+        let ast = parse_reg_exp_literal(&Wtf16::from(r"/(?:(?<a>x)|(?<a>y))\k<a>/"), None, &arena)
+            .unwrap();
+        let value = serde_json::to_value(SerializableNode::new(&arena, ast)).unwrap();
+        let elements = &value["pattern"]["alternatives"][0]["elements"];
+        assert_that!(&elements[1]["ambiguous"]).is_equal_to(serde_json::json!(true));
+
+        let group = arena
+            .preorder(ast)
+            .find(|&node| arena.node(node).kind() == NodeKind::Group)
+            .unwrap();
+        let value = serde_json::to_value(SerializableNode::new(&arena, group)).unwrap();
+        assert_that!(&value["parent"]).is_equal_to(serde_json::Value::Null);
+        assert_that!(&value["alternatives"][0]["elements"][0]["references"])
+            .is_equal_to(serde_json::json!([null]));
+
+        let reference = arena
+            .preorder(ast)
+            .find(|&node| arena.node(node).kind() == NodeKind::Backreference)
+            .unwrap();
+        let value = serde_json::to_value(SerializableNode::new(&arena, reference)).unwrap();
+        assert_that!(&value["parent"]).is_equal_to(serde_json::Value::Null);
+        assert_that!(&value["resolved"]).is_equal_to(serde_json::json!([null, null]));
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_serialize_lone_surrogate() {
+        let arena = AllArenas::default();
+        let source = Wtf16::new(vec![SOLIDUS as u16, 0xd800, 0x61, SOLIDUS as u16]);
+        let ast = parse_reg_exp_literal(&source, None, &arena).unwrap();
+        let serialized = serde_json::to_string(&SerializableNode::new(&arena, ast)).unwrap();
+        assert_that!(&serialized).contains(r#""raw":"/\ud800a/""#);
+        let loaded = arena.load_json(&serialized).unwrap();
+        assert_that!(&Wtf16::from(arena.node(loaded).raw())).is_equal_to(source);
+    }
+
+    #[cfg(not(feature = "serde_json"))]
+    #[test]
+    fn test_serialize_lone_surrogate_escaped() {
+        let arena = AllArenas::default();
+        let mut source = Wtf16::new(vec![SOLIDUS as u16, 0xd800]);
+        source.extend(Wtf16::from(r"\./").iter());
+        let ast = parse_reg_exp_literal(&source, None, &arena).unwrap();
+        let value = serde_json::to_value(SerializableNode::new(&arena, ast)).unwrap();
+        assert_that!(&value["raw"]).is_equal_to(serde_json::json!(r"/\ud800\\./"));
+    }
+
+    #[test]
+    fn test_syntax_error_kind() {
+        for (source, kind, index, span) in [
+            ("/a/x", RegExpSyntaxErrorKind::InvalidFlag('x'), 3, 3..4),
+            ("/(?/", RegExpSyntaxErrorKind::InvalidGroup, 3, 1..3),
+            (
+                "/a/gig",
+                RegExpSyntaxErrorKind::DuplicatedFlag('g'),
+                3,
+                5..6,
+            ),
+            (
+                "/a)/",
+                RegExpSyntaxErrorKind::UnmatchedRightParenthesis,
+                2,
+                2..3,
+            ),
+            (
+                "/a}/u",
+                RegExpSyntaxErrorKind::LoneQuantifierBrackets,
+                2,
+                2..3,
+            ),
+            (
+                "/(?<a>.)(?<a>.)/",
+                RegExpSyntaxErrorKind::DuplicateCaptureGroupName { name: "a".into() },
+                13,
+                10..13,
+            ),
+            (
+                "/\\k<b>(?<a>.)/",
+                RegExpSyntaxErrorKind::InvalidNamedCaptureReferenced { name: "b".into() },
+                13,
+                1..6,
+            ),
+            (
+                "/\\p{Script=Foo}/u",
+                RegExpSyntaxErrorKind::InvalidPropertyName {
+                    name: Some("Script".to_owned()),
+                    value: Some("Foo".to_owned()),
+                },
+                14,
+                1..15,
+            ),
+        ] {
+            let arena = AllArenas::default();
+            let error = parse_reg_exp_literal(&Wtf16::from(source), None, &arena).unwrap_err();
+
+            assert_that!(&error.kind).is_equal_to(kind);
+            assert_that!(&error.index).is_equal_to(index);
+            assert_that!(&error.span).is_equal_to(span);
+        }
+    }
+
+    #[test]
+    fn test_syntax_error_render() {
+        for (source, label, expected) in [
+            ("/a{3,1}/", None, "/a{3,1}/\n  ^^^^^"),
+            (
+                "/\\p{Foo}/u",
+                Some("unknown property"),
+                "/\\p{Foo}/u\n ^^^^^^^ unknown property",
+            ),
+            (
+                "/(a/",
+                Some("Unterminated group"),
+                "/(a/\n ^^ Unterminated group",
+            ),
+            ("/a/gg", None, "/a/gg\n    ^"),
+        ] {
+            let source: Wtf16 = source.into();
+            let arena = AllArenas::default();
+            let error = parse_reg_exp_literal(&source, None, &arena).unwrap_err();
+
+            assert_that!(&error.render(&source, label)).is_equal_to(expected.to_owned());
+        }
+    }
 }
@@ -0,0 +1,855 @@
+use std::{collections::HashMap, ops::Range};
+
+use id_arena::Id;
+use indexmap::IndexMap;
+
+use crate::{
+    ast::Node,
+    string_set::may_contain_strings,
+    unicode::{
+        case_folding::canonicalize, close_over_case, combine_surrogate_pair, is_lead_surrogate,
+        is_line_terminator, is_trail_surrogate, DIGIT_NINE, DIGIT_ZERO, LATIN_CAPITAL_LETTER_A,
+        LATIN_CAPITAL_LETTER_Z, LATIN_SMALL_LETTER_A, LATIN_SMALL_LETTER_Z, LOW_LINE,
+    },
+    validator::AssertionKind,
+    AllArenas, CharSet, CharSetFlags, CodePoint, StringSet, Wtf16,
+};
+
+/// The flags a [`Matcher`] runs with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MatcherFlags {
+    pub dot_all: bool,
+    pub global: bool,
+    pub has_indices: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub sticky: bool,
+    pub unicode: bool,
+    pub unicode_sets: bool,
+}
+
+impl MatcherFlags {
+    fn unicode_mode(self) -> bool {
+        self.unicode || self.unicode_sets
+    }
+}
+
+/// A successful match, like what `RegExp.prototype.exec()` returns
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchResult {
+    /// Where the match starts, in code units
+    pub index: usize,
+    /// The whole match, followed by each capturing group's match (or `None`
+    /// if it didn't participate)
+    pub captures: Vec<Option<Wtf16>>,
+    /// Each group name's match, in the order the names first appear, or
+    /// `None` if the pattern has no named groups
+    pub groups: Option<IndexMap<String, Option<Wtf16>>>,
+    /// Where each capture is, with the `d` flag
+    pub indices: Option<MatchIndices>,
+}
+
+/// The ranges of code units each capture spans, for the `d` flag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchIndices {
+    pub captures: Vec<Option<Range<usize>>>,
+    pub groups: Option<IndexMap<String, Option<Range<usize>>>>,
+}
+
+/// What a pattern compiles to, independent of the arena
+#[derive(Clone, Debug)]
+enum Term {
+    /// A single character: a code point in unicode mode, a code unit
+    /// otherwise
+    Char(CharSet),
+    /// A `v`-flag class with strings, which are tried longest first, before
+    /// single characters and then the empty string
+    Strings {
+        strings: Vec<Vec<CodePoint>>,
+        chars: CharSet,
+        empty: bool,
+        ignore_case: bool,
+    },
+    Seq(Vec<Term>),
+    Alt(Vec<Term>),
+    Capture(usize, Box<Term>),
+    Repeat {
+        min: u32,
+        /// `None` for no upper bound
+        max: Option<u32>,
+        greedy: bool,
+        /// The capturing groups inside, which are reset on each iteration
+        captures: Range<usize>,
+        term: Box<Term>,
+    },
+    /// The capturing groups are those with the name (or number); at most one
+    /// of them can have participated
+    Backreference {
+        captures: Vec<usize>,
+        ignore_case: bool,
+    },
+    Lookaround {
+        behind: bool,
+        negate: bool,
+        term: Box<Term>,
+    },
+    LineStart {
+        multiline: bool,
+    },
+    LineEnd {
+        multiline: bool,
+    },
+    WordBoundary {
+        negate: bool,
+        word: CharSet,
+    },
+}
+
+/// A compiled regex that finds matches in `Wtf16` input the way a
+/// JavaScript `RegExp` does, keeping its own `lastIndex`
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    _term: Term,
+    _flags: MatcherFlags,
+    /// The name of each capturing group, with the whole match at `0`
+    _names: Vec<Option<String>>,
+    _last_index: usize,
+}
+
+impl Matcher {
+    /// Compiles a `RegExpLiteral` with its flags, or a `Pattern` with those
+    /// of the `RegExpLiteral` it's in (or no flags, if it isn't in one)
+    ///
+    /// Panics if the node is anything else, or if the tree has errors from
+    /// tolerant parsing
+    pub fn new(arena: &AllArenas, node: impl Into<Id<Node>>) -> Self {
+        let node = node.into();
+        let (pattern, literal) = match &*arena.node(node) {
+            Node::RegExpLiteral(literal) => (literal.pattern.into(), Some(node)),
+            Node::Pattern(_) => (node, arena.ancestors(node).next()),
+            _ => panic!("Expected a RegExpLiteral or Pattern"),
+        };
+        let flags = literal.map_or_else(Default::default, |literal| {
+            let flags = arena.node(literal).as_reg_exp_literal().flags.get(arena);
+            MatcherFlags {
+                dot_all: flags.dot_all,
+                global: flags.global,
+                has_indices: flags.has_indices,
+                ignore_case: flags.ignore_case,
+                multiline: flags.multiline,
+                sticky: flags.sticky,
+                unicode: flags.unicode,
+                unicode_sets: flags.unicode_sets,
+            }
+        });
+        Self::with_flags(arena, pattern, flags)
+    }
+
+    /// Compiles a `RegExpLiteral` or `Pattern` with the given flags instead
+    /// of any the literal has
+    pub fn with_flags(arena: &AllArenas, node: impl Into<Id<Node>>, flags: MatcherFlags) -> Self {
+        let mut node = node.into();
+        if let Node::RegExpLiteral(literal) = &*arena.node(node) {
+            node = literal.pattern.into();
+        }
+        assert!(
+            matches!(&*arena.node(node), Node::Pattern(_)),
+            "Expected a RegExpLiteral or Pattern"
+        );
+        let mut names = vec![None];
+        let mut indices = HashMap::new();
+        for descendant in arena.preorder(node) {
+            if let Node::CapturingGroup(group) = &*arena.node(descendant) {
+                indices.insert(descendant, names.len());
+                names.push(
+                    group
+                        .name
+                        .as_ref()
+                        .map(|name| String::try_from(name).unwrap()),
+                );
+            }
+        }
+        let mut compiler = Compiler {
+            arena,
+            capture_indices: indices,
+            next_capture: 1,
+        };
+        let mode = Mode {
+            char_set: CharSetFlags {
+                ignore_case: flags.ignore_case,
+                dot_all: flags.dot_all,
+                unicode: flags.unicode,
+                unicode_sets: flags.unicode_sets,
+                ..Default::default()
+            },
+            multiline: flags.multiline,
+        };
+        Self {
+            _term: compiler.compile(node, mode),
+            _flags: flags,
+            _names: names,
+            _last_index: 0,
+        }
+    }
+
+    pub fn flags(&self) -> MatcherFlags {
+        self._flags
+    }
+
+    /// Where the next search starts, with the `g` or `y` flag
+    pub fn last_index(&self) -> usize {
+        self._last_index
+    }
+
+    pub fn set_last_index(&mut self, last_index: usize) {
+        self._last_index = last_index;
+    }
+
+    /// Looks for the next match like `RegExp.prototype.exec()`: from
+    /// `last_index` (and updating it) with the `g` or `y` flag, only there
+    /// with `y`, and from the start otherwise
+    pub fn exec(&mut self, input: &[u16]) -> Option<MatchResult> {
+        let flags = self._flags;
+        let updates_last_index = flags.global || flags.sticky;
+        let mut last_index = if updates_last_index {
+            self._last_index
+        } else {
+            0
+        };
+        let run = Run {
+            input,
+            unicode_mode: flags.unicode_mode(),
+        };
+        let (end, captures) = loop {
+            if last_index > input.len() {
+                if updates_last_index {
+                    self._last_index = 0;
+                }
+                return None;
+            }
+            if let Some(found) = run.match_at(&self._term, self._names.len(), last_index) {
+                break found;
+            }
+            if flags.sticky {
+                self._last_index = 0;
+                return None;
+            }
+            last_index = run.advance(last_index);
+        };
+        if updates_last_index {
+            self._last_index = end;
+        }
+        Some(self.result(input, last_index, end, captures))
+    }
+
+    /// Whether there's a match, updating `last_index` like [`Self::exec`]
+    pub fn test(&mut self, input: &[u16]) -> bool {
+        self.exec(input).is_some()
+    }
+
+    fn result(
+        &self,
+        input: &[u16],
+        start: usize,
+        end: usize,
+        mut captures: Captures,
+    ) -> MatchResult {
+        captures[0] = Some((start, end));
+        let ranges = captures
+            .iter()
+            .map(|capture| capture.map(|(start, end)| start..end))
+            .collect::<Vec<_>>();
+        let group_ranges = self._names.iter().any(Option::is_some).then(|| {
+            let mut groups: IndexMap<String, Option<Range<usize>>> = IndexMap::new();
+            for (name, range) in self._names.iter().zip(&ranges) {
+                let Some(name) = name else {
+                    continue;
+                };
+                let entry = groups.entry(name.clone()).or_default();
+                if entry.is_none() {
+                    *entry = range.clone();
+                }
+            }
+            groups
+        });
+        let substring = |range: &Option<Range<usize>>| {
+            range
+                .as_ref()
+                .map(|range| Wtf16::from(&input[range.clone()]))
+        };
+        MatchResult {
+            index: start,
+            captures: ranges.iter().map(substring).collect(),
+            groups: group_ranges.as_ref().map(|groups| {
+                groups
+                    .iter()
+                    .map(|(name, range)| (name.clone(), substring(range)))
+                    .collect()
+            }),
+            indices: self._flags.has_indices.then(|| MatchIndices {
+                captures: ranges.clone(),
+                groups: group_ranges.clone(),
+            }),
+        }
+    }
+}
+
+/// The flags that can change partway through a pattern, through modifiers
+#[derive(Copy, Clone)]
+struct Mode {
+    char_set: CharSetFlags,
+    multiline: bool,
+}
+
+struct Compiler<'a> {
+    arena: &'a AllArenas,
+    capture_indices: HashMap<Id<Node>, usize>,
+    next_capture: usize,
+}
+
+impl Compiler<'_> {
+    fn compile(&mut self, node: Id<Node>, mode: Mode) -> Term {
+        let arena = self.arena;
+        match &*arena.node(node) {
+            Node::Pattern(pattern) => {
+                self.compile_alternatives(pattern.alternatives.iter().map(|&id| id.into()), mode)
+            }
+            Node::Alternative(alternative) => Term::Seq(
+                alternative
+                    .elements
+                    .iter()
+                    .map(|&element| self.compile(element.into(), mode))
+                    .collect(),
+            ),
+            Node::Group(group) => {
+                let mut mode = mode;
+                if let Some(modifiers) = group.modifiers {
+                    let modifiers = modifiers.get(arena);
+                    let add = modifiers.add.get(arena);
+                    mode.char_set.ignore_case |= add.ignore_case;
+                    mode.char_set.dot_all |= add.dot_all;
+                    mode.multiline |= add.multiline;
+                    if let Some(remove) = modifiers.remove {
+                        let remove = remove.get(arena);
+                        mode.char_set.ignore_case &= !remove.ignore_case;
+                        mode.char_set.dot_all &= !remove.dot_all;
+                        mode.multiline &= !remove.multiline;
+                    }
+                }
+                self.compile_alternatives(group.alternatives.iter().map(|&id| id.into()), mode)
+            }
+            Node::CapturingGroup(group) => {
+                let index = self.next_capture;
+                self.next_capture += 1;
+                Term::Capture(
+                    index,
+                    Box::new(self.compile_alternatives(
+                        group.alternatives.iter().map(|&id| id.into()),
+                        mode,
+                    )),
+                )
+            }
+            Node::Quantifier(quantifier) => {
+                let first_capture = self.next_capture;
+                let term = self.compile(quantifier.element.into(), mode);
+                Term::Repeat {
+                    min: quantifier.min,
+                    max: (quantifier.max != u32::MAX).then_some(quantifier.max),
+                    greedy: quantifier.greedy,
+                    captures: first_capture..self.next_capture,
+                    term: Box::new(term),
+                }
+            }
+            Node::Assertion(assertion) => match assertion.kind {
+                AssertionKind::Start => Term::LineStart {
+                    multiline: mode.multiline,
+                },
+                AssertionKind::End => Term::LineEnd {
+                    multiline: mode.multiline,
+                },
+                AssertionKind::Word => {
+                    let word = CharSet::from_ranges([
+                        DIGIT_ZERO..=DIGIT_NINE,
+                        LATIN_CAPITAL_LETTER_A..=LATIN_CAPITAL_LETTER_Z,
+                        LOW_LINE..=LOW_LINE,
+                        LATIN_SMALL_LETTER_A..=LATIN_SMALL_LETTER_Z,
+                    ]);
+                    Term::WordBoundary {
+                        negate: assertion.negate == Some(true),
+                        word: if mode.char_set.ignore_case && mode.char_set.unicode_mode() {
+                            close_over_case(&word, true)
+                        } else {
+                            word
+                        },
+                    }
+                }
+                AssertionKind::Lookahead | AssertionKind::Lookbehind => Term::Lookaround {
+                    behind: assertion.kind == AssertionKind::Lookbehind,
+                    negate: assertion.negate == Some(true),
+                    term: Box::new(self.compile_alternatives(
+                        assertion.alternatives.iter().flatten().map(|&id| id.into()),
+                        mode,
+                    )),
+                },
+            },
+            Node::Backreference(backreference) => Term::Backreference {
+                captures: backreference
+                    .resolved
+                    .iter()
+                    .map(|&group| self.capture_indices[&group.into()])
+                    .collect(),
+                ignore_case: mode.char_set.ignore_case,
+            },
+            _ if mode.char_set.unicode_sets && may_contain_strings(arena, node) => {
+                let string_set = StringSet::from_node(arena, node, mode.char_set)
+                    .expect("a parsed class should have a known value");
+                let mut strings = string_set
+                    .strings()
+                    .filter(|string| !string.is_empty())
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>();
+                strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+                Term::Strings {
+                    empty: string_set.contains(&[]),
+                    strings,
+                    chars: string_set.chars().clone(),
+                    ignore_case: mode.char_set.ignore_case,
+                }
+            }
+            _ => Term::Char(
+                CharSet::from_node(arena, node, mode.char_set)
+                    .expect("a parsed class should have a known value"),
+            ),
+        }
+    }
+
+    fn compile_alternatives(
+        &mut self,
+        alternatives: impl Iterator<Item = Id<Node>>,
+        mode: Mode,
+    ) -> Term {
+        let mut terms = alternatives
+            .map(|alternative| self.compile(alternative, mode))
+            .collect::<Vec<_>>();
+        if terms.len() == 1 {
+            terms.pop().unwrap()
+        } else {
+            Term::Alt(terms)
+        }
+    }
+}
+
+/// Where matching is at: the position and the capturing groups' ranges
+#[derive(Clone)]
+struct State {
+    position: usize,
+    captures: Captures,
+}
+
+/// The start and end of each capturing group that participated
+type Captures = Vec<Option<(usize, usize)>>;
+
+/// Something a repetition can backtrack to
+enum Choice {
+    /// Matching what follows the repetition from the state
+    Continue(State),
+    /// Matching another iteration after the number of them, from the state,
+    /// in the nth way the term can match there
+    Iterate(u32, State, usize),
+}
+
+/// A continuation, which is given the state after a term matches and says
+/// whether the rest of the pattern did too
+type Continuation<'a> = dyn FnMut(&mut State) -> bool + 'a;
+
+/// Backtracking matching in the style of the spec's `Matcher`s: each term
+/// calls the continuation for each way it can match, in order of
+/// preference, and undoes any changes to the state when one fails
+struct Run<'a> {
+    input: &'a [u16],
+    unicode_mode: bool,
+}
+
+impl Run<'_> {
+    fn match_at(
+        &self,
+        term: &Term,
+        capture_count: usize,
+        position: usize,
+    ) -> Option<(usize, Captures)> {
+        let mut state = State {
+            position,
+            captures: vec![None; capture_count],
+        };
+        let mut found = None;
+        self.matches(term, true, &mut state, &mut |state| {
+            found = Some((state.position, state.captures.clone()));
+            true
+        });
+        found
+    }
+
+    /// `AdvanceStringIndex`
+    fn advance(&self, index: usize) -> usize {
+        match self.read(index, true) {
+            Some((_, next)) if self.unicode_mode => next,
+            _ => index + 1,
+        }
+    }
+
+    /// The character after (or before) the position, and the position past
+    /// it
+    fn read(&self, position: usize, forward: bool) -> Option<(CodePoint, usize)> {
+        let input = self.input;
+        if forward {
+            let unit = CodePoint::from(*input.get(position)?);
+            match input.get(position + 1) {
+                Some(&next)
+                    if self.unicode_mode
+                        && is_lead_surrogate(unit)
+                        && is_trail_surrogate(next.into()) =>
+                {
+                    Some((combine_surrogate_pair(unit, next.into()), position + 2))
+                }
+                _ => Some((unit, position + 1)),
+            }
+        } else {
+            let unit = CodePoint::from(*input.get(position.checked_sub(1)?)?);
+            match position.checked_sub(2).map(|index| input[index]) {
+                Some(previous)
+                    if self.unicode_mode
+                        && is_trail_surrogate(unit)
+                        && is_lead_surrogate(previous.into()) =>
+                {
+                    Some((combine_surrogate_pair(previous.into(), unit), position - 2))
+                }
+                _ => Some((unit, position - 1)),
+            }
+        }
+    }
+
+    fn canonicalize(&self, cp: CodePoint, ignore_case: bool) -> CodePoint {
+        if ignore_case {
+            canonicalize(cp, self.unicode_mode)
+        } else {
+            cp
+        }
+    }
+
+    /// Reads the characters, in the order they appear in the input, going
+    /// in the direction, and returns the position past them
+    fn read_string(
+        &self,
+        string: &[CodePoint],
+        position: usize,
+        forward: bool,
+        ignore_case: bool,
+    ) -> Option<usize> {
+        let mut position = position;
+        let mut read = |&cp: &CodePoint| {
+            let (actual, next) = self.read(position, forward)?;
+            (self.canonicalize(actual, ignore_case) == cp).then(|| position = next)
+        };
+        if forward {
+            string.iter().try_for_each(&mut read)?;
+        } else {
+            string.iter().rev().try_for_each(&mut read)?;
+        }
+        Some(position)
+    }
+
+    /// Moves to the position and calls the continuation, moving back if it
+    /// fails
+    fn step(&self, position: usize, state: &mut State, k: &mut Continuation) -> bool {
+        let previous = state.position;
+        state.position = position;
+        if k(state) {
+            return true;
+        }
+        state.position = previous;
+        false
+    }
+
+    fn matches(&self, term: &Term, forward: bool, state: &mut State, k: &mut Continuation) -> bool {
+        match term {
+            Term::Char(set) => match self.read(state.position, forward) {
+                Some((cp, next)) if set.contains(cp) => self.step(next, state, k),
+                _ => false,
+            },
+            Term::Strings {
+                strings,
+                chars,
+                empty,
+                ignore_case,
+            } => {
+                for string in strings {
+                    if let Some(next) =
+                        self.read_string(string, state.position, forward, *ignore_case)
+                    {
+                        if self.step(next, state, k) {
+                            return true;
+                        }
+                    }
+                }
+                if let Some((cp, next)) = self.read(state.position, forward) {
+                    if chars.contains(cp) && self.step(next, state, k) {
+                        return true;
+                    }
+                }
+                *empty && k(state)
+            }
+            Term::Seq(terms) => self.matches_seq(terms, forward, state, k),
+            Term::Alt(terms) => terms
+                .iter()
+                .any(|term| self.matches(term, forward, state, k)),
+            &Term::Capture(index, ref term) => {
+                let start = state.position;
+                self.matches(term, forward, state, &mut |state| {
+                    let previous = state.captures[index];
+                    state.captures[index] = Some(if forward {
+                        (start, state.position)
+                    } else {
+                        (state.position, start)
+                    });
+                    if k(state) {
+                        return true;
+                    }
+                    state.captures[index] = previous;
+                    false
+                })
+            }
+            Term::Repeat {
+                min,
+                max,
+                greedy,
+                captures,
+                term,
+            } => match &**term {
+                Term::Char(set) => self.repeat_char(set, *min, *max, *greedy, forward, state, k),
+                _ => self.repeat(term, *min, *max, *greedy, captures, forward, state, k),
+            },
+            Term::Backreference {
+                captures,
+                ignore_case,
+            } => {
+                let Some((start, end)) = captures.iter().find_map(|&index| state.captures[index])
+                else {
+                    return k(state);
+                };
+                let mut string = vec![];
+                let mut position = start;
+                while position < end {
+                    let (cp, next) = self.read(position, true).unwrap();
+                    string.push(self.canonicalize(cp, *ignore_case));
+                    position = next;
+                }
+                match self.read_string(&string, state.position, forward, *ignore_case) {
+                    Some(next) => self.step(next, state, k),
+                    None => false,
+                }
+            }
+            Term::Lookaround {
+                behind,
+                negate,
+                term,
+            } => {
+                let position = state.position;
+                let captures = state.captures.clone();
+                let matched = self.matches(term, !behind, state, &mut |_| true);
+                if *negate {
+                    if matched {
+                        state.position = position;
+                        state.captures = captures;
+                        return false;
+                    }
+                    return k(state);
+                }
+                if !matched {
+                    return false;
+                }
+                state.position = position;
+                if k(state) {
+                    return true;
+                }
+                state.captures = captures;
+                false
+            }
+            &Term::LineStart { multiline } => {
+                let position = state.position;
+                (position == 0 || multiline && is_line_terminator(self.input[position - 1].into()))
+                    && k(state)
+            }
+            &Term::LineEnd { multiline } => {
+                let position = state.position;
+                (position == self.input.len()
+                    || multiline && is_line_terminator(self.input[position].into()))
+                    && k(state)
+            }
+            Term::WordBoundary { negate, word } => {
+                let position = state.position;
+                let is_word = |index: Option<usize>| {
+                    index
+                        .and_then(|index| self.input.get(index))
+                        .is_some_and(|&unit| word.contains(unit.into()))
+                };
+                let at_boundary = is_word(position.checked_sub(1)) != is_word(Some(position));
+                at_boundary != *negate && k(state)
+            }
+        }
+    }
+
+    /// Going backward, as in a lookbehind, the terms match from last to
+    /// first
+    fn matches_seq(
+        &self,
+        terms: &[Term],
+        forward: bool,
+        state: &mut State,
+        k: &mut Continuation,
+    ) -> bool {
+        let split = if forward {
+            terms.split_first()
+        } else {
+            terms.split_last()
+        };
+        let Some((term, rest)) = split else {
+            return k(state);
+        };
+        self.matches(term, forward, state, &mut |state| {
+            self.matches_seq(rest, forward, state, k)
+        })
+    }
+
+    /// `RepeatMatcher`, which resets the capturing groups inside on each
+    /// iteration, and stops once an optional iteration matches the empty
+    /// string
+    ///
+    /// Rather than recursing for each iteration, which would overflow the
+    /// stack on long input, the choices it can backtrack to are kept on a
+    /// stack, and an iteration's later ways to match are found by matching
+    /// the term again and skipping the earlier ones
+    #[allow(clippy::too_many_arguments)]
+    fn repeat(
+        &self,
+        term: &Term,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        captures: &Range<usize>,
+        forward: bool,
+        state: &mut State,
+        k: &mut Continuation,
+    ) -> bool {
+        // The choices after the number of iterations, pushed so that the
+        // preferred one is popped first
+        let visit = |count: u32, state: State, choices: &mut Vec<Choice>| {
+            let iterate = max.is_none_or(|max| count < max);
+            let done = count >= min;
+            if greedy {
+                if done {
+                    choices.push(Choice::Continue(state.clone()));
+                }
+                if iterate {
+                    choices.push(Choice::Iterate(count, state, 0));
+                }
+            } else {
+                if iterate {
+                    choices.push(Choice::Iterate(count, state.clone(), 0));
+                }
+                if done {
+                    choices.push(Choice::Continue(state));
+                }
+            }
+        };
+        let original = state.clone();
+        let mut choices = vec![];
+        visit(0, original.clone(), &mut choices);
+        while let Some(choice) = choices.pop() {
+            match choice {
+                Choice::Continue(next) => {
+                    *state = next;
+                    if k(state) {
+                        return true;
+                    }
+                }
+                Choice::Iterate(count, from, nth) => {
+                    let mut next = from.clone();
+                    next.captures[captures.clone()].fill(None);
+                    let mut skip = nth;
+                    let matched = self.matches(term, forward, &mut next, &mut |next| {
+                        if count >= min && next.position == from.position {
+                            return false;
+                        }
+                        if skip > 0 {
+                            skip -= 1;
+                            return false;
+                        }
+                        true
+                    });
+                    if matched {
+                        choices.push(Choice::Iterate(count, from, nth + 1));
+                        visit(count + 1, next, &mut choices);
+                    }
+                }
+            }
+        }
+        *state = original;
+        false
+    }
+
+    /// A quantified single character, which can't match the empty string or
+    /// contain capturing groups, so can be matched without recursing
+    #[allow(clippy::too_many_arguments)]
+    fn repeat_char(
+        &self,
+        set: &CharSet,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+        forward: bool,
+        state: &mut State,
+        k: &mut Continuation,
+    ) -> bool {
+        let start = state.position;
+        let next = |position: usize| {
+            self.read(position, forward)
+                .filter(|&(cp, _)| set.contains(cp))
+                .map(|(_, next)| next)
+        };
+        let mut count = 0;
+        if greedy {
+            let mut positions = vec![start];
+            while max.is_none_or(|max| count < max) {
+                let Some(position) = next(*positions.last().unwrap()) else {
+                    break;
+                };
+                positions.push(position);
+                count += 1;
+            }
+            while count >= min {
+                if self.step(positions[count as usize], state, k) {
+                    return true;
+                }
+                if count == 0 {
+                    break;
+                }
+                count -= 1;
+            }
+            return false;
+        }
+        let mut position = start;
+        loop {
+            if count >= min && self.step(position, state, k) {
+                return true;
+            }
+            if max.is_some_and(|max| count >= max) {
+                return false;
+            }
+            let Some(next) = next(position) else {
+                return false;
+            };
+            position = next;
+            count += 1;
+        }
+    }
+}
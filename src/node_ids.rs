@@ -0,0 +1,237 @@
+use debug_cell::{Ref, RefMut};
+use id_arena::Id;
+
+use crate::{
+    ast::{
+        Alternative, Backreference, CapturingGroup, Character, Flags, ModifierFlags, Modifiers,
+        Pattern, StringAlternative,
+    },
+    validator::{AssertionKind, CharacterKind},
+    AllArenas, Node,
+};
+
+pub(crate) mod private {
+    use id_arena::Id;
+
+    use crate::Node;
+
+    pub trait Sealed {
+        fn new_unchecked(id: Id<Node>) -> Self;
+    }
+}
+
+/// Implemented by all of the typed handles
+pub trait NodeId: private::Sealed + Copy + Into<Id<Node>> {
+    /// Returns `None` if the node is the wrong kind for this handle
+    fn from_id(arena: &AllArenas, id: Id<Node>) -> Option<Self>;
+}
+
+pub(crate) fn new_unchecked<T: NodeId>(id: Id<Node>) -> T {
+    private::Sealed::new_unchecked(id)
+}
+
+macro_rules! id_common {
+    ($name:ident) => {
+        impl private::Sealed for $name {
+            fn new_unchecked(id: Id<Node>) -> Self {
+                Self(id)
+            }
+        }
+
+        impl NodeId for $name {
+            fn from_id(arena: &AllArenas, id: Id<Node>) -> Option<Self> {
+                Self::new(arena, id)
+            }
+        }
+
+        impl $name {
+            pub fn id(self) -> Id<Node> {
+                self.0
+            }
+        }
+
+        impl From<$name> for Id<Node> {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+// A handle only ever holds the id of a node of the kind(s) it's named after,
+// so e.g. passing an `Alternative` where a `Character` is expected fails to
+// compile instead of panicking in `Node::as_*`. Handles for a single kind
+// resolve to that kind's struct, handles for a union of kinds to the `Node`
+macro_rules! node_id {
+    ($(#[$meta:meta])* $name:ident => $kind:ident, $as:ident, $as_mut:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(Id<Node>);
+
+        id_common!($name);
+
+        impl $name {
+            /// Returns `None` if the node is some other kind
+            pub fn new(arena: &AllArenas, id: Id<Node>) -> Option<Self> {
+                matches!(&*arena.node(id), Node::$kind(_)).then_some(Self(id))
+            }
+
+            pub fn get(self, arena: &AllArenas) -> Ref<$kind> {
+                Ref::map(arena.node(self.0), Node::$as)
+            }
+
+            pub fn get_mut(self, arena: &AllArenas) -> RefMut<$kind> {
+                RefMut::map(arena.node_mut(self.0), Node::$as_mut)
+            }
+        }
+    };
+}
+
+macro_rules! union_node_id {
+    ($(#[$meta:meta])* $name:ident => $is:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(Id<Node>);
+
+        id_common!($name);
+
+        impl $name {
+            /// Returns `None` if the node is some other kind
+            pub fn new(arena: &AllArenas, id: Id<Node>) -> Option<Self> {
+                $is(&arena.node(id)).then_some(Self(id))
+            }
+
+            pub fn get(self, arena: &AllArenas) -> Ref<Node> {
+                arena.node(self.0)
+            }
+
+            pub fn get_mut(self, arena: &AllArenas) -> RefMut<Node> {
+                arena.node_mut(self.0)
+            }
+        }
+    };
+}
+
+/// Conversions from a handle to the handles for unions that include it
+macro_rules! widen {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> Self {
+                    Self(value.0)
+                }
+            }
+        )+
+    };
+}
+
+node_id!(PatternId => Pattern, as_pattern, as_pattern_mut);
+node_id!(FlagsId => Flags, as_flags, as_flags_mut);
+node_id!(AlternativeId => Alternative, as_alternative, as_alternative_mut);
+node_id!(ModifiersId => Modifiers, as_modifiers, as_modifiers_mut);
+node_id!(ModifierFlagsId => ModifierFlags, as_modifier_flags, as_modifier_flags_mut);
+node_id!(CapturingGroupId => CapturingGroup, as_capturing_group, as_capturing_group_mut);
+node_id!(BackreferenceId => Backreference, as_backreference, as_backreference_mut);
+node_id!(CharacterId => Character, as_character, as_character_mut);
+node_id!(
+    StringAlternativeId => StringAlternative,
+    as_string_alternative,
+    as_string_alternative_mut
+);
+
+union_node_id!(
+    /// Anything that can appear in an `Alternative`
+    ElementId => is_element
+);
+union_node_id!(
+    /// Anything that a `Quantifier` can apply to
+    QuantifiableElementId => is_quantifiable_element
+);
+union_node_id!(
+    /// Anything that can appear in a `CharacterClass`
+    CharacterClassElementId => is_character_class_element
+);
+union_node_id!(
+    /// An operand of a `ClassIntersection` or `ClassSubtraction`
+    ClassSetOperandId => is_class_set_operand
+);
+union_node_id!(
+    /// The left-hand side of a `ClassIntersection`, which is another
+    /// intersection when several are chained
+    ClassIntersectionLeftId => is_class_intersection_left
+);
+union_node_id!(
+    /// The left-hand side of a `ClassSubtraction`, which is another
+    /// subtraction when several are chained
+    ClassSubtractionLeftId => is_class_subtraction_left
+);
+union_node_id!(
+    /// The expression of an `ExpressionCharacterClass`
+    ClassSetExpressionId => is_class_set_expression
+);
+
+widen!(
+    CharacterId => ElementId,
+    QuantifiableElementId,
+    CharacterClassElementId,
+    ClassSetOperandId,
+    ClassIntersectionLeftId,
+    ClassSubtractionLeftId
+);
+widen!(CapturingGroupId => ElementId, QuantifiableElementId);
+widen!(BackreferenceId => ElementId, QuantifiableElementId);
+widen!(QuantifiableElementId => ElementId);
+widen!(
+    ClassSetOperandId => CharacterClassElementId,
+    ClassIntersectionLeftId,
+    ClassSubtractionLeftId
+);
+
+pub(crate) fn is_element(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Assertion(_) | Node::Quantifier(_) | Node::Error(_)
+    ) || is_quantifiable_element(node)
+}
+
+pub(crate) fn is_quantifiable_element(node: &Node) -> bool {
+    match node {
+        Node::Group(_)
+        | Node::CapturingGroup(_)
+        | Node::CharacterClass(_)
+        | Node::ExpressionCharacterClass(_)
+        | Node::CharacterSet(_)
+        | Node::Character(_)
+        | Node::Backreference(_) => true,
+        // Only allowed in legacy mode, but still a valid tree shape
+        Node::Assertion(node) => node.kind == AssertionKind::Lookahead,
+        _ => false,
+    }
+}
+
+pub(crate) fn is_character_class_element(node: &Node) -> bool {
+    matches!(node, Node::CharacterClassRange(_)) || is_class_set_operand(node)
+}
+
+pub(crate) fn is_class_set_operand(node: &Node) -> bool {
+    match node {
+        Node::Character(_)
+        | Node::ClassStringDisjunction(_)
+        | Node::CharacterClass(_)
+        | Node::ExpressionCharacterClass(_) => true,
+        Node::CharacterSet(node) => node.kind != CharacterKind::Any,
+        _ => false,
+    }
+}
+
+pub(crate) fn is_class_intersection_left(node: &Node) -> bool {
+    matches!(node, Node::ClassIntersection(_)) || is_class_set_operand(node)
+}
+
+pub(crate) fn is_class_subtraction_left(node: &Node) -> bool {
+    matches!(node, Node::ClassSubtraction(_)) || is_class_set_operand(node)
+}
+
+pub(crate) fn is_class_set_expression(node: &Node) -> bool {
+    matches!(node, Node::ClassIntersection(_) | Node::ClassSubtraction(_))
+}
@@ -0,0 +1,799 @@
+use id_arena::Id;
+
+use crate::{
+    ast::{Node, NodeInterface, NodeKind},
+    editor::resolve_backreferences,
+    fold::typed,
+    node_ids::{
+        private::Sealed, AlternativeId, CharacterId, FlagsId, ModifierFlagsId, ModifiersId, NodeId,
+        PatternId, StringAlternativeId,
+    },
+    validator::{AssertionKind, CapturingGroupKey, CharacterKind},
+    AllArenas, CodePoint, RegExpSyntaxErrorKind, Wtf16,
+};
+
+/// A tree that owns its nodes, for when an arena gets in the way (sending
+/// trees over channels, caching them, writing them out in tests)
+///
+/// There are no links up or across the tree: each node's parent is
+/// implied by where it is, and `CapturingGroup.references` and
+/// `Backreference.resolved` are worked out again when the tree is
+/// allocated back into an arena. Children whose kind is fixed are stored as
+/// that kind's struct, the rest as an `OwnedNode`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OwnedNode {
+    Alternative(Box<AlternativeOwned>),
+    CapturingGroup(Box<CapturingGroupOwned>),
+    CharacterClass(Box<CharacterClassOwned>),
+    CharacterClassRange(Box<CharacterClassRangeOwned>),
+    ClassIntersection(Box<ClassIntersectionOwned>),
+    ClassStringDisjunction(Box<ClassStringDisjunctionOwned>),
+    ClassSubtraction(Box<ClassSubtractionOwned>),
+    ExpressionCharacterClass(Box<ExpressionCharacterClassOwned>),
+    Group(Box<GroupOwned>),
+    Modifiers(Box<ModifiersOwned>),
+    ModifierFlags(Box<ModifierFlagsOwned>),
+    Assertion(Box<AssertionOwned>),
+    Pattern(Box<PatternOwned>),
+    Quantifier(Box<QuantifierOwned>),
+    RegExpLiteral(Box<RegExpLiteralOwned>),
+    StringAlternative(Box<StringAlternativeOwned>),
+    Backreference(Box<BackreferenceOwned>),
+    Character(Box<CharacterOwned>),
+    CharacterSet(Box<CharacterSetOwned>),
+    Flags(Box<FlagsOwned>),
+    Error(Box<ErrorOwned>),
+}
+
+/// What every kind of node has
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NodeBaseOwned {
+    pub start: usize,
+    pub end: usize,
+    pub raw: Wtf16,
+    pub synthesized: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RegExpLiteralOwned {
+    pub base: NodeBaseOwned,
+    pub pattern: PatternOwned,
+    pub flags: FlagsOwned,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PatternOwned {
+    pub base: NodeBaseOwned,
+    pub alternatives: Vec<AlternativeOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AlternativeOwned {
+    pub base: NodeBaseOwned,
+    pub elements: Vec<OwnedNode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GroupOwned {
+    pub base: NodeBaseOwned,
+    pub modifiers: Option<ModifiersOwned>,
+    pub alternatives: Vec<AlternativeOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModifiersOwned {
+    pub base: NodeBaseOwned,
+    pub add: ModifierFlagsOwned,
+    pub remove: Option<ModifierFlagsOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ModifierFlagsOwned {
+    pub base: NodeBaseOwned,
+    pub dot_all: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CapturingGroupOwned {
+    pub base: NodeBaseOwned,
+    pub name: Option<Wtf16>,
+    pub alternatives: Vec<AlternativeOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QuantifierOwned {
+    pub base: NodeBaseOwned,
+    pub min: u32,
+    pub max: u32,
+    pub greedy: bool,
+    pub element: OwnedNode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterClassOwned {
+    pub base: NodeBaseOwned,
+    pub unicode_sets: bool,
+    pub negate: bool,
+    pub elements: Vec<OwnedNode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterClassRangeOwned {
+    pub base: NodeBaseOwned,
+    pub min: CharacterOwned,
+    pub max: CharacterOwned,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AssertionOwned {
+    pub base: NodeBaseOwned,
+    pub kind: AssertionKind,
+    pub negate: Option<bool>,
+    pub alternatives: Option<Vec<AlternativeOwned>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterSetOwned {
+    pub base: NodeBaseOwned,
+    pub kind: CharacterKind,
+    pub strings: Option<bool>,
+    pub key: Option<Wtf16>,
+    pub value: Option<Wtf16>,
+    pub negate: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExpressionCharacterClassOwned {
+    pub base: NodeBaseOwned,
+    pub negate: bool,
+    pub expression: OwnedNode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClassIntersectionOwned {
+    pub base: NodeBaseOwned,
+    pub left: OwnedNode,
+    pub right: OwnedNode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClassSubtractionOwned {
+    pub base: NodeBaseOwned,
+    pub left: OwnedNode,
+    pub right: OwnedNode,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ClassStringDisjunctionOwned {
+    pub base: NodeBaseOwned,
+    pub alternatives: Vec<StringAlternativeOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StringAlternativeOwned {
+    pub base: NodeBaseOwned,
+    pub elements: Vec<CharacterOwned>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterOwned {
+    pub base: NodeBaseOwned,
+    pub value: CodePoint,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ErrorOwned {
+    pub base: NodeBaseOwned,
+    pub kind: RegExpSyntaxErrorKind,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BackreferenceOwned {
+    pub base: NodeBaseOwned,
+    pub ref_: CapturingGroupKey,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FlagsOwned {
+    pub base: NodeBaseOwned,
+    pub dot_all: bool,
+    pub global: bool,
+    pub has_indices: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub sticky: bool,
+    pub unicode: bool,
+    pub unicode_sets: bool,
+}
+
+/// Unwraps an `OwnedNode` that's known to be of the given kind
+macro_rules! expect_kind {
+    ($node:expr, $kind:ident) => {
+        match $node {
+            OwnedNode::$kind(node) => *node,
+            _ => unreachable!(),
+        }
+    };
+}
+
+impl OwnedNode {
+    /// Copies the tree rooted at `node` out of the arena
+    pub fn new(arena: &AllArenas, node: impl Into<Id<Node>>) -> Self {
+        let node = arena.node(node);
+        let base = NodeBaseOwned {
+            start: node.start(),
+            end: node.end(),
+            raw: node.raw().into(),
+            synthesized: node.is_synthesized(),
+        };
+        let alternatives = |alternatives: &[AlternativeId]| {
+            alternatives
+                .iter()
+                .map(|&alternative| expect_kind!(Self::new(arena, alternative), Alternative))
+                .collect()
+        };
+        match &*node {
+            Node::RegExpLiteral(node) => Self::RegExpLiteral(Box::new(RegExpLiteralOwned {
+                base,
+                pattern: expect_kind!(Self::new(arena, node.pattern), Pattern),
+                flags: expect_kind!(Self::new(arena, node.flags), Flags),
+            })),
+            Node::Pattern(node) => Self::Pattern(Box::new(PatternOwned {
+                base,
+                alternatives: alternatives(&node.alternatives),
+            })),
+            Node::Alternative(node) => Self::Alternative(Box::new(AlternativeOwned {
+                base,
+                elements: all_owned(arena, &node.elements),
+            })),
+            Node::Group(node) => Self::Group(Box::new(GroupOwned {
+                base,
+                modifiers: node
+                    .modifiers
+                    .map(|modifiers| expect_kind!(Self::new(arena, modifiers), Modifiers)),
+                alternatives: alternatives(&node.alternatives),
+            })),
+            Node::Modifiers(node) => Self::Modifiers(Box::new(ModifiersOwned {
+                base,
+                add: expect_kind!(Self::new(arena, node.add), ModifierFlags),
+                remove: node
+                    .remove
+                    .map(|remove| expect_kind!(Self::new(arena, remove), ModifierFlags)),
+            })),
+            Node::ModifierFlags(node) => Self::ModifierFlags(Box::new(ModifierFlagsOwned {
+                base,
+                dot_all: node.dot_all,
+                ignore_case: node.ignore_case,
+                multiline: node.multiline,
+            })),
+            Node::CapturingGroup(node) => Self::CapturingGroup(Box::new(CapturingGroupOwned {
+                base,
+                name: node.name.clone(),
+                alternatives: alternatives(&node.alternatives),
+            })),
+            Node::Quantifier(node) => Self::Quantifier(Box::new(QuantifierOwned {
+                base,
+                min: node.min,
+                max: node.max,
+                greedy: node.greedy,
+                element: Self::new(arena, node.element),
+            })),
+            Node::CharacterClass(node) => Self::CharacterClass(Box::new(CharacterClassOwned {
+                base,
+                unicode_sets: node.unicode_sets,
+                negate: node.negate,
+                elements: all_owned(arena, &node.elements),
+            })),
+            Node::CharacterClassRange(node) => {
+                Self::CharacterClassRange(Box::new(CharacterClassRangeOwned {
+                    base,
+                    min: expect_kind!(Self::new(arena, node.min), Character),
+                    max: expect_kind!(Self::new(arena, node.max), Character),
+                }))
+            }
+            Node::Assertion(node) => Self::Assertion(Box::new(AssertionOwned {
+                base,
+                kind: node.kind,
+                negate: node.negate,
+                alternatives: node.alternatives.as_deref().map(alternatives),
+            })),
+            Node::CharacterSet(node) => Self::CharacterSet(Box::new(CharacterSetOwned {
+                base,
+                kind: node.kind,
+                strings: node.strings,
+                key: node.key.clone(),
+                value: node.value.clone(),
+                negate: node.negate,
+            })),
+            Node::ExpressionCharacterClass(node) => {
+                Self::ExpressionCharacterClass(Box::new(ExpressionCharacterClassOwned {
+                    base,
+                    negate: node.negate,
+                    expression: Self::new(arena, node.expression),
+                }))
+            }
+            Node::ClassIntersection(node) => {
+                Self::ClassIntersection(Box::new(ClassIntersectionOwned {
+                    base,
+                    left: Self::new(arena, node.left),
+                    right: Self::new(arena, node.right),
+                }))
+            }
+            Node::ClassSubtraction(node) => {
+                Self::ClassSubtraction(Box::new(ClassSubtractionOwned {
+                    base,
+                    left: Self::new(arena, node.left),
+                    right: Self::new(arena, node.right),
+                }))
+            }
+            Node::ClassStringDisjunction(node) => {
+                Self::ClassStringDisjunction(Box::new(ClassStringDisjunctionOwned {
+                    base,
+                    alternatives: node
+                        .alternatives
+                        .iter()
+                        .map(|&alternative| {
+                            expect_kind!(Self::new(arena, alternative), StringAlternative)
+                        })
+                        .collect(),
+                }))
+            }
+            Node::StringAlternative(node) => {
+                Self::StringAlternative(Box::new(StringAlternativeOwned {
+                    base,
+                    elements: node
+                        .elements
+                        .iter()
+                        .map(|&element| expect_kind!(Self::new(arena, element), Character))
+                        .collect(),
+                }))
+            }
+            Node::Character(node) => Self::Character(Box::new(CharacterOwned {
+                base,
+                value: node.value,
+            })),
+            Node::Error(node) => Self::Error(Box::new(ErrorOwned {
+                base,
+                kind: node.kind.clone(),
+                message: node.message.clone(),
+            })),
+            Node::Backreference(node) => Self::Backreference(Box::new(BackreferenceOwned {
+                base,
+                ref_: node.ref_.clone(),
+            })),
+            Node::Flags(node) => Self::Flags(Box::new(FlagsOwned {
+                base,
+                dot_all: node.dot_all,
+                global: node.global,
+                has_indices: node.has_indices,
+                ignore_case: node.ignore_case,
+                multiline: node.multiline,
+                sticky: node.sticky,
+                unicode: node.unicode,
+                unicode_sets: node.unicode_sets,
+            })),
+        }
+    }
+
+    /// Copies the tree into the arena, returning the id of its root.
+    /// Backreferences are resolved against the tree's capturing groups
+    ///
+    /// Panics if a node is somewhere its kind can't go, e.g. a
+    /// `CharacterClassRange` in an `Alternative`'s `elements`
+    pub fn alloc(&self, arena: &AllArenas) -> Id<Node> {
+        let root = self.alloc_node(arena);
+        resolve_backreferences(arena, root);
+        root
+    }
+
+    fn alloc_node(&self, arena: &AllArenas) -> Id<Node> {
+        match self {
+            Self::RegExpLiteral(node) => {
+                let pattern = node.pattern.alloc(arena);
+                let flags = node.flags.alloc(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_reg_exp_literal(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        pattern,
+                        flags,
+                    ),
+                    &[pattern.id(), flags.id()],
+                )
+            }
+            Self::Pattern(node) => node.alloc(arena).id(),
+            Self::Alternative(node) => node.alloc(arena).id(),
+            Self::Group(node) => {
+                let modifiers = node
+                    .modifiers
+                    .as_ref()
+                    .map(|modifiers| modifiers.alloc(arena));
+                let alternatives = alloc_all(arena, &node.alternatives, AlternativeOwned::alloc);
+                let children = modifiers
+                    .map(ModifiersId::id)
+                    .into_iter()
+                    .chain(alternatives.iter().map(|alternative| alternative.id()))
+                    .collect::<Vec<_>>();
+                node.base.alloc(
+                    arena,
+                    Node::new_group(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        modifiers,
+                        alternatives,
+                    ),
+                    &children,
+                )
+            }
+            Self::Modifiers(node) => node.alloc(arena).id(),
+            Self::ModifierFlags(node) => node.alloc(arena).id(),
+            Self::CapturingGroup(node) => {
+                let alternatives = alloc_all(arena, &node.alternatives, AlternativeOwned::alloc);
+                node.base.alloc(
+                    arena,
+                    Node::new_capturing_group(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        node.name.clone(),
+                        alternatives.clone(),
+                        Default::default(),
+                    ),
+                    &alternatives,
+                )
+            }
+            Self::Quantifier(node) => {
+                let element = node.element.alloc_node(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_quantifier(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        node.min,
+                        node.max,
+                        node.greedy,
+                        typed(arena, element, NodeKind::Quantifier),
+                    ),
+                    &[element],
+                )
+            }
+            Self::CharacterClass(node) => {
+                let elements = alloc_all(arena, &node.elements, Self::alloc_node);
+                node.base.alloc(
+                    arena,
+                    Node::new_character_class(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        node.unicode_sets,
+                        node.negate,
+                        all_typed(arena, &elements, NodeKind::CharacterClass),
+                    ),
+                    &elements,
+                )
+            }
+            Self::CharacterClassRange(node) => {
+                let min = node.min.alloc(arena);
+                let max = node.max.alloc(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_character_class_range(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        min,
+                        max,
+                    ),
+                    &[min, max],
+                )
+            }
+            Self::Assertion(node) => {
+                let alternatives = node
+                    .alternatives
+                    .as_ref()
+                    .map(|alternatives| alloc_all(arena, alternatives, AlternativeOwned::alloc));
+                node.base.alloc(
+                    arena,
+                    Node::new_assertion(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        node.kind,
+                        node.negate,
+                        alternatives.clone(),
+                    ),
+                    alternatives.as_deref().unwrap_or_default(),
+                )
+            }
+            Self::CharacterSet(node) => node.base.alloc(
+                arena,
+                Node::new_character_set(
+                    None,
+                    node.base.start,
+                    node.base.end,
+                    node.base.raw.clone(),
+                    node.kind,
+                    node.strings,
+                    node.key.clone(),
+                    node.value.clone(),
+                    node.negate,
+                ),
+                &[] as &[Id<Node>],
+            ),
+            Self::ExpressionCharacterClass(node) => {
+                let expression = node.expression.alloc_node(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_expression_character_class(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        node.negate,
+                        typed(arena, expression, NodeKind::ExpressionCharacterClass),
+                    ),
+                    &[expression],
+                )
+            }
+            Self::ClassIntersection(node) => {
+                let left = node.left.alloc_node(arena);
+                let right = node.right.alloc_node(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_class_intersection(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        typed(arena, left, NodeKind::ClassIntersection),
+                        typed(arena, right, NodeKind::ClassIntersection),
+                    ),
+                    &[left, right],
+                )
+            }
+            Self::ClassSubtraction(node) => {
+                let left = node.left.alloc_node(arena);
+                let right = node.right.alloc_node(arena);
+                node.base.alloc(
+                    arena,
+                    Node::new_class_subtraction(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        typed(arena, left, NodeKind::ClassSubtraction),
+                        typed(arena, right, NodeKind::ClassSubtraction),
+                    ),
+                    &[left, right],
+                )
+            }
+            Self::ClassStringDisjunction(node) => {
+                let alternatives =
+                    alloc_all(arena, &node.alternatives, StringAlternativeOwned::alloc);
+                node.base.alloc(
+                    arena,
+                    Node::new_class_string_disjunction(
+                        None,
+                        node.base.start,
+                        node.base.end,
+                        node.base.raw.clone(),
+                        alternatives.clone(),
+                    ),
+                    &alternatives,
+                )
+            }
+            Self::StringAlternative(node) => node.alloc(arena).id(),
+            Self::Character(node) => node.alloc(arena).id(),
+            Self::Error(node) => node.base.alloc(
+                arena,
+                Node::new_error(
+                    None,
+                    node.base.start,
+                    node.base.end,
+                    node.base.raw.clone(),
+                    node.kind.clone(),
+                    node.message.clone(),
+                ),
+                &[] as &[Id<Node>],
+            ),
+            Self::Backreference(node) => node.base.alloc(
+                arena,
+                Node::new_backreference(
+                    None,
+                    node.base.start,
+                    node.base.end,
+                    node.base.raw.clone(),
+                    node.ref_.clone(),
+                    Default::default(),
+                ),
+                &[] as &[Id<Node>],
+            ),
+            Self::Flags(node) => node.alloc(arena).id(),
+        }
+    }
+}
+
+impl NodeBaseOwned {
+    /// Allocates the node and hooks its (already allocated) children up to
+    /// it
+    fn alloc(
+        &self,
+        arena: &AllArenas,
+        node: Node,
+        children: &[impl Into<Id<Node>> + Copy],
+    ) -> Id<Node> {
+        let node = arena.alloc_node(node);
+        arena.node_mut(node).set_synthesized(self.synthesized);
+        for &child in children {
+            arena.node_mut(child).set_parent(Some(node));
+        }
+        node
+    }
+}
+
+impl PatternOwned {
+    fn alloc(&self, arena: &AllArenas) -> PatternId {
+        let alternatives = alloc_all(arena, &self.alternatives, AlternativeOwned::alloc);
+        PatternId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_pattern(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                alternatives.clone(),
+            ),
+            &alternatives,
+        ))
+    }
+}
+
+impl AlternativeOwned {
+    fn alloc(&self, arena: &AllArenas) -> AlternativeId {
+        let elements = alloc_all(arena, &self.elements, OwnedNode::alloc_node);
+        AlternativeId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_alternative(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                all_typed(arena, &elements, NodeKind::Alternative),
+            ),
+            &elements,
+        ))
+    }
+}
+
+impl ModifiersOwned {
+    fn alloc(&self, arena: &AllArenas) -> ModifiersId {
+        let add = self.add.alloc(arena);
+        let remove = self.remove.as_ref().map(|remove| remove.alloc(arena));
+        let children = [Some(add), remove]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        ModifiersId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_modifiers(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                add,
+                remove,
+            ),
+            &children,
+        ))
+    }
+}
+
+impl ModifierFlagsOwned {
+    fn alloc(&self, arena: &AllArenas) -> ModifierFlagsId {
+        ModifierFlagsId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_modifier_flags(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                self.dot_all,
+                self.ignore_case,
+                self.multiline,
+            ),
+            &[] as &[Id<Node>],
+        ))
+    }
+}
+
+impl StringAlternativeOwned {
+    fn alloc(&self, arena: &AllArenas) -> StringAlternativeId {
+        let elements = alloc_all(arena, &self.elements, CharacterOwned::alloc);
+        StringAlternativeId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_string_alternative(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                elements.clone(),
+            ),
+            &elements,
+        ))
+    }
+}
+
+impl CharacterOwned {
+    fn alloc(&self, arena: &AllArenas) -> CharacterId {
+        CharacterId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_character(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                self.value,
+            ),
+            &[] as &[Id<Node>],
+        ))
+    }
+}
+
+impl FlagsOwned {
+    fn alloc(&self, arena: &AllArenas) -> FlagsId {
+        FlagsId::new_unchecked(self.base.alloc(
+            arena,
+            Node::new_flags(
+                None,
+                self.base.start,
+                self.base.end,
+                self.base.raw.clone(),
+                self.dot_all,
+                self.global,
+                self.has_indices,
+                self.ignore_case,
+                self.multiline,
+                self.sticky,
+                self.unicode,
+                self.unicode_sets,
+            ),
+            &[] as &[Id<Node>],
+        ))
+    }
+}
+
+fn all_owned(arena: &AllArenas, nodes: &[impl Into<Id<Node>> + Copy]) -> Vec<OwnedNode> {
+    nodes
+        .iter()
+        .map(|&node| OwnedNode::new(arena, node))
+        .collect()
+}
+
+fn alloc_all<TOwned, TId>(
+    arena: &AllArenas,
+    nodes: &[TOwned],
+    alloc: impl Fn(&TOwned, &AllArenas) -> TId,
+) -> Vec<TId> {
+    nodes.iter().map(|node| alloc(node, arena)).collect()
+}
+
+fn all_typed<T: NodeId>(arena: &AllArenas, nodes: &[Id<Node>], parent: NodeKind) -> Vec<T> {
+    nodes
+        .iter()
+        .map(|&node| typed(arena, node, parent))
+        .collect()
+}
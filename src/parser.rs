@@ -1,4 +1,4 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{collections::HashMap, ops::Range, rc::Rc};
 
 use debug_cell::{Ref, RefCell};
 use id_arena::Id;
@@ -9,11 +9,16 @@ use crate::{
     arena::AllArenas,
     ast::{Node, NodeInterface},
     ecma_versions::{EcmaVersion, LATEST_ECMA_VERSION},
+    node_ids::{
+        new_unchecked, private::Sealed, BackreferenceId, CapturingGroupId, CharacterId, FlagsId,
+        ModifierFlagsId, ModifiersId, PatternId, QuantifiableElementId,
+    },
     unicode::HYPHEN_MINUS,
     validator::{
-        self, AssertionKind, CapturingGroupKey, CharacterKind, RegExpFlags, ValidatePatternFlags,
+        self, AssertionKind, CapturingGroupKey, CharacterKind, RegExpFlags, RegExpModifierFlags,
+        ValidatePatternFlags,
     },
-    CodePoint, RegExpValidator, Result, Wtf16,
+    CodePoint, Reader, RegExpSyntaxError, RegExpValidator, Result, Wtf16,
 };
 
 #[derive(Copy, Clone, Default, Deserialize)]
@@ -50,9 +55,12 @@ struct RegExpParserState<'a> {
     ecma_version: EcmaVersion,
     _node: RefCell<Option<Id<Node> /*AppendableNode*/>>,
     _expression_buffer_map: RefCell<HashMap<Id<Node>, Id<Node>>>,
-    _flags: RefCell<Option<Id<Node> /*Flags*/>>,
-    _backreferences: RefCell<Vec<Id<Node> /*Backreference*/>>,
-    _capturing_groups: RefCell<Vec<Id<Node> /*CapturingGroup*/>>,
+    _flags: RefCell<Option<FlagsId>>,
+    _modifier_flags: RefCell<Vec<ModifierFlagsId>>,
+    _backreferences: RefCell<Vec<BackreferenceId>>,
+    _capturing_groups: RefCell<Vec<CapturingGroupId>>,
+    _tolerant: RefCell<bool>,
+    _error_ranges: RefCell<Vec<Range<usize>>>,
     source: RefCell<Wtf16>,
 }
 
@@ -69,8 +77,11 @@ impl<'a> RegExpParserState<'a> {
             _node: Default::default(),
             _expression_buffer_map: Default::default(),
             _flags: Default::default(),
+            _modifier_flags: Default::default(),
             _backreferences: Default::default(),
             _capturing_groups: Default::default(),
+            _tolerant: Default::default(),
+            _error_ranges: Default::default(),
             source: Default::default(),
         }
     }
@@ -79,13 +90,36 @@ impl<'a> RegExpParserState<'a> {
         self.source.borrow()
     }
 
-    fn pattern(&self) -> Id<Node> {
-        self._node.borrow().unwrap()
+    fn pattern(&self) -> PatternId {
+        PatternId::new_unchecked(self._node.borrow().unwrap())
     }
 
-    fn flags(&self) -> Id<Node> {
+    fn flags(&self) -> FlagsId {
         self._flags.borrow().unwrap()
     }
+
+    fn push_modifier_flags(&self, start: usize, end: usize, flags: RegExpModifierFlags) {
+        let node = self._arena.alloc_node(Node::new_modifier_flags(
+            None,
+            start,
+            end,
+            self.source()[start..end].into(),
+            flags.dot_all,
+            flags.ignore_case,
+            flags.multiline,
+        ));
+        self._modifier_flags
+            .borrow_mut()
+            .push(ModifierFlagsId::new_unchecked(node));
+    }
+
+    fn is_in_error(&self, node: impl Into<Id<Node>>) -> bool {
+        let start = self._arena.node(node).start();
+        self._error_ranges
+            .borrow()
+            .iter()
+            .any(|range| range.contains(&start))
+    }
 }
 
 impl<'a> validator::Options for RegExpParserState<'a> {
@@ -97,20 +131,26 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         Some(self.ecma_version)
     }
 
+    fn tolerant(&self) -> Option<bool> {
+        Some(*self._tolerant.borrow())
+    }
+
     fn on_reg_exp_flags(&self, start: usize, end: usize, flags: RegExpFlags) {
-        *self._flags.borrow_mut() = Some(self._arena.alloc_node(Node::new_flags(
-            None,
-            start,
-            end,
-            self.source()[start..end].into(),
-            flags.dot_all,
-            flags.global,
-            flags.has_indices,
-            flags.ignore_case,
-            flags.multiline,
-            flags.sticky,
-            flags.unicode,
-            flags.unicode_sets,
+        *self._flags.borrow_mut() = Some(FlagsId::new_unchecked(self._arena.alloc_node(
+            Node::new_flags(
+                None,
+                start,
+                end,
+                self.source()[start..end].into(),
+                flags.dot_all,
+                flags.global,
+                flags.has_indices,
+                flags.ignore_case,
+                flags.multiline,
+                flags.sticky,
+                flags.unicode,
+                flags.unicode_sets,
+            ),
         )));
     }
 
@@ -124,6 +164,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         )));
         self._backreferences.borrow_mut().clear();
         self._capturing_groups.borrow_mut().clear();
+        self._error_ranges.borrow_mut().clear();
     }
 
     fn on_pattern_leave(&self, start: usize, end: usize) {
@@ -134,28 +175,48 @@ impl<'a> validator::Options for RegExpParserState<'a> {
                 node.set_raw(self.source()[start..end].into());
             });
 
+        // Nodes inside a recovered error were discarded from the tree (and a
+        // group whose specifier failed was never entered), so each group is
+        // slotted by the position of its opening parenthesis to keep numeric
+        // references resolving by their original number
+        self._backreferences
+            .borrow_mut()
+            .retain(|&reference| !self.is_in_error(reference));
+        let groups_by_start = self
+            ._capturing_groups
+            .borrow()
+            .iter()
+            .filter(|&&group| !self.is_in_error(group))
+            .map(|&group| (self._arena.node(group).start(), group))
+            .collect::<HashMap<_, _>>();
+        let mut reader = Reader::default();
+        reader.reset(&self.source(), start, end, false);
+        let capturing_groups: Vec<Option<CapturingGroupId>> =
+            validator::capturing_paren_starts(&mut reader)
+                .into_iter()
+                .map(|paren| groups_by_start.get(&paren).copied())
+                .collect();
+
         for &reference in &*self._backreferences.borrow() {
-            let ref_ = self._arena.node(reference).as_backreference().ref_.clone();
-            let group = match ref_ {
-                CapturingGroupKey::Index(ref_) => self._capturing_groups.borrow()[ref_ - 1],
-                CapturingGroupKey::Name(ref_) => *self
-                    ._capturing_groups
-                    .borrow()
+            let ref_ = reference.get(self._arena).ref_.clone();
+            let groups: Vec<CapturingGroupId> = match ref_ {
+                CapturingGroupKey::Index(ref_) => capturing_groups
+                    .get(ref_ - 1)
+                    .copied()
+                    .flatten()
+                    .into_iter()
+                    .collect(),
+                CapturingGroupKey::Name(ref_) => capturing_groups
                     .iter()
-                    .find(|&&g| {
-                        self._arena.node(g).as_capturing_group().name.as_ref() == Some(&ref_)
-                    })
-                    .unwrap(),
+                    .flatten()
+                    .filter(|&&g| g.get(self._arena).name.as_ref() == Some(&ref_))
+                    .copied()
+                    .collect(),
             };
-            self._arena
-                .node_mut(reference)
-                .as_backreference_mut()
-                .resolved = Some(group);
-            self._arena
-                .node_mut(group)
-                .as_capturing_group_mut()
-                .references
-                .push(reference);
+            for &group in &groups {
+                group.get_mut(self._arena).references.push(reference);
+            }
+            reference.get_mut(self._arena).resolved = groups;
         }
     }
 
@@ -175,10 +236,14 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         )));
         let _node = self._node.borrow().unwrap();
         match &mut *self._arena.node_mut(parent) {
-            Node::Assertion(parent) => parent.alternatives.as_mut().unwrap().push(_node),
-            Node::CapturingGroup(parent) => parent.alternatives.push(_node),
-            Node::Group(parent) => parent.alternatives.push(_node),
-            Node::Pattern(parent) => parent.alternatives.push(_node),
+            Node::Assertion(parent) => parent
+                .alternatives
+                .as_mut()
+                .unwrap()
+                .push(new_unchecked(_node)),
+            Node::CapturingGroup(parent) => parent.alternatives.push(new_unchecked(_node)),
+            Node::Group(parent) => parent.alternatives.push(new_unchecked(_node)),
+            Node::Pattern(parent) => parent.alternatives.push(new_unchecked(_node)),
             _ => unreachable!(),
         }
     }
@@ -203,6 +268,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             start,
             start,
             Default::default(),
+            None,
             Default::default(),
         )));
         let _node = self._node.borrow().unwrap();
@@ -210,7 +276,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(_node);
+            .push(new_unchecked(_node));
     }
 
     fn on_group_leave(&self, start: usize, end: usize) {
@@ -227,6 +293,46 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         *self._node.borrow_mut() = self._arena.node(node).maybe_parent();
     }
 
+    fn on_modifiers_enter(&self, _start: usize) {
+        let parent = self._node.borrow().unwrap();
+        assert!(matches!(&*self._arena.node(parent), Node::Group(_)));
+
+        self._modifier_flags.borrow_mut().clear();
+    }
+
+    fn on_modifiers_leave(&self, start: usize, end: usize) {
+        let parent = self._node.borrow().unwrap();
+        assert!(matches!(&*self._arena.node(parent), Node::Group(_)));
+
+        let (add, remove) = {
+            let mut modifier_flags = self._modifier_flags.borrow_mut();
+            let mut modifier_flags = modifier_flags.drain(..);
+            (modifier_flags.next().unwrap(), modifier_flags.next())
+        };
+        let node = self._arena.alloc_node(Node::new_modifiers(
+            Some(parent),
+            start,
+            end,
+            self.source()[start..end].into(),
+            add,
+            remove,
+        ));
+        self._arena.node_mut(add).set_parent(Some(node));
+        if let Some(remove) = remove {
+            self._arena.node_mut(remove).set_parent(Some(node));
+        }
+        self._arena.node_mut(parent).as_group_mut().modifiers =
+            Some(ModifiersId::new_unchecked(node));
+    }
+
+    fn on_add_modifiers(&self, start: usize, end: usize, flags: RegExpModifierFlags) {
+        self.push_modifier_flags(start, end, flags);
+    }
+
+    fn on_remove_modifiers(&self, start: usize, end: usize, flags: RegExpModifierFlags) {
+        self.push_modifier_flags(start, end, flags);
+    }
+
     fn on_capturing_group_enter(&self, start: usize, name: Option<&Wtf16>) {
         let parent = self._node.borrow().unwrap();
         assert!(matches!(&*self._arena.node(parent), Node::Alternative(_)));
@@ -245,8 +351,10 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(_node);
-        self._capturing_groups.borrow_mut().push(_node);
+            .push(new_unchecked(_node));
+        self._capturing_groups
+            .borrow_mut()
+            .push(CapturingGroupId::new_unchecked(_node));
     }
 
     fn on_capturing_group_leave(&self, start: usize, end: usize, _name: Option<&Wtf16>) {
@@ -290,14 +398,14 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             min,
             max,
             greedy,
-            element,
+            QuantifiableElementId::new_unchecked(element.id()),
         );
         let node = self._arena.alloc_node(node);
         self._arena
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
         self._arena.node_mut(element).set_parent(Some(node));
     }
 
@@ -319,7 +427,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_lookaround_assertion_leave(
@@ -359,7 +467,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_word_boundary_assertion(
@@ -385,7 +493,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_any_character_set(&self, start: usize, end: usize, kind: CharacterKind) {
@@ -407,7 +515,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_escape_character_set(&self, start: usize, end: usize, kind: CharacterKind, negate: bool) {
@@ -429,8 +537,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             Some(negate),
         ));
         match &mut *self._arena.node_mut(parent) {
-            Node::Alternative(parent) => parent.elements.push(node),
-            Node::CharacterClass(parent) => parent.elements.push(node),
+            Node::Alternative(parent) => parent.elements.push(new_unchecked(node)),
+            Node::CharacterClass(parent) => parent.elements.push(new_unchecked(node)),
             _ => unreachable!(),
         }
     }
@@ -473,8 +581,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             Some(negate),
         ));
         match &mut *self._arena.node_mut(parent) {
-            Node::Alternative(parent) => parent.elements.push(node),
-            Node::CharacterClass(parent) => parent.elements.push(node),
+            Node::Alternative(parent) => parent.elements.push(new_unchecked(node)),
+            Node::CharacterClass(parent) => parent.elements.push(new_unchecked(node)),
             _ => unreachable!(),
         }
     }
@@ -494,9 +602,9 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             value,
         ));
         match &mut *self._arena.node_mut(parent) {
-            Node::Alternative(parent) => parent.elements.push(node),
-            Node::CharacterClass(parent) => parent.elements.push(node),
-            Node::StringAlternative(parent) => parent.elements.push(node),
+            Node::Alternative(parent) => parent.elements.push(new_unchecked(node)),
+            Node::CharacterClass(parent) => parent.elements.push(new_unchecked(node)),
+            Node::StringAlternative(parent) => parent.elements.push(new_unchecked(node)),
             _ => unreachable!(),
         }
     }
@@ -517,8 +625,10 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_alternative_mut()
             .elements
-            .push(node);
-        self._backreferences.borrow_mut().push(node);
+            .push(new_unchecked(node));
+        self._backreferences
+            .borrow_mut()
+            .push(BackreferenceId::new_unchecked(node));
     }
 
     fn on_character_class_enter(&self, start: usize, negate: bool, unicode_sets: bool) {
@@ -541,8 +651,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         ));
         *self._node.borrow_mut() = Some(node);
         match &mut *self._arena.node_mut(parent) {
-            Node::Alternative(parent) => parent.elements.push(node),
-            Node::CharacterClass(parent) => parent.elements.push(node),
+            Node::Alternative(parent) => parent.elements.push(new_unchecked(node)),
+            Node::CharacterClass(parent) => parent.elements.push(new_unchecked(node)),
             _ => unreachable!(),
         }
     }
@@ -579,21 +689,22 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             self._arena.node(node).end(),
             self._arena.node(node).raw().into(),
             self._arena.node(node).as_character_class().negate,
-            expression,
+            new_unchecked(expression),
         );
         let new_node = self._arena.alloc_node(new_node);
         self._arena.node_mut(expression).set_parent(Some(new_node));
         assert!(
             Some(node)
                 == match &mut *self._arena.node_mut(parent) {
-                    Node::Alternative(parent) => parent.elements.pop(),
-                    Node::CharacterClass(parent) => parent.elements.pop(),
+                    Node::Alternative(parent) => parent.elements.pop().map(|element| element.id()),
+                    Node::CharacterClass(parent) =>
+                        parent.elements.pop().map(|element| element.id()),
                     _ => unreachable!(),
                 }
         );
         match &mut *self._arena.node_mut(parent) {
-            Node::Alternative(parent) => parent.elements.push(new_node),
-            Node::CharacterClass(parent) => parent.elements.push(new_node),
+            Node::Alternative(parent) => parent.elements.push(new_unchecked(new_node)),
+            Node::CharacterClass(parent) => parent.elements.push(new_unchecked(new_node)),
             _ => unreachable!(),
         }
     }
@@ -639,8 +750,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             start,
             end,
             self.source()[start..end].into(),
-            min,
-            max,
+            CharacterId::new_unchecked(min.id()),
+            CharacterId::new_unchecked(max.id()),
         ));
         self._arena.node_mut(min).set_parent(Some(node));
         self._arena.node_mut(max).set_parent(Some(node));
@@ -648,7 +759,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_character_class_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_class_intersection(&self, start: usize, end: usize) {
@@ -663,7 +774,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .as_character_class_mut()
             .elements
             .pop()
-            .unwrap();
+            .unwrap()
+            .id();
         let left = self
             ._expression_buffer_map
             .borrow()
@@ -676,6 +788,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
                     .elements
                     .pop()
                     .unwrap()
+                    .id()
             });
         assert!(!matches!(
             &*self._arena.node(left),
@@ -691,8 +804,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             start,
             end,
             self.source()[start..end].into(),
-            left,
-            right,
+            new_unchecked(left),
+            new_unchecked(right),
         ));
         self._arena.node_mut(left).set_parent(Some(node));
         self._arena.node_mut(right).set_parent(Some(node));
@@ -714,7 +827,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .as_character_class_mut()
             .elements
             .pop()
-            .unwrap();
+            .unwrap()
+            .id();
         let left = self
             ._expression_buffer_map
             .borrow()
@@ -727,6 +841,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
                     .elements
                     .pop()
                     .unwrap()
+                    .id()
             });
         assert!(!matches!(
             &*self._arena.node(left),
@@ -742,8 +857,8 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             start,
             end,
             self.source()[start..end].into(),
-            left,
-            right,
+            new_unchecked(left),
+            new_unchecked(right),
         ));
         self._arena.node_mut(left).set_parent(Some(node));
         self._arena.node_mut(right).set_parent(Some(node));
@@ -772,7 +887,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_character_class_mut()
             .elements
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_class_string_disjunction_leave(&self, start: usize, end: usize) {
@@ -813,7 +928,7 @@ impl<'a> validator::Options for RegExpParserState<'a> {
             .node_mut(parent)
             .as_class_string_disjunction_mut()
             .alternatives
-            .push(node);
+            .push(new_unchecked(node));
     }
 
     fn on_string_alternative_leave(&self, start: usize, end: usize, _index: usize) {
@@ -829,8 +944,46 @@ impl<'a> validator::Options for RegExpParserState<'a> {
         });
         *self._node.borrow_mut() = self._arena.node(node).maybe_parent();
     }
+
+    fn on_error(&self, start: usize, end: usize, error: &RegExpSyntaxError) {
+        // The failed term may have left us anywhere inside of it, so climb
+        // back out to the alternative that it belongs to
+        let mut parent = self._node.borrow().unwrap();
+        while !matches!(
+            &*self._arena.node(parent),
+            node @ Node::Alternative(_) if node.start() <= start
+        ) {
+            parent = self._arena.node(parent).maybe_parent().unwrap();
+        }
+
+        let node = self._arena.alloc_node(Node::new_error(
+            Some(parent),
+            start,
+            end,
+            self.source()[start..end].into(),
+            error.kind.clone(),
+            error.message.clone(),
+        ));
+        let mut elements = self._arena.node(parent).as_alternative().elements.clone();
+        elements.retain(|&element| self._arena.node(element).start() < start);
+        elements.push(new_unchecked(node));
+        self._arena.node_mut(parent).as_alternative_mut().elements = elements;
+        *self._node.borrow_mut() = Some(parent);
+        self._error_ranges.borrow_mut().push(start..end);
+    }
 }
 
+/// The result of a tolerant parse: a tree in which each unparseable span is
+/// represented by an [`Node::Error`] node, along with every
+/// error that was recovered from
+pub struct TolerantParseResult {
+    pub ast: Id<Node>,
+    pub diagnostics: Vec<RegExpSyntaxError>,
+}
+
+/// Not `Send`, as its validator calls back into its state through an `Rc`:
+/// create one per thread (its options are `Copy`) and share the trees it
+/// builds by [freezing](AllArenas::freeze) their arenas
 pub struct RegExpParser<'a> {
     _arena: &'a AllArenas,
     _state: Rc<RegExpParserState<'a>>,
@@ -885,7 +1038,42 @@ impl<'a> RegExpParser<'a> {
         *self._state.source.borrow_mut() = source.into();
         self._validator
             .validate_pattern(source, Some(start), Some(end), flags)?;
-        Ok(self._state.pattern())
+        Ok(self._state.pattern().id())
+    }
+
+    /// Like [`parse_literal`](Self::parse_literal), but recovers from errors
+    /// in the pattern and flags instead of failing on the first one
+    pub fn parse_literal_tolerant(
+        &mut self,
+        source: &[u16],
+        start: Option<usize>,
+        end: Option<usize>,
+    ) -> Result<TolerantParseResult> {
+        *self._state._tolerant.borrow_mut() = true;
+        let ast = self.parse_literal(source, start, end);
+        *self._state._tolerant.borrow_mut() = false;
+        Ok(TolerantParseResult {
+            ast: ast?,
+            diagnostics: self._validator.take_diagnostics(),
+        })
+    }
+
+    /// Like [`parse_pattern`](Self::parse_pattern), but recovers from errors
+    /// in the pattern instead of failing on the first one
+    pub fn parse_pattern_tolerant(
+        &mut self,
+        source: &[u16],
+        start: Option<usize>,
+        end: Option<usize>,
+        flags: Option<ValidatePatternFlags>,
+    ) -> Result<TolerantParseResult> {
+        *self._state._tolerant.borrow_mut() = true;
+        let ast = self.parse_pattern(source, start, end, flags);
+        *self._state._tolerant.borrow_mut() = false;
+        Ok(TolerantParseResult {
+            ast: ast?,
+            diagnostics: self._validator.take_diagnostics(),
+        })
     }
 }
 
@@ -894,6 +1082,7 @@ mod tests {
     use speculoos::prelude::*;
 
     use super::*;
+    use crate::RegExpSyntaxErrorKind;
 
     #[test]
     fn test_parse_pattern_function() {
@@ -906,4 +1095,99 @@ mod tests {
         )
         .contains(r#"\ at end of pattern"#);
     }
+
+    #[test]
+    fn test_parse_literal_tolerant() {
+        let arena = AllArenas::default();
+        let result = RegExpParser::new(&arena, None)
+            .parse_literal_tolerant(&Wtf16::from("/a{2,1}|(b)|(c/gg"), None, None)
+            .unwrap();
+        assert_that!(&result
+            .diagnostics
+            .iter()
+            .map(|error| error.kind.clone())
+            .collect::<Vec<_>>())
+        .is_equal_to(vec![
+            RegExpSyntaxErrorKind::DuplicatedFlag('g'),
+            RegExpSyntaxErrorKind::NumbersOutOfOrderInQuantifier,
+            RegExpSyntaxErrorKind::UnterminatedGroup,
+        ]);
+
+        let pattern = arena.node(result.ast).as_reg_exp_literal().pattern;
+        let alternatives = arena.node(pattern).as_pattern().alternatives.clone();
+        assert_that!(&alternatives).has_length(3);
+        let raws = alternatives
+            .iter()
+            .map(|&alternative| {
+                arena
+                    .node(alternative)
+                    .as_alternative()
+                    .elements
+                    .iter()
+                    .map(|&element| {
+                        let element = arena.node(element);
+                        let raw = String::from_utf16_lossy(element.raw());
+                        match &*element {
+                            Node::Error(_) => format!("Error({raw})"),
+                            _ => raw,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_that!(&raws).is_equal_to(vec![
+            vec!["Error(a{2,1})".to_owned()],
+            vec!["(b)".to_owned()],
+            vec!["Error((c)".to_owned()],
+        ]);
+    }
+
+    #[test]
+    fn test_parse_literal_tolerant_numeric_backreference_after_error() {
+        let arena = AllArenas::default();
+        let result = RegExpParser::new(&arena, None)
+            .parse_literal_tolerant(&Wtf16::from(r"/(?<a>x)(?<a>y)|(z)\2\3/"), None, None)
+            .unwrap();
+        assert_that!(&result.diagnostics).has_length(1);
+
+        let pattern = arena.node(result.ast).as_reg_exp_literal().pattern;
+        let alternative = arena.node(pattern).as_pattern().alternatives[1];
+        let resolved = arena
+            .node(alternative)
+            .as_alternative()
+            .elements
+            .iter()
+            .filter_map(|&element| match &*arena.node(element) {
+                Node::Backreference(backreference) => Some(
+                    backreference
+                        .resolved
+                        .iter()
+                        .map(|&group| String::from_utf16_lossy(arena.node(group).raw()))
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_that!(&resolved).is_equal_to(vec![vec![], vec!["(z)".to_owned()]]);
+    }
+
+    #[test]
+    fn test_parse_pattern_tolerant_strict_by_default() {
+        let arena = AllArenas::default();
+        let mut parser = RegExpParser::new(&arena, None);
+        assert_that!(
+            &parser
+                .parse_pattern_tolerant(&Wtf16::from("a)b"), None, None, None)
+                .unwrap()
+                .diagnostics
+        )
+        .has_length(1);
+        assert_that!(
+            &parser
+                .parse_pattern(&Wtf16::from("a)b"), None, None, None)
+                .unwrap_err()
+                .kind
+        )
+        .is_equal_to(RegExpSyntaxErrorKind::UnmatchedRightParenthesis);
+    }
 }
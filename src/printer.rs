@@ -0,0 +1,632 @@
+use id_arena::Id;
+
+use crate::{
+    ast::{Node, NodeInterface},
+    formatter::{EscapeStyle, FormatOptions},
+    node_ids::CharacterClassElementId,
+    unicode::{
+        is_decimal_digit, is_lead_surrogate, is_trail_surrogate, CARRIAGE_RETURN,
+        CHARACTER_TABULATION, CIRCUMFLEX_ACCENT, FORM_FEED, HYPHEN_MINUS, LEFT_SQUARE_BRACKET,
+        LINE_FEED, LINE_SEPARATOR, LINE_TABULATION, PARAGRAPH_SEPARATOR, REVERSE_SOLIDUS,
+        RIGHT_SQUARE_BRACKET, SOLIDUS,
+    },
+    validator::{
+        is_class_set_reserved_double_punctuator_character, is_class_set_reserved_punctuator,
+        is_class_set_syntax_character, is_syntax_character, AssertionKind, CapturingGroupKey,
+        CharacterKind, ValidatePatternFlags,
+    },
+    AllArenas, CodePoint, Wtf16,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum CharacterContext {
+    Pattern,
+    CharacterClass,
+    ClassSet,
+}
+
+/// Where a character sits inside of a class, which decides whether some
+/// characters need escaping when formatting
+#[derive(Copy, Clone)]
+struct CharacterPosition {
+    could_negate: bool,
+    at_edge: bool,
+    previous: Option<CodePoint>,
+    next: Option<CodePoint>,
+    neighbors_known: bool,
+}
+
+impl Default for CharacterPosition {
+    fn default() -> Self {
+        Self {
+            could_negate: true,
+            at_edge: false,
+            previous: None,
+            next: None,
+            neighbors_known: false,
+        }
+    }
+}
+
+enum ClassItem {
+    Range(CodePoint, CodePoint),
+    Node(Id<Node>),
+}
+
+/// Generates pattern source text from a tree, escaping characters as
+/// required by the mode (legacy, `u` or `v`) that it will be parsed in
+pub struct RegExpPrinter<'a> {
+    _arena: &'a AllArenas,
+    _unicode_mode: bool,
+    _unicode_sets_mode: bool,
+    _after_decimal_escape: bool,
+    _format_options: Option<FormatOptions>,
+    _output: Vec<u16>,
+    _spans: Option<Vec<(Id<Node>, usize, usize)>>,
+}
+
+impl<'a> RegExpPrinter<'a> {
+    /// When `flags` isn't given, the mode is taken from the `Flags` of the
+    /// enclosing `RegExpLiteral` (if there is one)
+    pub fn new(arena: &'a AllArenas, flags: Option<ValidatePatternFlags>) -> Self {
+        let unicode_sets = flags
+            .as_ref()
+            .and_then(|flags| flags.unicode_sets)
+            .unwrap_or_default();
+        Self {
+            _arena: arena,
+            _unicode_mode: unicode_sets
+                || flags
+                    .as_ref()
+                    .and_then(|flags| flags.unicode)
+                    .unwrap_or_default(),
+            _unicode_sets_mode: unicode_sets,
+            _after_decimal_escape: false,
+            _format_options: Default::default(),
+            _output: Default::default(),
+            _spans: Default::default(),
+        }
+    }
+
+    /// Prints in the canonical style described by `options` rather than
+    /// escaping conservatively
+    pub fn with_format_options(mut self, options: FormatOptions) -> Self {
+        self._format_options = Some(options);
+        self
+    }
+
+    pub fn print(mut self, node: Id<Node>) -> Wtf16 {
+        self.print_root(node);
+        self._output.into()
+    }
+
+    /// Like [`print`](Self::print), but also returns where each printed node
+    /// ended up in the output as `(node, start, end)`
+    pub(crate) fn print_with_spans(
+        mut self,
+        node: Id<Node>,
+    ) -> (Wtf16, Vec<(Id<Node>, usize, usize)>) {
+        self._spans = Some(Default::default());
+        self.print_root(node);
+        (self._output.into(), self._spans.unwrap())
+    }
+
+    fn print_root(&mut self, node: Id<Node>) {
+        if let Some(literal) = self.find_reg_exp_literal(node) {
+            let flags = self._arena.node(literal).as_reg_exp_literal().flags;
+            if let Node::Flags(flags) = &*self._arena.node(flags) {
+                self._unicode_mode = flags.unicode || flags.unicode_sets;
+                self._unicode_sets_mode = flags.unicode_sets;
+            }
+        }
+        self.print_node(node, CharacterContext::Pattern);
+    }
+
+    fn find_reg_exp_literal(&self, node: Id<Node>) -> Option<Id<Node>> {
+        let mut current = Some(node);
+        while let Some(node) = current {
+            if matches!(&*self._arena.node(node), Node::RegExpLiteral(_)) {
+                return Some(node);
+            }
+            current = self._arena.node(node).maybe_parent();
+        }
+        None
+    }
+
+    fn write_units(&mut self, units: &[u16]) {
+        self._after_decimal_escape = false;
+        self._output.extend_from_slice(units);
+    }
+
+    fn write(&mut self, text: &str) {
+        self._after_decimal_escape = false;
+        self._output.extend(text.encode_utf16());
+    }
+
+    fn print_nodes(
+        &mut self,
+        nodes: &[impl Into<Id<Node>> + Copy],
+        separator: &str,
+        context: CharacterContext,
+    ) {
+        for (index, &node) in nodes.iter().enumerate() {
+            if index > 0 && !separator.is_empty() {
+                self.write(separator);
+            }
+            self.print_node(node, context);
+        }
+    }
+
+    fn record_span(&mut self, node: impl Into<Id<Node>>, start: usize) {
+        let end = self._output.len();
+        if let Some(spans) = self._spans.as_mut() {
+            spans.push((node.into(), start, end));
+        }
+    }
+
+    fn print_node(&mut self, id: impl Into<Id<Node>>, context: CharacterContext) {
+        let id = id.into();
+        let arena = self._arena;
+        let node = arena.node(id);
+        let start = self._output.len();
+        match &*node {
+            Node::RegExpLiteral(node) => {
+                self.write("/");
+                let is_empty = node
+                    .pattern
+                    .get(arena)
+                    .alternatives
+                    .iter()
+                    .all(|&alternative| alternative.get(arena).elements.is_empty());
+                if is_empty && node.pattern.get(arena).alternatives.len() <= 1 {
+                    let position = self._output.len();
+                    for &alternative in &node.pattern.get(arena).alternatives {
+                        self.record_span(alternative, position);
+                    }
+                    self.record_span(node.pattern, position);
+                    // `//` would start a comment
+                    self.write("(?:)");
+                } else {
+                    self.print_node(node.pattern, context);
+                }
+                self.write("/");
+                self.print_node(node.flags, context);
+            }
+            Node::Pattern(node) => {
+                self.print_nodes(&node.alternatives, "|", context);
+            }
+            Node::Alternative(node) => {
+                self.print_nodes(&node.elements, "", context);
+            }
+            Node::Group(node) => {
+                self.write("(?");
+                if let Some(modifiers) = node.modifiers {
+                    self.print_node(modifiers, context);
+                }
+                self.write(":");
+                self.print_nodes(&node.alternatives, "|", context);
+                self.write(")");
+            }
+            Node::Modifiers(node) => {
+                self.print_node(node.add, context);
+                if let Some(remove) = node.remove {
+                    self.write("-");
+                    self.print_node(remove, context);
+                }
+            }
+            Node::ModifierFlags(node) => {
+                for (enabled, flag) in [
+                    (node.ignore_case, "i"),
+                    (node.multiline, "m"),
+                    (node.dot_all, "s"),
+                ] {
+                    if enabled {
+                        self.write(flag);
+                    }
+                }
+            }
+            Node::CapturingGroup(node) => {
+                self.write("(");
+                if let Some(name) = node.name.as_ref() {
+                    self.write("?<");
+                    self.write_units(name);
+                    self.write(">");
+                }
+                self.print_nodes(&node.alternatives, "|", context);
+                self.write(")");
+            }
+            Node::Assertion(node) => {
+                let negate = node.negate.unwrap_or_default();
+                match node.kind {
+                    AssertionKind::Start => self.write("^"),
+                    AssertionKind::End => self.write("$"),
+                    AssertionKind::Word => self.write(if negate { "\\B" } else { "\\b" }),
+                    AssertionKind::Lookahead | AssertionKind::Lookbehind => {
+                        self.write(match (node.kind, negate) {
+                            (AssertionKind::Lookahead, false) => "(?=",
+                            (AssertionKind::Lookahead, true) => "(?!",
+                            (_, false) => "(?<=",
+                            (_, true) => "(?<!",
+                        });
+                        self.print_nodes(node.alternatives.as_deref().unwrap(), "|", context);
+                        self.write(")");
+                    }
+                }
+            }
+            Node::Quantifier(node) => {
+                self.print_node(node.element, context);
+                self.write(&match (node.min, node.max) {
+                    (0, u32::MAX) => "*".to_owned(),
+                    (1, u32::MAX) => "+".to_owned(),
+                    (0, 1) => "?".to_owned(),
+                    (min, u32::MAX) => format!("{{{min},}}"),
+                    (min, max) if min == max => format!("{{{min}}}"),
+                    (min, max) => format!("{{{min},{max}}}"),
+                });
+                if !node.greedy {
+                    self.write("?");
+                }
+            }
+            Node::Backreference(node) => match &node.ref_ {
+                CapturingGroupKey::Index(index) => {
+                    self.write(&format!("\\{index}"));
+                    self._after_decimal_escape = true;
+                }
+                CapturingGroupKey::Name(name) => {
+                    self.write("\\k<");
+                    self.write_units(name);
+                    self.write(">");
+                }
+            },
+            Node::CharacterSet(node) => {
+                let negate = node.negate.unwrap_or_default();
+                match node.kind {
+                    CharacterKind::Any => self.write("."),
+                    CharacterKind::Digit => self.write(if negate { "\\D" } else { "\\d" }),
+                    CharacterKind::Space => self.write(if negate { "\\S" } else { "\\s" }),
+                    CharacterKind::Word => self.write(if negate { "\\W" } else { "\\w" }),
+                    CharacterKind::Property => {
+                        self.write(if negate { "\\P{" } else { "\\p{" });
+                        if let Some(key) = node.key.as_ref() {
+                            self.write_units(key);
+                        }
+                        if let Some(value) = node.value.as_ref() {
+                            self.write("=");
+                            self.write_units(value);
+                        }
+                        self.write("}");
+                    }
+                }
+            }
+            Node::CharacterClass(node) => {
+                self.write(if node.negate { "[^" } else { "[" });
+                let context = if self._unicode_sets_mode {
+                    CharacterContext::ClassSet
+                } else {
+                    CharacterContext::CharacterClass
+                };
+                if self._format_options.is_some() {
+                    self.print_class_items(&node.elements, node.negate, context);
+                } else {
+                    self.print_nodes(&node.elements, "", context);
+                }
+                self.write("]");
+            }
+            Node::ExpressionCharacterClass(node) => {
+                self.write(if node.negate { "[^" } else { "[" });
+                self.print_node(node.expression, CharacterContext::ClassSet);
+                self.write("]");
+            }
+            Node::CharacterClassRange(node) => {
+                self.print_node(node.min, context);
+                self.write("-");
+                self.print_node(node.max, context);
+            }
+            Node::ClassIntersection(node) => {
+                self.print_node(node.left, context);
+                self.write("&&");
+                self.print_node(node.right, context);
+            }
+            Node::ClassSubtraction(node) => {
+                self.print_node(node.left, context);
+                self.write("--");
+                self.print_node(node.right, context);
+            }
+            Node::ClassStringDisjunction(node) => {
+                self.write("\\q{");
+                self.print_nodes(&node.alternatives, "|", context);
+                self.write("}");
+            }
+            Node::StringAlternative(node) => {
+                if self._format_options.is_some() {
+                    let values = node
+                        .elements
+                        .iter()
+                        .map(|&element| element.get(arena).value)
+                        .collect::<Vec<_>>();
+                    for (index, &value) in values.iter().enumerate() {
+                        self.print_character(
+                            value,
+                            context,
+                            CharacterPosition {
+                                could_negate: false,
+                                at_edge: false,
+                                previous: index.checked_sub(1).map(|index| values[index]),
+                                next: values.get(index + 1).copied(),
+                                neighbors_known: true,
+                            },
+                        );
+                    }
+                } else {
+                    self.print_nodes(&node.elements, "", context);
+                }
+            }
+            Node::Character(node) => {
+                self.print_character(node.value, context, Default::default());
+            }
+            Node::Flags(node) => {
+                for (enabled, flag) in [
+                    (node.has_indices, "d"),
+                    (node.global, "g"),
+                    (node.ignore_case, "i"),
+                    (node.multiline, "m"),
+                    (node.dot_all, "s"),
+                    (node.unicode, "u"),
+                    (node.unicode_sets, "v"),
+                    (node.sticky, "y"),
+                ] {
+                    if enabled {
+                        self.write(flag);
+                    }
+                }
+            }
+            Node::Error(_) => {
+                self.write_units(node.raw());
+            }
+        }
+        self.record_span(id, start);
+    }
+
+    fn class_items(&self, elements: &[CharacterClassElementId]) -> Vec<ClassItem> {
+        let range = |element: CharacterClassElementId| match &*self._arena.node(element) {
+            Node::Character(node) => Some((node.value, node.value)),
+            Node::CharacterClassRange(node) => Some((
+                node.min.get(self._arena).value,
+                node.max.get(self._arena).value,
+            )),
+            _ => None,
+        };
+        if !self._format_options.unwrap().sort_class_ranges() {
+            return elements
+                .iter()
+                .map(|&element| match &*self._arena.node(element) {
+                    Node::Character(node) => ClassItem::Range(node.value, node.value),
+                    _ => ClassItem::Node(element.id()),
+                })
+                .collect();
+        }
+
+        let mut ranges = elements
+            .iter()
+            .filter_map(|&element| range(element))
+            .collect::<Vec<_>>();
+        ranges.sort_unstable();
+        let mut merged: Vec<(CodePoint, CodePoint)> = Default::default();
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                Some(last) if min <= last.1.saturating_add(1) => {
+                    last.1 = last.1.max(max);
+                }
+                _ => merged.push((min, max)),
+            }
+        }
+        merged
+            .into_iter()
+            .map(|(min, max)| ClassItem::Range(min, max))
+            .chain(
+                elements
+                    .iter()
+                    .filter(|&&element| range(element).is_none())
+                    .map(|&element| ClassItem::Node(element.id())),
+            )
+            .collect()
+    }
+
+    fn print_class_items(
+        &mut self,
+        elements: &[CharacterClassElementId],
+        negate: bool,
+        context: CharacterContext,
+    ) {
+        let items = self.class_items(elements);
+        let sort = self._format_options.unwrap().sort_class_ranges();
+        let first_value = |item: Option<&ClassItem>| match item {
+            Some(&ClassItem::Range(min, _)) => Some(min),
+            _ => None,
+        };
+        let last_value = |item: Option<&ClassItem>| match item {
+            Some(&ClassItem::Range(_, max)) => Some(max),
+            _ => None,
+        };
+        for (index, item) in items.iter().enumerate() {
+            let previous = index
+                .checked_sub(1)
+                .and_then(|index| last_value(items.get(index)));
+            let next = first_value(items.get(index + 1));
+            let is_first = index == 0;
+            let is_last = index == items.len() - 1;
+            match *item {
+                ClassItem::Node(node) => self.print_node(node, context),
+                ClassItem::Range(min, max) if min == max || max == min + 1 && sort => {
+                    self.print_character(
+                        min,
+                        context,
+                        CharacterPosition {
+                            could_negate: is_first && !negate,
+                            at_edge: is_first || is_last && min == max,
+                            previous,
+                            next: if min == max { next } else { Some(max) },
+                            neighbors_known: true,
+                        },
+                    );
+                    if min != max {
+                        self.print_character(
+                            max,
+                            context,
+                            CharacterPosition {
+                                could_negate: false,
+                                at_edge: is_last,
+                                previous: Some(min),
+                                next,
+                                neighbors_known: true,
+                            },
+                        );
+                    }
+                }
+                ClassItem::Range(min, max) => {
+                    self.print_character(
+                        min,
+                        context,
+                        CharacterPosition {
+                            could_negate: is_first && !negate,
+                            at_edge: false,
+                            previous,
+                            next: None,
+                            neighbors_known: true,
+                        },
+                    );
+                    self.write("-");
+                    self.print_character(
+                        max,
+                        context,
+                        CharacterPosition {
+                            could_negate: false,
+                            at_edge: false,
+                            previous: None,
+                            next,
+                            neighbors_known: true,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn needs_escape(
+        &self,
+        value: CodePoint,
+        context: CharacterContext,
+        position: CharacterPosition,
+    ) -> bool {
+        if value == SOLIDUS {
+            return true;
+        }
+        if self._format_options.is_none() {
+            return match context {
+                CharacterContext::Pattern => is_syntax_character(value),
+                CharacterContext::CharacterClass => {
+                    matches!(
+                        value,
+                        REVERSE_SOLIDUS
+                            | LEFT_SQUARE_BRACKET
+                            | RIGHT_SQUARE_BRACKET
+                            | CIRCUMFLEX_ACCENT
+                            | HYPHEN_MINUS
+                    )
+                }
+                CharacterContext::ClassSet => {
+                    is_syntax_character(value)
+                        || is_class_set_syntax_character(value)
+                        || is_class_set_reserved_punctuator(Some(value))
+                }
+            };
+        }
+
+        match context {
+            CharacterContext::Pattern => is_syntax_character(value),
+            CharacterContext::CharacterClass => match value {
+                REVERSE_SOLIDUS | RIGHT_SQUARE_BRACKET => true,
+                CIRCUMFLEX_ACCENT => position.could_negate,
+                HYPHEN_MINUS => !position.at_edge,
+                _ => false,
+            },
+            CharacterContext::ClassSet => {
+                is_class_set_syntax_character(value)
+                    || value == CIRCUMFLEX_ACCENT && position.could_negate
+                    || is_class_set_reserved_double_punctuator_character(Some(value))
+                        && (!position.neighbors_known
+                            || position.previous == Some(value)
+                            || position.next == Some(value))
+            }
+        }
+    }
+
+    fn print_character(
+        &mut self,
+        value: CodePoint,
+        context: CharacterContext,
+        position: CharacterPosition,
+    ) {
+        if self.needs_escape(value, context, position) {
+            self.write("\\");
+            self.write(&char::try_from(value).unwrap().to_string());
+            return;
+        }
+
+        match value {
+            CHARACTER_TABULATION => self.write("\\t"),
+            LINE_FEED => self.write("\\n"),
+            LINE_TABULATION => self.write("\\v"),
+            FORM_FEED => self.write("\\f"),
+            CARRIAGE_RETURN => self.write("\\r"),
+            value if self._format_options.is_some() => {
+                let is_surrogate = is_lead_surrogate(value) || is_trail_surrogate(value);
+                if value < 0x20
+                    || (0x7f..=0x9f).contains(&value)
+                    || matches!(value, LINE_SEPARATOR | PARAGRAPH_SEPARATOR)
+                    || is_surrogate
+                    // `\1` followed by `0` would read as `\10`
+                    || is_decimal_digit(value) && self._after_decimal_escape
+                {
+                    self.write_escape(value);
+                } else {
+                    self.write_units(&Wtf16::from(value));
+                }
+            }
+            LINE_SEPARATOR | PARAGRAPH_SEPARATOR => self.write(&format!("\\u{value:04x}")),
+            value if value < 0x20 || value == 0x7f => self.write(&format!("\\x{value:02x}")),
+            // `\1` followed by `0` would read as `\10`
+            value if is_decimal_digit(value) && self._after_decimal_escape => {
+                self.write(&format!("\\x{value:02x}"))
+            }
+            // A lone surrogate written as-is could pair up with a neighbor
+            value
+                if self._unicode_mode
+                    && (is_lead_surrogate(value) || is_trail_surrogate(value)) =>
+            {
+                self.write(&format!("\\u{{{value:04x}}}"))
+            }
+            value => self.write_units(&Wtf16::from(value)),
+        }
+    }
+
+    fn write_escape(&mut self, value: CodePoint) {
+        let options = self._format_options.unwrap();
+        let is_surrogate = is_lead_surrogate(value) || is_trail_surrogate(value);
+        let mut escape = if self._unicode_mode
+            // `\uHHHH` for a lone surrogate could pair up with a neighbor
+            && (options.escape_style() == EscapeStyle::Braced || is_surrogate)
+        {
+            format!("\\u{{{value:x}}}")
+        } else if value <= 0xff {
+            format!("\\x{value:02x}")
+        } else {
+            format!("\\u{value:04x}")
+        };
+        if options.uppercase_hex() {
+            // Leave the `x`/`u` alone
+            escape = format!("{}{}", &escape[..2], escape[2..].to_uppercase());
+        }
+        self.write(&escape);
+    }
+}
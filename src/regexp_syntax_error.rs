@@ -1,14 +1,145 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt, ops::Range};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use squalid::NonEmpty;
 
-use crate::validator::{RegExpValidatorSourceContext, RegExpValidatorSourceContextKind};
+use crate::{
+    validator::{RegExpValidatorSourceContext, RegExpValidatorSourceContextKind},
+    Wtf16,
+};
 
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct RegExpSyntaxError {
+    pub kind: RegExpSyntaxErrorKind,
     pub message: String,
     pub index: usize,
+    pub span: Range<usize>,
+}
+
+impl RegExpSyntaxError {
+    /// Renders `source` (the same text that was passed to the validator or
+    /// parser) with the error's span underlined by carets, followed by
+    /// `label` if one is given
+    ///
+    /// Columns are counted in `char`s, so the carets only line up in a
+    /// terminal when everything up to the end of the span is one column wide:
+    /// wide (e.g. CJK or emoji) and combining characters shift them
+    pub fn render(&self, source: &[u16], label: Option<&str>) -> String {
+        let start = self.span.start.min(source.len());
+        let end = self.span.end.clamp(start, source.len());
+        let column = String::from_utf16_lossy(&source[..start]).chars().count();
+        let width = String::from_utf16_lossy(&source[start..end])
+            .chars()
+            .count()
+            .max(1);
+        let mut rendered = format!(
+            "{}\n{}{}",
+            String::from_utf16_lossy(source),
+            " ".repeat(column),
+            "^".repeat(width)
+        );
+        if let Some(label) = label {
+            rendered.push(' ');
+            rendered.push_str(label);
+        }
+        rendered
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RegExpSyntaxErrorKind {
+    BackslashAtEndOfPattern,
+    DuplicateCaptureGroupName {
+        name: Wtf16,
+    },
+    DuplicatedFlag(char),
+    Empty,
+    IncompleteQuantifier,
+    InvalidCaptureGroupName,
+    InvalidCharacterClass,
+    InvalidCharacterInCharacterClass,
+    InvalidEmptyFlags,
+    InvalidEscape,
+    InvalidFlag(char),
+    InvalidGroup,
+    InvalidNamedCaptureReferenced {
+        name: Wtf16,
+    },
+    InvalidNamedReference,
+    InvalidPropertyName {
+        name: Option<String>,
+        value: Option<String>,
+    },
+    InvalidRegularExpressionFlags,
+    InvalidSetOperationInCharacterClass,
+    InvalidUnicodeEscape,
+    LoneQuantifierBrackets,
+    NegatedCharacterClassMayContainStrings,
+    NegatedPropertyOfStrings {
+        name: String,
+    },
+    NothingToRepeat,
+    NumbersOutOfOrderInQuantifier,
+    RangeOutOfOrderInCharacterClass,
+    UnexpectedCharacter(char),
+    UnmatchedRightParenthesis,
+    UnterminatedCharacterClass,
+    UnterminatedClassStringDisjunction,
+    UnterminatedGroup,
+    UnterminatedRegularExpression,
+}
+
+impl fmt::Display for RegExpSyntaxErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BackslashAtEndOfPattern => write!(f, "\\ at end of pattern"),
+            Self::DuplicateCaptureGroupName { .. } => write!(f, "Duplicate capture group name"),
+            Self::DuplicatedFlag(flag) => write!(f, "Duplicated flag '{flag}'"),
+            Self::Empty => write!(f, "Empty"),
+            Self::IncompleteQuantifier => write!(f, "Incomplete quantifier"),
+            Self::InvalidCaptureGroupName => write!(f, "Invalid capture group name"),
+            Self::InvalidCharacterClass => write!(f, "Invalid character class"),
+            Self::InvalidCharacterInCharacterClass => {
+                write!(f, "Invalid character in character class")
+            }
+            Self::InvalidEmptyFlags => write!(f, "Invalid empty flags"),
+            Self::InvalidEscape => write!(f, "Invalid escape"),
+            Self::InvalidFlag(flag) => write!(f, "Invalid flag '{flag}'"),
+            Self::InvalidGroup => write!(f, "Invalid group"),
+            Self::InvalidNamedCaptureReferenced { .. } => {
+                write!(f, "Invalid named capture referenced")
+            }
+            Self::InvalidNamedReference => write!(f, "Invalid named reference"),
+            Self::InvalidPropertyName { .. } | Self::NegatedPropertyOfStrings { .. } => {
+                write!(f, "Invalid property name")
+            }
+            Self::InvalidRegularExpressionFlags => write!(f, "Invalid regular expression flags"),
+            Self::InvalidSetOperationInCharacterClass => {
+                write!(f, "Invalid set operation in character class")
+            }
+            Self::InvalidUnicodeEscape => write!(f, "Invalid unicode escape"),
+            Self::LoneQuantifierBrackets => write!(f, "Lone quantifier brackets"),
+            Self::NegatedCharacterClassMayContainStrings => {
+                write!(f, "Negated character class may contain strings")
+            }
+            Self::NothingToRepeat => write!(f, "Nothing to repeat"),
+            Self::NumbersOutOfOrderInQuantifier => {
+                write!(f, "numbers out of order in {{}} quantifier")
+            }
+            Self::RangeOutOfOrderInCharacterClass => {
+                write!(f, "Range out of order in character class")
+            }
+            Self::UnexpectedCharacter(c) => write!(f, "Unexpected character '{c}'"),
+            Self::UnmatchedRightParenthesis => write!(f, "Unmatched ')'"),
+            Self::UnterminatedCharacterClass => write!(f, "Unterminated character class"),
+            Self::UnterminatedClassStringDisjunction => {
+                write!(f, "Unterminated class string disjunction")
+            }
+            Self::UnterminatedGroup => write!(f, "Unterminated group"),
+            Self::UnterminatedRegularExpression => write!(f, "Unterminated regular expression"),
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -21,7 +152,8 @@ pub fn new_reg_exp_syntax_error(
     src_ctx: &RegExpValidatorSourceContext,
     flags: Flags,
     index: usize,
-    message: &str,
+    span: Range<usize>,
+    kind: RegExpSyntaxErrorKind,
 ) -> RegExpSyntaxError {
     let mut source: Cow<'_, str> = "".into();
     match src_ctx.kind {
@@ -44,7 +176,9 @@ pub fn new_reg_exp_syntax_error(
     }
 
     RegExpSyntaxError {
-        message: format!("Invalid regular expression{source}: {message}"),
+        message: format!("Invalid regular expression{source}: {kind}"),
+        kind,
         index,
+        span,
     }
 }
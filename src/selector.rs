@@ -0,0 +1,474 @@
+use std::{fmt, str::FromStr};
+
+use id_arena::Id;
+use serde_json::Value;
+
+use crate::{
+    ast::{Node, NodeInterface},
+    AllArenas, Wtf16,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SelectorError {
+    /// The selector ended in the middle of something
+    UnexpectedEnd,
+    /// The character at this (`char`) index can't go where it is
+    UnexpectedCharacter(usize),
+    /// A `:name` that isn't one of the supported pseudo-classes
+    UnknownPseudoClass(String),
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "Unexpected end of selector"),
+            Self::UnexpectedCharacter(index) => {
+                write!(f, "Unexpected character at index {index}")
+            }
+            Self::UnknownPseudoClass(name) => write!(f, "Unknown pseudo-class ':{name}'"),
+        }
+    }
+}
+
+/// An esquery-style selector, e.g.
+/// `Assertion[kind=lookbehind][negate=true] Backreference`
+///
+/// Supported:
+/// - node types (compared case-insensitively) and `*`
+/// - attributes, named as in the JSON from
+///   [`SerializableNode`](crate::SerializableNode): `[name]`,
+///   `[name=value]`, `[name!=value]`, and `<`, `<=`, `>`, `>=` for numbers.
+///   Values can be quoted strings or bare words
+/// - the descendant (` `), child (`>`), adjacent sibling (`+`) and general
+///   sibling (`~`) combinators
+/// - `:not(..)`, `:is(..)`/`:matches(..)`, `:has(..)`, `:first-child` and
+///   `:last-child`
+/// - lists of selectors separated by `,`
+///
+/// Combinators look at the whole tree a node is in, not just the part below
+/// the node a query started from
+#[derive(Clone, Debug)]
+pub struct Selector {
+    _alternatives: Vec<Complex>,
+}
+
+/// Compounds joined by combinators, with `_combinators[i]` between
+/// `_compounds[i]` and `_compounds[i + 1]`
+#[derive(Clone, Debug)]
+struct Complex {
+    _compounds: Vec<Compound>,
+    _combinators: Vec<Combinator>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+    Adjacent,
+    Sibling,
+}
+
+#[derive(Clone, Debug)]
+struct Compound {
+    /// `None` for `*` (or no type at all)
+    _type: Option<String>,
+    _conditions: Vec<Condition>,
+}
+
+#[derive(Clone, Debug)]
+enum Condition {
+    Attribute(String, Option<(Operator, String)>),
+    Not(Selector),
+    Is(Selector),
+    Has(Selector),
+    FirstChild,
+    LastChild,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Selector {
+    pub fn parse(source: &str) -> Result<Self, SelectorError> {
+        let mut parser = SelectorParser {
+            _chars: source.chars().collect(),
+            _index: 0,
+        };
+        let selector = parser.selector()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(selector),
+            Some(_) => Err(SelectorError::UnexpectedCharacter(parser._index)),
+        }
+    }
+
+    pub fn matches(&self, node: impl Into<Id<Node>>, arena: &AllArenas) -> bool {
+        let node = node.into();
+        self._alternatives
+            .iter()
+            .any(|complex| complex.matches(complex._compounds.len() - 1, node, arena))
+    }
+
+    /// The matching nodes at or below `node`, in pre-order
+    pub fn query(&self, node: impl Into<Id<Node>>, arena: &AllArenas) -> Vec<Id<Node>> {
+        arena
+            .preorder(node)
+            .filter(|&node| self.matches(node, arena))
+            .collect()
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+impl Complex {
+    /// Whether `node` matches the compound at `index` along with everything
+    /// to the left of it
+    fn matches(&self, index: usize, node: Id<Node>, arena: &AllArenas) -> bool {
+        if !self._compounds[index].matches(node, arena) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+        let matches_left = |node: Id<Node>| self.matches(index - 1, node, arena);
+        match self._combinators[index - 1] {
+            Combinator::Descendant => arena.ancestors(node).any(matches_left),
+            Combinator::Child => arena.node(node).maybe_parent().is_some_and(matches_left),
+            Combinator::Adjacent => preceding_siblings(node, arena)
+                .last()
+                .copied()
+                .is_some_and(matches_left),
+            Combinator::Sibling => preceding_siblings(node, arena)
+                .into_iter()
+                .any(matches_left),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, node: Id<Node>, arena: &AllArenas) -> bool {
+        if let Some(type_) = self._type.as_ref() {
+            if !arena.node(node).kind().as_str().eq_ignore_ascii_case(type_) {
+                return false;
+            }
+        }
+        self._conditions
+            .iter()
+            .all(|condition| condition.matches(node, arena))
+    }
+}
+
+impl Condition {
+    fn matches(&self, node: Id<Node>, arena: &AllArenas) -> bool {
+        match self {
+            Self::Attribute(name, test) => {
+                let Some(value) = attribute(&arena.node(node), name) else {
+                    return false;
+                };
+                let Some((operator, expected)) = test else {
+                    return true;
+                };
+                let value = match value {
+                    Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                match operator {
+                    Operator::Equal => value == *expected,
+                    Operator::NotEqual => value != *expected,
+                    operator => {
+                        let (Ok(value), Ok(expected)) =
+                            (value.parse::<f64>(), expected.parse::<f64>())
+                        else {
+                            return false;
+                        };
+                        match operator {
+                            Operator::Less => value < expected,
+                            Operator::LessOrEqual => value <= expected,
+                            Operator::Greater => value > expected,
+                            _ => value >= expected,
+                        }
+                    }
+                }
+            }
+            Self::Not(selector) => !selector.matches(node, arena),
+            Self::Is(selector) => selector.matches(node, arena),
+            Self::Has(selector) => arena
+                .descendants(node)
+                .any(|descendant| selector.matches(descendant, arena)),
+            Self::FirstChild => siblings(node, arena).first() == Some(&node),
+            Self::LastChild => siblings(node, arena).last() == Some(&node),
+        }
+    }
+}
+
+/// Empty for the root
+fn siblings(node: Id<Node>, arena: &AllArenas) -> Vec<Id<Node>> {
+    let parent = arena.node(node).maybe_parent();
+    parent
+        .map(|parent| arena.node(parent).children().collect())
+        .unwrap_or_default()
+}
+
+/// Nearest last
+fn preceding_siblings(node: Id<Node>, arena: &AllArenas) -> Vec<Id<Node>> {
+    let mut siblings = siblings(node, arena);
+    let index = siblings.iter().position(|&sibling| sibling == node);
+    siblings.truncate(index.unwrap_or_default());
+    siblings
+}
+
+/// The attribute as it appears in the node's JSON. Links to other nodes
+/// aren't attributes, and neither are missing or `null` values
+fn attribute(node: &Node, name: &str) -> Option<Value> {
+    fn json(value: impl serde::Serialize) -> Option<Value> {
+        serde_json::to_value(value)
+            .ok()
+            .filter(|value| !value.is_null())
+    }
+
+    match (node, name) {
+        (_, "type") => json(node.kind().as_str()),
+        (_, "start") => json(node.start()),
+        (_, "end") => json(node.end()),
+        (_, "raw") => json(Wtf16::from(node.raw())),
+        (Node::CapturingGroup(node), "name") => json(&node.name),
+        (Node::CharacterClass(node), "unicodeSets") => json(node.unicode_sets),
+        (Node::CharacterClass(node), "negate") => json(node.negate),
+        (Node::ExpressionCharacterClass(node), "negate") => json(node.negate),
+        (Node::ModifierFlags(node), "dotAll") => json(node.dot_all),
+        (Node::ModifierFlags(node), "ignoreCase") => json(node.ignore_case),
+        (Node::ModifierFlags(node), "multiline") => json(node.multiline),
+        (Node::Assertion(node), "kind") => json(node.kind),
+        (Node::Assertion(node), "negate") => json(node.negate),
+        (Node::Quantifier(node), "min") => json(node.min),
+        (Node::Quantifier(node), "max") if node.max == u32::MAX => json("Infinity"),
+        (Node::Quantifier(node), "max") => json(node.max),
+        (Node::Quantifier(node), "greedy") => json(node.greedy),
+        (Node::Backreference(node), "ref") => json(&node.ref_),
+        (Node::Character(node), "value") => json(node.value),
+        (Node::CharacterSet(node), "kind") => json(node.kind),
+        (Node::CharacterSet(node), "strings") => json(node.strings),
+        (Node::CharacterSet(node), "key") => json(&node.key),
+        (Node::CharacterSet(node), "value") => json(&node.value),
+        (Node::CharacterSet(node), "negate") => json(node.negate),
+        (Node::Flags(node), "global") => json(node.global),
+        (Node::Flags(node), "ignoreCase") => json(node.ignore_case),
+        (Node::Flags(node), "multiline") => json(node.multiline),
+        (Node::Flags(node), "unicode") => json(node.unicode),
+        (Node::Flags(node), "sticky") => json(node.sticky),
+        (Node::Flags(node), "dotAll") => json(node.dot_all),
+        (Node::Flags(node), "hasIndices") => json(node.has_indices),
+        (Node::Flags(node), "unicodeSets") => json(node.unicode_sets),
+        (Node::Error(node), "kind") => json(&node.kind),
+        (Node::Error(node), "message") => json(&node.message),
+        _ => None,
+    }
+}
+
+struct SelectorParser {
+    _chars: Vec<char>,
+    _index: usize,
+}
+
+impl SelectorParser {
+    fn peek(&self) -> Option<char> {
+        self._chars.get(self._index).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self._index += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn unexpected(&self) -> SelectorError {
+        match self.peek() {
+            None => SelectorError::UnexpectedEnd,
+            Some(_) => SelectorError::UnexpectedCharacter(self._index),
+        }
+    }
+
+    /// Returns whether there was any
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self._index;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self._index += 1;
+        }
+        self._index > start
+    }
+
+    fn selector(&mut self) -> Result<Selector, SelectorError> {
+        let mut alternatives = vec![];
+        loop {
+            self.skip_whitespace();
+            alternatives.push(self.complex()?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(Selector {
+                    _alternatives: alternatives,
+                });
+            }
+        }
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        let mut compounds = vec![self.compound()?];
+        let mut combinators = vec![];
+        loop {
+            let had_whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::Adjacent,
+                Some('~') => Combinator::Sibling,
+                None | Some(',' | ')') => break,
+                Some(_) if had_whitespace => Combinator::Descendant,
+                Some(_) => return Err(self.unexpected()),
+            };
+            if combinator != Combinator::Descendant {
+                self._index += 1;
+                self.skip_whitespace();
+            }
+            combinators.push(combinator);
+            compounds.push(self.compound()?);
+        }
+        Ok(Complex {
+            _compounds: compounds,
+            _combinators: combinators,
+        })
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let universal = self.eat('*');
+        let type_ = if universal { None } else { self.identifier() };
+        let mut conditions = vec![];
+        loop {
+            if self.eat('[') {
+                conditions.push(self.attribute()?);
+            } else if self.eat(':') {
+                conditions.push(self.pseudo_class()?);
+            } else {
+                break;
+            }
+        }
+        if !universal && type_.is_none() && conditions.is_empty() {
+            return Err(self.unexpected());
+        }
+        Ok(Compound {
+            _type: type_,
+            _conditions: conditions,
+        })
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        let start = self._index;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '$'))
+        {
+            self._index += 1;
+        }
+        (self._index > start).then(|| self._chars[start..self._index].iter().collect())
+    }
+
+    fn attribute(&mut self) -> Result<Condition, SelectorError> {
+        self.skip_whitespace();
+        let name = self.identifier().ok_or_else(|| self.unexpected())?;
+        self.skip_whitespace();
+        if self.eat(']') {
+            return Ok(Condition::Attribute(name, None));
+        }
+        let operator = if self.eat('=') {
+            Operator::Equal
+        } else if self.eat('!') {
+            self.expect('=')?;
+            Operator::NotEqual
+        } else if self.eat('<') {
+            if self.eat('=') {
+                Operator::LessOrEqual
+            } else {
+                Operator::Less
+            }
+        } else if self.eat('>') {
+            if self.eat('=') {
+                Operator::GreaterOrEqual
+            } else {
+                Operator::Greater
+            }
+        } else {
+            return Err(self.unexpected());
+        };
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self._index += 1;
+                self.string(quote)?
+            }
+            _ => self.identifier().ok_or_else(|| self.unexpected())?,
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok(Condition::Attribute(name, Some((operator, value))))
+    }
+
+    /// After the opening quote
+    fn string(&mut self, quote: char) -> Result<String, SelectorError> {
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(SelectorError::UnexpectedEnd),
+                Some(c) if c == quote => {
+                    self._index += 1;
+                    return Ok(value);
+                }
+                Some('\\') => {
+                    self._index += 1;
+                    value.push(self.peek().ok_or(SelectorError::UnexpectedEnd)?);
+                    self._index += 1;
+                }
+                Some(c) => {
+                    value.push(c);
+                    self._index += 1;
+                }
+            }
+        }
+    }
+
+    fn pseudo_class(&mut self) -> Result<Condition, SelectorError> {
+        let name = self.identifier().ok_or_else(|| self.unexpected())?;
+        let wrap: fn(Selector) -> Condition = match &*name {
+            "first-child" => return Ok(Condition::FirstChild),
+            "last-child" => return Ok(Condition::LastChild),
+            "not" => Condition::Not,
+            "is" | "matches" => Condition::Is,
+            "has" => Condition::Has,
+            _ => return Err(SelectorError::UnknownPseudoClass(name)),
+        };
+        self.expect('(')?;
+        let selector = self.selector()?;
+        self.expect(')')?;
+        Ok(wrap(selector))
+    }
+}
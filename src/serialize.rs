@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use id_arena::Id;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::{
+    ast::{get_relative_path, resolve_location, Node, NodeInterface},
+    AllArenas, Wtf16,
+};
+
+/// Serializes the tree rooted at a node to the same JSON shape that
+/// regexpp produces: every node has `type`, `parent`, `start`, `end` and
+/// `raw`, and links to other nodes in the tree (`parent`, `references` and
+/// `resolved`) are written as relative `"♻️../.."`-style paths
+///
+/// Links to nodes outside of the serialized tree (eg the parent of a
+/// non-root node) are written as `null`
+pub struct SerializableNode<'a> {
+    arena: &'a AllArenas,
+    node: Id<Node>,
+    path_map: HashMap<Id<Node>, String>,
+}
+
+impl<'a> SerializableNode<'a> {
+    pub fn new(arena: &'a AllArenas, node: Id<Node>) -> Self {
+        let mut path: Vec<String> = Default::default();
+        let mut path_map: HashMap<Id<Node>, String> = Default::default();
+        resolve_location(arena, node, &mut path, &mut path_map);
+        Self {
+            arena,
+            node,
+            path_map,
+        }
+    }
+}
+
+impl<'a> Serialize for SerializableNode<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        NodeWithPaths {
+            arena: self.arena,
+            node: self.node,
+            path_map: &self.path_map,
+        }
+        .serialize(serializer)
+    }
+}
+
+struct NodeWithPaths<'a> {
+    arena: &'a AllArenas,
+    node: Id<Node>,
+    path_map: &'a HashMap<Id<Node>, String>,
+}
+
+impl<'a> NodeWithPaths<'a> {
+    fn child(&self, node: impl Into<Id<Node>>) -> Self {
+        Self {
+            arena: self.arena,
+            node: node.into(),
+            path_map: self.path_map,
+        }
+    }
+
+    fn children(&self, nodes: &[impl Into<Id<Node>> + Copy]) -> Vec<Self> {
+        nodes.iter().map(|&node| self.child(node)).collect()
+    }
+
+    fn relative_path(&self, to: impl Into<Id<Node>>) -> Option<String> {
+        let to = to.into();
+        self.path_map
+            .contains_key(&to)
+            .then(|| get_relative_path(self.node, to, self.path_map))
+    }
+}
+
+impl<'a> Serialize for NodeWithPaths<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let node = self.arena.node(self.node);
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", node.kind().as_str())?;
+        map.serialize_entry(
+            "parent",
+            &node
+                .maybe_parent()
+                .and_then(|parent| self.relative_path(parent)),
+        )?;
+        map.serialize_entry("start", &node.start())?;
+        map.serialize_entry("end", &node.end())?;
+        map.serialize_entry("raw", &Wtf16::from(node.raw()))?;
+        match &*node {
+            Node::Alternative(node) => {
+                map.serialize_entry("elements", &self.children(&node.elements))?;
+            }
+            Node::CapturingGroup(node) => {
+                map.serialize_entry("name", &node.name)?;
+                map.serialize_entry("alternatives", &self.children(&node.alternatives))?;
+                map.serialize_entry(
+                    "references",
+                    &node
+                        .references
+                        .iter()
+                        .map(|&reference| self.relative_path(reference))
+                        .collect::<Vec<_>>(),
+                )?;
+            }
+            Node::CharacterClass(node) => {
+                map.serialize_entry("unicodeSets", &node.unicode_sets)?;
+                map.serialize_entry("negate", &node.negate)?;
+                map.serialize_entry("elements", &self.children(&node.elements))?;
+            }
+            Node::CharacterClassRange(node) => {
+                map.serialize_entry("min", &self.child(node.min))?;
+                map.serialize_entry("max", &self.child(node.max))?;
+            }
+            Node::ClassIntersection(node) => {
+                map.serialize_entry("left", &self.child(node.left))?;
+                map.serialize_entry("right", &self.child(node.right))?;
+            }
+            Node::ClassStringDisjunction(node) => {
+                map.serialize_entry("alternatives", &self.children(&node.alternatives))?;
+            }
+            Node::ClassSubtraction(node) => {
+                map.serialize_entry("left", &self.child(node.left))?;
+                map.serialize_entry("right", &self.child(node.right))?;
+            }
+            Node::ExpressionCharacterClass(node) => {
+                map.serialize_entry("negate", &node.negate)?;
+                map.serialize_entry("expression", &self.child(node.expression))?;
+            }
+            Node::Group(node) => {
+                map.serialize_entry(
+                    "modifiers",
+                    &node.modifiers.map(|modifiers| self.child(modifiers)),
+                )?;
+                map.serialize_entry("alternatives", &self.children(&node.alternatives))?;
+            }
+            Node::Modifiers(node) => {
+                map.serialize_entry("add", &self.child(node.add))?;
+                map.serialize_entry("remove", &node.remove.map(|remove| self.child(remove)))?;
+            }
+            Node::ModifierFlags(node) => {
+                map.serialize_entry("dotAll", &node.dot_all)?;
+                map.serialize_entry("ignoreCase", &node.ignore_case)?;
+                map.serialize_entry("multiline", &node.multiline)?;
+            }
+            Node::Assertion(node) => {
+                map.serialize_entry("kind", &node.kind)?;
+                if let Some(negate) = node.negate {
+                    map.serialize_entry("negate", &negate)?;
+                }
+                if let Some(alternatives) = node.alternatives.as_ref() {
+                    map.serialize_entry("alternatives", &self.children(alternatives))?;
+                }
+            }
+            Node::Pattern(node) => {
+                map.serialize_entry("alternatives", &self.children(&node.alternatives))?;
+            }
+            Node::Quantifier(node) => {
+                map.serialize_entry("min", &node.min)?;
+                if node.max == u32::MAX {
+                    map.serialize_entry("max", "$$Infinity")?;
+                } else {
+                    map.serialize_entry("max", &node.max)?;
+                }
+                map.serialize_entry("greedy", &node.greedy)?;
+                map.serialize_entry("element", &self.child(node.element))?;
+            }
+            Node::RegExpLiteral(node) => {
+                map.serialize_entry("pattern", &self.child(node.pattern))?;
+                map.serialize_entry("flags", &self.child(node.flags))?;
+            }
+            Node::StringAlternative(node) => {
+                map.serialize_entry("elements", &self.children(&node.elements))?;
+            }
+            Node::Backreference(node) => {
+                map.serialize_entry("ref", &node.ref_)?;
+                map.serialize_entry("ambiguous", &(node.resolved.len() != 1))?;
+                match &*node.resolved {
+                    [resolved] => {
+                        map.serialize_entry("resolved", &self.relative_path(*resolved))?;
+                    }
+                    resolved => {
+                        map.serialize_entry(
+                            "resolved",
+                            &resolved
+                                .iter()
+                                .map(|&resolved| self.relative_path(resolved))
+                                .collect::<Vec<_>>(),
+                        )?;
+                    }
+                }
+            }
+            Node::Character(node) => {
+                map.serialize_entry("value", &node.value)?;
+            }
+            Node::CharacterSet(node) => {
+                map.serialize_entry("kind", &node.kind)?;
+                if let Some(strings) = node.strings {
+                    map.serialize_entry("strings", &strings)?;
+                }
+                if let Some(key) = node.key.as_ref() {
+                    map.serialize_entry("key", key)?;
+                    map.serialize_entry("value", &node.value)?;
+                }
+                if let Some(negate) = node.negate {
+                    map.serialize_entry("negate", &negate)?;
+                }
+            }
+            Node::Flags(node) => {
+                map.serialize_entry("global", &node.global)?;
+                map.serialize_entry("ignoreCase", &node.ignore_case)?;
+                map.serialize_entry("multiline", &node.multiline)?;
+                map.serialize_entry("unicode", &node.unicode)?;
+                map.serialize_entry("sticky", &node.sticky)?;
+                map.serialize_entry("dotAll", &node.dot_all)?;
+                map.serialize_entry("hasIndices", &node.has_indices)?;
+                map.serialize_entry("unicodeSets", &node.unicode_sets)?;
+            }
+            Node::Error(node) => {
+                map.serialize_entry("kind", &node.kind)?;
+                map.serialize_entry("message", &node.message)?;
+            }
+        }
+        map.end()
+    }
+}
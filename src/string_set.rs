@@ -0,0 +1,221 @@
+use std::collections::BTreeSet;
+
+use id_arena::Id;
+
+use crate::{
+    ast::Node,
+    ecma_versions::EcmaVersion,
+    unicode::{case_folding::canonicalize, close_over_case, unicode_property_of_strings},
+    AllArenas, CharSet, CharSetError, CharSetFlags, CodePoint,
+};
+
+/// A [`CharSet`] plus a finite set of strings, which is what a class in
+/// `v`-flag mode (or a property of strings) matches
+///
+/// Strings of exactly one code point are always kept in the `CharSet`, so
+/// equal sets compare equal
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StringSet {
+    _chars: CharSet,
+    _strings: BTreeSet<Vec<CodePoint>>,
+}
+
+impl StringSet {
+    pub fn empty() -> Self {
+        Default::default()
+    }
+
+    pub fn from_strings(strings: impl IntoIterator<Item = Vec<CodePoint>>) -> Self {
+        let mut string_set = Self::empty();
+        let mut chars = vec![];
+        for string in strings {
+            match &string[..] {
+                &[cp] => chars.push(cp..=cp),
+                _ => {
+                    string_set._strings.insert(string);
+                }
+            }
+        }
+        string_set._chars = CharSet::from_ranges(chars);
+        string_set
+    }
+
+    /// The strings with a property of strings (like `RGI_Emoji`) in the
+    /// ECMAScript version, or `None` if the version doesn't have the property
+    pub fn from_property(version: EcmaVersion, name: &str) -> Option<Self> {
+        unicode_property_of_strings(version, name)
+    }
+
+    /// The single code points
+    pub fn chars(&self) -> &CharSet {
+        &self._chars
+    }
+
+    /// Everything that isn't a single code point, including the empty string
+    /// if it's in the set
+    pub fn strings(&self) -> impl Iterator<Item = &[CodePoint]> + '_ {
+        self._strings.iter().map(|string| &string[..])
+    }
+
+    pub fn has_strings(&self) -> bool {
+        !self._strings.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self._chars.is_empty() && self._strings.is_empty()
+    }
+
+    pub fn contains(&self, string: &[CodePoint]) -> bool {
+        match string {
+            &[cp] => self._chars.contains(cp),
+            _ => self._strings.contains(string),
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            _chars: self._chars.union(&other._chars),
+            _strings: &self._strings | &other._strings,
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            _chars: self._chars.intersection(&other._chars),
+            _strings: &self._strings & &other._strings,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            _chars: self._chars.difference(&other._chars),
+            _strings: &self._strings - &other._strings,
+        }
+    }
+
+    /// What the node matches, which can be anything [`CharSet::from_node`]
+    /// accepts, but which can also contain strings
+    ///
+    /// With the `i` flag, single code points are closed over case like in a
+    /// `CharSet`, but strings are kept case-folded, with each code point
+    /// replaced by what [`canonicalize`] maps it to
+    pub fn from_node(
+        arena: &AllArenas,
+        node: impl Into<Id<Node>>,
+        flags: CharSetFlags,
+    ) -> Result<Self, CharSetError> {
+        let node = node.into();
+        let negated = |string_set: Self, negate: bool| {
+            if !negate {
+                return Ok(string_set);
+            }
+            if string_set.has_strings() {
+                return Err(CharSetError::Strings);
+            }
+            Ok(flags.all().difference(&string_set._chars).into())
+        };
+        Ok(match &*arena.node(node) {
+            Node::CharacterSet(character_set) if character_set.strings == Some(true) => {
+                let name = String::try_from(character_set.key.as_ref().unwrap()).unwrap();
+                Self::from_property(flags.ecma_version, &name)
+                    .ok_or(CharSetError::UnknownProperty)?
+                    .with_case_ignored(flags)
+            }
+            Node::CharacterClass(node) => {
+                let mut string_set = Self::empty();
+                for &element in &node.elements {
+                    string_set = string_set.union(&Self::from_node(arena, element, flags)?);
+                }
+                negated(string_set, node.negate)?
+            }
+            Node::ClassStringDisjunction(node) => Self::from_strings(
+                node.alternatives
+                    .iter()
+                    .map(|alternative| {
+                        alternative
+                            .get(arena)
+                            .elements
+                            .iter()
+                            .map(|character| character.get(arena).value)
+                            .collect()
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .with_case_ignored(flags),
+            Node::ClassIntersection(node) => Self::from_node(arena, node.left, flags)?
+                .intersection(&Self::from_node(arena, node.right, flags)?),
+            Node::ClassSubtraction(node) => Self::from_node(arena, node.left, flags)?
+                .difference(&Self::from_node(arena, node.right, flags)?),
+            Node::ExpressionCharacterClass(node) => {
+                negated(Self::from_node(arena, node.expression, flags)?, node.negate)?
+            }
+            _ => CharSet::from_node(arena, node, flags)?.into(),
+        })
+    }
+
+    fn with_case_ignored(self, flags: CharSetFlags) -> Self {
+        if !flags.ignore_case {
+            return self;
+        }
+        Self {
+            _chars: close_over_case(&self._chars, flags.unicode_mode()),
+            _strings: self
+                ._strings
+                .into_iter()
+                .map(|string| {
+                    string
+                        .into_iter()
+                        .map(|cp| canonicalize(cp, true))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<CharSet> for StringSet {
+    fn from(chars: CharSet) -> Self {
+        Self {
+            _chars: chars,
+            _strings: Default::default(),
+        }
+    }
+}
+
+impl TryFrom<StringSet> for CharSet {
+    type Error = CharSetError;
+
+    fn try_from(string_set: StringSet) -> Result<Self, Self::Error> {
+        if string_set.has_strings() {
+            return Err(CharSetError::Strings);
+        }
+        Ok(string_set._chars)
+    }
+}
+
+/// Whether a class can match strings (other than single code points), going
+/// by its syntax alone, like the spec's `MayContainStrings`
+pub(crate) fn may_contain_strings(arena: &AllArenas, node: impl Into<Id<Node>>) -> bool {
+    match &*arena.node(node) {
+        Node::CharacterSet(node) => node.strings == Some(true),
+        Node::CharacterClass(node) => {
+            !node.negate
+                && node
+                    .elements
+                    .iter()
+                    .any(|&element| may_contain_strings(arena, element))
+        }
+        Node::ClassStringDisjunction(node) => node
+            .alternatives
+            .iter()
+            .any(|alternative| alternative.get(arena).elements.len() != 1),
+        Node::ClassIntersection(node) => {
+            may_contain_strings(arena, node.left) && may_contain_strings(arena, node.right)
+        }
+        Node::ClassSubtraction(node) => may_contain_strings(arena, node.left),
+        Node::ExpressionCharacterClass(node) => {
+            !node.negate && may_contain_strings(arena, node.expression)
+        }
+        _ => false,
+    }
+}
@@ -10,7 +10,7 @@ use itertools::{Either, Itertools};
 use once_cell::sync::Lazy;
 use serde::Deserialize;
 
-use crate::{ast::NodeUnresolved, parser, RegExpSyntaxError};
+use crate::{ast::NodeUnresolved, parser};
 
 pub type FixtureData = IndexMap<PathBuf, FixtureDataValue>;
 
@@ -24,7 +24,13 @@ pub struct FixtureDataValue {
 #[serde(rename_all = "camelCase")]
 pub enum AstOrError {
     Ast(NodeUnresolved),
-    Error(RegExpSyntaxError),
+    Error(RegExpSyntaxErrorUnresolved),
+}
+
+#[derive(Deserialize)]
+pub struct RegExpSyntaxErrorUnresolved {
+    pub message: String,
+    pub index: usize,
 }
 
 static FIXTURES_ROOT: Lazy<PathBuf> =
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use super::case_folding_data::{LEGACY_CASE_CLASS_DATA, SIMPLE_CASE_FOLDING_CLASS_DATA};
+use crate::{CharSet, CodePoint};
+
+/// The groups of code points that unicode mode's `Canonicalize` (simple
+/// case folding, statuses C and S of CaseFolding.txt in Unicode 16.0.0)
+/// maps to the same code point, which is the first in each group
+static SIMPLE_CASE_FOLDING_CLASSES: Lazy<Vec<Vec<CodePoint>>> =
+    Lazy::new(|| restore_classes(SIMPLE_CASE_FOLDING_CLASS_DATA));
+
+/// The same for the legacy `Canonicalize`, which upper-cases a code unit
+/// (full case mapping in Unicode 16.0.0) unless that would take it out of
+/// (or into) ASCII or make it longer
+static LEGACY_CASE_CLASSES: Lazy<Vec<Vec<CodePoint>>> =
+    Lazy::new(|| restore_classes(LEGACY_CASE_CLASS_DATA));
+
+/// Simple case folding, for the code points it changes
+static SIMPLE_CASE_FOLDING: Lazy<HashMap<CodePoint, CodePoint>> =
+    Lazy::new(|| index_classes(&SIMPLE_CASE_FOLDING_CLASSES));
+
+/// The legacy `Canonicalize`, for the code points it changes
+static LEGACY_CANONICALIZE: Lazy<HashMap<CodePoint, CodePoint>> =
+    Lazy::new(|| index_classes(&LEGACY_CASE_CLASSES));
+
+/// The spec's `Canonicalize`, which is what the `i` flag compares: simple
+/// case folding in unicode mode (with the `u` or `v` flag), otherwise
+/// upper-casing, unless that would take a code point out of (or into) ASCII
+/// or map it to more than one code unit
+pub fn canonicalize(cp: CodePoint, unicode_mode: bool) -> CodePoint {
+    let mapping = if unicode_mode {
+        &*SIMPLE_CASE_FOLDING
+    } else {
+        &*LEGACY_CANONICALIZE
+    };
+    mapping.get(&cp).copied().unwrap_or(cp)
+}
+
+/// Everything the `i` flag treats as the same as the code point, including
+/// itself
+pub fn case_equivalents(cp: CodePoint, unicode_mode: bool) -> CharSet {
+    close_over_case(&CharSet::from_code_point(cp), unicode_mode)
+}
+
+/// Adds everything that the `i` flag treats as the same as something in the
+/// set
+pub fn close_over_case(set: &CharSet, unicode_mode: bool) -> CharSet {
+    let classes = if unicode_mode {
+        &*SIMPLE_CASE_FOLDING_CLASSES
+    } else {
+        &*LEGACY_CASE_CLASSES
+    };
+    let equivalents = classes
+        .iter()
+        .filter(|class| class.iter().any(|&cp| set.contains(cp)))
+        .flatten()
+        .map(|&cp| cp..=cp);
+    set.union(&CharSet::from_ranges(equivalents))
+}
+
+fn index_classes(classes: &[Vec<CodePoint>]) -> HashMap<CodePoint, CodePoint> {
+    classes
+        .iter()
+        .flat_map(|class| class[1..].iter().map(|&cp| (cp, class[0])))
+        .collect()
+}
+
+/// Classes are separated by commas. The first code point of each is
+/// relative to the first of the previous class, the rest (which can be
+/// negative) to the first of their own, all in base 36
+fn restore_classes(data: &str) -> Vec<Vec<CodePoint>> {
+    let mut last = 0;
+    data.split(',')
+        .map(|class| {
+            let mut offsets = class
+                .split(' ')
+                .map(|s| i32::from_str_radix(s, 36).unwrap());
+            last += offsets.next().unwrap();
+            let first = last;
+            [first]
+                .into_iter()
+                .chain(offsets.map(|offset| first + offset))
+                .map(|cp| cp as CodePoint)
+                .collect()
+        })
+        .collect()
+}
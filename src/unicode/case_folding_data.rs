@@ -0,0 +1,14 @@
+// Generated by scripts/generate_unicode_data.py from the Unicode 16.0.0
+// CaseFolding.txt, UnicodeData.txt and SpecialCasing.txt in data/unicode;
+// don't edit by hand
+//
+// Each is a list of the groups of code points that a `Canonicalize` maps to
+// the same code point, which is the first in each group, encoded as
+// `restore_classes` in `case_folding.rs` expects
+
+/// Simple case folding, statuses C and S of CaseFolding.txt
+pub(super) static SIMPLE_CASE_FOLDING_CLASS_DATA: &str = "2p -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w 6gv,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w 7g,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,2t 5vj,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w 6di,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,2 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 3d,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,4 -1,2 -1,2 -1,3 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,3 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,3 -1,2 -1,2 -1,2 5f,3 -1,2 -1,3 -1,4 -1,6 -1,3 2p,4 -1,1 4j,1 wu9,3 3m,3 -1,2 -1,2 -1,3 -1,5 -1,3 -1,4 -1,2 -1,3 -1,4 -1,2 1k,7 -2 -1,3 -2 -1,3 -2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,1 -27,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,4 -2 -1,2 -1,4 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,4 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,9 -1,3 8cf,1 8cf,2 -1,5 -1,2 -1,2 -1,2 -1,2 -1,1 8bj,1 8bg,1 8bi,1 -5u,1 -5q,2 -5p,1 -5p,2 -5m,2 -5n,1 wnj,4 -5p,1 wnf,2 -5r,1 wo7,1 wmg,1 wn8,2 -5t,1 -5v,1 wn8,1 8af,1 wn5,3 -5v,2 8al,1 -5x,3 -5y,8 89z,3 -62,2 wn7,1 -62,4 wmi,1 -62,1 -1x,1 -61,1 -61,1 -1z,6 -63,b wlx,1 wlu,5v -1,2 -1,4 -1,4 3m,1 3m,1 3m,j 5kz,s -12,1 -11,1 -11,1 -11,1 5kj,1 -w,1 -w u,1 -w,1 -w,1 -w 1s,1 -w,1 -w,1 -w p 1o,1 -38 -w 5j9,1 -w 1i,1 -w,1 -lj -w,1 -w,1 -w,1 -w,1 -w m,1 -w 1c,2 -w -1,1 -w,1 -w,1 -w f,1 -w,1 -w,1 -w 5st,1 -w,1 -w,1 -1s,1 -1r,1 -1r,9 -8,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,3 7,1 -38,5 -1,3 -1,1h -w,1 -w,1 -w 4su,1 -w,1 -w 4st,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w 4sk,1 -w,1 -w,1 -w 4si,1 -w 4si 4sj,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w 4sc,1 -w,1 -w,1 -w,1 -w,1 -w,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,1 -28,2 -1,2 -1 4ro,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,a -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,3 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,1 -f,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,1e -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,28a 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,1 2bk,3 2bk,1 2bk,1 2bk,ip tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 8,1 8,1 8,1 8,1 8,1 8,1p1 -1,6n r9g,4 2xy,h raw,37 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1 1m,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,c -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,1 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,1 8,1 8,1 8,1 8,b 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,1 8,1 8,1 8,1 8,c 8,2 8,2 8,2 8,9 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 22,1 22,1 2e,1 2e,1 2e,1 2e,1 2s,1 2s,1 3k,1 3k,1 34,1 34,1 3i,1 3i,3 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,1 8,1 8,1 8,1 8,1 8,1 8,9 8,1 8,2 9,g 9,d 8,1 8,f 8,1 8,4 7,e 9,9n -s,y -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,1 -g,5 -1,ng -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1 -q,1fr -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,1 -1c,2 -1,4 -8bv,1 -8bs,2 -1,2 -1,2 -1,7 -1,3 -1,b -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,9 -1,2 -1,5 -1,d -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,1 -5ls,2 -5ls,6 -5ls,nx0 -1,2 -1,2 -1,2 -1,2 -1,2 -r7n -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,k -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,3s -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,4 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,b -1,2 -1,3 -1,2 -1,2 -1,2 -1,2 -1,5 -1,5 -1,2 -1,1 1c,3 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,c -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,2 -1,5 -1,2 -1,3 -1,4 -1,6 -1,2 -1,2 -1,r -1,nx -ps,fqr -1,u3 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,y6 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,3t -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,1 -14,4c -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,2 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,2 -13,1 -13,1 -13,1 -13,1 -13,1 -13,1 -13,2 -13,1 -13,1dw -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,1 -1s,3i -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,27v -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,gw1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,1 -w,o83 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y,1 -y";
+
+/// Upper-casing, for the code units it maps to another single code unit on
+/// the same side of ASCII
+pub(super) static LEGACY_CASE_CLASS_DATA: &str = "1t w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,2u w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,2 w,1 w,1 w,1 w,1 w,1 w,1 w,y 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,4 1,2 1,2 1,3 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,3 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 -3d,1 1,2 1,2 1,4 5u,1 1,2 1,2 5q,1 1,2 5p,1 5p,1 1,3 27,1 5m,1 5n,1 1,2 5p,1 5r,2 5v,1 5t,1 1,4 5v,1 5x,2 5y,1 1,2 1,2 1,2 62,1 1,2 62,3 1,2 62,1 1,2 61,1 61,1 1,2 1,2 63,1 1,4 1,8 1 2,3 1 2,3 1 2,3 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,3 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,3 1 2,3 1,2 -2p,1 -1k,1 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 -3m,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,8 8bv,1 1,2 -4j,1 8bs,3 1,2 -5f,1 1x,1 1z,1 1,2 1,2 1,2 1,2 1,82 1,2 1,4 1,9 38,7 12,2 11,1 11,1 11,2 1s,2 1r,1 1r,2 w,1 w 1q,1 w,1 w,1 w 2o,1 w,1 w,1 w 1l,1 -2c w 5k5,1 w 2e,1 w,1 -kn w,1 w,1 w,1 w,1 w 1i,1 w 28,2 v w,1 w,1 w,1 w 1b,1 w,1 w,1 w,1 w,1 w,10 8,9 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,9 1,2 -7,1 1,3 -3m,1 -3m,1 -3m,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 28,1 w,1 w,1 w 4tq,1 w,1 w 4tp,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w 4tg,1 w,1 w,1 w 4te,1 w 4te 4tf,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w 4t8,1 w,1 w,1 w,1 w,1 w,1d 1,2 1 4rp,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,a 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 f,1 1,2 1,2 1,2 1,2 1,2 1,2 1,3 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,3 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,28a 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,1 5ls,2 5ls,6 5ls,k3 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 tzk,1 8,1 8,1 8,1 8,1 8,1 8,1p0 1,7 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,1 -2bk,3 -2bk,1 -2bk,1 -2bk,8x 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1 1n,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,c 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,a -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,9 -8,1 -8,1 -8,1 -8,1 -8,1 -8,b -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,9 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,9 -8,1 -8,1 -8,1 -8,1 -8,1 -8,c -8,2 -8,2 -8,2 -8,9 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,1 -8,21 -8,1 -8,1 -22,1 -22,d -2e,1 -2e,1 -2e,1 -2e,d -8,1 -8,1 -2s,1 -2s,d -8,1 -8,1 -34,1 -34,1 -7,c -3k,1 -3k,1 -3i,1 -3i,8n s,1a g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,1 g,k 1,mr q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1 q,1f5 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1 1c,1d 1,2 -8af,1 -2xy,1 -89z,3 1,2 1,2 1,2 -8bg,1 -8al,1 -8bj,1 -8bi,2 1,3 1,9 -8cf,1 -8cf,1 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,9 1,2 1,5 1,nym 1,2 1,2 1,2 1,2 1,2 -r7m 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,k 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,3s 1,2 1,2 1,2 1,2 1,2 1,2 1,4 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,b 1,2 1,2 -r9g,1 1,2 1,2 1,2 1,2 1,5 1,2 -wmg,3 1,2 1,4 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 -wn8,1 -wnj,1 -wnf,1 -wn5,1 -wn8,2 -wlu,1 -wmi,1 -wlx,1 ps,1 1,2 1,2 1,2 1,2 1,2 1,2 1,2 1,2 -1c,1 -wn7,1 -raw,1 1,2 1,2 -wo7,1 1,4 1,6 1,2 1,2 1,2 -wu9,p 1,h7w w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w,1 w";
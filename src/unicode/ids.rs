@@ -88,7 +88,7 @@ fn is_in_range(cp: CodePoint, ranges: &[CodePoint]) -> bool {
     false
 }
 
-fn restore_ranges(data: &str) -> Vec<CodePoint> {
+pub(super) fn restore_ranges(data: &str) -> Vec<CodePoint> {
     let mut last = 0;
     data.split(' ')
         .map(|s| {
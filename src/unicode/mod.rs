@@ -1,123 +1,127 @@
 use crate::CodePoint;
 
+pub mod case_folding;
+mod case_folding_data;
 mod ids;
 mod properties;
+mod property_data;
 
-pub use ids::*;
-pub use properties::*;
+pub(crate) use case_folding::close_over_case;
+pub(crate) use ids::*;
+pub(crate) use properties::*;
 
 #[allow(dead_code)]
-pub const NULL: CodePoint = 0x00;
-pub const BACKSPACE: CodePoint = 0x08;
-pub const CHARACTER_TABULATION: CodePoint = 0x09;
-pub const LINE_FEED: CodePoint = 0x0a;
-pub const LINE_TABULATION: CodePoint = 0x0b;
-pub const FORM_FEED: CodePoint = 0x0c;
-pub const CARRIAGE_RETURN: CodePoint = 0x0d;
-pub const EXCLAMATION_MARK: CodePoint = 0x21;
-pub const NUMBER_SIGN: CodePoint = 0x23;
-pub const DOLLAR_SIGN: CodePoint = 0x24;
-pub const PERCENT_SIGN: CodePoint = 0x25;
-pub const AMPERSAND: CodePoint = 0x26;
-pub const LEFT_PARENTHESIS: CodePoint = 0x28;
-pub const RIGHT_PARENTHESIS: CodePoint = 0x29;
-pub const ASTERISK: CodePoint = 0x2a;
-pub const PLUS_SIGN: CodePoint = 0x2b;
-pub const COMMA: CodePoint = 0x2c;
-pub const HYPHEN_MINUS: CodePoint = 0x2d;
-pub const FULL_STOP: CodePoint = 0x2e;
-pub const SOLIDUS: CodePoint = 0x2f;
-pub const DIGIT_ZERO: CodePoint = 0x30;
-pub const DIGIT_ONE: CodePoint = 0x31;
-pub const DIGIT_SEVEN: CodePoint = 0x37;
-pub const DIGIT_NINE: CodePoint = 0x39;
-pub const COLON: CodePoint = 0x3a;
-pub const SEMICOLON: CodePoint = 0x3b;
-pub const LESS_THAN_SIGN: CodePoint = 0x3c;
-pub const EQUALS_SIGN: CodePoint = 0x3d;
-pub const GREATER_THAN_SIGN: CodePoint = 0x3e;
-pub const QUESTION_MARK: CodePoint = 0x3f;
-pub const COMMERCIAL_AT: CodePoint = 0x40;
-pub const LATIN_CAPITAL_LETTER_A: CodePoint = 0x41;
-pub const LATIN_CAPITAL_LETTER_B: CodePoint = 0x42;
-pub const LATIN_CAPITAL_LETTER_D: CodePoint = 0x44;
-pub const LATIN_CAPITAL_LETTER_F: CodePoint = 0x46;
-pub const LATIN_CAPITAL_LETTER_P: CodePoint = 0x50;
-pub const LATIN_CAPITAL_LETTER_S: CodePoint = 0x53;
-pub const LATIN_CAPITAL_LETTER_W: CodePoint = 0x57;
-pub const LATIN_CAPITAL_LETTER_Z: CodePoint = 0x5a;
-pub const LOW_LINE: CodePoint = 0x5f;
-pub const LATIN_SMALL_LETTER_A: CodePoint = 0x61;
-pub const LATIN_SMALL_LETTER_B: CodePoint = 0x62;
-pub const LATIN_SMALL_LETTER_C: CodePoint = 0x63;
-pub const LATIN_SMALL_LETTER_D: CodePoint = 0x64;
-pub const LATIN_SMALL_LETTER_F: CodePoint = 0x66;
-pub const LATIN_SMALL_LETTER_G: CodePoint = 0x67;
-pub const LATIN_SMALL_LETTER_I: CodePoint = 0x69;
-pub const LATIN_SMALL_LETTER_K: CodePoint = 0x6b;
-pub const LATIN_SMALL_LETTER_M: CodePoint = 0x6d;
-pub const LATIN_SMALL_LETTER_N: CodePoint = 0x6e;
-pub const LATIN_SMALL_LETTER_P: CodePoint = 0x70;
-pub const LATIN_SMALL_LETTER_Q: CodePoint = 0x71;
-pub const LATIN_SMALL_LETTER_R: CodePoint = 0x72;
-pub const LATIN_SMALL_LETTER_S: CodePoint = 0x73;
-pub const LATIN_SMALL_LETTER_T: CodePoint = 0x74;
-pub const LATIN_SMALL_LETTER_U: CodePoint = 0x75;
-pub const LATIN_SMALL_LETTER_V: CodePoint = 0x76;
-pub const LATIN_SMALL_LETTER_W: CodePoint = 0x77;
-pub const LATIN_SMALL_LETTER_X: CodePoint = 0x78;
-pub const LATIN_SMALL_LETTER_Y: CodePoint = 0x79;
-pub const LATIN_SMALL_LETTER_Z: CodePoint = 0x7a;
-pub const LEFT_SQUARE_BRACKET: CodePoint = 0x5b;
-pub const REVERSE_SOLIDUS: CodePoint = 0x5c;
-pub const RIGHT_SQUARE_BRACKET: CodePoint = 0x5d;
-pub const CIRCUMFLEX_ACCENT: CodePoint = 0x5e;
-pub const GRAVE_ACCENT: CodePoint = 0x60;
-pub const LEFT_CURLY_BRACKET: CodePoint = 0x7b;
-pub const VERTICAL_LINE: CodePoint = 0x7c;
-pub const RIGHT_CURLY_BRACKET: CodePoint = 0x7d;
-pub const TILDE: CodePoint = 0x7e;
-pub const ZERO_WIDTH_NON_JOINER: CodePoint = 0x200c;
-pub const ZERO_WIDTH_JOINER: CodePoint = 0x200d;
-pub const LINE_SEPARATOR: CodePoint = 0x2028;
-pub const PARAGRAPH_SEPARATOR: CodePoint = 0x2029;
+pub(crate) const NULL: CodePoint = 0x00;
+pub(crate) const BACKSPACE: CodePoint = 0x08;
+pub(crate) const CHARACTER_TABULATION: CodePoint = 0x09;
+pub(crate) const LINE_FEED: CodePoint = 0x0a;
+pub(crate) const LINE_TABULATION: CodePoint = 0x0b;
+pub(crate) const FORM_FEED: CodePoint = 0x0c;
+pub(crate) const CARRIAGE_RETURN: CodePoint = 0x0d;
+pub(crate) const EXCLAMATION_MARK: CodePoint = 0x21;
+pub(crate) const NUMBER_SIGN: CodePoint = 0x23;
+pub(crate) const DOLLAR_SIGN: CodePoint = 0x24;
+pub(crate) const PERCENT_SIGN: CodePoint = 0x25;
+pub(crate) const AMPERSAND: CodePoint = 0x26;
+pub(crate) const LEFT_PARENTHESIS: CodePoint = 0x28;
+pub(crate) const RIGHT_PARENTHESIS: CodePoint = 0x29;
+pub(crate) const ASTERISK: CodePoint = 0x2a;
+pub(crate) const PLUS_SIGN: CodePoint = 0x2b;
+pub(crate) const COMMA: CodePoint = 0x2c;
+pub(crate) const HYPHEN_MINUS: CodePoint = 0x2d;
+pub(crate) const FULL_STOP: CodePoint = 0x2e;
+pub(crate) const SOLIDUS: CodePoint = 0x2f;
+pub(crate) const DIGIT_ZERO: CodePoint = 0x30;
+pub(crate) const DIGIT_ONE: CodePoint = 0x31;
+pub(crate) const DIGIT_SEVEN: CodePoint = 0x37;
+pub(crate) const DIGIT_NINE: CodePoint = 0x39;
+pub(crate) const COLON: CodePoint = 0x3a;
+pub(crate) const SEMICOLON: CodePoint = 0x3b;
+pub(crate) const LESS_THAN_SIGN: CodePoint = 0x3c;
+pub(crate) const EQUALS_SIGN: CodePoint = 0x3d;
+pub(crate) const GREATER_THAN_SIGN: CodePoint = 0x3e;
+pub(crate) const QUESTION_MARK: CodePoint = 0x3f;
+pub(crate) const COMMERCIAL_AT: CodePoint = 0x40;
+pub(crate) const LATIN_CAPITAL_LETTER_A: CodePoint = 0x41;
+pub(crate) const LATIN_CAPITAL_LETTER_B: CodePoint = 0x42;
+pub(crate) const LATIN_CAPITAL_LETTER_D: CodePoint = 0x44;
+pub(crate) const LATIN_CAPITAL_LETTER_F: CodePoint = 0x46;
+pub(crate) const LATIN_CAPITAL_LETTER_P: CodePoint = 0x50;
+pub(crate) const LATIN_CAPITAL_LETTER_S: CodePoint = 0x53;
+pub(crate) const LATIN_CAPITAL_LETTER_W: CodePoint = 0x57;
+pub(crate) const LATIN_CAPITAL_LETTER_Z: CodePoint = 0x5a;
+pub(crate) const LOW_LINE: CodePoint = 0x5f;
+pub(crate) const LATIN_SMALL_LETTER_A: CodePoint = 0x61;
+pub(crate) const LATIN_SMALL_LETTER_B: CodePoint = 0x62;
+pub(crate) const LATIN_SMALL_LETTER_C: CodePoint = 0x63;
+pub(crate) const LATIN_SMALL_LETTER_D: CodePoint = 0x64;
+pub(crate) const LATIN_SMALL_LETTER_F: CodePoint = 0x66;
+pub(crate) const LATIN_SMALL_LETTER_G: CodePoint = 0x67;
+pub(crate) const LATIN_SMALL_LETTER_I: CodePoint = 0x69;
+pub(crate) const LATIN_SMALL_LETTER_K: CodePoint = 0x6b;
+pub(crate) const LATIN_SMALL_LETTER_M: CodePoint = 0x6d;
+pub(crate) const LATIN_SMALL_LETTER_N: CodePoint = 0x6e;
+pub(crate) const LATIN_SMALL_LETTER_P: CodePoint = 0x70;
+pub(crate) const LATIN_SMALL_LETTER_Q: CodePoint = 0x71;
+pub(crate) const LATIN_SMALL_LETTER_R: CodePoint = 0x72;
+pub(crate) const LATIN_SMALL_LETTER_S: CodePoint = 0x73;
+pub(crate) const LATIN_SMALL_LETTER_T: CodePoint = 0x74;
+pub(crate) const LATIN_SMALL_LETTER_U: CodePoint = 0x75;
+pub(crate) const LATIN_SMALL_LETTER_V: CodePoint = 0x76;
+pub(crate) const LATIN_SMALL_LETTER_W: CodePoint = 0x77;
+pub(crate) const LATIN_SMALL_LETTER_X: CodePoint = 0x78;
+pub(crate) const LATIN_SMALL_LETTER_Y: CodePoint = 0x79;
+pub(crate) const LATIN_SMALL_LETTER_Z: CodePoint = 0x7a;
+pub(crate) const LEFT_SQUARE_BRACKET: CodePoint = 0x5b;
+pub(crate) const REVERSE_SOLIDUS: CodePoint = 0x5c;
+pub(crate) const RIGHT_SQUARE_BRACKET: CodePoint = 0x5d;
+pub(crate) const CIRCUMFLEX_ACCENT: CodePoint = 0x5e;
+pub(crate) const GRAVE_ACCENT: CodePoint = 0x60;
+pub(crate) const LEFT_CURLY_BRACKET: CodePoint = 0x7b;
+pub(crate) const VERTICAL_LINE: CodePoint = 0x7c;
+pub(crate) const RIGHT_CURLY_BRACKET: CodePoint = 0x7d;
+pub(crate) const TILDE: CodePoint = 0x7e;
+pub(crate) const ZERO_WIDTH_NON_JOINER: CodePoint = 0x200c;
+pub(crate) const ZERO_WIDTH_JOINER: CodePoint = 0x200d;
+pub(crate) const LINE_SEPARATOR: CodePoint = 0x2028;
+pub(crate) const PARAGRAPH_SEPARATOR: CodePoint = 0x2029;
 
 #[allow(dead_code)]
-pub const MIN_CODE_POINT: CodePoint = 0x00;
-pub const MAX_CODE_POINT: CodePoint = 0x10ffff;
+pub(crate) const MIN_CODE_POINT: CodePoint = 0x00;
+pub(crate) const MAX_CODE_POINT: CodePoint = 0x10ffff;
 
-pub fn is_latin_letter(code: CodePoint) -> bool {
+pub(crate) fn is_latin_letter(code: CodePoint) -> bool {
     (LATIN_CAPITAL_LETTER_A..=LATIN_CAPITAL_LETTER_Z).contains(&code)
         || (LATIN_SMALL_LETTER_A..=LATIN_SMALL_LETTER_Z).contains(&code)
 }
 
-pub fn is_decimal_digit(code: CodePoint) -> bool {
+pub(crate) fn is_decimal_digit(code: CodePoint) -> bool {
     (DIGIT_ZERO..=DIGIT_NINE).contains(&code)
 }
 
-pub fn is_octal_digit(code: CodePoint) -> bool {
+pub(crate) fn is_octal_digit(code: CodePoint) -> bool {
     (DIGIT_ZERO..=DIGIT_SEVEN).contains(&code)
 }
 
-pub fn is_hex_digit(code: CodePoint) -> bool {
+pub(crate) fn is_hex_digit(code: CodePoint) -> bool {
     (DIGIT_ZERO..=DIGIT_NINE).contains(&code)
         || (LATIN_CAPITAL_LETTER_A..=LATIN_CAPITAL_LETTER_F).contains(&code)
         || (LATIN_SMALL_LETTER_A..=LATIN_SMALL_LETTER_F).contains(&code)
 }
 
-pub fn is_line_terminator(code: CodePoint) -> bool {
+pub(crate) fn is_line_terminator(code: CodePoint) -> bool {
     matches!(
         code,
         LINE_FEED | CARRIAGE_RETURN | LINE_SEPARATOR | PARAGRAPH_SEPARATOR
     )
 }
 
-pub fn is_valid_unicode(code: CodePoint) -> bool {
+pub(crate) fn is_valid_unicode(code: CodePoint) -> bool {
     /*code >= MIN_CODE_POINT &&*/
     code <= MAX_CODE_POINT
 }
 
-pub fn digit_to_int(code: CodePoint) -> CodePoint {
+pub(crate) fn digit_to_int(code: CodePoint) -> CodePoint {
     if (LATIN_SMALL_LETTER_A..=LATIN_SMALL_LETTER_F).contains(&code) {
         return code - LATIN_SMALL_LETTER_A + 10;
     }
@@ -127,14 +131,14 @@ pub fn digit_to_int(code: CodePoint) -> CodePoint {
     code - DIGIT_ZERO
 }
 
-pub fn is_lead_surrogate(code: CodePoint) -> bool {
+pub(crate) fn is_lead_surrogate(code: CodePoint) -> bool {
     (0xd800..=0xdbff).contains(&code)
 }
 
-pub fn is_trail_surrogate(code: CodePoint) -> bool {
+pub(crate) fn is_trail_surrogate(code: CodePoint) -> bool {
     (0xdc00..=0xdfff).contains(&code)
 }
 
-pub fn combine_surrogate_pair(lead: CodePoint, trail: CodePoint) -> CodePoint {
+pub(crate) fn combine_surrogate_pair(lead: CodePoint, trail: CodePoint) -> CodePoint {
     (lead - 0xd800) * 0x400 + (trail - 0xdc00) + 0x10000
 }
@@ -1,8 +1,18 @@
-use std::{cell::OnceCell, collections::HashSet};
+use std::{
+    cell::OnceCell,
+    collections::{HashMap, HashSet},
+};
 
 use once_cell::sync::Lazy;
 
-use crate::ecma_versions::EcmaVersion;
+use super::{
+    ids::restore_ranges,
+    property_data::{
+        BINARY_PROPERTY_RANGES, GENERAL_CATEGORY_RANGES, SCRIPT_EXTENSIONS_RANGES, SCRIPT_RANGES,
+        STRING_PROPERTY_SEQUENCES, UNICODE_VERSIONS,
+    },
+};
+use crate::{ecma_versions::EcmaVersion, CharSet, CodePoint, StringSet};
 
 #[derive(Clone, Debug)]
 struct DataSet {
@@ -13,6 +23,7 @@ struct DataSet {
     _raw2022: &'static str,
     _raw2023: &'static str,
     _raw2024: &'static str,
+    _raw2025: &'static str,
     _set2018: OnceCell<HashSet<&'static str>>,
     _set2019: OnceCell<HashSet<&'static str>>,
     _set2020: OnceCell<HashSet<&'static str>>,
@@ -20,9 +31,11 @@ struct DataSet {
     _set2022: OnceCell<HashSet<&'static str>>,
     _set2023: OnceCell<HashSet<&'static str>>,
     _set2024: OnceCell<HashSet<&'static str>>,
+    _set2025: OnceCell<HashSet<&'static str>>,
 }
 
 impl DataSet {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         raw2018: &'static str,
         raw2019: &'static str,
@@ -31,6 +44,7 @@ impl DataSet {
         raw2022: &'static str,
         raw2023: &'static str,
         raw2024: &'static str,
+        raw2025: &'static str,
     ) -> Self {
         Self {
             _raw2018: raw2018,
@@ -40,6 +54,7 @@ impl DataSet {
             _raw2022: raw2022,
             _raw2023: raw2023,
             _raw2024: raw2024,
+            _raw2025: raw2025,
             _set2018: Default::default(),
             _set2019: Default::default(),
             _set2020: Default::default(),
@@ -47,6 +62,7 @@ impl DataSet {
             _set2022: Default::default(),
             _set2023: Default::default(),
             _set2024: Default::default(),
+            _set2025: Default::default(),
         }
     }
 
@@ -84,6 +100,11 @@ impl DataSet {
         self._set2024
             .get_or_init(|| self._raw2024.split(' ').collect())
     }
+
+    pub fn es2025(&self) -> &HashSet<&'static str> {
+        self._set2025
+            .get_or_init(|| self._raw2025.split(' ').collect())
+    }
 }
 
 static GC_NAME_SET: Lazy<HashSet<&'static str>> =
@@ -105,6 +126,7 @@ thread_local! {
             "",
             "",
             "",
+            "",
         )
     });
 
@@ -117,6 +139,7 @@ thread_local! {
             "Cpmn Cypro_Minoan Old_Uyghur Ougr Tangsa Tnsa Toto Vith Vithkuqi",
             "Hrkt Katakana_Or_Hiragana Kawi Nag_Mundari Nagm Unknown Zzzz",
             "",
+            "Gara Garay Gukh Gurung_Khema Kirat_Rai Krai Onao Ol_Onal Sunu Sunuwar Todhri Todr Tulu_Tigalari Tutg",
         )
     });
 
@@ -129,6 +152,7 @@ thread_local! {
             "",
             "",
             "",
+            "",
         )
     });
 
@@ -141,6 +165,7 @@ thread_local! {
             "",
             "",
             "Basic_Emoji Emoji_Keycap_Sequence RGI_Emoji RGI_Emoji_Flag_Sequence RGI_Emoji_Modifier_Sequence RGI_Emoji_Tag_Sequence RGI_Emoji_ZWJ_Sequence",
+            "",
         )
     });
 }
@@ -158,6 +183,7 @@ pub fn is_valid_unicode_property(version: EcmaVersion, name: &str, value: &str)
                 || version >= EcmaVersion::_2021 && sc_value_sets.es2021().contains(value)
                 || version >= EcmaVersion::_2022 && sc_value_sets.es2022().contains(value)
                 || version >= EcmaVersion::_2023 && sc_value_sets.es2023().contains(value)
+                || version >= EcmaVersion::_2025 && sc_value_sets.es2025().contains(value)
         });
     }
     false
@@ -176,3 +202,138 @@ pub fn is_valid_lone_unicode_property_of_string(version: EcmaVersion, value: &st
         version >= EcmaVersion::_2024 && bin_property_of_strings_sets.es2024().contains(value)
     })
 }
+
+static GC_RANGES: Lazy<HashMap<&'static str, &'static [&'static str]>> =
+    Lazy::new(|| index_ranges(GENERAL_CATEGORY_RANGES));
+
+static SC_RANGES: Lazy<HashMap<&'static str, &'static [&'static str]>> =
+    Lazy::new(|| index_ranges(SCRIPT_RANGES));
+
+static SCX_RANGES: Lazy<HashMap<&'static str, &'static [&'static str]>> =
+    Lazy::new(|| index_ranges(SCRIPT_EXTENSIONS_RANGES));
+
+static BIN_PROPERTY_RANGES: Lazy<HashMap<&'static str, &'static [&'static str]>> =
+    Lazy::new(|| index_ranges(BINARY_PROPERTY_RANGES));
+
+fn index_ranges<const N: usize>(
+    table: &'static [(&'static str, [&'static str; N])],
+) -> HashMap<&'static str, &'static [&'static str]> {
+    table
+        .iter()
+        .flat_map(|(names, data)| names.split(' ').map(move |name| (name, &data[..])))
+        .collect()
+}
+
+fn decode_ranges(data: &str) -> CharSet {
+    if data.is_empty() {
+        return CharSet::empty();
+    }
+    CharSet::from_ranges(
+        restore_ranges(data)
+            .chunks(2)
+            .map(|range| range[0]..=range[1]),
+    )
+}
+
+/// The code points of a table entry as of the Unicode version at
+/// `version_index`: the latest version's, with the changes of each version
+/// back to that one undone
+fn decode_ranges_as_of(data: &[&str], version_index: usize) -> CharSet {
+    let (latest, changes) = data.split_last().unwrap();
+    changes[version_index..]
+        .iter()
+        .rev()
+        .fold(decode_ranges(latest), |set, changes| {
+            let changes = decode_ranges(changes);
+            set.difference(&changes).union(&changes.difference(&set))
+        })
+}
+
+/// The index into `UNICODE_VERSIONS` of the Unicode version the ECMAScript
+/// version uses
+fn unicode_version_index(version: EcmaVersion) -> Option<usize> {
+    Some(match version {
+        EcmaVersion::_5 | EcmaVersion::_2015 | EcmaVersion::_2016 | EcmaVersion::_2017 => {
+            return None
+        }
+        EcmaVersion::_2018 => 0,
+        EcmaVersion::_2019 => 1,
+        EcmaVersion::_2020 => 2,
+        EcmaVersion::_2021 => 3,
+        EcmaVersion::_2022 => 4,
+        EcmaVersion::_2023 => 5,
+        EcmaVersion::_2024 => 6,
+        EcmaVersion::_2025 => 7,
+    })
+}
+
+/// The code points with a property, as of the Unicode version the ECMAScript
+/// version uses, or `None` if it isn't a valid property for that version
+///
+/// Takes the `key` and `value` of a `CharacterSet` node, so a binary property
+/// is the `key` with no `value`
+pub fn unicode_property_char_set(
+    version: EcmaVersion,
+    key: &str,
+    value: Option<&str>,
+) -> Option<CharSet> {
+    let version_index = unicode_version_index(version)?;
+    let Some(value) = value else {
+        if !is_valid_lone_unicode_property(version, key) {
+            return None;
+        }
+        return Some(match key {
+            "Any" => CharSet::all(),
+            "ASCII" => CharSet::from_ranges([0x00..=0x7f]),
+            "Assigned" => unicode_property_char_set(version, "gc", Some("Cn"))?.complement(),
+            _ => decode_ranges_as_of(BIN_PROPERTY_RANGES.get(key)?, version_index),
+        });
+    };
+    if !is_valid_unicode_property(version, key, value) {
+        return None;
+    }
+    let ranges = match key {
+        "General_Category" | "gc" => &GC_RANGES,
+        "Script" | "sc" => &SC_RANGES,
+        _ => &SCX_RANGES,
+    };
+    Some(decode_ranges_as_of(ranges.get(value)?, version_index))
+}
+
+/// The strings with a property of strings (like `RGI_Emoji`), as of the
+/// Unicode version the ECMAScript version uses, or `None` if it isn't a valid
+/// property of strings for that version
+pub fn unicode_property_of_strings(version: EcmaVersion, name: &str) -> Option<StringSet> {
+    if !is_valid_lone_unicode_property_of_string(version, name) {
+        return None;
+    }
+    let version_index = unicode_version_index(version)?;
+    let sequences = STRING_PROPERTY_SEQUENCES
+        .iter()
+        .filter(|&&(property, _)| name == "RGI_Emoji" || name == property)
+        .map(|(_, added)| {
+            // The tables only cover the last few versions, so there's
+            // nothing to look up for an earlier one
+            let last = (version_index + added.len()).checked_sub(UNICODE_VERSIONS.len())?;
+            Some(&added[..=last])
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .filter(|data| !data.is_empty())
+        .flat_map(|data| data.split(' '))
+        .map(|sequence| {
+            sequence
+                .split('.')
+                .map(|cp| CodePoint::from_str_radix(cp, 36).unwrap())
+                .collect()
+        });
+    let mut string_set = StringSet::from_strings(sequences);
+    if name == "Basic_Emoji" || name == "RGI_Emoji" {
+        let singles = unicode_property_char_set(version, "Emoji_Presentation", None)?.difference(
+            &unicode_property_char_set(version, "Regional_Indicator", None)?,
+        );
+        string_set = string_set.union(&singles.into());
+    }
+    Some(string_set)
+}
@@ -0,0 +1,5690 @@
+// Generated by scripts/generate_unicode_data.py from the Unicode Character
+// Database files in data/unicode; don't edit by hand
+//
+// Each entry has the code points of the names for every version in
+// `UNICODE_VERSIONS`: the last string has the ones for the latest version, and
+// each of the others those whose membership differs from the next version.
+// Each string is a list of inclusive ranges encoded the same way as in
+// `ids.rs`; names with identical code points in every version share an entry
+
+pub(super) static UNICODE_VERSIONS: [&str; 8] = [
+    "10.0.0", "11.0.0", "12.1.0", "13.0.0", "14.0.0", "15.0.0", "15.1.0", "16.0.0",
+];
+
+pub(super) static GENERAL_CATEGORY_RANGES: &[(&str, [&str; 8])] = &[
+    (
+        "C Other",
+        [
+            "128 0 14 0 2v 0 em 2 5w 0 8b 0 3c 0 b2 0 3k 0 2d0 0 t4 16 3 2 2yj 2 n o 5 e gc 4 kh 0 lvg 4 1j4 0 9 1 91 1 j79 1 j 0 jc 13 9 9 cn 13 9 15 dn 2 dx 0 83 0 jg 0 6e 1n gy 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n o fns 2i 503 4 esv j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "2gn 0 en 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 2xb 0 1i 0 gg 0 xc 0 n23 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 a 4g 1 117 1d e 0 fnq 5 5 0 1c 8 2j 1 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 1 a9 1l 6 0 18s 0 qe 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 3br 0 jd 2 o9 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 3 h0x 0 2jo 15 2 2 3 1 73 r ak 0 3r 1 i3 0 6 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 b a 9 193 0 fuc 0 c 1 5c2 de 17 8 jr9 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9 xr2 6 hlv 3t6",
+            "17h 0 gj u a 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 1d 1 ho 0 jq 0 29b 0 1c 0 dw a mg0 2 1j5 1 f 1 2 0 2 4 p 2 gjy 0 am f 3k 0 1b 1 1gx a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu p 6f 5 25 0 16f 0 3r 6 oa f 441 2q bjy 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m a 37 fa 1 2l2 u oi u 10y 6 2 3 2 1 2 e 2xr 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6 xyg 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 1q7 9 s7 g 2 14 4 r 446 0 h l onx 0 z 0 6ln j 2fm 5 7a 1p y 0 u9 15 3j7 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1 116p 0 i6v 38f",
+            "9h8 3 ds 0 3tvl h9",
+            "1p3 0 3p3 1 1c 0 7e 1 1i5 2 2pn 1 nae 2 d 2 ijo 1f 1fx 11 4 s 9 1 8j 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1 ku j yl x f 9 o1 0 45i 32y 5qe 1l 2dj 1l 686 0 cg1 6x 7 c3 4kd 16 5 0 3oz 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "0 v 2o w e 0 jv 1 7 3 8 0 2 0 l 0 b2 0 13 1 1f 1 4 0 1k 7 s 3 7 g n 0 5d 0 1d 1 1o 1 2u d 1o 1 1e 1 g 0 t 1 2 0 c 4 w 7 24 0 4i 0 9 1 3 1 n 0 8 0 2 2 5 1 a 1 3 1 5 7 2 3 3 0 6 1 q 1 4 0 7 3 3 1 n 0 8 0 3 0 3 0 3 1 2 0 6 3 3 1 4 2 2 6 5 0 2 6 i 9 4 0 a 0 4 0 n 0 8 0 3 0 6 1 b 0 4 0 4 1 2 e 5 1 d 6 8 0 4 0 9 1 3 1 n 0 8 0 3 0 6 1 a 1 3 1 4 6 4 3 3 0 6 1 j 9 3 0 7 2 4 0 5 2 3 0 2 0 3 2 3 2 4 2 d 3 6 2 4 0 5 1 2 5 2 d m 4 e 0 4 0 o 0 h 1 a 0 4 0 5 6 3 0 4 1 2 1 5 1 b 6 n 0 4 0 o 0 b 0 6 1 a 0 4 0 5 6 3 5 3 0 5 1 b 0 4 b e 0 4 0 1g 0 4 0 7 3 h 1 r 0 4 0 j 2 p 0 a 0 2 1 8 2 2 3 7 0 2 0 9 5 b 1 4 b 1n 3 u 10 3 0 2 0 6 0 p 0 2 0 o 1 6 0 2 0 8 0 b 1 5 v 21 0 11 3 14 0 11 0 g 0 e 10 5j 0 2 4 2 1 ai 0 5 1 8 0 2 0 5 1 16 0 5 1 y 0 5 1 8 0 2 0 5 1 g 0 1m 0 5 1 1w 1 x 2 r 5 2f 1 7 1 im 2 2i 6 n 8 p 8 l b e 0 4 0 3 b 2n 1 b 5 b 5 f 0 c 5 2i 6 18 4 1z 9 w 0 d 3 d 3 2 2 17 1 6 a 19 3 r 5 c 2 1r 1 1u 0 u 1 c 5 b 5 f 1 w 1c 26 0 4n 7 1p 2 g 2 1r 4 18 1 c 7 18 4 ev 1 7 1 13 1 7 1 9 0 2 0 2 0 2 0 w 1 1i 0 g 0 f 1 7 0 k 1 4 0 a 0 c 4 r 4 1e f 3 1 s 0 e 2 y e y e 3x 3 ij l c k 1ed 1 x 0 9q 4 1a 0 2 4 2 1 1l 6 3 d p 8 8 0 8 0 8 0 8 0 8 0 8 0 8 0 8 0 3j x r 0 2i b 5z p 29 0 2f 1 2w 4 18 0 2n 0 2f 8 1d 0 mlq 2 1k 8 9p j 55 7 5r 1 3 0 2 0 9 k 1o 2 b 5 1l 7 1z 7 d 5 39 a v 2 27 0 c 3 y 0 1k 8 f 1 b 1 2w n t 9 7 1 7 1 7 8 8 0 8 0 1p 3 3j 1 b 5 8md b o 3 1e 6ir a7 1 2z 11 8 b 6 4 r 0 6 0 2 0 3 0 3 0 3i f ce 1 1j 6 2 v 17 5 1g 0 k 0 5 3 6 0 3s 3 5b 2 7 1 7 1 7 1 4 2 8 0 8 c 3 1 d 0 r 0 k 0 3 0 g 1 f x 3g 4 4 3 1a 2 2h 0 e 2 2 1a 1b 3l u 2 1e e t 3 11 8 v 4 18 4 v 0 12 3 f 15 4f 1 b 5 11 3 11 3 15 7 1h a d 0 g 0 8 0 3 0 c 0 g 0 8 0 3 2 1h b 8o 8 n 9 9 n 7 0 17 0 a 1w 7 1 2 0 19 0 3 2 2 1 o 0 21 7 a 1b k 0 3 4 y 2 s 4 2 1r 1l 3 l 1 1f 0 3 4 9 0 4 0 u 1 4 3 b 6 a 6 1t v 14 3 d 8 1j 2 u 1 s 4 r 6 5 b 8 27 22 1i 1g c 1g 6 1b 7 b 5 13 2 u 7 3 5r w 0 17 0 4 1 3 f 4 1i 19 7 17 l r 11 t j o 8 27 3 11 8 1r 0 6 c q 6 b 5 1i 0 j 7 14 8 2p 0 l a j 0 1c 1p 8 0 2 0 5 0 g 0 c 5 1o 4 b 5 5 0 9 1 3 1 n 0 8 0 3 0 6 0 b 1 3 1 4 1 2 5 2 4 8 1 8 2 6 a b 0 2 1 2 0 13 0 b 0 2 1 2 0 5 0 b 0 3 7 3 s 2l 0 6 t 21 7 b 4l 1j 1 13 x 1y a b 5 e i 1n 5 b 5 l r s 1 g 3 o 54 1p 2r 2c b 9 1 2 1 9 0 3 0 v 0 3 1 d 8 b 1x 9 1 1b 1 c q 21 7 2c c 22 6 b 51 z d b 5 a 0 1a 0 f 9 u 2 x 1 n 0 f 20 8 0 3 0 19 2 2 0 3 0 a 7 b 5 7 0 3 0 12 0 3 0 7 6 b 8l q 6 i 0 16 2 u 2c 2 e 1f c po 2t 34 0 6 a 5h 217 2s c tt f n 9 330 4 g8 5a0 1n 1c5 fu 6 w 0 b 3 2a 0 b 5 v 1 7 9 1z 9 b 0 8 0 m 4 k bz 1n 5h 2k 2s 24 3 1m 6 i 1r 6 a 3 d 4qh 7 yf 14 b 6w6 5 0 8 0 3 0 84 e 2 s 4 1 2 d 5 7 b1 1s3 30 4 e 2 a 6 b 1 5 31b 6z 5 c5 23 1b 1 o 8 39 1n 6v 9 14 1 23 7 35 k 1z 3d l b l b 2g 8 q 3q 2e 0 20 0 3 1 2 1 3 1 5 0 d 0 2 0 8 0 1u 0 5 1 9 0 8 0 t 0 5 0 6 0 2 2 8 0 9h 1 85 1 jj e 6 0 g un w 5 7 5w 8 0 i 1 8 0 3 0 6 4 1r w 2 33 1a 2 f 1 b 3 3 8v w g 1n 4 2 cv 17 5x 18 3 2 db 8 0 5 0 3 0 g 0 5i 1 h 14 25 3 b 3 3 ls 1x 23 1q 5d 5 0 s 0 3 0 2 1 2 0 b 0 5 0 2 0 2 5 2 3 2 0 2 0 2 0 4 0 3 0 2 1 2 0 2 0 2 0 2 0 2 0 3 0 2 1 5 0 8 0 5 0 5 0 2 0 b 0 i 4 4 0 6 0 i 1f 3 7h 19 3 2t b g 1 g 0 g 0 12 9 4v 1j u c 19 3 a 6 3 d 7 49 rd 3 i 2 e 2 3c 3 2o 5 d 3 2 e d 3 1l 7 b 5 15 7 v 1 d 3 3 1p 9h b f 1 e 2 b 4 1l 6 g 1 c 5 a 6 44 0 2v sl wyp v 37f 5 67 1 4g3 d 5rm e hb 1wh f3 15t 3t8 4 38h f9e7 6p 47bj",
+        ],
+    ),
+    (
+        "Cased_Letter LC",
+        [
+            "128 0 14 0 288 16 3 2 2a9 16 3 2 rg0 0 9 1 137r 1r",
+            "x4q 5 3 4 ps 1",
+            "x53 3 17 1 oi 0",
+            "8q7 0 1c 0 odd 1 f 1 2 0 2 4 ihj a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 16x0 9 2 j",
+            "2mn9 5",
+            "",
+            "5mx 1 ri9 2 d 2 k1g l b l",
+            "1t p 7 p 1n 0 b m 2 u 2 5e 2 3 5 5r 2 q 5d 3 3 1 4 2 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 a 14 26w 11 2 0 6 0 3 16 3 2 ip 2d 3 5 1oj a 6 16 3 2 1t 17 1s c 2 x 2u 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 7a 0 5 0 3 9 2 0 4 4 7 0 2 0 2 0 2 3 2 5 5 0 3 3 6 4 5 0 1h 1 22k 3f 3 2u 7 3 4 1 d 11 2 0 6 0 nwz 19 j r 3r 25 2 m 4 3 2 1p 3 1 2 0 2 7 p 1 4 0 mu 16 6 8 8 27 fnl 6 d 4 sq p 7 p x2 27 2p z 5 z 39 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 1c4 1e e 1e 2m l b l 26z 1r gv5 1r k1t 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 9 2 j 7 5 1xy 1v",
+        ],
+    ),
+    (
+        "Cc Control cntrl",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "0 v 2o w",
+        ],
+    ),
+    (
+        "Cf Format",
+        [
+            "1hvx 0",
+            "1ovk 8",
+            "",
+            "1ow 1",
+            "1ovt 6",
+            "",
+            "",
+            "4t 0 11v 5 n 0 5d 0 1e 0 ap 1 29 0 2zw 0 1kt 4 r 4 1e 4 2 9 17yo 0 6y 2 3b6 0 g 0 6zn f qxt 3 440 7 h407 0 v 2n",
+        ],
+    ),
+    (
+        "Close_Punctuation Pe",
+        [
+            "",
+            "",
+            "",
+            "95i 0 2 0 2 0 2 0",
+            "",
+            "",
+            "",
+            "15 0 1g 0 w 0 2wu 0 2 0 1gf 0 1wq 0 1k 0 g 0 hn 0 2 0 v 0 u7 0 2 0 2 0 2 0 2 0 2 0 2 0 29 0 x 0 2 0 2 0 2 0 2 0 b9 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1t 0 2 0 y 0 ti 0 2 0 2 0 2 0 19 0 2 0 2 0 2 0 bx 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 3 1 14in 0 62 0 u 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 i 0 2 0 2 0 4r 0 1g 0 w 0 3 0 3 0",
+        ],
+    ),
+    (
+        "Cn Unassigned",
+        [
+            "128 0 14 0 2v 0 em 2 5w 0 8b 0 3c 0 b2 0 3k 0 2d0 0 t4 16 3 2 2yj 2 n o 5 e gc 4 kh 0 lvg 4 1j4 0 9 1 91 1 j79 1 j 0 jc 13 9 9 cn 13 9 15 ac 0 3b 2 dx 0 83 0 jg 0 6e 1n gy 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n o fns 2i 503 4 esv j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "2gn 0 en 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 2xb 0 1i 0 gg 0 xc 0 n23 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 a 4g 1 117 1d e 0 3zl 8 bnx 5 5 0 1c 8 2j 1 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 1 a9 1l 6 0 18s 0 qe 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 3br 0 jd 2 o9 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 3 h0x 0 2jo 15 2 2 3 1 73 r ak 0 3r 1 i3 0 6 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 b a 9 193 0 fuc 0 c 1 5c2 de 17 8 jr9 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9 xr2 6 hlv 3t6",
+            "17h 0 gj u 2 1 7 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 1d 1 ho 0 jq 0 29b 0 1c 0 dw a mg0 2 1j5 1 f 1 2 0 2 4 p 2 gjy 0 am f 3k 0 1b 1 1gx a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu p 6f 5 25 0 16f 0 3r 6 oa f 441 2q bjy 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m a 37 fa 1 2l2 u oi u 10y 6 2 3 2 1 2 e 2xr 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6 xyg 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 1q7 9 s7 g 2 14 4 r 446 0 a s onx 0 z 0 6ln j 2fm 5 7a 1p y 0 u9 15 3j7 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1 116p 0 i6v 38f",
+            "9h8 3 ds 0 3tvl h9",
+            "1p3 0 3p3 1 1c 0 7e 1 1i5 2 2pn 1 nae 2 d 2 ijo 1f 1fx 11 4 s 9 1 8j 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1 ku j yl x f 9 o1 0 45i 32y 5qe 1l 2dj 1l 686 0 cg1 6x 7 c3 4kd 16 5 0 3oz 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "oo 1 7 3 8 0 2 0 l 0 b2 0 13 1 1f 1 4 0 1k 7 s 3 7 a 7j 0 1p 1 2u d 1o 1 1e 1 g 0 t 1 2 0 c 4 w 0 3 4 6m 0 9 1 3 1 n 0 8 0 2 2 5 1 a 1 3 1 5 7 2 3 3 0 6 1 q 1 4 0 7 3 3 1 n 0 8 0 3 0 3 0 3 1 2 0 6 3 3 1 4 2 2 6 5 0 2 6 i 9 4 0 a 0 4 0 n 0 8 0 3 0 6 1 b 0 4 0 4 1 2 e 5 1 d 6 8 0 4 0 9 1 3 1 n 0 8 0 3 0 6 1 a 1 3 1 4 6 4 3 3 0 6 1 j 9 3 0 7 2 4 0 5 2 3 0 2 0 3 2 3 2 4 2 d 3 6 2 4 0 5 1 2 5 2 d m 4 e 0 4 0 o 0 h 1 a 0 4 0 5 6 3 0 4 1 2 1 5 1 b 6 n 0 4 0 o 0 b 0 6 1 a 0 4 0 5 6 3 5 3 0 5 1 b 0 4 b e 0 4 0 1g 0 4 0 7 3 h 1 r 0 4 0 j 2 p 0 a 0 2 1 8 2 2 3 7 0 2 0 9 5 b 1 4 b 1n 3 u 10 3 0 2 0 6 0 p 0 2 0 o 1 6 0 2 0 8 0 b 1 5 v 21 0 11 3 14 0 11 0 g 0 e 10 5j 0 2 4 2 1 ai 0 5 1 8 0 2 0 5 1 16 0 5 1 y 0 5 1 8 0 2 0 5 1 g 0 1m 0 5 1 1w 1 x 2 r 5 2f 1 7 1 im 2 2i 6 n 8 p 8 l b e 0 4 0 3 b 2n 1 b 5 b 5 r 5 2i 6 18 4 1z 9 w 0 d 3 d 3 2 2 17 1 6 a 19 3 r 5 c 2 1r 1 1u 0 u 1 c 5 b 5 f 1 w 1c 26 0 4n 7 1p 2 g 2 1r 4 18 1 c 7 18 4 ev 1 7 1 13 1 7 1 9 0 2 0 2 0 2 0 w 1 1i 0 g 0 f 1 7 0 k 1 4 0 a 0 2u 0 d 1 s 0 e 2 y e y e 3x 3 ij l c k 1ed 1 x 0 9q 4 1a 0 2 4 2 1 1l 6 3 d p 8 8 0 8 0 8 0 8 0 8 0 8 0 8 0 8 0 3j x r 0 2i b 5z p 29 0 2f 1 2w 4 18 0 2n 0 2f 8 1d 0 mlq 2 1k 8 9p j 55 7 5r 1 3 0 2 0 9 k 1o 2 b 5 1l 7 1z 7 d 5 39 a v 2 27 0 c 3 y 0 1k 8 f 1 b 1 2w n t 9 7 1 7 1 7 8 8 0 8 0 1p 3 3j 1 b 5 8md b o 3 1e 3 6sv 1 2z 11 8 b 6 4 r 0 6 0 2 0 3 0 3 0 3i f ce 1 1j 6 2 v 17 5 1g 0 k 0 5 3 6 0 3s 1 2 0 5b 2 7 1 7 1 7 1 4 2 8 0 8 9 6 1 d 0 r 0 k 0 3 0 g 1 f x 3g 4 4 3 1a 2 2h 0 e 2 2 1a 1b 3l u 2 1e e t 3 11 8 v 4 18 4 v 0 12 3 f 15 4f 1 b 5 11 3 11 3 15 7 1h a d 0 g 0 8 0 3 0 c 0 g 0 8 0 3 2 1h b 8o 8 n 9 9 n 7 0 17 0 a 1w 7 1 2 0 19 0 3 2 2 1 o 0 21 7 a 1b k 0 3 4 y 2 s 4 2 1r 1l 3 l 1 1f 0 3 4 9 0 4 0 u 1 4 3 b 6 a 6 1t v 14 3 d 8 1j 2 u 1 s 4 r 6 5 b 8 27 22 1i 1g c 1g 6 1b 7 b 5 13 2 u 7 3 5r w 0 17 0 4 1 3 f 4 1i 19 7 17 l r 11 t j o 8 27 3 11 8 1x 9 2 1 q 6 b 5 1i 0 j 7 14 8 2p 0 l a j 0 1c 1p 8 0 2 0 5 0 g 0 c 5 1o 4 b 5 5 0 9 1 3 1 n 0 8 0 3 0 6 0 b 1 3 1 4 1 2 5 2 4 8 1 8 2 6 a b 0 2 1 2 0 13 0 b 0 2 1 2 0 5 0 b 0 3 7 3 s 2l 0 6 t 21 7 b 4l 1j 1 13 x 1y a b 5 e i 1n 5 b 5 l r s 1 g 3 o 54 1p 2r 2c b 9 1 2 1 9 0 3 0 v 0 3 1 d 8 b 1x 9 1 1b 1 c q 21 7 2c c 22 6 b 51 z d b 5 a 0 1a 0 f 9 u 2 x 1 n 0 f 20 8 0 3 0 19 2 2 0 3 0 a 7 b 5 7 0 3 0 12 0 3 0 7 6 b 8l q 6 i 0 16 2 u 2c 2 e 1f c po 2t 34 0 6 a 5h 217 2s c uv 9 330 4 g8 5a0 1n 1c5 fu 6 w 0 b 3 2a 0 b 5 v 1 7 9 1z 9 b 0 8 0 m 4 k bz 1n 5h 2k 2s 24 3 1m 6 i 1r 6 a 3 d 4qh 7 yf 14 b 6w6 5 0 8 0 3 0 84 e 2 s 4 1 2 d 5 7 b1 1s3 30 4 e 2 a 6 b 1 9 317 6z 5 c5 23 1b 1 o 8 39 1n 6v 9 14 1 5f k 1z 3d l b l b 2g 8 q 3q 2e 0 20 0 3 1 2 1 3 1 5 0 d 0 2 0 8 0 1u 0 5 1 9 0 8 0 t 0 5 0 6 0 2 2 8 0 9h 1 85 1 jj e 6 0 g un w 5 7 5w 8 0 i 1 8 0 3 0 6 4 1r w 2 33 1a 2 f 1 b 3 3 8v w g 1n 4 2 cv 17 5x 18 3 2 db 8 0 5 0 3 0 g 0 5i 1 h 14 25 3 b 3 3 ls 1x 23 1q 5d 5 0 s 0 3 0 2 1 2 0 b 0 5 0 2 0 2 5 2 3 2 0 2 0 2 0 4 0 3 0 2 1 2 0 2 0 2 0 2 0 2 0 3 0 2 1 5 0 8 0 5 0 5 0 2 0 b 0 i 4 4 0 6 0 i 1f 3 7h 19 3 2t b g 1 g 0 g 0 12 9 4v 1j u c 19 3 a 6 3 d 7 49 rd 3 i 2 e 2 3c 3 2o 5 d 3 2 e d 3 1l 7 b 5 15 7 v 1 d 3 3 1p 9h b f 1 e 2 b 4 1l 6 g 1 c 5 a 6 44 0 2v sl wyp v 37f 5 67 1 4g3 d 5rm e hb 1wh f3 15t 3t8 4 38h f974 2 t 2p 3j 6p 1e6n 1ekf 1 1ekf 1",
+        ],
+    ),
+    (
+        "Co Private_Use",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "188w 4xr jpc1 1ekd 3 1ekd",
+        ],
+    ),
+    (
+        "Combining_Mark M Mark",
+        [
+            "1kt 0 5y 0 8b 0 ee 0 v0b 0 js5 3 f3 a dx 1 3n 0 aa 0 83 0 r2 e 11s 4 2 1 2 4 9o 3",
+            "2wq 0 2t4 1 1dy6 6 3 6 4 0 gvf 0 1c 8 mg9 6 c6 3",
+            "28l 0 fg 0 2m6 1 rxo 0 k8v 1 ma 1 1gh 5 2 1 3 3 2 0 2 1 h41 0 c 1",
+            "1p4 7 17 8 oa 0 255 0 6y 0 j6 d mk 0 1bpk 3 6j 0 3 1 26 0 11ku 19 3 m 3u0 0",
+            "2k3 0 d7 0 1elr 2 n6 0 2in 1 2 0 1d 6 4 4 45a 0 7 e y0q 0 v1 3",
+            "",
+            "1p3 0 1fiq 4 b3 0 xo 8 2 0 3 0 2 3 2 4 2 0 f 1 29k 0 czo h q7z 1",
+            "lc 33 7o 6 7c 18 2 0 2 1 2 1 2 0 21 a 1d k h 0 2u 6 3 5 3 1 2 3 10 0 v q 2k a 1n 8 a 0 p 3 2 8 2 2 2 4 18 2 1o 8 17 n 2 w 1j 2 2 h 2 6 b 1 u 2 1l 0 2 6 3 1 3 2 a 0 b 1 r 0 3 2 1l 0 2 4 5 1 3 2 4 0 v 1 4 0 c 2 1l 0 2 7 2 2 2 2 l 1 n 5 2 2 1l 0 2 6 3 1 3 2 8 2 b 1 v 0 1o 4 4 2 2 3 a 0 15 4 1k 0 2 6 2 2 2 3 8 1 c 1 u 2 1l 0 2 6 2 2 2 3 8 1 c 1 g 0 d 3 1k 1 2 6 2 2 2 3 a 0 b 1 u 2 1z 0 5 5 2 0 2 7 j 1 1q 0 3 6 d 7 2r 0 3 8 c 6 22 1 s 0 2 0 2 0 5 1 1e j 2 1 6 a 2 z a 0 2t j o 3 5 2 2 2 3 6 4 3 e b 2 0 b 3 jk 2 qb 3 t 2 u 1 v 1 1t v a 0 1a 2 2 0 3a 1 z 0 3b b 5 b 64 4 1m 9 2 s 3 0 1d u 1e 4 1c g 13 8 d 2 v c 1l d 1d j 49 2 2 k 5 0 7 0 3 2 5j 1r k1 w 2db 2 3y 0 2p v ff 5 2y 1 n9x 3 2 9 x 1 29 1 7l 0 4 0 5 0 o 4 5 0 2c 1 1f h r h e 0 13 7 q c 19 3 1c d 11 0 1w d d 0 9 1 1a 2 1f 0 2 2 3 1 6 1 2 0 16 4 6 1 6l 7 2 1 fn5 0 ki f h f r2 0 6b 0 46 4 1af 2 2 1 6 3 15 2 5 0 4m 1 fy 3 1u 4 8u 1 28 3 1z a 1e 3 3f 2 1i e 16 0 3 1 b 3 1a a 8 0 1q 2 11 d h 1 19 0 d 2 1d d 9 3 2 1 2l b 7 0 3 0 4e b m 3 1k 1 2 6 3 1 3 2 a 0 b 1 3 6 4 4 1w 8 2 0 3 0 2 3 2 4 2 0 f 1 2b h o 0 2a j 6k 6 3 8 s 1 2b g 2z c 2u e 75 e 6u 5 2 1 3 3 2 0 2 1 3y 6 3 6 4 0 t 9 15 6 2 3 9 0 a a 1b f ba 7 2 7 2b l 2 d 3f 5 4 0 2 1 2 6 2 0 1v 4 2 1 2 4 9o 3 a 1 2 0 1d 6 4 4 o 0 44m 0 7 e 8uh h 1xd 4 1o 6 t5 0 2 1i 8 3 2a 0 c 1 f58 1 3mq 19 3 m f3 4 4 5 9 7 3 6 v 3 45 2 1j0 1i 5 1d 9 0 f 0 n 4 2 e 11t 6 2 g 3 6 2 1 2 4 2t 0 4h 6 ag 0 1q 3 e5 3 73 1 kh 6 32 6 gzhy 6n",
+        ],
+    ),
+    (
+        "Connector_Punctuation Pc",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2n 0 6ao 1 k 0 17tr 1 p 2 6o 0",
+        ],
+    ),
+    (
+        "Cs Surrogate",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "16o0 1kv",
+        ],
+    ),
+    (
+        "Currency_Symbol Sc",
+        [
+            "1ku 1 2nqp 0",
+            "1kv1 3 12jj 0",
+            "",
+            "6gw 0",
+            "",
+            "",
+            "",
+            "10 0 3i 3 yy 0 3g 0 dv 1 dv 1 8 0 6u 0 7c 0 g6 0 1wc 0 1qd w qrc 0 gxw 0 31 0 4b 0 64 1 4 1 6bb 3 12jj 0 1wx 0",
+        ],
+    ),
+    (
+        "Dash_Punctuation Pd",
+        [
+            "",
+            "",
+            "1hgt 0",
+            "95p 0",
+            "",
+            "",
+            "1h7y 0",
+            "19 0 125 0 1g 0 2te 0 sm 0 1l6 5 2rm 0 3 0 w 1 5 0 t 0 cf 0 k 0 34 0 14lt 1 12 0 b 0 4q 0 2u9 0 8v 0",
+        ],
+    ),
+    (
+        "Decimal_Number Nd digit",
+        [
+            "1h68 9 38n 9",
+            "2n28 9 br 9",
+            "1jkg 9 18rb 9",
+            "1znk 9",
+            "1kr4 9 1313 9",
+            "",
+            "1h6o 9 1vr j zx 9 do7 9 2ev 9 iuv 9 4xk 9",
+            "1c 9 17r 9 3r 9 5j 9 bh 9 3b 9 3b 9 3b 9 3b 9 3b 9 3b 9 3b 9 3b 9 3b 9 2p 9 3b 9 1z 9 7r 9 1z 9 1fr 9 13 9 8d 9 3l 9 4n 9 7 9 53 9 2f 9 3r 9 7 9 r7r 9 iv 9 13 9 5j 9 n 9 2f 9 bb 9 gev 9 13b 9 1on 9 7 9 m5 9 3l 9 1p 9 41 9 7r 9 9j 9 3b 9 af 9 2v 9 7 j 25 9 br 9 2v 9 if 9 2f 9 6v 9 1z 9 br 9 d07 9 1t3 9 2f 9 3r 9 ev 9 iuv 9 251 1d 1tt 9 br 9 dz 9 6w 9 nq 9 3o7 9",
+        ],
+    ),
+    (
+        "Enclosing_Mark Me",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "w8 1 4dx 0 17j 3 2 2 qdo 2",
+        ],
+    ),
+    (
+        "Final_Punctuation Pf",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "57 0 672 0 4 0 t 0 2q1 0 2 0 5 0 3 0 g 0 4 0",
+        ],
+    ),
+    (
+        "Initial_Punctuation Pi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4r 0 67h 0 3 1 3 0 q 0 2q1 0 2 0 5 0 3 0 g 0 4 0",
+        ],
+    ),
+    (
+        "L Letter",
+        [
+            "128 0 14 0 2v 0 3nt 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 0 j7a 1 jv z d9 s b 0 9 l e7 0 15i 0 6e 17 he 0 jn 5 2 1 2 v f 0 94 i fny 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 2ti 1 7 0 reo 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 12 h 0 2 0 4h 1 gqo 5 49 0 4r3 5 861 2 i 3 9eh 18 b 6 h 0 aa 17 19c 0",
+            "1q6 9 u5 0 793 4 5iv 9 g8h c 1jf 3 17 1 oi 1 jkn 15 7 1 73 k ar 0 m1 1 wv 6 3 0 3 7 2 1 2 n g 0 2 0 19r 0 l6r de 17 8 1jnz 6 hlv 3t6",
+            "1o0 n 2 5 13 0 j 1 pg 0 3k 0 20g 0 i 0 tp 0 3c3 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu h 6o 1 3 0 1cb 6 oa f 441 2o bk0 26 do2 3 2 6 2 1 81 3 926 u oi t 10z 6 2 3 2 1 2 e 11pc 1 386 3",
+            "1i67 1 2iq 0 2 c 2 x 458 0 i 5 ooc 0 z 0 91s 5 7a 1p v7 r 15ji 0 i6v 38f",
+            "43qo h9",
+            "5mx 1 ri9 2 d 2 ijo 1f 1g7 r a m 8t 2 xo 9 2 0 3 0 2 11 2 0 q 0 2 0 1kd w 4u8 32y 5qe t 2eb 18 68j 0 hjl t 3 0",
+            "1t p 7 p 1c 0 b 0 5 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 3m 4 2 1 3 3 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 3 0 7 14 20 q 5 3 1a 16 10 1 2 2q 2 0 g 1 8 1 b 2 3 0 h 0 2 t u 2g c 0 p w a 1 5 0 6 l 5 0 a 0 4 0 o o 8 a 6 n 2 5 i 15 1n 1h 4 0 j 0 8 9 g f 5 7 3 1 3 l 2 6 2 0 4 3 4 0 h 0 e 1 2 2 f 1 b 0 9 5 5 1 3 l 2 6 2 1 2 1 2 1 w 3 2 0 k 2 h 8 2 2 2 l 2 6 2 1 2 4 4 0 j 0 g 1 o 0 c 7 3 1 3 l 2 6 2 1 2 4 4 0 v 1 2 2 g 0 i 0 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b n 0 1h 7 2 2 2 m 2 f 4 0 r 2 3 0 3 1 v 0 5 7 2 2 2 m 2 9 2 4 4 0 w 1 2 1 g 1 i 8 2 2 2 14 3 0 h 0 6 2 9 2 p 5 6 h 4 n 2 8 2 0 3 6 1n 1b 2 1 d 6 1n 1 2 0 2 4 2 n 2 0 2 9 2 1 a 0 3 4 2 0 m 3 x 0 1s 7 2 z s 4 38 16 l 0 h 5 5 3 4 0 4 1 8 2 5 c d 0 i 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 12 f h 2d 3 5 4 h7 3 g 2 p 6 22 7 7 8 h e i f h f c 2 2 g 1f 10 0 5 0 1w 2g 8 4 3 x 2 0 6 1x b u 1e t 3 4 c 17 5 p 1j m a 1g 2b 0 2m 1a i 7 1j t e 1 b 17 r z 16 2 b z 3 a 6 16 3 2 16 3 2 5 2 1 4 0 6 5b 1t 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 39 0 e 0 h c 2u 0 5 0 3 9 2 0 4 4 7 0 2 0 2 0 2 3 2 a 3 3 6 4 5 0 1h 1 22k 6c 7 3 4 1 d 11 2 0 6 0 3 1j 8 0 h m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 29 0 d2 1 17 4 6 1 5 2d 7 2 2 2h 2 3 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 f b 1 l 1a h u 3 1x 1e 8 3 2u 3 1u 3 1 2 0 2 7 m f 2 2 2 3 2 m u 1f f 1d 1r 5 4 0 2 1 c r b m q s 8 1a t 0 h 4 2 9 b 4 2 14 o 2 2 7 l m 4 0 4 1d 2 0 4 1 3 4 3 0 2 0 p 2 3 a 8 2 d 5 3 5 3 5 a 6 2 6 2 16 2 d 7 36 u 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 0 2 9 2 c 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 b 39 4 2 3q 11 p 7 p c 2g 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e au s 4 1c 1c v e j 2 7 7 11 b t 3 z 5 7 1d 4d j z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 0 g 3 2 2 2 s 17 s 4 s 10 7 2 r s 1h b l b i e h 33 20 1k 1e e 1e e z 13 r a m 6z 15 7 1 h 2 1o s b 0 9 l 17 h 1b k s m d 1g 1m 1 3 0 e 18 x o r z u 0 3 0 9 y 4 0 d 1b f 3 m 0 2 0 10 h 2 o k 1 1s 6 2 0 2 3 2 e 2 9 8 1a 13 7 3 1 3 l 2 6 2 1 2 4 4 0 j 0 d 4 v 9 2 0 3 0 2 11 2 0 q 0 2 0 19 1g j 3 l 2 v 1b l 1 2 0 55 1a 16 3 11 1b l 0 1o 16 e 0 20 q 12 6 56 17 39 1r w 7 3 0 3 7 2 1 2 n g 0 2 0 2n 7 3 12 h 0 2 0 t 0 b 13 8 0 m 0 c 19 k 0 j 20 5k w w 8 2 10 i 0 1e t 35 6 2 1 2 11 m 0 q 5 2 1 2 v f 0 94 i g 0 2 c 2 x 3h 0 28 pl 6f 5f 219 2o g tr i 5 q 32y 6 g6 5a2 t 1cz fs 8 u i 26 i t j 1b h 3 w k 6 i c1 18 5w 1r 3l 22 6 0 1v c 1t 1 2 0 t 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 4mf 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 u 7 5 7a 1p 43 18 b 6 h 0 8y t j 17 dh r 6d t 3 0 ds 6 2 3 2 1 2 e 2 5g 1o 1v 8 0 xh 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 3et wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Letter_Number Nl",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4j2 2 228 y 3 3 2v3 0 q 8 f 2 nfw 9 hu9 1g ct 0 9 0 3r 4 6cr 32",
+        ],
+    ),
+    (
+        "Line_Separator Zl",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6co 0",
+        ],
+    ),
+    (
+        "Ll Lowercase_Letter",
+        [
+            "128 0 14 0 288 16 3 2 trk 0 a 0 138n v",
+            "x4r 0 2 0 2 0 4 0 pv 1",
+            "x54 0 2 0 18 0 oi 0",
+            "8rj 0 ode 0 g 0 2 0 2 0 2 0 2 0 iim a 2 e 2 6 2 1 16x0 9 2 j",
+            "2mn9 5",
+            "",
+            "5my 0 rib 0 e 0 k2d l",
+            "2p p 1n 0 16 n 2 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 2 3 0 2 0 3 0 4 1 5 0 3 0 4 2 3 0 3 0 2 0 2 0 3 0 2 1 2 0 3 0 4 0 2 0 3 1 3 2 7 0 3 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 6 3 0 3 1 2 0 5 0 2 0 2 0 2 0 2 1w 2 q 5e 0 2 0 4 0 4 2 j 0 s y 2 1 4 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 0 3 0 3 1 1g 1b 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1d 14 288 16 3 2 l5 5 1oj 8 2 0 3a 17 1s c 2 x 2v 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 9 5 b 7 9 7 9 5 b 7 9 7 9 d 3 7 9 7 9 7 9 4 2 1 7 0 4 2 2 1 9 3 3 1 9 7 b 2 2 1 7n 0 4 1 4 0 s 0 5 0 5 0 3 1 9 3 5 0 1i 0 23w 1b 2 0 4 1 2 0 2 0 2 0 5 0 2 1 2 5 6 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 8 0 2 0 5 0 d 11 2 0 6 0 nx0 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 7 2 0 2 0 3 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 0 6 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 4 0 2 0 2 0 2 0 2 0 2 0 r 0 4 0 mu 16 6 8 8 27 fnl 6 d 4 tm p y6 13 3t z 4c a 2 e 2 6 2 1 1dw 1e 3i l 27v v gw1 v k2j p r 6 2 h r p r 3 2 0 2 6 2 a r p r p r p r p r p r p r p r p r r t o 2 5 r o 2 5 r o 2 5 r o 2 5 r o 2 5 2 0 1f9 9 2 j 7 5 1yw x",
+        ],
+    ),
+    (
+        "Lm Modifier_Letter",
+        [
+            "",
+            "20o3 0 mdw 6 1la 0",
+            "xux 0",
+            "1qh 0 vft 2 ivg 5 2 15 2 8 x86 3 2 6 2 1",
+            "2muo 1p vy 0",
+            "",
+            "1h72 0 x 0 ixd 2 15 1",
+            "j4 h 5 b f 4 8 0 2 0 3q 0 6 0 db 0 6f 0 4l 1 7i 1 5 0 w 0 a 0 4 0 4h 0 4o 0 yd 0 3k 0 fq 0 1cr 0 30 0 h0 0 cx 5 4v 1q e 0 z 10 j6 0 e 0 h c 2cg 1 6q 0 5c 0 d2 0 18 4 6 0 2q 1 2m 2 lxz 0 yr 5 7j 0 37 0 t 1 3e 8 29 0 o 0 2y 2 4 1 d2 0 n 0 3u 0 31 0 m 1 2w 3 a 0 glj 0 1a 1 1k1 5 2 15 2 8 13o 0 x 0 ij5 3 e5 2 15 1 fb c 1t 1 2 0 cnh 3 2 6 2 1 9iq 1p 5m 6 q6 0 v4 0",
+        ],
+    ),
+    (
+        "Lo Other_Letter",
+        [
+            "167 0 25d 16 3 2 1h5 0 4vr 0 lvg 4 1sf 0 j7a 1 jv z d9 s b 0 9 l e7 0 15i 0 6e 17 he 0 jn 5 2 1 2 v f 0 94 i kqj 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 2ti 1 7 0 1bza m vd 0 gp 0 ko 7 3 12 h 0 2 0 4h 1 gqo 5 4vc 5 861 2 i 3 9eh 18 y 0 aa 17",
+            "1q6 9 u5 0 793 4 5iv 9 g8h c ltw 15 7 1 73 k ar 0 m1 1 wv 6 3 0 3 7 2 1 2 n g 0 2 0 19r 0 l6r de 17 8 1jnz 6 hlv 3t6",
+            "1o0 n 2 5 13 0 j 0 ph 0 3k 0 20g 0 i 0 tp 0 q7l 2 m0h h 6o 1 3 0 1cb 6 oa f 441 2o bk0 26 dwh 3 92g 0 p2 t 10z 6 2 3 2 1 2 e 11pc 1 386 3",
+            "1i67 1 2iq 0 2 c 2 x 458 0 i 5 ooc 0 z 0 a63 q 15jj 0 i6v 38f",
+            "43qo h9",
+            "1fpc 1f 1g7 3 2 0 ab 2 xo 9 2 0 3 0 2 11 2 0 q 0 2 0 1kd w 4u8 32y 5qe t 2ee 13 68l 0 hjl t 3 0",
+            "4q 0 g 0 75 0 5 3 5t 0 n0 q 5 3 1a v 2 9 10 1 2 2q 2 0 p 1 b 2 3 0 h 0 2 t u 2g c 0 p w m l 17 o 8 a 6 n 2 5 i 14 1o 1h 4 0 j 0 8 9 h e 5 7 3 1 3 l 2 6 2 0 4 3 4 0 h 0 e 1 2 2 f 1 b 0 9 5 5 1 3 l 2 6 2 1 2 1 2 1 w 3 2 0 k 2 h 8 2 2 2 l 2 6 2 1 2 4 4 0 j 0 g 1 o 0 c 7 3 1 3 l 2 6 2 1 2 4 4 0 v 1 2 2 g 0 i 0 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b n 0 1h 7 2 2 2 m 2 f 4 0 r 2 3 0 3 1 v 0 5 7 2 2 2 m 2 9 2 4 4 0 w 1 2 1 g 1 i 8 2 2 2 14 3 0 h 0 6 2 9 2 p 5 6 h 4 n 2 8 2 0 3 6 1n 1b 2 1 d 5 1o 1 2 0 2 4 2 n 2 0 2 9 2 1 a 0 3 4 o 3 x 0 1s 7 2 z s 4 38 16 l 0 h 5 5 3 4 0 4 1 8 2 5 c d 0 36 94 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 12 f 36 h7 3 g 2 p 6 22 7 7 8 h e i f h f c 2 2 g 1f 15 0 1w y 2 1g 8 4 3 x 2 0 6 1x b u 1e t 3 4 c 17 5 p 1j m a 1g 4x 1a i 7 1j t e 1 b 17 r z 16 2 b t 36 3 2 5 2 1 4 0 u3 3 2d4 1j p m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 fc 0 1i 0 5 2d 9 0 2 2h 5 0 6 16 2 2l i v 1d f e9 533 1t g7o 2 vq 1w 13 9 7f 5 f b 1 1v 0 1e 1x 4q 0 2w 0 4 6 2 2 2 3 2 m u 1f f 1d 1r 5 4 0 2 1 c r b m q s 8 1a 1a 4 3 8 b 4 2 14 o 2 2 7 l f 2 5 4 0 4 1d 2 0 4 1 3 4 3 0 2 0 p 1 4 a 8 0 f 5 3 5 3 5 a 6 2 6 42 y u 8mb d m 5 1c 6it a5 3 2x 1w 0 2 9 2 c 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 b 39 4 2 3q 2y 9 2 18 3 u 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e au s 4 1c 1c v e j 2 7 7 11 b t 3 z 5 7 3l 25 2r 13 9 1f 2l 1f d 8m a l b 7 49 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 0 g 3 2 2 2 s 17 s 4 s 10 7 2 r s 1h b l b i e h 33 20 54 z 13 3 2 0 8h 15 7 1 h 2 1o s b 0 9 l 17 h 1b k s m d 1g 1m 1 3 0 e 18 x o r z u 0 3 0 9 y 4 0 d 1b f 3 m 0 2 0 10 h 2 o k 1 1s 6 2 0 2 3 2 e 2 9 8 1a 13 7 3 1 3 l 2 6 2 1 2 4 4 0 j 0 d 4 v 9 2 0 3 0 2 11 2 0 q 0 2 0 19 1g j 3 l 2 v 1b l 1 2 0 55 1a 16 3 11 1b l 0 1o 16 e 0 20 q 12 6 56 17 5w 7 3 0 3 7 2 1 2 n g 0 2 0 2n 7 3 12 h 0 2 0 t 0 b 13 8 0 m 0 c 19 k 0 j 20 5k w w 8 2 10 i 0 1e t 35 6 2 1 2 11 m 0 q 5 2 1 2 v f 0 94 i g 0 2 c 2 x 3h 0 28 pl 6f 5f 219 2o g tr i 5 q 32y 6 g6 5a2 t 1cz fs 8 u i 26 i t j 1b 1g k 6 i c4 13 ba 22 6 0 4w 4qf 9 yd 16 9 6wo 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 6sx 0 dy 18 y 0 8y t j 17 dh q 6e t 3 0 ds 6 2 3 2 1 2 e 2 5g 118 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 3et wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Lt Titlecase_Letter",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "cl 0 3 0 3 0 13 0 5ue 7 9 7 9 7 d 0 g 0 1c 0",
+        ],
+    ),
+    (
+        "Lu Uppercase_Letter",
+        [
+            "5n4 16 3 2 rg9 0 137s v",
+            "x4q 0 2 0 2 0 4 0 2 2",
+            "x53 0 2 0 18 0",
+            "8q7 0 oep 0 g 0 6 0 2 0 ihk a 2 e 2 6 2 1",
+            "",
+            "",
+            "5mx 0 ria 1 e 0 2 0 k1g l",
+            "1t p 2u m 2 6 y 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 4 1 2 0 2 1 2 2 3 3 2 1 2 2 4 1 2 1 2 0 2 0 2 1 2 0 3 0 2 1 2 2 2 0 2 1 4 0 8 0 3 0 3 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 3 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 8 1 2 1 3 0 2 3 2 0 2 0 2 0 2 0 82 0 2 0 4 0 9 0 7 0 2 2 2 0 2 1 2 g 2 8 10 0 3 2 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 0 3 0 2 1 3 1e 1d 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 11 28a 11 2 0 6 0 k3 2d 1p0 0 7 16 3 2 8x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 7 9 5 b 7 9 7 9 5 c 0 2 0 2 0 2 0 9 7 21 3 d 3 d 3 d 4 c 3 7b 0 5 0 4 2 3 2 3 0 4 4 7 0 2 0 2 0 2 3 3 3 b 1 6 0 1q 0 22l 1b 1d 0 2 2 3 0 2 0 2 0 2 3 2 0 3 0 9 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 nym 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 2 1 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 3 2 0 2 1 4 0 6 0 2 0 2 0 2 0 p 0 h7w p xy 13 3t z 4d a 2 e 2 6 2 1 1d7 1e 4e l 27v v gw1 v k2p p r p r p r 0 2 1 3 0 3 1 3 3 2 7 r p r 1 2 3 3 7 2 6 s 1 2 3 2 4 2 0 4 6 s p r p r p r p r p r p v o y o y o y o y o y 0 3ee x",
+        ],
+    ),
+    (
+        "Math_Symbol Sm",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1h8u 1",
+            "17 0 h 2 1q 0 2 0 1a 0 5 0 12 0 w 0 lb 0 eo 2 56k 0 e 0 14 2 e 2 3w 0 14 4 7 0 1x 4 6 1 5 0 3 0 3 0 8 0 w 1 3 0 2 0 w 7f x 1 2j 0 v o 15 5 d2 0 a 0 1j 7 34 0 9d 4 3 u b f 75 3m n 1q 5 v 3 75 1d k 3 5 1525 0 mx 0 2 2 4l 0 h 2 1q 0 2 0 3o 0 7 3 2oy 1 13qq 0 q 0 w 0 q 0 w 0 q 0 w 0 q 0 w 0 q 0 4kt 1",
+        ],
+    ),
+    (
+        "Mc Spacing_Mark",
+        [
+            "1hz9 1 1d2 2 a 0 cv 1 oy 4 5 1 2 0 9r 1",
+            "5pu 1 rt6 0 m50 2 9 3 5 0 gwr 8",
+            "1i32 0 1gi 5 2 1 5 0 3 0 2 0 h4e 1",
+            "4k5 0 v 0",
+            "2k3 0 1i4w 0 1d 1 9 1 2 0",
+            "",
+            "1igo 2 8 0 3 0 2 3 2 1 2 0 nj 0 emk 2",
+            "1s3 0 1k 0 3 2 9 3 2 1 1f 1 1n 2 7 1 3 1 b 0 18 0 1n 2 1v 0 1n 2 9 0 2 1 1i 1 1n 0 2 0 7 1 3 1 b 0 2v 1 2 1 4 2 2 2 b 0 16 2 1q 3 1q 1 1n 0 2 4 3 1 2 1 a 1 t 0 f 1 1n 2 6 2 2 2 b 0 17 1 24 2 7 7 j 1 97 1 1s 0 4s 1 5 0 7 0 3 1 q 1 b 2 3 6 m 1 3 5 3 0 b 2 1a1 0 v 0 3m 0 8 7 2 1 9n 3 3 2 5 1 2 5 69 1 1n 0 2 0 a 0 2 1 9 5 42 0 1d 0 6 0 2 4 2 1 1q 0 v 0 5 1 3 0 1p 0 3 2 2 0 4 1 1d 7 9 1 4s 0 m 0 3sn 1 np0 1 3 0 2h 1 1f f 3z 1 1c 0 1d 1 5 1 3 2 33 1 3 1 p 0 1a 0 2 0 32 0 3 1 6 0 6m 1 2 1 2 1 2 0 jro 0 2 0 3k 0 1a 2 5 1 38 0 p 1 1o 0 1d 2 a 1 e 0 2m 2 4 1 2 0 4r 2 w 1 1n 1 2 3 3 1 3 2 a 0 b 1 2d 2 8 0 3 0 2 3 2 1 2 0 2u 2 9 1 4 0 2z 2 7 0 2 3 3 0 6m 2 7 3 3 0 36 2 9 1 2 0 32 0 2 1 7 0 2w 0 2 1 5 0 7a 2 a 0 6w 5 2 1 5 0 3 0 2 0 3z 2 9 3 5 0 2d 0 u 1 1r 0 bc 0 f 0 2z 0 8 0 3 0 5y 4 5 1 2 0 9r 1 d 0 1d 1 9 1 2 0 d0p 2 2sl 1i 2x 1 j90 1 7 5",
+        ],
+    ),
+    (
+        "Mn Nonspacing_Mark",
+        [
+            "1kt 0 5y 0 8b 0 ee 0 v0b 0 js5 3 f3 a hl 0 aa 0 83 0 r5 8 2 1 ct 1 p4 1 4 0 2 0 9o 1",
+            "2wq 0 umb 0 m53 3 3 1 5 0 gvj 0 mht 6 c6 3",
+            "28l 0 fg 0 2m6 1 rxo 0 k8v 1 mb 0 1gs 1 2 0 5 0 h41 0",
+            "1p4 7 17 8 oa 0 260 0 63 0 j6 d mk 0 1bpk 3 6j 0 3 1 26 0 11ku 19 3 m 3u0 0",
+            "2xa 0 1elr 2 n6 0 2in 1 1h 4 6 0 2 0 45a 0 7 e y0q 0 v1 3",
+            "",
+            "1p3 0 1fiq 4 b3 0 xr 5 e 0 2 0 2 0 f 1 n0 0 1mk 0 czo b 4 2 q7z 1",
+            "lc 33 7o 4 7e 18 2 0 2 1 2 1 2 0 21 a 1d k h 0 2u 6 3 5 3 1 2 3 10 0 v q 2k a 1n 8 a 0 p 3 2 8 2 2 2 4 18 2 1o 8 17 n 2 v 1k 0 2 0 5 7 5 0 4 6 b 1 u 0 1n 0 5 3 9 0 l 1 r 0 3 1 1m 0 5 1 5 1 3 2 4 0 v 1 4 0 c 1 1m 0 5 4 2 1 5 0 l 1 n 5 2 0 1n 0 3 0 2 3 9 0 8 1 c 1 v 0 1q 0 d 0 1f 0 4 0 1k 0 2 2 6 2 2 3 8 1 c 1 u 0 1n 0 3 0 7 0 6 1 l 1 t 1 1m 1 5 3 9 0 l 1 u 0 21 0 8 2 2 0 2j 0 3 6 d 7 2r 0 3 8 c 6 22 1 s 0 2 0 2 0 1k d 2 4 2 1 6 a 2 z a 0 2v 3 2 5 2 1 3 1 q 1 5 2 h 3 e 0 3 1 7 0 g 0 jk 2 qb 2 u 1 v 1 v 1 1t 1 2 6 9 0 3 a a 0 1a 2 2 0 3a 1 z 0 3b 2 5 1 a 0 7 2 64 1 3 0 1n 0 2 6 2 0 2 0 3 7 7 9 3 0 1d d 2 f 1e 3 1d 0 2 4 2 0 6 0 15 8 d 1 x 3 3 1 2 2 1l 0 2 1 4 0 2 2 1n 7 3 1 49 2 2 c 2 6 5 0 7 0 4 1 5j 1r k1 c 5 0 4 b 2db 2 3y 0 2p v ff 3 30 1 n9x 0 5 9 x 1 29 1 7l 0 4 0 5 0 q 1 6 0 48 1 r h e 0 13 7 q a 1b 2 1d 0 3 3 3 1 14 0 1w 5 3 1 3 1 d 0 9 0 1c 0 1g 0 2 2 3 1 6 1 2 0 17 1 9 0 6n 0 3 0 5 0 fn5 0 ki f h f r2 0 6b 0 46 4 1af 2 2 1 6 3 15 2 5 0 4m 1 fy 3 1u 4 8u 1 28 3 1z a 1e 3 3g 0 1j e 16 0 3 1 b 2 1e 3 3 1 8 0 1q 2 11 4 2 7 1r 0 d 1 1h 8 b 3 3 0 2o 2 3 0 2 1 7 0 3 0 4e 0 4 7 m 1 1m 1 4 0 12 6 4 4 1z 5 e 0 2 0 2 0 f 1 2e 7 3 2 2 0 o 0 2d 5 2 0 5 1 2 1 6n 3 7 1 2 1 s 1 2e 7 3 0 2 1 2z 0 2 0 3 5 2 0 2u 0 2 0 3 3 2 4 78 8 2 1 75 1 2 0 5 0 41 3 3 1 5 0 x 9 15 5 3 3 9 0 a 5 3 2 1b c 2 1 bb 6 2 5 2 0 2b l 3 6 2 1 2 1 3f 5 4 0 2 1 2 6 2 0 21 1 4 0 2 0 9o 1 c 1 1h 4 6 0 2 0 o 0 44m 0 7 e 8uh b 4 2 1xd 4 1o 6 t5 0 1s 3 2a 0 f5l 1 3mq 19 3 m f5 2 i 7 3 6 v 3 45 2 1j0 1i 5 1d 9 0 f 0 n 4 2 e 11t 6 2 g 3 6 2 1 2 4 2t 0 4h 6 ag 0 1q 3 e5 3 73 1 kh 6 32 6 gzhy 6n",
+        ],
+    ),
+    (
+        "Modifier_Symbol Sk",
+        [
+            "",
+            "",
+            "xuy 1",
+            "1oo 0 1c1m 0",
+            "",
+            "",
+            "",
+            "2m 0 2 0 20 0 7 0 5 0 4 0 ei 3 d d 6 6 2 0 2 g 3a 0 f 1 zn 0 4l1 0 2 2 c 2 e 2 e 2 e 1 3a5 1 ndw m a 1 2w 1 r5 0 f 1 fuv g os 0 2 0 4j 0 1c7s 4",
+        ],
+    ),
+    (
+        "N Number",
+        [
+            "1glk 0 ko 9 dg 9 17 3 2to 9 fyv m jt6 j 3j 6 4xl 1m 2 2 2 3",
+            "1ku8 k 127g 9 br 9 1zc 18 2 e",
+            "1hol 6 1vp 9 18rb 9",
+            "1znk 9",
+            "1kr4 9 zfr j 3kt 9",
+            "",
+            "1h6o 9 1vr j zx 9 do7 9 2ev 9 iuv 9 4xk 9",
+            "1c 9 3d 1 6 0 3 2 142 9 3r 9 5j 9 bh 9 3b 9 5 5 31 9 3b 9 3b 9 3 5 33 c 38 9 9 6 2w 9 2x 6 8 i 32 9 2p 9 3b 9 1z j 7h 9 1z 9 k0 j oi 2 6o 9 7 9 n 9 8d 9 3l a 4m 9 7 9 53 9 2f 9 3r 9 7 9 t3 0 4 5 7 9 5j 1e 3 4 k7 1n 27 l hj t 12i 0 lm 0 q 8 f 2 9k 3 3v 9 v 7 2 e x 9 14 e msh 9 59 9 8x 5 4b 9 13 9 5j 9 n 9 2f 9 bb 9 gev 9 dq 18 d 1k i 1 9i q 11 3 u 0 9 0 3r 4 5n 9 q7 7 q 6 14 8 24 4 n 5 4h 1 3 f 3 19 1t 8 1h 1 v 2 24 4 2x 7 p 7 16 6 97 5 1d 9 7 9 7r u 4f 9 17 3 35 6 3r t 3l 9 1p 9 41 9 8 j 70 9 9j 9 3b 9 af 9 2v 9 7 j 25 b bp i 2m 9 if 9 2f s 6c 9 1z 9 br 9 2v k to 32 c02 9 1t3 9 2f 9 3r 9 2 6 en 9 7b m imy 9 153 j d j 31 o uu 1d 1tt 9 br 9 dz 9 6w 9 jx 8 3l 9 m0 1m 2 2 2 3 25 18 2 e qr c 25g 9",
+        ],
+    ),
+    (
+        "No Other_Number",
+        [
+            "1glk 0 yd 9 17 3 iss m jt6 j 3j 6 4xl 1m 2 2 2 3",
+            "1ku8 k 14j1 18 2 e",
+            "1hol 6",
+            "",
+            "2k74 j",
+            "",
+            "",
+            "4y 1 6 0 3 2 1ti 5 ah 5 3d 2 3q 6 62 6 i 8 c2 9 ty j vo 9 dd 0 1au 0 4 5 7 9 5j f 16 0 k7 1n 27 l hj t 12i 0 wl 3 3v 9 v 7 2 e x 9 14 e n75 5 hjm 18 1u 3 i 1 9i q 11 3 111 7 q 6 14 8 24 4 n 5 4h 1 3 f 3 19 1t 8 1h 1 v 2 24 4 2x 7 p 7 16 6 97 5 9t u 4f 9 17 3 35 6 3r j ak j 11i 1 bz 8 o8 i no k ex3 6 m7 m jsa j d j 31 o 47j 8 pu 1m 2 2 2 3 25 18 2 e qr c",
+        ],
+    ),
+    (
+        "Open_Punctuation Ps",
+        [
+            "",
+            "",
+            "",
+            "95h 0 2 0 2 0 2 0",
+            "",
+            "",
+            "",
+            "14 0 1f 0 w 0 2wv 0 2 0 1gf 0 1vj 0 4 0 13 0 1k 0 g 0 hn 0 2 0 v 0 u7 0 2 0 2 0 2 0 2 0 2 0 2 0 29 0 x 0 2 0 2 0 2 0 2 0 b9 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1t 0 2 0 y 0 ti 0 2 0 2 0 2 0 q 0 j 0 2 0 2 0 2 0 bx 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 3 0 14iq 0 60 0 u 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 i 0 2 0 2 0 4r 0 1f 0 w 0 4 0 3 0",
+        ],
+    ),
+    (
+        "Other_Punctuation Po",
+        [
+            "22e 0 em 0 6o6 4 18g7 4 hc 0 19u 0 1bw 1 fq7 3",
+            "2gn 0 1yu 0 4pu 0 1aj7 0 17h 0 fs3 0",
+            "95e 0 19fs 0 yy 2",
+            "17h 0 480 1 3px 1 18he 3 1f4 0 4zc 1",
+            "1jwg 9 u2 c",
+            "",
+            "5e6 1 1c 0 1d1x 1 2 1 1l5 0 g3w 2 ntc 0",
+            "x 2 2 2 3 0 2 0 2 1 b 1 4 1 s 0 1x 0 6 0 f 1 8 0 jj 0 9 0 cz 5 16 0 1j 0 3 0 3 0 19 1 l 1 2 1 e 0 2 2 23 3 2v 0 18 d 6i 2 1j e w 0 7a 1 b 0 3x 0 3d 0 3e 0 av 0 d 0 a8 0 2j 0 b 1 4p e 2 0 35 0 23 4 5 1 34 5 4s 0 h1 8 li 0 3h 2 20 1 4e 2 2 2 12 5 2 3 8q 1 61 1 3l 6 2 5 4h 1 b 6 t 2 3h 3 1o 4 1r 1 1t 7 c 0 n7 1 9 7 9 8 3 3 3 2 4 a 2 0 2 9 2hn 3 2 1 35 0 40 1 5 2 3 0 3 8 2 1 2 0 3 1 b 4 2 9 3 3 2 0 2 c 3 2 bx 2 1m 0 5a 0 mwz 1 7i 2 2s 0 b 0 38 5 al 3 2f 1 15 2 2 0 1e 1 1c 0 2q c h 1 3h 3 3j 1 h 1 6y 0 g85 6 3 0 n 0 l 1 3 3 4 2 2 3 8 2 7 0 2 1 46 2 2 2 3 0 2 0 2 1 b 1 4 1 s 0 11 0 3 1 bf 2 il 0 1d 0 bj 0 ko 0 5k 0 w 0 7l 8 13 0 35 6 1v 6 2i 3 qh 4 19 3 5a 6 32 1 2 3 3j 3 1d 1 28 3 5 0 e 0 2 2 2h 5 30 0 8b 1 2 1 37 4 b 1 2 0 2x 0 6z m 2y 2 t c 25 0 3n 2 71 0 7d 2 4c 0 2l 7 2c 2 2 4 2m 9 60 0 2o 4 17 1 hy 1 23 c 4w 0 vl 4 29p 1 bjw 1 3q 0 1u 4 9 0 fd 2 88 3 94 0 f5p 0 5wo 4 29g 0 nz 1",
+        ],
+    ),
+    (
+        "Other_Symbol So",
+        [
+            "8my 2 n o 5 e 2gmm 0 w3 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "4fh 0 47w 0 1i 0 1ds 0 1as6 7 5 g 1272 0 2cf 0 u6 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "8lz 0 jd 1 15qj 0 1bap 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i",
+            "1e0w f 3k 0 1b 1 15ch 37 fa 1 7ar 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2rbw 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "9h8 3 ds 0",
+            "753 2 2pn 1 290b 6n h c3 8an 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "4m 0 3 0 5 0 2 0 r6 0 7f 1 3k 1 5r 0 b 0 k 1 6w 0 ec 0 ae 0 3n 5 2 0 3p 0 5s 0 16 0 aw 2 g 0 2 2 3 5 l 0 2 0 2 0 3q 7 2 5 2 1 6 3 5i 1 kx 9 k4 0 k3 0 4e x 9u 9 a 8 138 1 2 3 2 1 b 0 2 1 7 5 2 0 2 0 2 0 5 0 c 1 f 0 2 1 2 0 1n 1 a 4 3 3 2 1 2 1 2 6 2 u 3 1 2 0 2 u 7h 7 5 j 3 6 3 28 2 t q 13 7 1z n a 2a 25 n 52 2 8 2 1h 9 32 2 6v 19 17 1t 73 e9 1b m 1 7 12 3 v 2 2w 6e 5 9y 1 1b p 2 2g d 5x r f 5 0 e 1 d 0 m 1 7 1 9d 1 5 9 x 11 a 0 h u c t 9 0 g v b 12 g 8v 535 1r h3l 1i o2 3 b 1 2 0 fy 2 gcn f 3k 0 1a 2 dh 0 4 0 5 1 e 1 8q 8 1m g 3 2 2 c 4 0 1c 18 1a3 1 gg 0 2gn 0 1p2 7 5 g evf 3 6 0 g2f 0 31g 6n h c3 4d 37 1p 6t b 12 3 1n 6 2 n 1 8 t 5 1o m 1t 4 0 57 2e x6 e7 1k 3 1f 7 2 d 2 1 1c9 0 28t 0 3m 0 k2 17 5 2r d e 3 e 2 e 2 10 o 4g 1l s e 17 5 8 8 1 f 5 4b 6y 6 k7 5 g 4 c 4 3a 5 2m 7 b 5 0 g b 5 1j 9 9 7 13 9 t 3 b 5 1 1r 9f d d 3 c 4 9 6 1j 8 e 3 a 7 8 8 42 2 2j",
+        ],
+    ),
+    (
+        "P Punctuation punct",
+        [
+            "22e 0 em 0 6o6 4 18g7 4 hc 0 19u 0 1bw 1 fq7 3",
+            "2gn 0 1yu 0 4pu 0 1aj7 0 17h 0 fs3 0",
+            "95e 0 18bf 0 14d 0 yy 2",
+            "17h 0 480 1 3px a 18h5 3 1f4 0 4zc 1",
+            "1jwg 9 u2 c",
+            "",
+            "5e6 1 1c 0 1bsf 0 19i 1 2 1 1l5 0 g3w 2 ntc 0",
+            "x 2 2 5 2 3 b 1 4 1 r 2 2 0 s 0 2 0 10 0 6 0 4 0 b 1 4 0 4 0 jj 0 9 0 cz 5 16 1 1g 0 2 0 3 0 3 0 19 1 l 1 2 1 e 0 2 2 23 3 2v 0 18 d 6i 2 1j e w 0 7a 1 b 0 3x 0 3d 0 3e 0 av 0 d 0 a8 0 2j 0 b 1 4p e 2 0 12 3 20 0 23 4 5 1 34 5 4s 0 h1 8 48 0 ha 0 19 1 27 2 20 1 4e 2 2 2 12 a 8q 1 61 1 3l 6 2 5 4h 1 b 6 t 2 3h 3 1o 4 1r 1 1t 7 c 0 n1 n 9 j 2 c 2 b v 1 f 1 hm 3 u 1 u6 d 28 1 w 9 b8 l 1s 3 x 1 l8 3 2 1 35 0 40 1a 2 v 3 b bo 2 5 9 3 b h 0 d 0 2r 0 2j 0 mwz 1 7i 2 2s 0 b 0 38 5 al 3 2f 1 15 2 2 0 1e 1 1c 0 2q c h 1 3h 3 3j 1 h 1 6y 0 g2b 1 5t 9 n y 2 d 2 0 5 0 2 1 46 2 2 5 2 3 b 1 4 1 r 2 2 0 s 0 2 0 2 6 bf 2 il 0 1d 0 bj 0 ko 0 5k 0 w 0 7l 8 13 0 35 6 1v 6 2i 3 cy 0 8v 0 4o 4 19 3 5a 6 32 1 2 3 3j 3 1d 1 28 3 5 0 e 0 2 2 2h 5 30 0 8b 1 2 1 37 4 b 1 2 0 2x 0 6z m 2y 2 t c 25 0 3n 2 71 0 7d 2 4c 0 2l 7 2c 2 2 4 2m 9 60 0 2o 4 17 1 hy 1 23 c 4w 0 vl 4 29p 1 bjw 1 3q 0 1u 4 9 0 fd 2 88 3 94 0 f5p 0 5wo 4 29g 0 nz 1",
+        ],
+    ),
+    (
+        "Paragraph_Separator Zp",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6cp 0",
+        ],
+    ),
+    (
+        "S Symbol",
+        [
+            "1ku 1 723 2 n o 5 e 2gmm 0 4 0 vz 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "4fh 0 47w 0 1i 0 1ds 0 1as6 s 1272 0 c0 0 20f 0 u6 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "8lz 0 jd 1 opl 1 h0x 0 1bap 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i",
+            "1oo 0 4s8 0 179e 0 am f 3k 0 1b 1 15ch 37 fa 1 7ar 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2rbw 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "9h8 3 ds 0",
+            "753 2 2pn 1 17e1 1 11m9 6n h c3 8an 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "10 0 7 0 h 2 w 0 2 0 s 0 2 0 10 4 2 1 3 0 2 3 3 0 4 0 v 0 w 0 cr 3 d d 6 6 2 0 2 g 3a 0 f 1 35 0 3w 0 7f 2 3b 2 3 0 3 1 5r 0 b 0 k 1 6w 0 8 1 3t 0 a2 1 7 1 6u 0 3j 0 3n 7 3p 0 5s 0 16 0 5i 0 5e 2 g 0 2 2 3 5 l 0 2 0 2 0 3q 7 2 5 2 1 6 3 5i 1 kx 9 k4 0 a6 0 9x 0 4e x 9u 9 a 8 u9 0 2 2 c 2 e 2 e 2 e 1 1y 0 e 0 14 2 e 2 k w 1s 1 2 3 2 1 b 0 2 2 6 5 2 0 2 0 2 0 5 0 c 1 5 4 6 3 2 0 1n 1 5 af 5 s 3 72 n a 2a 25 n h3 19 1c 3 u b b6 n 1q 5 v 3 ad 3 v 2 2w 6e 5 9y 1 1b p 2 2g d 5x r f 5 0 e 1 d 0 m 1 7 1 2k 1 6s 1 5 9 x 11 a 0 h u c t 9 0 g v b 12 g 8v 535 1r h3l 1i fu m a 1 2w 1 4e 3 b 3 fy 2 6a 0 f 1 fr2 0 3t g am f 3k 0 19 3 2r 0 2 2 3 0 4b 0 7 0 h 2 w 0 2 0 s 0 2 0 3m 6 2 6 e 1 8q 8 1m g 3 2 2 c 4 0 1c 18 1a3 1 gg 0 jq 1 1ww 0 1p2 s evf 3 6 0 g2f 0 31g 6n h c3 4d 37 1p 6t b 12 3 1n 6 2 n 1 8 t 5 1o m 1t 4 0 57 2e ob 0 q 0 w 0 q 0 w 0 q 0 w 0 q 0 w 0 q 0 1p e7 1k 3 1f 7 2 d 2 1 1c9 0 c0 0 1wt 0 4 0 3i 0 ci 1 7j 17 5 2r d e 3 e 2 e 2 10 o 4g 1l s e 17 5 8 8 1 f 5 4b rb 5 g 4 c 4 3a 5 2m 7 b 5 0 g b 5 1j 9 9 7 13 9 t 3 b 5 1 1r 9f d d 3 c 4 9 6 1j 8 e 3 a 7 8 8 42 2 2j",
+        ],
+    ),
+    (
+        "Separator Z",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "w 0 3k 0 4bk 0 1vk a u 1 6 0 1c 0 335 0",
+        ],
+    ),
+    (
+        "Space_Separator Zs",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "w 0 3k 0 4bk 0 1vk a 11 0 1c 0 335 0",
+        ],
+    ),
+];
+
+pub(super) static SCRIPT_RANGES: &[(&str, [&str; 8])] = &[
+    (
+        "Adlam Adlm",
+        [
+            "",
+            "2onf 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2olc 23 5 9 5 1",
+        ],
+    ),
+    (
+        "Aghb Caucasian_Albanian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1flc 1f c 0",
+        ],
+    ),
+    (
+        "Ahom Ahom",
+        [
+            "1j4q 0",
+            "",
+            "",
+            "1j5s 6",
+            "",
+            "",
+            "",
+            "1j40 q 3 e 5 m",
+        ],
+    ),
+    (
+        "Anatolian_Hieroglyphs Hluw",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1s00 g6",
+        ],
+    ),
+    (
+        "Arab Arabic",
+        [
+            "1qr 0",
+            "",
+            "1q6 9",
+            "17h 0 gj u 2 1 7 7 m 0 j a 1bzk 0 am f 3k 0 1b 1",
+            "1hj1 2",
+            "",
+            "1p3 0 1fsb 2 1k 0",
+            "16o 4 2 5 2 d 2 2 2 v 2 9 c p 2 2z 2 x 29 1b 6p u 2 1 6 22 2 s 1bv5 36 h a2 3 27 3 1h 8 0 x f 35 4 2 3q 31g u 1w 2 1k 3 181t 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 1h 1",
+        ],
+    ),
+    (
+        "Armenian Armn",
+        [
+            "128 0 14 0",
+            "",
+            "13d 0",
+            "",
+            "",
+            "",
+            "",
+            "10x 11 3 1d 3 2 1chw 4",
+        ],
+    ),
+    (
+        "Armi Imperial_Aramaic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g74 l 2 8",
+        ],
+    ),
+    (
+        "Avestan Avst",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gqo 1h 4 6",
+        ],
+    ),
+    (
+        "Bali Balinese",
+        [
+            "",
+            "",
+            "",
+            "5e4 0 1d 1",
+            "",
+            "",
+            "5e6 1 1c 0",
+            "5c0 24 2 1d",
+        ],
+    ),
+    (
+        "Bamu Bamum",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "www 2f 124p fs",
+        ],
+    ),
+    (
+        "Bass Bassa_Vah",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zo0 t 3 5",
+        ],
+    ),
+    (
+        "Batak Batk",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5hc 1f 9 3",
+        ],
+    ),
+    (
+        "Beng Bengali",
+        [
+            "1z2 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1vk 3 2 7 3 1 3 l 2 6 2 0 4 3 3 8 3 1 3 3 9 0 5 1 2 4 3 o",
+        ],
+    ),
+    (
+        "Bhaiksuki Bhks",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1k3k 8 2 18 2 d b s",
+        ],
+    ),
+    (
+        "Bopo Bopomofo",
+        [
+            "9pr 0",
+            "",
+            "9tn 4",
+            "",
+            "",
+            "",
+            "",
+            "kq 1 93u 16 35 v",
+        ],
+    ),
+    (
+        "Brah Brahmi",
+        [
+            "",
+            "",
+            "",
+            "1htc 5",
+            "",
+            "",
+            "",
+            "1hq8 25 5 z a 0",
+        ],
+    ),
+    (
+        "Brai Braille",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "7wg 73",
+        ],
+    ),
+    (
+        "Bugi Buginese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "54w r 3 1",
+        ],
+    ),
+    (
+        "Buhd Buhid",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4lc j",
+        ],
+    ),
+    (
+        "Cakm Chakma",
+        [
+            "1hz8 2",
+            "",
+            "1hzb 0",
+            "",
+            "",
+            "",
+            "",
+            "1hxc 1g 2 h",
+        ],
+    ),
+    (
+        "Canadian_Aboriginal Cans",
+        [
+            "",
+            "",
+            "",
+            "1ju8 f",
+            "",
+            "",
+            "",
+            "3y8 hr fl 1x 1ewr f",
+        ],
+    ),
+    (
+        "Cari Carian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f34 1c",
+        ],
+    ),
+    (
+        "Cham Cham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xkw 1i a d 3 9 3 3",
+        ],
+    ),
+    (
+        "Cher Cherokee",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "3vk 2d 3 5 twz 27",
+        ],
+    ),
+    (
+        "Chorasmian Chrs",
+        [
+            "",
+            "",
+            "1ho0 r",
+            "",
+            "",
+            "",
+            "",
+            "1ho0 r",
+        ],
+    ),
+    (
+        "Common Zyyy",
+        [
+            "8my 2 n o 5 e gc 4 2b2q j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "5q2 0 2xb 0 1i 0 gg 0 xc 0 1qlf 1 opq 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "13d 0 7im 0 jd 2 opk 1 h0x 0 1bap 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9",
+            "6gw 0 2oj a 1rid 1 iul 37 fa 1 7ar 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2k74 j 749 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "9h8 3 ds 0",
+            "753 2 2pn 1 290b 6x 7 c3 8an 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "0 1s r 5 r 1a 2 e 2 4 o 0 w 0 ci 12 6 4 3 j 39 0 a 0 7 0 2 0 hq 0 7 0 f 0 4 0 x 0 4d 0 ed 0 3m 1 yi 0 ba 3 83 0 168 2 20 1 5o 1 2 0 y6 0 e 0 8 3 2 5 2 2 3 0 li b 3 2e 2 a 4 a 2 e i w 1s 11 2 2 3 5 2 q 2 g 16 2 5 ih n a m pr 75 hf 3 v 2 2w e9 2l b7 k 2 0 2 o g 7 5 3 2k 1 4 0 2j 1 44 f x 11 a 0 1d 1r w 28 1c 0 2h 4n 535 1r hkx x 2v 2 4m 9 6t 0 4h 0 b0 0 f 1 g5v 1 5t 9 n y 2 i 2 3 44 0 2 v r 5 r a b 0 1a 1 1t 6 2 6 b 4 77 2 5 18 4 8 29 c 1g 18 6d q 10o5 3 319 6x 7 c3 4d 37 1p 6t b 12 3 1p 4 g 9 1 8 t 5 1o 5y j d j d 2e a o 3s 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 83 3 1d 41e 1v 25 1o jn 17 5 2r d e 3 e 2 e 2 10 b 4t 1l p 2 1 e 17 5 8 8 1 f 5 4b rb 5 g 4 c 4 3a 5 2m 7 b 5 0 g b 5 1j 9 9 7 13 9 t 3 b 5 1 1r 9f d d 3 c 4 9 6 1j 8 e 3 a 7 8 8 42 2 2t gvm0 0 v 2n",
+        ],
+    ),
+    (
+        "Copt Coptic Qaac",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "rm d 80h 37 6 6",
+        ],
+    ),
+    (
+        "Cpmn Cypro_Minoan",
+        [
+            "",
+            "",
+            "",
+            "1nyo 2q",
+            "",
+            "",
+            "",
+            "1nyo 2q",
+        ],
+    ),
+    (
+        "Cprt Cypriot",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g5c 5 3 0 2 17 2 1 4 0 3 0",
+        ],
+    ),
+    (
+        "Cuneiform Xsux",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1kw0 pl 2v 32 2 4 c 5f",
+        ],
+    ),
+    (
+        "Cyrillic Cyrl",
+        [
+            "",
+            "",
+            "",
+            "",
+            "2muo 1p y 0",
+            "",
+            "5mx 1",
+            "sg 3o 3 4o 4lt a 4h 0 25 0 38o v nr5 2n han 1 18n5 1p y 0",
+        ],
+    ),
+    (
+        "Deseret Dsrt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fcw 27",
+        ],
+    ),
+    (
+        "Deva Devanagari",
+        [
+            "xdq 1",
+            "1ub 1",
+            "",
+            "",
+            "1jwg 9",
+            "",
+            "",
+            "1s0 28 5 e 3 p vhd v mip 9",
+        ],
+    ),
+    (
+        "Diak Dives_Akuru",
+        [
+            "",
+            "",
+            "1ji8 6 3 0 3 7 2 1 2 t 2 1 3 b a 9",
+            "",
+            "",
+            "",
+            "",
+            "1ji8 6 3 0 3 7 2 1 2 t 2 1 3 b a 9",
+        ],
+    ),
+    (
+        "Dogr Dogra",
+        [
+            "1jb4 1n",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jb4 1n",
+        ],
+    ),
+    (
+        "Dupl Duployan",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2fpc 2y 6 c 4 8 8 9 3 3",
+        ],
+    ),
+    (
+        "Egyp Egyptian_Hieroglyphs",
+        [
+            "",
+            "1ovk 8",
+            "",
+            "",
+            "1ovj 0 a s",
+            "",
+            "1oww 32y",
+            "1o1s ut b 32y",
+        ],
+    ),
+    (
+        "Elba Elbasan",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fk0 13",
+        ],
+    ),
+    (
+        "Elym Elymaic",
+        [
+            "",
+            "1hpc m",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hpc m",
+        ],
+    ),
+    (
+        "Ethi Ethiopic",
+        [
+            "",
+            "",
+            "",
+            "2odc 6 2 3 2 1 2 e",
+            "",
+            "",
+            "",
+            "3k0 20 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 3 v 4 p 547 m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 opv 5 3 5 3 5 a 6 2 6 1qk2 6 2 3 2 1 2 e",
+        ],
+    ),
+    (
+        "Gara Garay",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1h6o 11 4 s 9 1",
+            "1h6o 11 4 s 9 1",
+        ],
+    ),
+    (
+        "Geor Georgian",
+        [
+            "5n4 16 3 2",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "3a8 11 2 0 6 0 3 16 2 3 2a9 16 3 2 37l 11 2 0 6 0",
+        ],
+    ),
+    (
+        "Glag Glagolitic",
+        [
+            "",
+            "",
+            "",
+            "8q7 0 1c 0",
+            "",
+            "",
+            "",
+            "8ow 2n 2e1t 6 2 g 3 6 2 1 2 4",
+        ],
+    ),
+    (
+        "Gong Gunjala_Gondi",
+        [
+            "1kdc 5 2 1 2 10 2 1 2 5 8 9",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1kdc 5 2 1 2 10 2 1 2 5 8 9",
+        ],
+    ),
+    (
+        "Gonm Masaram_Gondi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1kao 6 2 1 2 17 4 0 2 1 2 8 9 9",
+        ],
+    ),
+    (
+        "Goth Gothic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f74 q",
+        ],
+    ),
+    (
+        "Gran Grantha",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ibk 3 2 7 3 1 3 l 2 6 2 1 2 4 3 8 3 1 3 2 3 0 7 0 6 6 3 6 4 4",
+        ],
+    ),
+    (
+        "Greek Grek",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "og 3 2 2 3 3 2 0 5 0 2 0 2 2 2 0 2 j 2 1q f f 4yv 4 1f 4 5 4 2d 0 8x l 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 e 2 d 3 5 2 i 3 2 2 8 88 0 rb3 0 gyj 26 i 0 155s 1x",
+        ],
+    ),
+    (
+        "Gujarati Gujr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "22p 2 2 8 2 2 2 l 2 6 2 1 2 4 3 9 2 2 2 2 3 0 g 3 3 b 8 6",
+        ],
+    ),
+    (
+        "Gukh Gurung_Khema",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1xq8 1l",
+            "1xq8 1l",
+        ],
+    ),
+    (
+        "Gurmukhi Guru",
+        [
+            "22e 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1z5 2 2 5 5 1 3 l 2 6 2 1 2 1 2 1 3 0 2 4 5 1 3 2 4 0 8 3 2 0 8 g",
+        ],
+    ),
+    (
+        "Han Hani",
+        [
+            "vl7 4",
+            "",
+            "fcm 9 g8h c 152s 1 1peu 6 hlv 3t6",
+            "vlp 2 152b 1 1pff 1 386 3",
+            "3tbt 0 i6v 38f",
+            "43qo h9",
+            "",
+            "96o p 2 2g d 5x 1c 0 2 0 q 8 f 3 qt 533 1t g73 hkx a5 3 2x n49 1 d 1 sgf wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Hang Hangul",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "3cw 73 5yn 1 76 2l 36 u 1u u nhe s hw 8mb d m 5 1c 7tx u 4 5 3 5 3 5 3 2",
+        ],
+    ),
+    (
+        "Hanifi_Rohingya Rohg",
+        [
+            "1h4w 13 9 9",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1h4w 13 9 9",
+        ],
+    ),
+    (
+        "Hano Hanunoo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4kg k",
+        ],
+    ),
+    (
+        "Hatr Hatran",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gbk i 2 1 6 4",
+        ],
+    ),
+    (
+        "Hebr Hebrew",
+        [
+            "167 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "13l 1i 9 q 5 5 1cfd p 2 4 2 0 2 1 2 1 2 9",
+        ],
+    ),
+    (
+        "Hira Hiragana",
+        [
+            "",
+            "2dlc 2",
+            "",
+            "2djz 0",
+            "2dki 0",
+            "",
+            "",
+            "9j5 2d 7 2 23qa 7y j 0 u 2 cry 0",
+        ],
+    ),
+    (
+        "Hmng Pahawh_Hmong",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zpc 1x b 9 2 6 2 k 6 i",
+        ],
+    ),
+    (
+        "Hmnp Nyiakeng_Puachue_Hmong",
+        [
+            "",
+            "2n0g 18 4 d 3 9 5 1",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2n0g 18 4 d 3 9 5 1",
+        ],
+    ),
+    (
+        "Hrkt Katakana_Or_Hiragana",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        ],
+    ),
+    (
+        "Hung Old_Hungarian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1h1c 1e e 1e 8 5",
+        ],
+    ),
+    (
+        "Inherited Qaai Zinh",
+        [
+            "1id7 0",
+            "1ub 1",
+            "5a7 1",
+            "5a9 d mk 0 2dja 19 3 m",
+            "",
+            "",
+            "",
+            "lc 33 7q 1 cl a r 0 kh 3 3fg u ea 2 2 c 2 6 5 0 7 0 4 1 5j 1r el 1 5f w 30a 3 30 1 14km f h d r4 0 6b 0 38b 0 1139 19 3 m f5 2 i 7 3 6 v 3 h45v 6n",
+        ],
+    ),
+    (
+        "Inscriptional_Pahlavi Phli",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gtc i 6 7",
+        ],
+    ),
+    (
+        "Inscriptional_Parthian Prti",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gsg l 3 7",
+        ],
+    ),
+    (
+        "Ital Old_Italic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f5s z a 2",
+        ],
+    ),
+    (
+        "Java Javanese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xhc 25 3 9 5 1",
+        ],
+    ),
+    (
+        "Kaithi Kthi",
+        [
+            "1hvx 0",
+            "",
+            "",
+            "1hvm 0",
+            "",
+            "",
+            "",
+            "1hts 1u b 0",
+        ],
+    ),
+    (
+        "Kali Kayah_Li",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xds 19 2 0",
+        ],
+    ),
+    (
+        "Kana Katakana",
+        [
+            "",
+            "2dlw 3",
+            "",
+            "2dbk 3 2 6 2 1 82 2",
+            "2dlh 0",
+            "",
+            "",
+            "9lt 2h 3 2 6p f 5t 1a 2 2f 14b3 9 2 18 ytv 3 2 6 2 1 2 0 80 2 1f 0 f 3",
+        ],
+    ),
+    (
+        "Kannada Knda",
+        [
+            "2h0 0",
+            "",
+            "",
+            "2jh 0",
+            "2k3 0",
+            "",
+            "",
+            "2gw c 2 2 2 m 2 9 2 4 3 8 2 2 2 3 8 1 7 1 2 3 3 9 2 2",
+        ],
+    ),
+    (
+        "Kawi Kawi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "1kow g 2 14 4 r",
+            "",
+            "1kre 0",
+            "1kow g 2 14 4 s",
+        ],
+    ),
+    (
+        "Khar Kharoshthi",
+        [
+            "1gl0 1 j 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gjk 3 2 1 6 7 2 2 2 s 3 2 5 9 8 8",
+        ],
+    ),
+    (
+        "Khitan_Small_Script Kits",
+        [
+            "",
+            "",
+            "20o4 0 5cs d1",
+            "",
+            "",
+            "",
+            "26f3 0",
+            "20o4 0 5cs d1 16 0",
+        ],
+    ),
+    (
+        "Khmer Khmr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4n4 2l 3 9 7 9 dj v",
+        ],
+    ),
+    (
+        "Khoj Khojki",
+        [
+            "",
+            "",
+            "",
+            "",
+            "1i67 2",
+            "",
+            "",
+            "1i4g h 2 1a",
+        ],
+    ),
+    (
+        "Khudawadi Sind",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1i9c 1m 6 9",
+        ],
+    ),
+    (
+        "Kirat_Rai Krai",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "205c 1l",
+            "205c 1l",
+        ],
+    ),
+    (
+        "Lana Tai_Tham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "55s 1q 2 s 3 a 7 9 7 d",
+        ],
+    ),
+    (
+        "Lao Laoo",
+        [
+            "",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0",
+            "",
+            "",
+            "2xa 0",
+            "",
+            "",
+            "2v5 1 2 0 2 4 2 n 2 0 2 m 3 4 2 0 2 6 2 9 3 3",
+        ],
+    ),
+    (
+        "Latin Latn",
+        [
+            "x4f 0 9 1",
+            "x4q 5 3 4 ps 1",
+            "x53 3 17 1 oi 1",
+            "x4w 1 f 1 2 0 2 4 p 2 ivg 5 2 15 2 8 16iu u",
+            "2mn9 5",
+            "",
+            "x57 2 d 2",
+            "1t p 7 p 1c 0 g 0 6 m 2 u 2 cg 14 4 55o 11 7 1c 6 3 6 c 2 1x 1u 73 aa 0 e 0 h c 3y 1 7 0 s 0 i 14 254 v o83 2t 4 1u 3 1 2 0 2 7 m d mp 16 2 8 2 3 fpz 6 t7 p 7 p 1ly 5 2 15 2 8 16iu u 7 5",
+        ],
+    ),
+    (
+        "Lepc Lepcha",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5j4 1j 4 e 4 2",
+        ],
+    ),
+    (
+        "Limb Limbu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4xs u 2 b 5 b 5 0 4 b",
+        ],
+    ),
+    (
+        "Lina Linear_A",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fr4 8m a l b 7",
+        ],
+    ),
+    (
+        "Linb Linear_B",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ekg b 2 p 2 i 2 1 2 e 3 d z 3e",
+        ],
+    ),
+    (
+        "Lisu Lisu",
+        [
+            "",
+            "",
+            "1kts 0",
+            "",
+            "",
+            "",
+            "",
+            "wk0 1b o8h 0",
+        ],
+    ),
+    (
+        "Lyci Lycian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f28 s",
+        ],
+    ),
+    (
+        "Lydi Lydian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gdc p 6 0",
+        ],
+    ),
+    (
+        "Mahajani Mahj",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hzk 12",
+        ],
+    ),
+    (
+        "Maka Makasar",
+        [
+            "1ko0 o",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ko0 o",
+        ],
+    ),
+    (
+        "Malayalam Mlym",
+        [
+            "",
+            "",
+            "2kk 0",
+            "",
+            "",
+            "",
+            "",
+            "2kg c 2 2 2 1e 2 2 2 5 5 f 3 p",
+        ],
+    ),
+    (
+        "Mand Mandaic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1mo r 3 0",
+        ],
+    ),
+    (
+        "Mani Manichaean",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gow 12 5 b",
+        ],
+    ),
+    (
+        "Marc Marchen",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1k6o v 3 l 2 d",
+        ],
+    ),
+    (
+        "Medefaidrin Medf",
+        [
+            "20cg 2i",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20cg 2i",
+        ],
+    ),
+    (
+        "Meetei_Mayek Mtei",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xr4 m 5m 19 3 9",
+        ],
+    ),
+    (
+        "Mend Mende_Kikakui",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2oe8 5g 3 f",
+        ],
+    ),
+    (
+        "Merc Meroitic_Cursive",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ggw n 5 j 3 19",
+        ],
+    ),
+    (
+        "Mero Meroitic_Hieroglyphs",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gg0 v",
+        ],
+    ),
+    (
+        "Miao Plrd",
+        [
+            "",
+            "20jp 5 5 0 1c 8",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20hs 22 5 1k 8 g",
+        ],
+    ),
+    (
+        "Modi Modi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1iww 1w c 9",
+        ],
+    ),
+    (
+        "Mong Mongolian",
+        [
+            "4u0 0",
+            "",
+            "",
+            "4r3 0",
+            "",
+            "",
+            "",
+            "4qo 1 3 0 2 j 7 2g 8 16 1e46 c",
+        ],
+    ),
+    (
+        "Mro Mroo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zk0 u 2 9 5 1",
+        ],
+    ),
+    (
+        "Mult Multani",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1i80 6 2 0 2 3 2 e 2 a",
+        ],
+    ),
+    (
+        "Myanmar Mymr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1j2o j",
+            "35s 4f u9t u 2q v le9 j",
+        ],
+    ),
+    (
+        "Nabataean Nbat",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g8w u 9 8",
+        ],
+    ),
+    (
+        "Nag_Mundari Nagm",
+        [
+            "",
+            "",
+            "",
+            "",
+            "2nrk 15",
+            "",
+            "",
+            "2nrk 15",
+        ],
+    ),
+    (
+        "Nand Nandinagari",
+        [
+            "",
+            "1jmo 7 3 19 3 a",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jmo 7 3 19 3 a",
+        ],
+    ),
+    (
+        "Narb Old_North_Arabian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gn4 v",
+        ],
+    ),
+    (
+        "New_Tai_Lue Talu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "51c 17 5 p 7 a 4 1",
+        ],
+    ),
+    (
+        "Newa Newa",
+        [
+            "1ila 0",
+            "1ilb 0",
+            "1il6 0 6 1",
+            "",
+            "",
+            "",
+            "",
+            "1iio 2j 2 4",
+        ],
+    ),
+    (
+        "Nko Nkoo",
+        [
+            "1kt 2",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1j4 1m 3 2",
+        ],
+    ),
+    (
+        "Nshu Nushu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20o1 0 cy7 az",
+        ],
+    ),
+    (
+        "Ogam Ogham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4g0 s",
+        ],
+    ),
+    (
+        "Ol_Chiki Olck",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5lc 1b",
+        ],
+    ),
+    (
+        "Ol_Onal Onao",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2nyo 16 5 0",
+            "2nyo 16 5 0",
+        ],
+    ),
+    (
+        "Old_Permic Perm",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f80 16",
+        ],
+    ),
+    (
+        "Old_Persian Xpeo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fa8 z 5 d",
+        ],
+    ),
+    (
+        "Old_Sogdian Sogo",
+        [
+            "1hj4 13",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hj4 13",
+        ],
+    ),
+    (
+        "Old_South_Arabian Sarb",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gm8 v",
+        ],
+    ),
+    (
+        "Old_Turkic Orkh",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gxs 20",
+        ],
+    ),
+    (
+        "Old_Uyghur Ougr",
+        [
+            "",
+            "",
+            "",
+            "1hm8 p",
+            "",
+            "",
+            "",
+            "1hm8 p",
+        ],
+    ),
+    (
+        "Oriya Orya",
+        [
+            "",
+            "",
+            "28l 0",
+            "",
+            "",
+            "",
+            "",
+            "269 2 2 7 3 1 3 l 2 6 2 1 2 4 3 8 3 1 3 2 8 2 5 1 2 4 3 h",
+        ],
+    ),
+    (
+        "Osage Osge",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fhs z 5 z",
+        ],
+    ),
+    (
+        "Osma Osmanya",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fgg t 3 9",
+        ],
+    ),
+    (
+        "Palm Palmyrene",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g80 v",
+        ],
+    ),
+    (
+        "Pau_Cin_Hau Pauc",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1juo 1k",
+        ],
+    ),
+    (
+        "Phag Phags_Pa",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "x8g 1j",
+        ],
+    ),
+    (
+        "Phlp Psalter_Pahlavi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gu8 h 8 3 d 6",
+        ],
+    ),
+    (
+        "Phnx Phoenician",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gcg r 4 0",
+        ],
+    ),
+    (
+        "Rejang Rjng",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xf4 z c 0",
+        ],
+    ),
+    (
+        "Runic Runr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4gw 22 4 a",
+        ],
+    ),
+    (
+        "Samaritan Samr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1kw 19 3 e",
+        ],
+    ),
+    (
+        "Saur Saurashtra",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xa8 1x 9 b",
+        ],
+    ),
+    (
+        "Sgnw SignWriting",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2l8g i3 g 4 2 e",
+        ],
+    ),
+    (
+        "Sharada Shrd",
+        [
+            "",
+            "",
+            "1i32 1",
+            "",
+            "",
+            "",
+            "",
+            "1i0w 2n",
+        ],
+    ),
+    (
+        "Shavian Shaw",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ff4 1b",
+        ],
+    ),
+    (
+        "Sidd Siddham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1itc 1h 3 11",
+        ],
+    ),
+    (
+        "Sinh Sinhala",
+        [
+            "",
+            "",
+            "2o1 0",
+            "",
+            "",
+            "",
+            "",
+            "2o1 2 2 h 4 n 2 8 2 0 3 6 4 0 5 5 2 0 2 7 7 9 3 2 1fcd j",
+        ],
+    ),
+    (
+        "Sogd Sogdian",
+        [
+            "1hkg 15",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hkg 15",
+        ],
+    ),
+    (
+        "Sora Sora_Sompeng",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hw0 o 8 9",
+        ],
+    ),
+    (
+        "Soyo Soyombo",
+        [
+            "1jtp 0",
+            "1jt0 1",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jrk 2a",
+        ],
+    ),
+    (
+        "Sund Sundanese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5fk 1r 75 7",
+        ],
+    ),
+    (
+        "Sunu Sunuwar",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1k1s x f 9",
+            "1k1s x f 9",
+        ],
+    ),
+    (
+        "Sylo Syloti_Nagri",
+        [
+            "",
+            "",
+            "x7w 0",
+            "",
+            "",
+            "",
+            "",
+            "x6o 18",
+        ],
+    ),
+    (
+        "Syrc Syriac",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ds d 2 1n 3 2 7l a",
+        ],
+    ),
+    (
+        "Tagalog Tglg",
+        [
+            "",
+            "",
+            "",
+            "4jx 0 8 0 a 0",
+            "",
+            "",
+            "",
+            "4jk l a 0",
+        ],
+    ),
+    (
+        "Tagb Tagbanwa",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4m8 c 2 2 2 1",
+        ],
+    ),
+    (
+        "Tai_Le Tale",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "500 t 3 4",
+        ],
+    ),
+    (
+        "Tai_Viet Tavt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xog 1u p 4",
+        ],
+    ),
+    (
+        "Takr Takri",
+        [
+            "",
+            "1j20 0",
+            "",
+            "1j21 0",
+            "",
+            "",
+            "",
+            "1j0g 1l 7 9",
+        ],
+    ),
+    (
+        "Tamil Taml",
+        [
+            "",
+            "1ku8 1d e 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "29u 1 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 5 4 4 2 2 3 3 0 7 0 f k 1ih2 1d e 0",
+        ],
+    ),
+    (
+        "Tang Tangut",
+        [
+            "25f1 4",
+            "25f6 5",
+            "260j c e9 8",
+            "",
+            "",
+            "",
+            "",
+            "20o0 0 w 4qf 9 lb e9 8",
+        ],
+    ),
+    (
+        "Tangsa Tnsa",
+        [
+            "",
+            "",
+            "",
+            "1zlc 26 2 9",
+            "",
+            "",
+            "",
+            "1zlc 26 2 9",
+        ],
+    ),
+    (
+        "Telu Telugu",
+        [
+            "2dg 0",
+            "2gn 0",
+            "",
+            "2f0 0 x 0",
+            "",
+            "",
+            "",
+            "2dc c 2 2 2 m 2 f 3 8 2 2 2 3 8 1 2 2 3 0 3 3 3 9 8 8",
+        ],
+    ),
+    (
+        "Tfng Tifinagh",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "8xc 1j 8 1 f 0",
+        ],
+    ),
+    (
+        "Thaa Thaana",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hc 1d",
+        ],
+    ),
+    (
+        "Thai Thai",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2rl 1l 6 r",
+        ],
+    ),
+    (
+        "Tibetan Tibt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2yo 1z 2 z 5 12 2 z 2 e 2 6 5 1",
+        ],
+    ),
+    (
+        "Tirh Tirhuta",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1im8 1z 9 9",
+        ],
+    ),
+    (
+        "Todhri Todr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fpc 1f",
+            "1fpc 1f",
+        ],
+    ),
+    (
+        "Toto Toto",
+        [
+            "",
+            "",
+            "",
+            "2nbk u",
+            "",
+            "",
+            "",
+            "2nbk u",
+        ],
+    ),
+    (
+        "Tulu_Tigalari Tutg",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1if4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1",
+            "1if4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1",
+        ],
+    ),
+    (
+        "Ugar Ugaritic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f9c t 2 0",
+        ],
+    ),
+    (
+        "Unknown Zzzz",
+        [
+            "128 0 14 0 2v 0 em 2 5w 0 8b 0 3c 0 b2 0 3k 0 2d0 0 t4 16 3 2 2yj 2 n o 5 e gc 4 kh 0 lvg 4 1j4 0 9 1 91 1 j79 1 j 0 jc 13 9 9 cn 13 9 15 ac 0 3b 2 dx 0 83 0 jg 0 6e 1n gy 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n o fns 2i 503 4 esv j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "2gn 0 en 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 2xb 0 1i 0 gg 0 xc 0 n23 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 a 4g 1 117 1d e 0 3zl 8 bnx 5 5 0 1c 8 2j 1 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 1 a9 1l 6 0 18s 0 qe 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 3br 0 jd 2 o9 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 3 h0x 0 2jo 15 2 2 3 1 73 r ak 0 3r 1 i3 0 6 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 b a 9 193 0 fuc 0 c 1 5c2 de 17 8 jr9 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9 xr2 6 hlv 3t6",
+            "17h 0 gj u 2 1 7 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 1d 1 ho 0 jq 0 29b 0 1c 0 dw a mg0 2 1j5 1 f 1 2 0 2 4 p 2 gjy 0 am f 3k 0 1b 1 1gx a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu p 6f 5 25 0 16f 0 3r 6 oa f 441 2q bjy 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m a 37 fa 1 2l2 u oi u 10y 6 2 3 2 1 2 e 2xr 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6 xyg 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 1q7 9 s7 g 2 14 4 r 446 0 a s onx 0 z 0 6ln j 2fm 5 7a 1p y 0 u9 15 3j7 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1 116p 0 i6v 38f",
+            "9h8 3 ds 0 3tvl h9",
+            "1p3 0 3p3 1 1c 0 7e 1 1i5 2 2pn 1 nae 2 d 2 ijo 1f 1fx 11 4 s 9 1 8j 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1 ku j yl x f 9 o1 0 45i 32y 5qe 1l 2dj 1l 686 0 cg1 6x 7 c3 4kd 16 5 0 3oz 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "oo 1 7 3 8 0 2 0 l 0 b2 0 13 1 1f 1 4 0 1k 7 s 3 7 a 7j 0 1p 1 2u d 1o 1 1e 1 g 0 t 1 2 0 c 4 w 0 3 4 6m 0 9 1 3 1 n 0 8 0 2 2 5 1 a 1 3 1 5 7 2 3 3 0 6 1 q 1 4 0 7 3 3 1 n 0 8 0 3 0 3 0 3 1 2 0 6 3 3 1 4 2 2 6 5 0 2 6 i 9 4 0 a 0 4 0 n 0 8 0 3 0 6 1 b 0 4 0 4 1 2 e 5 1 d 6 8 0 4 0 9 1 3 1 n 0 8 0 3 0 6 1 a 1 3 1 4 6 4 3 3 0 6 1 j 9 3 0 7 2 4 0 5 2 3 0 2 0 3 2 3 2 4 2 d 3 6 2 4 0 5 1 2 5 2 d m 4 e 0 4 0 o 0 h 1 a 0 4 0 5 6 3 0 4 1 2 1 5 1 b 6 n 0 4 0 o 0 b 0 6 1 a 0 4 0 5 6 3 5 3 0 5 1 b 0 4 b e 0 4 0 1g 0 4 0 7 3 h 1 r 0 4 0 j 2 p 0 a 0 2 1 8 2 2 3 7 0 2 0 9 5 b 1 4 b 1n 3 u 10 3 0 2 0 6 0 p 0 2 0 o 1 6 0 2 0 8 0 b 1 5 v 21 0 11 3 14 0 11 0 g 0 e 10 5j 0 2 4 2 1 ai 0 5 1 8 0 2 0 5 1 16 0 5 1 y 0 5 1 8 0 2 0 5 1 g 0 1m 0 5 1 1w 1 x 2 r 5 2f 1 7 1 im 2 2i 6 n 8 p 8 l b e 0 4 0 3 b 2n 1 b 5 b 5 r 5 2i 6 18 4 1z 9 w 0 d 3 d 3 2 2 17 1 6 a 19 3 r 5 c 2 1r 1 1u 0 u 1 c 5 b 5 f 1 w 1c 26 0 4n 7 1p 2 g 2 1r 4 18 1 c 7 18 4 ev 1 7 1 13 1 7 1 9 0 2 0 2 0 2 0 w 1 1i 0 g 0 f 1 7 0 k 1 4 0 a 0 2u 0 d 1 s 0 e 2 y e y e 3x 3 ij l c k 1ed 1 x 0 9q 4 1a 0 2 4 2 1 1l 6 3 d p 8 8 0 8 0 8 0 8 0 8 0 8 0 8 0 8 0 3j x r 0 2i b 5z p 29 0 2f 1 2w 4 18 0 2n 0 2f 8 1d 0 mlq 2 1k 8 9p j 55 7 5r 1 3 0 2 0 9 k 1o 2 b 5 1l 7 1z 7 d 5 39 a v 2 27 0 c 3 y 0 1k 8 f 1 b 1 2w n t 9 7 1 7 1 7 8 8 0 8 0 1p 3 3j 1 b 5 8md b o 3 1e 6ir a7 1 2z 11 8 b 6 4 r 0 6 0 2 0 3 0 3 0 3i f ce 1 1j 6 2 v 17 5 1g 0 k 0 5 3 6 0 3s 1 2 0 5b 2 7 1 7 1 7 1 4 2 8 0 8 9 6 1 d 0 r 0 k 0 3 0 g 1 f x 3g 4 4 3 1a 2 2h 0 e 2 2 1a 1b 3l u 2 1e e t 3 11 8 v 4 18 4 v 0 12 3 f 15 4f 1 b 5 11 3 11 3 15 7 1h a d 0 g 0 8 0 3 0 c 0 g 0 8 0 3 2 1h b 8o 8 n 9 9 n 7 0 17 0 a 1w 7 1 2 0 19 0 3 2 2 1 o 0 21 7 a 1b k 0 3 4 y 2 s 4 2 1r 1l 3 l 1 1f 0 3 4 9 0 4 0 u 1 4 3 b 6 a 6 1t v 14 3 d 8 1j 2 u 1 s 4 r 6 5 b 8 27 22 1i 1g c 1g 6 1b 7 b 5 13 2 u 7 3 5r w 0 17 0 4 1 3 f 4 1i 19 7 17 l r 11 t j o 8 27 3 11 8 1x 9 2 1 q 6 b 5 1i 0 j 7 14 8 2p 0 l a j 0 1c 1p 8 0 2 0 5 0 g 0 c 5 1o 4 b 5 5 0 9 1 3 1 n 0 8 0 3 0 6 0 b 1 3 1 4 1 2 5 2 4 8 1 8 2 6 a b 0 2 1 2 0 13 0 b 0 2 1 2 0 5 0 b 0 3 7 3 s 2l 0 6 t 21 7 b 4l 1j 1 13 x 1y a b 5 e i 1n 5 b 5 l r s 1 g 3 o 54 1p 2r 2c b 9 1 2 1 9 0 3 0 v 0 3 1 d 8 b 1x 9 1 1b 1 c q 21 7 2c c 22 6 b 51 z d b 5 a 0 1a 0 f 9 u 2 x 1 n 0 f 20 8 0 3 0 19 2 2 0 3 0 a 7 b 5 7 0 3 0 12 0 3 0 7 6 b 8l q 6 i 0 16 2 u 2c 2 e 1f c po 2t 34 0 6 a 5h 217 2s c uv 9 330 4 g8 5a0 1n 1c5 fu 6 w 0 b 3 2a 0 b 5 v 1 7 9 1z 9 b 0 8 0 m 4 k bz 1n 5h 2k 2s 24 3 1m 6 i 1r 6 a 3 d 4qh 7 yf 14 b 6w6 5 0 8 0 3 0 84 e 2 s 4 1 2 d 5 7 b1 1s3 30 4 e 2 a 6 b 1 9 317 6z 5 c5 23 1b 1 o 8 39 1n 6v 9 14 1 5f k 1z 3d l b l b 2g 8 q 3q 2e 0 20 0 3 1 2 1 3 1 5 0 d 0 2 0 8 0 1u 0 5 1 9 0 8 0 t 0 5 0 6 0 2 2 8 0 9h 1 85 1 jj e 6 0 g un w 5 7 5w 8 0 i 1 8 0 3 0 6 4 1r w 2 33 1a 2 f 1 b 3 3 8v w g 1n 4 2 cv 17 5x 18 3 2 db 8 0 5 0 3 0 g 0 5i 1 h 14 25 3 b 3 3 ls 1x 23 1q 5d 5 0 s 0 3 0 2 1 2 0 b 0 5 0 2 0 2 5 2 3 2 0 2 0 2 0 4 0 3 0 2 1 2 0 2 0 2 0 2 0 2 0 3 0 2 1 5 0 8 0 5 0 5 0 2 0 b 0 i 4 4 0 6 0 i 1f 3 7h 19 3 2t b g 1 g 0 g 0 12 9 4v 1j u c 19 3 a 6 3 d 7 49 rd 3 i 2 e 2 3c 3 2o 5 d 3 2 e d 3 1l 7 b 5 15 7 v 1 d 3 3 1p 9h b f 1 e 2 b 4 1l 6 g 1 c 5 a 6 44 0 2v sl wyp v 37f 5 67 1 4g3 d 5rm e hb 1wh f3 15t 3t8 4 38h f974 2 t 2p 3j 6p 47bj",
+        ],
+    ),
+    (
+        "Vai Vaii",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "wlc 8b",
+        ],
+    ),
+    (
+        "Vith Vithkuqi",
+        [
+            "",
+            "",
+            "",
+            "1fn4 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1",
+            "",
+            "",
+            "",
+            "1fn4 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1",
+        ],
+    ),
+    (
+        "Wancho Wcho",
+        [
+            "",
+            "2ncw 1l 6 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2ncw 1l 6 0",
+        ],
+    ),
+    (
+        "Wara Warang_Citi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jfk 2a d 0",
+        ],
+    ),
+    (
+        "Yezi Yezidi",
+        [
+            "",
+            "",
+            "1hfk 15 2 2 3 1",
+            "",
+            "",
+            "",
+            "",
+            "1hfk 15 2 2 3 1",
+        ],
+    ),
+    (
+        "Yi Yiii",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "vls wc 4 1i",
+        ],
+    ),
+    (
+        "Zanabazar_Square Zanb",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jpc 1z",
+        ],
+    ),
+];
+
+pub(super) static SCRIPT_EXTENSIONS_RANGES: &[(&str, [&str; 8])] = &[
+    (
+        "Adlam Adlm",
+        [
+            "",
+            "2onf 0",
+            "",
+            "17j 0",
+            "",
+            "",
+            "6dr 0 2r6 0",
+            "17j 0 x 0 55b 0 2r6 0 2fgf 23 5 9 5 1",
+        ],
+    ),
+    (
+        "Aghb Caucasian_Albanian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lg 0 19 0 19 0",
+            "lg 0 19 0 19 0 1exe 1f c 0",
+        ],
+    ),
+    (
+        "Ahom Ahom",
+        [
+            "1j4q 0",
+            "",
+            "",
+            "1j5s 6",
+            "",
+            "",
+            "",
+            "1j40 q 3 e 5 m",
+        ],
+    ),
+    (
+        "Anatolian_Hieroglyphs Hluw",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1s00 g6",
+        ],
+    ),
+    (
+        "Arab Arabic",
+        [
+            "1qr 0",
+            "",
+            "1q6 9",
+            "17h 0 gj u 2 1 7 7 m 0 j a 1bzk 0 ak h 3k 0 1b 1",
+            "1hj1 2",
+            "",
+            "1p3 0 4oo 0 2r6 0 18ch 2 1k 0",
+            "16o 4 2 5y 2 x 29 1b 6p u 2 1 6 22 2 s 4ls 0 2r6 0 14i7 36 h cc 3 1h 8 0 x f 35 4 2 3q ro r 291 u 1w 2 1k 3 181t 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 1h 1",
+        ],
+    ),
+    (
+        "Armenian Armn",
+        [
+            "128 0 14 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lk 0",
+            "lk 0 fd 11 3 1d 3 2 1chw 4",
+        ],
+    ),
+    (
+        "Armi Imperial_Aramaic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g74 l 2 8",
+        ],
+    ),
+    (
+        "Avestan Avst",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 8zd 1",
+            "53 0 8zd 1 17m7 1h 4 6",
+        ],
+    ),
+    (
+        "Bali Balinese",
+        [
+            "",
+            "",
+            "",
+            "5e4 0 1d 1",
+            "",
+            "",
+            "5e6 1 1c 0",
+            "5c0 24 2 1d",
+        ],
+    ),
+    (
+        "Bamu Bamum",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "www 2f 124p fs",
+        ],
+    ),
+    (
+        "Bass Bassa_Vah",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zo0 t 3 5",
+        ],
+    ),
+    (
+        "Batak Batk",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5hc 1f 9 3",
+        ],
+    ),
+    (
+        "Beng Bengali",
+        [
+            "1z2 0 3pu 0 2 0 3 1 2 0 9 0 9 0 3 0 8 1",
+            "5pu 0",
+            "",
+            "",
+            "",
+            "",
+            "jg 0",
+            "jg 0 1at 1 i 1 r 3 2 7 3 1 3 l 2 6 2 0 4 3 3 8 3 1 3 3 9 0 5 1 2 4 3 o 3pu 0 2 0 3 1 2 0 9 0 9 0 3 0 5 0 3 2 rne 0",
+        ],
+    ),
+    (
+        "Bhaiksuki Bhks",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1k3k 8 2 18 2 d b s",
+        ],
+    ),
+    (
+        "Bopo Bopomofo",
+        [
+            "9pr 0",
+            "",
+            "9tn 4",
+            "",
+            "",
+            "",
+            "jr 0 2 2 e 0",
+            "jr 0 2 2 e 0 h 1 8wm 2 5 9 2 c b 3 3 0 7 0 5g 0 a 16 35 v 14ee 1 7v 4",
+        ],
+    ),
+    (
+        "Brah Brahmi",
+        [
+            "",
+            "",
+            "",
+            "1htc 5",
+            "",
+            "",
+            "",
+            "1hq8 25 5 z a 0",
+        ],
+    ),
+    (
+        "Brai Braille",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "7wg 73",
+        ],
+    ),
+    (
+        "Bugi Buginese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "54w r 3 1 sds 0",
+        ],
+    ),
+    (
+        "Buhd Buhid",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4l1 1 a j",
+        ],
+    ),
+    (
+        "Cakm Chakma",
+        [
+            "1hz8 2",
+            "",
+            "1hzb 0",
+            "",
+            "",
+            "",
+            "",
+            "1ye 9 18x 9 1epj 1g 2 h",
+        ],
+    ),
+    (
+        "Canadian_Aboriginal Cans",
+        [
+            "",
+            "",
+            "",
+            "1ju8 f",
+            "",
+            "",
+            "",
+            "3y8 hr fl 1x 1ewr f",
+        ],
+    ),
+    (
+        "Cari Carian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 68z 0 3 0 2qc 0",
+            "53 0 68z 0 3 0 2qc 0 15yn 1c",
+        ],
+    ),
+    (
+        "Cham Cham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xkw 1i a d 3 9 3 3",
+        ],
+    ),
+    (
+        "Cher Cherokee",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lc 2 2 0 7 1 n 1 c 1",
+            "lc 2 2 0 7 1 n 1 c 1 38v 2d 3 5 twz 27",
+        ],
+    ),
+    (
+        "Chorasmian Chrs",
+        [
+            "",
+            "",
+            "1ho0 r",
+            "",
+            "",
+            "",
+            "",
+            "1ho0 r",
+        ],
+    ),
+    (
+        "Common Zyyy",
+        [
+            "8my 2 n o 5 e gc 4 2b2q j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "6cv 0 2ai 0 1i 0 gg 0 1rir 1 opq 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "8lz 0 jd 2 nu6 7 v7 1 h0x 0 1bap 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9",
+            "6gw 0 2oj a 14v5 1 mn7 1 iul 37 fa 1 7ar 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2k74 j 749 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "9h8 3 ds 0",
+            "53 0 ed 0 b 0 2 2 2 0 a 0 2 0 4b 0 3uf 2 1uq 0 b 0 3 0 qy 2 1ym 0 p 1 b 0 5 0 bz f ds 0 2901 6x 7 c3 8an 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "0 1s r 5 r 1a 2 b 2 1 2 4 o 0 w 0 ci 2 2 9 2 0 4 0 2 8 2 0 2 5 6 4 3 j 3j 0 7 0 2 0 hq 0 60 0 ed 0 125 0 ba 3 36w b 3 w 2 u 2 9 2 1 2 6 2 a 4 a 2 e i w 1s 11 2 2 3 5 2 q 2 g 16 2 5 ih n a m pr 75 hf 3 v 2 2w e9 m 2 n 3 9 2 3 2 0 2 p bn 0 4 0 e 0 e 0 m 0 eq n w 0 1e e d 3 4i 9 6 2n w 0 535 1r hl5 p 2v 2 r5 0 f 1 gbp 9 n k 3 b 2 i 2 3 44 0 2 v r 5 r 5 3k 6 2 6 b 4 b7 c 1g 18 13wk 6x 7 c3 4d 37 1p 6t b 12 3 1p 4 g 9 1 8 t 5 1o 5y j d j d 2e s 6 3s 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 83 3 1d 41e 1v 25 1o jn 17 5 2r d e 3 e 2 e 2 10 b 4t 1l p 2 1 e 17 5 8 o 5 4b rb 5 g 4 c 4 3a 5 2m 7 b 5 0 g b 5 1j 9 9 7 13 9 t 3 b 5 1 1r 9f d d 3 c 4 9 6 1j 8 e 3 a 7 8 8 42 2 2t gvm0 0 v 2n",
+        ],
+    ),
+    (
+        "Copt Coptic Qaac",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 g9 0 4 1 2 0 31 1 8f6 0",
+            "53 0 g9 0 4 1 2 0 31 1 31 d 80h 37 6 6 7s 0 1615 r",
+        ],
+    ),
+    (
+        "Cpmn Cypro_Minoan",
+        [
+            "",
+            "",
+            "",
+            "1erk 1 973 2q",
+            "",
+            "",
+            "",
+            "1erk 1 973 2q",
+        ],
+    ),
+    (
+        "Cprt Cypriot",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1erk 2 5 18 4 8 1c1 5 3 0 2 17 2 1 4 0 3 0",
+        ],
+    ),
+    (
+        "Cuneiform Xsux",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1kw0 pl 2v 32 2 4 c 5f",
+        ],
+    ),
+    (
+        "Cyrillic Cyrl",
+        [
+            "",
+            "",
+            "5x4 0",
+            "",
+            "2muo 1p y 0",
+            "",
+            "jg 0 1w 2 2 0 2 0 2 0 3 0 6 0 514 1",
+            "jg 0 1w 2 2 0 2 0 2 0 3 0 6 0 6n 8f 4lt a 4h 0 25 0 3k 0 354 v 1w 0 np9 2n han 1 18n5 1p y 0",
+        ],
+    ),
+    (
+        "Deseret Dsrt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fcw 27",
+        ],
+    ),
+    (
+        "Deva Devanagari",
+        [
+            "xdq 1",
+            "1ub 1",
+            "",
+            "",
+            "1jwg 9",
+            "",
+            "jg 0",
+            "jg 0 18k 2a 3 16 3td 12 2 1 s7 0 qps 9 4n v mip 9",
+        ],
+    ),
+    (
+        "Diak Dives_Akuru",
+        [
+            "",
+            "",
+            "1ji8 6 3 0 3 7 2 1 2 t 2 1 3 b a 9",
+            "",
+            "",
+            "",
+            "",
+            "1ji8 6 3 0 3 7 2 1 2 t 2 1 3 b a 9",
+        ],
+    ),
+    (
+        "Dogr Dogra",
+        [
+            "1us b vcx 9 m2v 1n",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1us b vcx 9 m2v 1n",
+        ],
+    ),
+    (
+        "Dupl Duployan",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 gg 1 2 0 p 1 8ig 0",
+            "53 0 gg 1 2 0 p 1 8ig 0 26kk 2y 6 c 4 8 8 9 3 7",
+        ],
+    ),
+    (
+        "Egyp Egyptian_Hieroglyphs",
+        [
+            "",
+            "1ovk 8",
+            "",
+            "",
+            "1ovj 0 a s",
+            "",
+            "1oww 32y",
+            "1o1s ut b 32y",
+        ],
+    ),
+    (
+        "Elba Elbasan",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 ge 0",
+            "53 0 ge 0 1eyj 13",
+        ],
+    ),
+    (
+        "Elym Elymaic",
+        [
+            "",
+            "1hpc m",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hpc m",
+        ],
+    ),
+    (
+        "Ethi Ethiopic",
+        [
+            "",
+            "",
+            "",
+            "2odc 6 2 3 2 1 2 e",
+            "",
+            "",
+            "lq 0",
+            "lq 0 2ya 20 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 3 v 4 p 547 m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 opv 5 3 5 3 5 a 6 2 6 1qk2 6 2 3 2 1 2 e",
+        ],
+    ),
+    (
+        "Gara Garay",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "170 0 f 0 4 0 1fz5 11 4 s 9 1",
+            "170 0 f 0 4 0 1fz5 11 4 s 9 1",
+        ],
+    ),
+    (
+        "Geor Georgian",
+        [
+            "5n4 16 3 2",
+            "",
+            "13d 0",
+            "",
+            "",
+            "",
+            "53 0 ya 0 5ap 0 2qf 0",
+            "53 0 ya 0 26v 11 2 0 6 0 3 1b 2a9 16 3 2 pn 0 2hy 11 2 0 6 0 78 0",
+        ],
+    ),
+    (
+        "Glag Glagolitic",
+        [
+            "",
+            "",
+            "",
+            "8q7 0 1c 0",
+            "",
+            "",
+            "53 0 gc 0 2 0 hw 0 29e 0 31b 0",
+            "53 0 gc 0 2 0 an 0 3 0 76 0 29e 0 31b 0 2au 2n dg 0 nqk 0 1pxt 6 2 g 3 6 2 1 2 4",
+        ],
+    ),
+    (
+        "Gong Gunjala_Gondi",
+        [
+            "1us 1 1iij 5 2 1 2 10 2 1 2 5 8 9",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0",
+            "53 0 1pp 1 1iij 5 2 1 2 10 2 1 2 5 8 9",
+        ],
+    ),
+    (
+        "Gonm Masaram_Gondi",
+        [
+            "",
+            "1us 1",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1us 1 1ifv 6 2 1 2 17 4 0 2 1 2 8 9 9",
+        ],
+    ),
+    (
+        "Goth Gothic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 gd 1 3 0 15 0",
+            "53 0 gd 1 3 0 15 0 1ekf q",
+        ],
+    ),
+    (
+        "Gran Grantha",
+        [
+            "2ay 0 b 0 1q 0 1g08 0",
+            "1kuo 1 2 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 ht d 3bx 0 2 1 v 2 4 1 s7 0 1btc 3 2 7 3 1 3 l 2 6 2 1 2 4 2 9 3 1 3 2 3 0 7 0 6 6 3 6 4 4 2fw 1 2 0",
+        ],
+    ),
+    (
+        "Greek Grek",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 g9 1 3 0 2 0 2 0 b 0 2p 0 5pl 0",
+            "53 0 g9 1 3 0 2 0 2 0 b 0 1b 0 3 0 17 7 3 3 2 0 5 0 2 0 2 2 2 0 2 j 2 1q f f 4yv 4 1f 4 5 4 2d 2 8v l 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 e 2 d 3 5 2 i 3 2 2 8 2n 0 5l 0 rb3 0 gyj 26 i 0 155s 1x",
+        ],
+    ),
+    (
+        "Gujarati Gujr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 7w 2 2 8 2 2 2 l 2 6 2 1 2 4 3 9 2 2 2 2 3 0 g 3 3 b 8 6 v1t 9",
+        ],
+    ),
+    (
+        "Gukh Gurung_Khema",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ut 0 1vvf 1l",
+            "1ut 0 1vvf 1l",
+        ],
+    ),
+    (
+        "Gurmukhi Guru",
+        [
+            "22e 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 4c 2 2 5 5 1 3 l 2 6 2 1 2 1 2 1 3 0 2 4 5 1 3 2 4 0 8 3 2 0 8 g v5m 9",
+        ],
+    ),
+    (
+        "Han Hani",
+        [
+            "vl7 4",
+            "a2n 0",
+            "fcm 9 g8h c 1dw 7 13op 1 1peu 6 hlv 3t6",
+            "vlp 2 152b 1 1pff 1 386 3",
+            "3tbt 0 i6v 38f",
+            "43qo h9",
+            "53 0 9bt f dh 1 a 0",
+            "53 0 91l p 2 2g d 5x r f 2 2 2 c 2 c 2 c 3 0 7 8 58 0 45 f x 11 a 0 1d 13 1l 1c g b 1g 0 2h o b 4 2p u 2 533 1t g73 1dt 7 g6x a5 3 2x oc 1 7v 4 m7x 1 d 1 jn3 h 63j 1 2pb wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Hang Hangul",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "3cw 73 5xe 2 5 9 2 c f 2 7 0 5g 0 1i 2l 36 u 1u u nhe s hw 8mb d m 5 1c 7ka 1 7v 4 1n u 4 5 3 5 3 5 3 2",
+        ],
+    ),
+    (
+        "Hanifi_Rohingya Rohg",
+        [
+            "170 0 f 0 4 0 x 0 44 0 1fsc 13 9 9",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "170 0 f 0 4 0 x 0 44 0 1fsc 13 9 9",
+        ],
+    ),
+    (
+        "Hano Hanunoo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4kg m",
+        ],
+    ),
+    (
+        "Hatr Hatran",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gbk i 2 1 6 4",
+        ],
+    ),
+    (
+        "Hebr Hebrew",
+        [
+            "167 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lj 1",
+            "lj 1 i1 1i 9 q 5 5 1cfd p 2 4 2 0 2 1 2 1 2 9",
+        ],
+    ),
+    (
+        "Hira Hiragana",
+        [
+            "",
+            "2dlc 2",
+            "",
+            "2djz 0",
+            "2dki 0",
+            "",
+            "",
+            "9hd 2 5 9 2 c h 5 2 0 5 1 4 2d 3 7 2j 1 14jt 1 7v 4 b 0 1a 1 yua 7y j 0 u 2 cry 0",
+        ],
+    ),
+    (
+        "Hmng Pahawh_Hmong",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zpc 1x b 9 2 6 2 k 6 i",
+        ],
+    ),
+    (
+        "Hmnp Nyiakeng_Puachue_Hmong",
+        [
+            "",
+            "2n0g 18 4 d 3 9 5 1",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2n0g 18 4 d 3 9 5 1",
+        ],
+    ),
+    (
+        "Hrkt Katakana_Or_Hiragana",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        ],
+    ),
+    (
+        "Hung Old_Hungarian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6e2 0 3 0 2qc 0 g 0",
+            "6e2 0 3 0 2qc 0 g 0 17wf 1e e 1e 8 5",
+        ],
+    ),
+    (
+        "Inherited Qaai Zinh",
+        [
+            "",
+            "1ub 1",
+            "5a7 1 mw 0",
+            "5a9 d 2e5u 19 3 m",
+            "",
+            "",
+            "lc e 2 1 2 0 d 0 3 2 8 1 2 1 13 0 6 0",
+            "lr 0 3 0 2 b 2 1 4 6 3 0 3 f 2 1 2 h 2 4 2 3 169 1 3fg u l0 1h 2 0 2 4 el 1 5f v 17o1 f h d r4 0 14hv 19 3 m f5 2 i 7 3 6 v 3 h45v 6n",
+        ],
+    ),
+    (
+        "Inscriptional_Pahlavi Phli",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gtc i 6 7",
+        ],
+    ),
+    (
+        "Inscriptional_Parthian Prti",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gsg l 3 7",
+        ],
+    ),
+    (
+        "Ital Old_Italic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f5s z a 2",
+        ],
+    ),
+    (
+        "Java Javanese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xhc 25 2 a 5 1",
+        ],
+    ),
+    (
+        "Kaithi Kthi",
+        [
+            "1hvx 0",
+            "",
+            "",
+            "1hvm 0",
+            "",
+            "",
+            "94h 0",
+            "1uu 9 79e 0 o3j 9 klj 1u b 0",
+        ],
+    ),
+    (
+        "Kali Kayah_Li",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xds 1b",
+        ],
+    ),
+    (
+        "Kana Katakana",
+        [
+            "",
+            "2dlw 3",
+            "",
+            "2dbk 3 2 6 2 1 82 2",
+            "2dlh 0",
+            "",
+            "lh 0 u 0",
+            "lh 0 u 0 8v2 2 5 9 2 c h 5 2 0 5 1 2k 3 4 2n 6p f 5t 1a 2 2f 1432 1 7v 1q ytt 3 2 6 2 1 2 0 80 2 1f 0 f 3",
+        ],
+    ),
+    (
+        "Kannada Knda",
+        [
+            "2h0 0 37w 0 2 0 y 1",
+            "5pu 0",
+            "",
+            "2jh 0",
+            "2k3 0",
+            "",
+            "5oz 0",
+            "1u9 1 i 1 m3 c 2 2 2 m 2 9 2 4 3 8 2 2 2 3 8 1 7 1 2 3 3 9 2 2 34t 0 2 1 7 0 o 0 2 0 ri4 5",
+        ],
+    ),
+    (
+        "Kawi Kawi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "1kow g 2 14 4 r",
+            "",
+            "1kre 0",
+            "1kow g 2 14 4 s",
+        ],
+    ),
+    (
+        "Khar Kharoshthi",
+        [
+            "1gl0 1 j 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gjk 3 2 1 6 7 2 2 2 s 3 2 5 9 8 8",
+        ],
+    ),
+    (
+        "Khitan_Small_Script Kits",
+        [
+            "",
+            "",
+            "20o4 0 5cs d1",
+            "",
+            "",
+            "",
+            "26f3 0",
+            "20o4 0 5cs d1 16 0",
+        ],
+    ),
+    (
+        "Khmer Khmr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4n4 2l 3 9 7 9 dj v",
+        ],
+    ),
+    (
+        "Khoj Khojki",
+        [
+            "x80 9",
+            "",
+            "",
+            "",
+            "1i67 2",
+            "",
+            "",
+            "25i 9 v29 9 kw7 h 2 1a",
+        ],
+    ),
+    (
+        "Khudawadi Sind",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1us 1 vd7 9 l13 1m 6 9",
+        ],
+    ),
+    (
+        "Kirat_Rai Krai",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "205c 1l",
+            "205c 1l",
+        ],
+    ),
+    (
+        "Lana Tai_Tham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "55s 1q 2 s 3 a 7 9 7 d",
+        ],
+    ),
+    (
+        "Lao Laoo",
+        [
+            "",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0",
+            "",
+            "",
+            "2xa 0",
+            "",
+            "",
+            "2v5 1 2 0 2 4 2 n 2 0 2 m 3 4 2 0 2 6 2 9 3 3",
+        ],
+    ),
+    (
+        "Latin Latn",
+        [
+            "x4f 0 9 1",
+            "6cv 0 qrv 5 3 4 ps 1",
+            "wzk 7 5c 3 17 1 oi 1",
+            "x4w 1 f 1 2 0 2 4 p 2 ivg 5 2 15 2 8 16iu u",
+            "2mn9 5",
+            "",
+            "53 0 ed 0 b 0 2 2 2 0 a 0 2 0 13 e 2 1 2 0 d 0 3 2 8 1 2 1 13 0 6 0 596 0 36n 0 o1g 2 d 2",
+            "1t p 7 p 1c 0 d 0 3 0 6 m 2 u 2 cg 4 0 b 0 2 2 2 0 a 0 2 0 7 4 s e 2 1 2 0 d 0 3 2 8 1 2 1 13 0 6 0 5 c 7q 1 y3 1 1ih 0 2dh 11 7 1c 6 3 6 c 2 1x 1m 0 8 73 8g 0 1u 0 e 0 h c 2c 0 1m 1 7 0 s 0 i 14 254 v bc 0 nvt 7 r 2t 4 1u 3 1 2 0 2 7 m d 8f 0 ea 16 2 8 2 3 fpz 6 t7 p 7 p 1ly 5 2 15 2 8 16iu u 7 5",
+        ],
+    ),
+    (
+        "Lepc Lepcha",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5j4 1j 4 e 4 2",
+        ],
+    ),
+    (
+        "Limb Limbu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ut 0 32z u 2 b 5 b 5 0 4 b",
+        ],
+    ),
+    (
+        "Lina Linear_A",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1err 18 y5 8m a l b 7",
+        ],
+    ),
+    (
+        "Linb Linear_B",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ekg b 2 p 2 i 2 1 2 e 3 d z 3e 6 2 5 18 4 8",
+        ],
+    ),
+    (
+        "Lisu Lisu",
+        [
+            "",
+            "",
+            "1kts 0",
+            "",
+            "",
+            "",
+            "jg 0 h 0 8xp 1",
+            "jg 0 h 0 8xp 1 n2d 1b o8h 0",
+        ],
+    ),
+    (
+        "Lyci Lycian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6e2 0",
+            "6e2 0 18o6 s",
+        ],
+    ),
+    (
+        "Lydi Lydian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 8ze 0",
+            "53 0 8ze 0 178v p 6 0",
+        ],
+    ),
+    (
+        "Mahajani Mahj",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0",
+            "53 0 1pp b vcx 9 krb 12",
+        ],
+    ),
+    (
+        "Maka Makasar",
+        [
+            "1ko0 o",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ko0 o",
+        ],
+    ),
+    (
+        "Malayalam Mlym",
+        [
+            "x80 2",
+            "",
+            "2kk 0",
+            "",
+            "",
+            "5pu 0",
+            "",
+            "1u9 1 i 1 pn c 2 2 2 1e 2 2 2 5 5 f 3 p 317 0 o 0 ri6 2",
+        ],
+    ),
+    (
+        "Mand Mandaic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "18g 0 e8 r 3 0",
+        ],
+    ),
+    (
+        "Mani Manichaean",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "18g 0 1fgg 12 5 b",
+        ],
+    ),
+    (
+        "Marc Marchen",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1k6o v 3 l 2 d",
+        ],
+    ),
+    (
+        "Medefaidrin Medf",
+        [
+            "20cg 2i",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20cg 2i",
+        ],
+    ),
+    (
+        "Meetei_Mayek Mtei",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xr4 m 5m 19 3 9",
+        ],
+    ),
+    (
+        "Mend Mende_Kikakui",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2oe8 5g 3 f",
+        ],
+    ),
+    (
+        "Merc Meroitic_Cursive",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1ggw n 5 j 3 19",
+        ],
+    ),
+    (
+        "Mero Meroitic_Hieroglyphs",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6e5 0",
+            "6e5 0 1a1v v",
+        ],
+    ),
+    (
+        "Miao Plrd",
+        [
+            "",
+            "20jp 5 5 0 1c 8",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20hs 22 5 1k 8 g",
+        ],
+    ),
+    (
+        "Modi Modi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "x80 9 lon 1w c 9",
+        ],
+    ),
+    (
+        "Mong Mongolian",
+        [
+            "4u0 0",
+            "6cv 0",
+            "",
+            "4r3 0",
+            "",
+            "",
+            "9hd 1 6 3",
+            "4qo p 7 2g 8 16 1hh 0 34i 1 6 3 19hx c",
+        ],
+    ),
+    (
+        "Mro Mroo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1zk0 u 2 9 5 1",
+        ],
+    ),
+    (
+        "Mult Multani",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "21y 9 1g5t 6 2 0 2 3 2 e 2 a",
+        ],
+    ),
+    (
+        "Myanmar Mymr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1j2o j",
+            "35s 4f u4v 0 4y u 2q v le9 j",
+        ],
+    ),
+    (
+        "Nabataean Nbat",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g8w u 9 8",
+        ],
+    ),
+    (
+        "Nag_Mundari Nagm",
+        [
+            "",
+            "",
+            "",
+            "",
+            "2nrk 15",
+            "",
+            "",
+            "2nrk 15",
+        ],
+    ),
+    (
+        "Nand Nandinagari",
+        [
+            "",
+            "1us 1 ox 9 35m 0 9 0 8 0 rhy 5 mej 7 3 19 3 a",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1us 1 ox 9 35m 0 9 0 8 0 rhy 5 mej 7 3 19 3 a",
+        ],
+    ),
+    (
+        "Narb Old_North_Arabian",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gn4 v",
+        ],
+    ),
+    (
+        "New_Tai_Lue Talu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "51c 17 5 p 7 a 4 1",
+        ],
+    ),
+    (
+        "Newa Newa",
+        [
+            "1ila 0",
+            "1ilb 0",
+            "1il6 0 6 1",
+            "",
+            "",
+            "",
+            "",
+            "1iio 2j 2 4",
+        ],
+    ),
+    (
+        "Nko Nkoo",
+        [
+            "1kt 2",
+            "",
+            "",
+            "170 0 f 0 4 0 1ctb 1",
+            "",
+            "",
+            "",
+            "170 0 f 0 4 0 bl 1m 3 2 1cfz 1",
+        ],
+    ),
+    (
+        "Nshu Nushu",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "20o1 0 cy7 az",
+        ],
+    ),
+    (
+        "Ogam Ogham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4g0 s",
+        ],
+    ),
+    (
+        "Ol_Chiki Olck",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5lc 1b",
+        ],
+    ),
+    (
+        "Ol_Onal Onao",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1us 1 2m3v 16 5 0",
+            "1us 1 2m3v 16 5 0",
+        ],
+    ),
+    (
+        "Old_Permic Perm",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0 g9 0 6 2 b 0",
+            "53 0 g9 0 6 2 b 0 a8 0 1ebx 16",
+        ],
+    ),
+    (
+        "Old_Persian Xpeo",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fa8 z 5 d",
+        ],
+    ),
+    (
+        "Old_Sogdian Sogo",
+        [
+            "1hj4 13",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hj4 13",
+        ],
+    ),
+    (
+        "Old_South_Arabian Sarb",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gm8 v",
+        ],
+    ),
+    (
+        "Old_Turkic Orkh",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6e2 0 2qe 0",
+            "6e2 0 2qe 0 17tc 20",
+        ],
+    ),
+    (
+        "Old_Uyghur Ougr",
+        [
+            "",
+            "",
+            "",
+            "18g 0 1fhu 0 vy p",
+            "",
+            "",
+            "",
+            "18g 0 1fhu 0 vy p",
+        ],
+    ),
+    (
+        "Oriya Orya",
+        [
+            "5p6 0",
+            "5pu 0",
+            "28l 0",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 bg 2 2 7 3 1 3 l 2 6 2 1 2 4 3 8 3 1 3 2 8 2 5 1 2 4 3 h 3fn 0 o 0",
+        ],
+    ),
+    (
+        "Osage Osge",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "ld 0 3 0 7 0 25 0",
+            "ld 0 3 0 7 0 25 0 1eu0 z 5 z",
+        ],
+    ),
+    (
+        "Osma Osmanya",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1fgg t 3 9",
+        ],
+    ),
+    (
+        "Palm Palmyrene",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1g80 v",
+        ],
+    ),
+    (
+        "Pau_Cin_Hau Pauc",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1juo 1k",
+        ],
+    ),
+    (
+        "Phag Phags_Pa",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6cv 0 34j 0",
+            "4qq 1 2 0 1m2 0 34j 0 nr2 1j",
+        ],
+    ),
+    (
+        "Phlp Psalter_Pahlavi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "18g 0 1fls h 8 3 d 6",
+        ],
+    ),
+    (
+        "Phnx Phoenician",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1gcg r 4 0",
+        ],
+    ),
+    (
+        "Rejang Rjng",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xf4 z c 0",
+        ],
+    ),
+    (
+        "Runic Runr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4iz 2",
+            "4gw 2g",
+        ],
+    ),
+    (
+        "Samaritan Samr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "94h 0",
+            "1kw 19 3 e 7hv 0",
+        ],
+    ),
+    (
+        "Saur Saurashtra",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xa8 1x 9 b",
+        ],
+    ),
+    (
+        "Sgnw SignWriting",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2l8g i3 g 4 2 e",
+        ],
+    ),
+    (
+        "Sharada Shrd",
+        [
+            "",
+            "",
+            "1i32 1",
+            "",
+            "",
+            "x80 5 3 0",
+            "",
+            "1u9 0 3uu 0 2 0 3 1 3 0 rio 5 3 0 kso 2n",
+        ],
+    ),
+    (
+        "Shavian Shaw",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "53 0",
+            "53 0 1fa1 1b",
+        ],
+    ),
+    (
+        "Sidd Siddham",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1itc 1h 3 11",
+        ],
+    ),
+    (
+        "Sinh Sinhala",
+        [
+            "",
+            "",
+            "2o1 0",
+            "",
+            "",
+            "5pu 0",
+            "",
+            "1us 1 t8 2 2 h 4 n 2 8 2 0 3 6 4 0 5 5 2 0 2 7 7 9 3 2 2ym 0 1cdr j",
+        ],
+    ),
+    (
+        "Sogd Sogdian",
+        [
+            "18g 0 1gc0 15",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "18g 0 1gc0 15",
+        ],
+    ),
+    (
+        "Sora Sora_Sompeng",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1hw0 o 8 9",
+        ],
+    ),
+    (
+        "Soyo Soyombo",
+        [
+            "1jtp 0",
+            "1jt0 1",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jrk 2a",
+        ],
+    ),
+    (
+        "Sund Sundanese",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "5fk 1r 75 7",
+        ],
+    ),
+    (
+        "Sunu Sunuwar",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lc 1 2 0 a 0 3 0 t 0 4 0 1jf3 x f 9",
+            "lc 1 2 0 a 0 3 0 t 0 4 0 1jf3 x f 9",
+        ],
+    ),
+    (
+        "Sylo Syloti_Nagri",
+        [
+            "",
+            "",
+            "x7w 0",
+            "",
+            "",
+            "",
+            "",
+            "1us 1 3l 9 v81 18",
+        ],
+    ),
+    (
+        "Syrc Syriac",
+        [
+            "",
+            "",
+            "5x4 0",
+            "5x6 0",
+            "",
+            "",
+            "lf 1 3 1 2 0 m 0 3 2 8 1 2 0",
+            "lf 1 3 1 2 0 m 0 3 2 8 1 2 0 kc 0 f 1 3 0 x 0 b a r 0 40 d 2 1n 3 2 7l a 49a 0 2 0",
+        ],
+    ),
+    (
+        "Tagalog Tglg",
+        [
+            "",
+            "",
+            "",
+            "4jx 0 8 0 a 0",
+            "",
+            "",
+            "",
+            "4jk l a 0 m 1",
+        ],
+    ),
+    (
+        "Tagb Tagbanwa",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "4l1 1 16 c 2 2 2 1",
+        ],
+    ),
+    (
+        "Tai_Le Tale",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "lc 1 6 1 4 0",
+            "lc 1 6 1 4 0 2lw 9 1s7 t 3 4",
+        ],
+    ),
+    (
+        "Tai_Viet Tavt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "xog 1u p 4",
+        ],
+    ),
+    (
+        "Takr Takri",
+        [
+            "",
+            "1j20 0",
+            "",
+            "1j21 0",
+            "",
+            "",
+            "",
+            "1us 1 vd7 9 ls7 1l 7 9",
+        ],
+    ),
+    (
+        "Tamil Taml",
+        [
+            "1id7 0",
+            "1ku8 1d e 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 f1 1 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 5 4 4 2 2 3 3 0 7 0 f k 3c0 0 ro9 0 ky6 0 2 0 1k 1 2h0 1d e 0",
+        ],
+    ),
+    (
+        "Tang Tangut",
+        [
+            "25f1 4",
+            "25f6 5",
+            "260j c e9 8",
+            "",
+            "",
+            "",
+            "9gw f ds 0",
+            "9gw f ds 0 1qsx 0 w 4qf 9 lb e9 8",
+        ],
+    ),
+    (
+        "Tangsa Tnsa",
+        [
+            "",
+            "",
+            "",
+            "1zlc 26 2 9",
+            "",
+            "",
+            "",
+            "1zlc 26 2 9",
+        ],
+    ),
+    (
+        "Telu Telugu",
+        [
+            "2dg 0",
+            "2gn 0 397 0",
+            "",
+            "2f0 0 x 0",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 ij c 2 2 2 m 2 f 3 8 2 2 2 3 8 1 2 2 3 0 3 3 3 9 8 8 38b 0 o 0",
+        ],
+    ),
+    (
+        "Tfng Tifinagh",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "le 0 2 0 3 0 2 0",
+            "le 0 2 0 3 0 2 0 8br 1j 8 1 f 0",
+        ],
+    ),
+    (
+        "Thaa Thaana",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "170 0 f 1 3 0 1t 9 7r 1d 1cn5 0 b 0",
+        ],
+    ),
+    (
+        "Thai Thai",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "jg 0 r 0 18 0 1a 0",
+            "jg 0 r 0 18 0 1a 0 24w 1l 6 r",
+        ],
+    ),
+    (
+        "Tibetan Tibt",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9hk 3",
+            "2yo 1z 2 z 5 12 2 z 2 e 2 6 5 1 6cu 3",
+        ],
+    ),
+    (
+        "Tirh Tirhuta",
+        [
+            "1u9 1",
+            "5pu 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1u9 1 i 1 3v1 0 ri6 9 ldz 1z 9 9",
+        ],
+    ),
+    (
+        "Todhri Todr",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "ld 0 3 0 3 0 a 0 2 0 23 0 1f1e 1f",
+            "ld 0 3 0 3 0 a 0 2 0 23 0 1f1e 1f",
+        ],
+    ),
+    (
+        "Toto Toto",
+        [
+            "",
+            "",
+            "",
+            "2nbk u",
+            "",
+            "",
+            "jg 0",
+            "jg 0 2ms4 u",
+        ],
+    ),
+    (
+        "Tulu_Tigalari Tutg",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2jq 9 35v 0 2 0 ri4 5 58 0 l1r 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1",
+            "2jq 9 35v 0 2 0 ri4 5 58 0 l1r 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1",
+        ],
+    ),
+    (
+        "Ugar Ugaritic",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1f9c t 2 0",
+        ],
+    ),
+    (
+        "Unknown Zzzz",
+        [
+            "128 0 14 0 2v 0 em 2 5w 0 8b 0 3c 0 b2 0 3k 0 2d0 0 t4 16 3 2 2yj 2 n o 5 e gc 4 kh 0 lvg 4 1j4 0 9 1 91 1 j79 1 j 0 jc 13 9 9 cn 13 9 15 ac 0 3b 2 dx 0 83 0 jg 0 6e 1n gy 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n o fns 2i 503 4 esv j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "2gn 0 en 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 2xb 0 1i 0 gg 0 xc 0 n23 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 a 4g 1 117 1d e 0 3zl 8 bnx 5 5 0 1c 8 2j 1 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 1 a9 1l 6 0 18s 0 qe 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 3br 0 jd 2 o9 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 3 h0x 0 2jo 15 2 2 3 1 73 r ak 0 3r 1 i3 0 6 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 b a 9 193 0 fuc 0 c 1 5c2 de 17 8 jr9 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9 xr2 6 hlv 3t6",
+            "17h 0 gj u 2 1 7 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 1d 1 ho 0 jq 0 29b 0 1c 0 dw a mg0 2 1j5 1 f 1 2 0 2 4 p 2 gjy 0 am f 3k 0 1b 1 1gx a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu p 6f 5 25 0 16f 0 3r 6 oa f 441 2q bjy 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m a 37 fa 1 2l2 u oi u 10y 6 2 3 2 1 2 e 2xr 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6 xyg 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 1q7 9 s7 g 2 14 4 r 446 0 a s onx 0 z 0 6ln j 2fm 5 7a 1p y 0 u9 15 3j7 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1 116p 0 i6v 38f",
+            "9h8 3 ds 0 3tvl h9",
+            "1p3 0 3p3 1 1c 0 7e 1 1i5 2 2pn 1 nae 2 d 2 ijo 1f 1fx 11 4 s 9 1 8j 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 9 2 1 9 1 ku j yl x f 9 o1 0 45i 32y 5qe 1l 2dj 1l 686 0 cg1 6x 7 c3 4kd 16 5 0 3oz 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "oo 1 7 3 8 0 2 0 l 0 b2 0 13 1 1f 1 4 0 1k 7 s 3 7 a 7j 0 1p 1 2u d 1o 1 1e 1 g 0 t 1 2 0 c 4 w 0 3 4 6m 0 9 1 3 1 n 0 8 0 2 2 5 1 a 1 3 1 5 7 2 3 3 0 6 1 q 1 4 0 7 3 3 1 n 0 8 0 3 0 3 0 3 1 2 0 6 3 3 1 4 2 2 6 5 0 2 6 i 9 4 0 a 0 4 0 n 0 8 0 3 0 6 1 b 0 4 0 4 1 2 e 5 1 d 6 8 0 4 0 9 1 3 1 n 0 8 0 3 0 6 1 a 1 3 1 4 6 4 3 3 0 6 1 j 9 3 0 7 2 4 0 5 2 3 0 2 0 3 2 3 2 4 2 d 3 6 2 4 0 5 1 2 5 2 d m 4 e 0 4 0 o 0 h 1 a 0 4 0 5 6 3 0 4 1 2 1 5 1 b 6 n 0 4 0 o 0 b 0 6 1 a 0 4 0 5 6 3 5 3 0 5 1 b 0 4 b e 0 4 0 1g 0 4 0 7 3 h 1 r 0 4 0 j 2 p 0 a 0 2 1 8 2 2 3 7 0 2 0 9 5 b 1 4 b 1n 3 u 10 3 0 2 0 6 0 p 0 2 0 o 1 6 0 2 0 8 0 b 1 5 v 21 0 11 3 14 0 11 0 g 0 e 10 5j 0 2 4 2 1 ai 0 5 1 8 0 2 0 5 1 16 0 5 1 y 0 5 1 8 0 2 0 5 1 g 0 1m 0 5 1 1w 1 x 2 r 5 2f 1 7 1 im 2 2i 6 n 8 p 8 l b e 0 4 0 3 b 2n 1 b 5 b 5 r 5 2i 6 18 4 1z 9 w 0 d 3 d 3 2 2 17 1 6 a 19 3 r 5 c 2 1r 1 1u 0 u 1 c 5 b 5 f 1 w 1c 26 0 4n 7 1p 2 g 2 1r 4 18 1 c 7 18 4 ev 1 7 1 13 1 7 1 9 0 2 0 2 0 2 0 w 1 1i 0 g 0 f 1 7 0 k 1 4 0 a 0 2u 0 d 1 s 0 e 2 y e y e 3x 3 ij l c k 1ed 1 x 0 9q 4 1a 0 2 4 2 1 1l 6 3 d p 8 8 0 8 0 8 0 8 0 8 0 8 0 8 0 8 0 3j x r 0 2i b 5z p 29 0 2f 1 2w 4 18 0 2n 0 2f 8 1d 0 mlq 2 1k 8 9p j 55 7 5r 1 3 0 2 0 9 k 1o 2 b 5 1l 7 1z 7 d 5 39 a v 2 27 0 c 3 y 0 1k 8 f 1 b 1 2w n t 9 7 1 7 1 7 8 8 0 8 0 1p 3 3j 1 b 5 8md b o 3 1e 6ir a7 1 2z 11 8 b 6 4 r 0 6 0 2 0 3 0 3 0 3i f ce 1 1j 6 2 v 17 5 1g 0 k 0 5 3 6 0 3s 1 2 0 5b 2 7 1 7 1 7 1 4 2 8 0 8 9 6 1 d 0 r 0 k 0 3 0 g 1 f x 3g 4 4 3 1a 2 2h 0 e 2 2 1a 1b 3l u 2 1e e t 3 11 8 v 4 18 4 v 0 12 3 f 15 4f 1 b 5 11 3 11 3 15 7 1h a d 0 g 0 8 0 3 0 c 0 g 0 8 0 3 2 1h b 8o 8 n 9 9 n 7 0 17 0 a 1w 7 1 2 0 19 0 3 2 2 1 o 0 21 7 a 1b k 0 3 4 y 2 s 4 2 1r 1l 3 l 1 1f 0 3 4 9 0 4 0 u 1 4 3 b 6 a 6 1t v 14 3 d 8 1j 2 u 1 s 4 r 6 5 b 8 27 22 1i 1g c 1g 6 1b 7 b 5 13 2 u 7 3 5r w 0 17 0 4 1 3 f 4 1i 19 7 17 l r 11 t j o 8 27 3 11 8 1x 9 2 1 q 6 b 5 1i 0 j 7 14 8 2p 0 l a j 0 1c 1p 8 0 2 0 5 0 g 0 c 5 1o 4 b 5 5 0 9 1 3 1 n 0 8 0 3 0 6 0 b 1 3 1 4 1 2 5 2 4 8 1 8 2 6 a b 0 2 1 2 0 13 0 b 0 2 1 2 0 5 0 b 0 3 7 3 s 2l 0 6 t 21 7 b 4l 1j 1 13 x 1y a b 5 e i 1n 5 b 5 l r s 1 g 3 o 54 1p 2r 2c b 9 1 2 1 9 0 3 0 v 0 3 1 d 8 b 1x 9 1 1b 1 c q 21 7 2c c 22 6 b 51 z d b 5 a 0 1a 0 f 9 u 2 x 1 n 0 f 20 8 0 3 0 19 2 2 0 3 0 a 7 b 5 7 0 3 0 12 0 3 0 7 6 b 8l q 6 i 0 16 2 u 2c 2 e 1f c po 2t 34 0 6 a 5h 217 2s c uv 9 330 4 g8 5a0 1n 1c5 fu 6 w 0 b 3 2a 0 b 5 v 1 7 9 1z 9 b 0 8 0 m 4 k bz 1n 5h 2k 2s 24 3 1m 6 i 1r 6 a 3 d 4qh 7 yf 14 b 6w6 5 0 8 0 3 0 84 e 2 s 4 1 2 d 5 7 b1 1s3 30 4 e 2 a 6 b 1 9 317 6z 5 c5 23 1b 1 o 8 39 1n 6v 9 14 1 5f k 1z 3d l b l b 2g 8 q 3q 2e 0 20 0 3 1 2 1 3 1 5 0 d 0 2 0 8 0 1u 0 5 1 9 0 8 0 t 0 5 0 6 0 2 2 8 0 9h 1 85 1 jj e 6 0 g un w 5 7 5w 8 0 i 1 8 0 3 0 6 4 1r w 2 33 1a 2 f 1 b 3 3 8v w g 1n 4 2 cv 17 5x 18 3 2 db 8 0 5 0 3 0 g 0 5i 1 h 14 25 3 b 3 3 ls 1x 23 1q 5d 5 0 s 0 3 0 2 1 2 0 b 0 5 0 2 0 2 5 2 3 2 0 2 0 2 0 4 0 3 0 2 1 2 0 2 0 2 0 2 0 2 0 3 0 2 1 5 0 8 0 5 0 5 0 2 0 b 0 i 4 4 0 6 0 i 1f 3 7h 19 3 2t b g 1 g 0 g 0 12 9 4v 1j u c 19 3 a 6 3 d 7 49 rd 3 i 2 e 2 3c 3 2o 5 d 3 2 e d 3 1l 7 b 5 15 7 v 1 d 3 3 1p 9h b f 1 e 2 b 4 1l 6 g 1 c 5 a 6 44 0 2v sl wyp v 37f 5 67 1 4g3 d 5rm e hb 1wh f3 15t 3t8 4 38h f974 2 t 2p 3j 6p 47bj",
+        ],
+    ),
+    (
+        "Vai Vaii",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "wlc 8b",
+        ],
+    ),
+    (
+        "Vith Vithkuqi",
+        [
+            "",
+            "",
+            "",
+            "1fn4 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1",
+            "",
+            "",
+            "",
+            "1fn4 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1",
+        ],
+    ),
+    (
+        "Wancho Wcho",
+        [
+            "",
+            "2ncw 1l 6 0",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2ncw 1l 6 0",
+        ],
+    ),
+    (
+        "Wara Warang_Citi",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jfk 2a d 0",
+        ],
+    ),
+    (
+        "Yezi Yezidi",
+        [
+            "",
+            "",
+            "170 0 f 0 4 0 1t 9 1g5z 15 2 2 3 1",
+            "",
+            "",
+            "",
+            "",
+            "170 0 f 0 4 0 1t 9 1g5z 15 2 2 3 1",
+        ],
+    ),
+    (
+        "Yi Yiii",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9hd 1 6 9 3 7 68 0 lxh wc 4 1i hwb 4",
+        ],
+    ),
+    (
+        "Zanabazar_Square Zanb",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1jpc 1z",
+        ],
+    ),
+];
+
+pub(super) static BINARY_PROPERTY_RANGES: &[(&str, [&str; 8])] = &[
+    (
+        "AHex ASCII_Hex_Digit",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1c 9 8 5 r 5",
+        ],
+    ),
+    (
+        "Alpha Alphabetic",
+        [
+            "128 0 14 0 2v 0 3nt 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 0 j7a 1 jv 13 d5 s b 0 9 l e7 2 15g 0 6e 1k h1 0 jn 5 2 1 2 10 2 1 2 3 2 0 94 m fnu 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 c7 1 5 4 q 6 2 0 b 1 jo 0 1qv 0 5g 0 reo 5 3 4 1o 0 9 0 6s 0 6e 0 46 2 6h 1 juh m vd 0 gp 0 ko 7 3 19 3 5 2 0 2 1 4g 1 fxn 6 sv 5 5 0 1c 8 8 3 29 0 4r3 5 861 2 i 3 9eh 18 b 6 h 0 aa 17 19c 0",
+            "1q6 9 u5 0 3h 0 2m6 1 4jf 4 5iv 9 g8h c 1jf 3 17 1 oi 1 jkn 15 2 1 4 1 73 k ar 0 3r 1 i9 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 1 3 3 19q 0 fuo 1 5c2 de 17 8 1jnz 6 hlv 3t6",
+            "1o0 n 2 5 13 0 j 1 pg 0 3k 0 20g 0 i 0 q5 2 3i 0 3c3 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu h 6o 4 25 0 1a6 6 oa f 441 2o bk0 26 do2 3 2 6 2 1 81 3 926 u oi t 10z 6 2 3 2 1 2 e 11pc 1 386 3",
+            "2dg 0 6n 0 i7 1 1erh 1 ce 2 2in g 2 14 4 2 44v 0 i 5 ooc 0 z 0 91s 5 7a 1p y 0 u9 r 15ji 0 i6v 38f",
+            "43qo h9",
+            "o3 c 10o 0 3xu 1 95 j r8l 2 d 2 ijo 1f 1g7 r 4 0 6 m 8t 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 1 4 0 2 0 1kd w 4u8 32y 5qe 1a 2du 18 68j 0 hjl t 3 0",
+            "1t p 7 p 1c 0 b 0 5 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 2f 0 u h 2 1 3 3 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 3 0 7 14 14 d 2 0 2 1 2 1 2 0 9 q 5 3 u a 6 1j 2 6 f 2t 2 7 5 7 5 2 b 2 3 0 h 1b e 2s p w a 1 5 0 6 n 3 i k o 8 a 6 n 2 5 9 0 9 15 b b 4 6 7 23 2 f 2 2 5 e e i 2 7 3 1 3 l 2 6 2 0 4 3 4 7 3 1 3 1 2 0 9 0 5 1 2 4 d 1 b 0 5 2 2 5 5 1 3 l 2 6 2 1 2 1 2 1 5 4 5 1 3 1 5 0 8 3 2 0 i 5 c 2 2 8 2 2 2 l 2 6 2 1 2 4 4 8 2 2 2 1 4 0 g 3 m 3 5 2 2 7 3 1 3 l 2 6 2 1 2 4 4 7 3 1 3 1 a 1 5 1 2 4 e 0 h 1 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 5 4 4 2 2 2 4 0 7 0 15 c 2 2 2 m 2 f 4 7 2 2 2 2 9 1 2 2 3 0 3 3 t 3 2 7 2 2 2 m 2 9 2 4 4 7 2 2 2 2 9 1 7 1 2 3 e 2 d c 2 2 2 14 3 7 2 2 2 2 2 0 6 3 8 4 n 5 2 2 2 h 4 n 2 8 2 0 3 6 9 5 2 0 2 7 j 1 e 1l 6 6 7 0 1g 1 2 0 2 4 2 n 2 0 2 i 2 2 3 4 2 0 7 0 f 3 x 0 1s 7 2 z 5 i 5 f 2 z 1w 1i 2 0 3 4 h 1r b 3 3 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 12 f h 2d 3 5 4 h7 3 g 2 p 6 22 4 a 8 j c k d j d c 2 2 2 1 d 1f 3 i f 0 5 0 1w 2g 8 16 6 1x b u 2 b 5 8 o t 3 4 c 17 5 p 1j r 5 1q 3 j 1f 0 o 1 c 2 1e 1f 2 e 2 7 1g 15 3 3 b 17 2 a f 1i n 2 b z 3 a 6 16 3 2 16 3 2 5 2 1 4 0 6 5b k x c 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 39 0 e 0 h c 2u 0 5 0 3 9 2 0 4 4 7 0 2 0 2 0 2 3 2 a 3 3 6 4 5 0 i 14 mm 1f 1ef 6c 7 3 4 1 d 11 2 0 6 0 3 1j 8 0 h m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 2 v 1c 0 d2 2 q 8 8 4 3 4 5 2d 7 2 2 2h 2 3 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 f b 1 l 1a 6 7 4 34 14 8 3 2u 3 1u 3 1 2 0 2 7 m j 2 w p 1f d 1v 2 0 19 5 4 0 2 2 b w 6 y e s 4 1e 2 b g 0 h f b 4 2 1i a d j m 4 1w 2 0 2 0 p 2 3 f 3 3 c 5 3 5 3 5 a 6 2 6 2 16 2 d 7 3e m 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 b 2 c 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 b 39 4 2 3q 11 p 7 p c 2g 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e 1y 1g 7g s 4 1c 1c v e t 6 16 6 t 3 z 5 7 2 4 17 4d j z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 3 2 1 6 7 2 2 2 s 17 s 4 s 10 7 2 r s 1h b l b i e h 33 20 1k 1e e 1e e 13 z r 4 0 6 m 6z 15 2 1 4 1 h 2 1k 0 4 s b 0 9 l 17 h 1b k s m a 1x 18 4 b 1k a 0 e o o 1e i 3 9 y 4 0 a 1r 2 3 a 1 b 0 2 0 10 h 2 x 3 0 7 3 1r 6 2 0 2 3 2 e 2 9 8 1k o 3 2 7 3 1 3 l 2 6 2 1 2 4 4 7 3 1 3 1 4 0 7 0 6 6 t 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 1 4 0 2 0 19 1t 2 2 2 3 l 2 v 1t 3 1 2 0 55 1h 3 6 q 5 z 1q 2 0 4 0 1o 1h 3 0 20 q 3 d m 6 56 1k 2w 1r w 7 3 0 3 7 2 1 2 t 2 1 3 1 3 3 2m 7 3 19 3 5 2 0 2 1 s 1e 3 9 i 1z 6 0 j 20 5k w w 8 2 18 2 6 2 0 1e t 3 l 2 d 22 6 2 1 2 17 4 0 2 1 2 2 2 0 3 1 p 5 2 1 2 10 2 1 2 3 2 0 94 m a g 2 14 4 2 34 0 28 pl 2v 32 i 5f 219 2o g tr i 5 q 32y 6 g6 5a2 1a 1ci fs 8 u i 26 i t j 1b h 3 w k 6 i c1 18 5w 1r 3l 22 5 1k 8 g 1t 1 2 0 d 1 f 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 5 0 4ma 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 u 7 5 5y 6 2 g 3 6 2 1 2 4 6 1p y 0 35 18 b 6 h 0 8y t j 17 dh r 6d t 3 0 ds 6 2 3 2 1 2 e 2 5g 1o 1v 4 0 4 0 xh 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g hh p 7 p 7 p 2uv wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Bidi_C Bidi_Control",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "17g 0 54i 1 r 4 1k 3",
+        ],
+    ),
+    (
+        "Bidi_M Bidi_Mirrored",
+        [
+            "881 0 gt 0",
+            "",
+            "",
+            "95h 7",
+            "",
+            "",
+            "6st 0",
+            "14 1 j 0 2 0 t 0 2 0 u 0 2 0 1a 0 g 0 2v3 3 1ge 1 1wd 1 b 1 1j 1 f 1 4y 0 5d 3 4 5 4 0 4 1 4 3 2 3 2 0 2 0 5 8 6 0 2 h 6 3 a 1 2 0 2 7 2 v 3 3 6 0 a 1 3 i 6 1 a 4 3 1 5 n 3 f 9 3 l 1 8 1 u6 d 23 0 3 3 2 1 2 2 6 3 6 2 4 d b8 l 3 5 2 d 9 0 8 5 4 0 5 4 2 1 3 4 5 0 2 2 3 1 b 5 3 1 d i 2 3 3 0 2 0 3 0 2 3 6 1 7 2 p 1 c 1 5 3 2 1 3 1 5 16 3 7 2 13 6 0 2 0 4 4 6 2 5 0 4 4 2 0 75 0 ec 3 4 1 2 1 f 1 3 9 18 7 bw 9 3 7 14qm 5 6 1 4j 1 j 0 2 0 t 0 2 0 u 0 2 0 2 1 2 1 16k8 0 1m 0 1m 0 1m 0 1m 0",
+        ],
+    ),
+    (
+        "CI Case_Ignorable",
+        [
+            "1kt 0 5y 0 8b 0 ee 0 v0b 0 js5 3 f3 a al 0 70 0 aa 0 83 0 r5 8 2 1 ct 1 p4 1 4 0 2 0 9o 1",
+            "2wq 0 umb 0 m53 3 3 1 5 0 574 8 bo7 0 44 0 mdp d bz 3 198 0",
+            "127 0 16e 0 fg 0 2m6 1 rxo 0 n1 2 jls 1 mb 0 1gs 1 2 0 5 0 h41 0",
+            "1oo 0 8 1 7 7 16 9 oa 0 260 0 63 0 j6 d mk 0 r94 2 gjy 0 2bi 5 2 15 2 8 1jc 3 6j 0 3 1 26 0 vfy 3 2 6 2 1 64i 19 3 m 3u0 0",
+            "2xa 0 1elr 2 n6 0 2in 1 1h 4 6 0 2 0 453 7 7 e xy3 1p y 0 v0 4",
+            "",
+            "1p3 0 1fhz 0 r 4 2 0 b1 0 xr 5 e 0 2 0 2 0 f 1 n0 0 1mk 0 czo b 4 2 2dt 2 15 1 nsy 1",
+            "13 0 7 0 c 0 10 0 2 0 20 0 5 0 2 0 5 0 3 1 e0 5b 5 1 5 0 a 1 2 0 70 6 5s 0 6 0 1e 18 2 0 2 1 2 1 2 0 19 0 c 5 b a 2 0 10 0 b k h 0 2u 7 2 9 2 3 y 0 2 0 v q 2k a 1n a 5 0 3 0 p n 18 2 19 0 8 1 6 8 16 1l 1k 0 2 0 5 7 5 0 4 6 b 1 e 0 g 0 1n 0 5 3 9 0 l 1 r 0 3 1 1m 0 5 1 5 1 3 2 4 0 v 1 4 0 c 1 1m 0 5 4 2 1 5 0 l 1 n 5 2 0 1n 0 3 0 2 3 9 0 8 1 c 1 v 0 1q 0 d 0 1f 0 4 0 1k 0 2 2 6 2 2 3 8 1 c 1 u 0 1n 0 3 0 7 0 6 1 l 1 t 1 1m 1 5 3 9 0 l 1 u 0 21 0 8 2 2 0 2j 0 3 6 c 8 2r 0 3 8 a 0 2 6 22 1 s 0 2 0 2 0 1k d 2 4 2 1 6 a 2 z a 0 2v 3 2 5 2 1 3 1 q 1 5 2 h 3 e 0 3 1 7 0 g 0 2n 0 gx 2 qb 2 u 1 v 1 v 1 1t 1 2 6 9 0 3 a 4 0 6 0 1a 4 1g 0 1u 1 z 0 3b 2 5 1 a 0 7 2 64 1 3 0 1n 0 2 6 2 0 2 0 3 7 7 9 3 0 14 0 9 u 1e 3 1d 0 2 4 2 0 6 0 15 8 d 1 x 3 3 1 2 2 1l 0 2 1 4 0 2 2 1n 7 3 1 1t 5 2b 2 2 c 2 6 5 0 7 0 4 1 1f 1q e 0 z 2s ce 0 2 2 c 2 e 2 e 2 e 1 d 4 9 1 b 0 3 0 3 4 1e 4 2 9 2 0 e 0 h c 1g w 2a4 1 36 2 3i 0 g 0 2p v 1c 0 d2 0 11 3 4 4 6 0 2m 5 2m 2 lxz 0 yr 5 7j 0 2r 3 2 9 2 0 t 3 29 1 f x 27 0 o 2 2w 2 4 1 9 0 4 0 5 0 q 1 6 0 48 1 r h e 0 13 7 q a 1b 2 1d 0 3 3 3 1 i 0 m 1 1v 5 3 1 3 1 d 0 9 0 10 0 c 0 1g 0 2 2 3 1 6 1 2 0 s 0 f 1 6 1 2 0 2t 4 a 2 3e 0 3 0 5 0 fn5 0 44 g fy f 4 0 d f z 0 3 0 4q 0 8 0 7 0 c 0 10 0 2 0 1c 0 1a 1 1w 0 m 2 ea 0 6b 0 46 4 sm 5 2 15 2 8 g7 2 2 1 6 3 15 2 5 0 4m 1 fy 3 13 0 r 4 2 0 8s 1 28 3 1z a 1e 3 3g 0 1j e 16 0 3 1 b 2 1e 3 3 1 3 0 5 0 b 0 1f 2 11 4 2 7 1r 0 d 1 1h 8 b 3 3 0 2o 2 3 0 2 1 7 0 3 0 4e 0 4 7 m 1 1m 1 4 0 12 6 4 4 1z 5 e 0 2 0 2 0 f 1 2e 7 3 2 2 0 o 0 2d 5 2 0 5 1 2 1 6n 3 7 1 2 1 s 1 2e 7 3 0 2 1 2z 0 2 0 3 5 2 0 2u 0 2 0 3 3 2 4 78 8 2 1 75 1 2 0 5 0 41 3 3 1 5 0 x 9 15 5 3 3 9 0 a 5 3 2 1b c 2 1 bb 6 2 5 2 0 2b l 3 6 2 1 2 1 3f 5 4 0 2 1 2 6 2 0 21 1 4 0 2 0 9o 1 c 1 1h 4 6 0 2 0 o 0 446 g 7 e 8uh b 4 2 1xd 4 1o 6 a 3 e5 2 15 1 df 0 1s g 1t 1 2 1 cng 3 2 6 2 1 2hr 1 2 3 3ml 19 3 m f5 2 a f 3 6 v 3 45 2 1j0 1i 5 1d 9 0 f 0 n 4 2 e 11t 6 2 g 3 6 2 1 2 4 6 1p y 0 4h d a9 0 1q 3 e4 4 73 1 kh 6 32 7 240 4 gx6q 0 v 2n 3l 6n",
+        ],
+    ),
+    (
+        "CWCF Changes_When_Casefolded",
+        [
+            "5n4 16 3 2 rg9 0 137s v",
+            "x4q 0 2 0 2 0 4 0 2 2",
+            "x53 0 2 0 18 0",
+            "8q7 0 oep 0 g 0 6 0 2 0 ihk a 2 e 2 6 2 1",
+            "",
+            "",
+            "5mx 0 ria 1 e 0 2 0 k1g l",
+            "1t p 2j 0 b m 2 7 x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 1 2 0 2 1 2 2 3 3 2 1 2 2 4 1 2 1 2 0 2 0 2 1 2 0 3 0 2 1 2 2 2 0 2 1 4 0 8 1 2 1 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 1 2 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 8 1 2 1 3 0 2 3 2 0 2 0 2 0 2 0 6v 0 17 0 2 0 4 0 9 0 7 0 2 2 2 0 2 1 2 g 2 8 n 0 d 2 4 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 3 1 2 0 2 1 3 1e 1d 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 11 1d 0 26x 11 2 0 6 0 mj 5 1oj 9 7 16 3 2 8x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 1 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 7 9 5 b 7 9 7 9 5 c 0 2 0 2 0 2 0 9 7 h 1b 3 2 3 5 6 2 3 5 c 3 d 4 6 2 3 5 8a 0 4 1 7 0 1a f k 0 mr p 1f5 1b 1d 0 2 2 3 0 2 0 2 0 2 3 2 0 3 0 9 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 nym 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 2 1 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 3 2 0 2 1 4 0 6 0 2 0 2 0 2 0 p 0 or 27 fnl 6 d 4 sq p xy 13 3t z 4d a 2 e 2 6 2 1 1d7 1e 4e l 27v v gw1 v o81 x",
+        ],
+    ),
+    (
+        "CWCM Changes_When_Casemapped",
+        [
+            "3bk 16 3 2 2a9 16 3 2 rg9 1 137r 1r",
+            "hu 0 5cc 0 r9i 0 12 5 3 4",
+            "x53 3 17 1",
+            "8q7 0 1c 0 odd 1 f 1 5 3 ihj a 2 e 2 6 2 1 2 a 2 e 2 6 2 1",
+            "",
+            "",
+            "bf 0 5l 0 55x 1 ri9 2 d 2 k1g l b l",
+            "1t p 7 p 1n 0 b m 2 u 2 1r 2 2b 2 r 3 d 3 1 2 0 5 2k 2 h 7 q 2 1 2 0 2 1 4 1 2 3 2 4 3 0 2 1 3 0 8 0 3 0 2 1 4 5 6 0 b 1 4n 0 17 3 3 1 4 2 2 0 7 0 2 2 2 0 2 j 2 1a 4 w 2 4 2 3o 9 4l 2 11 b 12 26x 11 2 0 6 0 3 16 3 2 ip 2d 3 5 1oj a 6 16 3 2 56 0 4 0 h 0 36 4b 3 0 2 39 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 8a 0 4 1 7 0 s 0 i v 4 1 mq 1f 1ef 34 2 1 2 1 8 2t 8 3 4 1 d 11 2 0 6 0 nwz 19 j r 3r d 3 1p a e 4 2 3 4 2 o 2 t 3 1 5 6 p 1 nx 0 t 27 fnl 6 d 4 sq p 7 p x2 27 2p z 5 z 39 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 1c4 1e e 1e 2m l b l 26z 1r gv5 1r o75 1v",
+        ],
+    ),
+    (
+        "CWKCF Changes_When_NFKC_Casefolded",
+        [
+            "5n4 16 3 2 rg9 0 137s v",
+            "a2n 0 n23 0 2 0 2 0 4 0 2 2 1t46 0",
+            "x53 0 2 0 18 0 ok 0 1uh3 9",
+            "4r3 0 3z4 0 oep 0 g 0 6 0 2 0 q 2 igs a 2 e 2 6 2 1 do 4 2 15 2 8",
+            "2muo 1p",
+            "",
+            "5mx 0 ria 1 e 0 2 0 k1g l 11td z",
+            "1t p 1y 0 8 0 2 0 3 0 2 0 3 3 3 2 2 2 2 m 2 7 x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 2 2 0 3 0 2 0 2 0 2 2 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 1 2 0 2 1 2 2 3 3 2 1 2 2 4 1 2 1 2 0 2 0 2 1 2 0 3 0 2 1 2 2 2 0 2 1 4 0 8 9 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 3 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 8 1 2 1 3 0 2 3 2 0 2 0 2 0 2 0 2q 8 w 5 3 4 2k 1 2 2 a 0 x 0 2 0 2 0 2 0 4 0 4 1 5 6 2 0 2 1 2 g 2 8 n 0 d 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 2 2 1 2 0 2 1 3 1e 1d 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 11 1d 0 45 0 2h 3 kg 7 3h 1 2 0 2c 0 3 0 z 2 3 0 72 1 k6 0 3k 0 15 1 1b 0 1j 0 a 0 5 0 5 0 5 0 d 0 a 0 2 4 8 0 i 0 a 0 5 0 5 0 5 0 d 0 6f 11 2 0 6 0 1b 0 2r 1 ig 5 qf 1 2e 4 vl 9 7 16 3 2 31 2 2 a 2 h 2 r e 0 z 10 1t 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 1 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 7 9 5 b 7 9 7 9 5 c 0 2 0 2 0 2 0 9 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 1b 3 2 3 d 3 8 4 0 5 3 2 2 4 0 5 7 3 2 3 7 2 f 2 0 6 0 d 2 4 5 4 1 2 1 5 0 2 0 9 2 e 0 8 i 3 q 2 c c 0 2g 3 2 2 2 a 2 1 3 4 3 2 2 0 2 0 2 0 2 3 2 a 2 5 5 4 7 1b 4 0 6 0 4j 1 2 1 6x 1 8m 3u 10i 0 2w 2 2u 0 84 1b 1d 0 2 2 3 0 2 0 2 0 2 3 2 0 3 0 7 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 3h 0 8g 0 2c 0 d 5x 17 0 1i 0 2 2 2p 1 3 0 2o 0 1e 2l 4 d 2p u 2 13 9 1a 2 an mkh 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 3p 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 2 1 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 3 2 0 2 1 4 0 6 0 2 0 2 0 2 0 m 3 3 1 o3 3 a 0 7 27 f9d 7h 3 0 2 0 3 9 2 0 2 0 3 1 4 1v 3 2x 13 6 d 4 6 0 2 n 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 c 4 p n k 3 b 2 i 2 3 5 2 2 0 2 3q 3 0 2 59 4 5 3 5 3 5 3 2 4 6 2 6 2 8 so 13 3t z 4d a 2 e 2 6 2 1 do 4 2 15 2 8 xy 1e 4e l 27v v gw1 v fgh 3 377 z v9 6 f 7 1t 5 g0 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 83 3 1d 1m9 1p 1oz x yn 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g g5 a 6 u 2 v r 2 10 0 34 2 e 17 5 8 8 1 1wf 9 1ds7 f1 fher 35r",
+        ],
+    ),
+    (
+        "CWL Changes_When_Lowercased",
+        [
+            "5n4 16 3 2 rg9 0 137s v",
+            "x4q 0 2 0 2 0 4 0 2 2",
+            "x53 0 2 0 18 0",
+            "8q7 0 oep 0 g 0 6 0 2 0 ihk a 2 e 2 6 2 1",
+            "",
+            "",
+            "5mx 0 ria 1 e 0 2 0 k1g l",
+            "1t p 2u m 2 6 y 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 4 1 2 0 2 1 2 2 3 3 2 1 2 2 4 1 2 1 2 0 2 0 2 1 2 0 3 0 2 1 2 2 2 0 2 1 4 0 8 1 2 1 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 1 2 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 8 1 2 1 3 0 2 3 2 0 2 0 2 0 2 0 82 0 2 0 4 0 9 0 7 0 2 2 2 0 2 1 2 g 2 8 10 0 9 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 0 3 0 2 1 3 1e 1d 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 11 28a 11 2 0 6 0 k3 2d 1p0 0 7 16 3 2 8x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 7 9 5 b 7 9 7 9 5 c 0 2 0 2 0 2 0 9 7 p 7 9 7 9 7 9 4 c 4 c 3 d 4 c 4 8a 0 4 1 7 0 1a f k 0 mr p 1f5 1b 1d 0 2 2 3 0 2 0 2 0 2 3 2 0 3 0 9 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 nym 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 2 1 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 3 2 0 2 1 4 0 6 0 2 0 2 0 2 0 p 0 h7w p xy 13 3t z 4d a 2 e 2 6 2 1 1d7 1e 4e l 27v v gw1 v o81 x",
+        ],
+    ),
+    (
+        "CWT Changes_When_Titlecased",
+        [
+            "x4p 0 138n v",
+            "hu 0 5cc 0 r9i 0 13 0 2 0 2 0 4 0",
+            "x54 0 2 0 18 0",
+            "8rj 0 ode 0 g 0 6 0 2 0 iim a 2 e 2 6 2 1",
+            "",
+            "",
+            "bf 0 5l 0 55y 0 rib 0 e 0 k2d l",
+            "2p p 1n 0 16 n 2 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 2 3 0 2 0 3 0 4 0 6 0 3 0 4 2 3 0 3 0 2 0 2 0 3 0 5 0 3 0 4 0 2 0 3 0 4 0 2 0 5 0 2 1 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 2 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 3 1 2 0 5 0 2 0 2 0 2 0 2 5 2 1 2 0 2 1 4 1 2 3 2 4 3 0 2 1 3 0 8 0 3 0 2 1 4 5 6 0 b 1 4n 0 18 0 2 0 4 0 4 2 j 0 s y 2 1 4 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 0 3 0 3 0 1h 1b 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1e 12 2up 5 1oj 8 2 0 6n 0 4 0 h 0 37 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 6 6 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 9 5 b 7 9 7 9 5 b 7 9 7 9 d 3 7 9 7 9 7 9 4 2 1 7 0 4 2 2 1 9 3 3 1 9 7 b 2 2 1 9j 0 y f 5 0 ng p 1fr 1b 2 0 4 1 2 0 2 0 2 0 7 0 3 0 b 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 d 11 2 0 6 0 nx0 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 3 0 2 0 2 0 2 0 2 0 5 0 5 0 2 1 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 c 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 4 0 6 0 2 0 2 0 r 0 nx 0 t 27 fnl 6 d 4 tm p y6 13 3t z 4c a 2 e 2 6 2 1 1dw 1e 3i l 27v v gw1 v o83 x",
+        ],
+    ),
+    (
+        "CWU Changes_When_Uppercased",
+        [
+            "3bk 16 3 2 tru 0 138n v",
+            "hu 0 5cc 0 r9i 0 13 0 2 0 2 0 4 0",
+            "x54 0 2 0 18 0",
+            "8rj 0 ode 0 g 0 6 0 2 0 iim a 2 e 2 6 2 1",
+            "",
+            "",
+            "bf 0 5l 0 55y 0 rib 0 e 0 k2d l",
+            "2p p 1n 0 16 n 2 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 2 3 0 2 0 3 0 4 0 6 0 3 0 4 2 3 0 3 0 2 0 2 0 3 0 5 0 3 0 4 0 2 0 3 0 4 0 2 0 6 1 2 1 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 1 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 3 1 2 0 5 0 2 0 2 0 2 0 2 5 2 1 2 0 2 1 4 1 2 3 2 4 3 0 2 1 3 0 8 0 3 0 2 1 4 5 6 0 b 1 4n 0 18 0 2 0 4 0 4 2 j 0 s y 2 1 4 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 0 3 0 3 0 1h 1b 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1e 12 289 16 3 2 l5 5 1oj 8 2 0 6n 0 4 0 h 0 37 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 6 6 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 9 5 b 7 9 7 9 5 b 7 9 7 9 d 3 1g 2 1 5 0 2 0 4 2 2 1 5 0 4 3 3 1 9 7 b 2 2 1 5 0 9e 0 y f 5 0 ng p 1fr 1b 2 0 4 1 2 0 2 0 2 0 7 0 3 0 b 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 d 11 2 0 6 0 nx0 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 3 0 2 0 2 0 2 0 2 0 5 0 5 0 2 1 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 c 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 4 0 6 0 2 0 2 0 r 0 nx 0 t 27 fnl 6 d 4 tm p y6 13 3t z 4c a 2 e 2 6 2 1 1dw 1e 3i l 27v v gw1 v o83 x",
+        ],
+    ),
+    (
+        "Cased Cased",
+        [
+            "128 0 14 0 288 16 3 2 2a9 16 3 2 rg0 0 9 1 137r 1r",
+            "x4q 5 3 4 ps 1",
+            "x53 3 17 1 oi 0",
+            "8q7 0 1c 0 odd 1 f 1 2 0 2 4 ihj a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 0 3 2 2 15 2 8 16iu 9 2 j",
+            "3cs 0 tti 2 ol 0 1osc 5 7a 1p",
+            "",
+            "5mx 1 ri9 2 d 2 k1g l b l",
+            "1t p 7 p 1c 0 b 0 5 0 6 m 2 u 2 5e 2 3 5 5r 2 z 8 1 v 4 2p 0 17 3 3 1 3 3 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 a 14 26w 11 2 0 6 0 3 16 2 3 ip 2d 3 5 1oj a 6 16 3 2 1t 5b 1t 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 39 0 e 0 h c 2u 0 5 0 3 9 2 0 4 4 7 0 2 0 2 0 2 3 2 5 5 0 3 3 6 4 5 0 i v 4 1 mq 1f 1ef 6c 7 3 4 1 d 11 2 0 6 0 nwz 19 j t 3p 2t 4 3 2 1p 3 1 2 0 2 7 m 4 2 2 mu 16 2 d 7 27 fnl 6 d 4 sq p 7 p x2 27 2p z 5 z 39 a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 0 3 2 2 15 2 8 xy 1e e 1e 2m l b l 26z 1r gv5 1r k1t 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 9 2 j 7 5 7a 1p 1oz 1v 1kd p 7 p 7 p",
+        ],
+    ),
+    (
+        "DI Default_Ignorable_Code_Point",
+        [
+            "",
+            "",
+            "",
+            "4r3 0",
+            "",
+            "",
+            "",
+            "4t 0 iq 0 jx 0 283 1 190 1 2e 4 1ks 4 r 4 1e f 3cl 0 14f0 f 6o 0 4h 0 28 8 119k 3 440 7 h406 35r",
+        ],
+    ),
+    (
+        "Dash Dash",
+        [
+            "",
+            "",
+            "1hgt 0",
+            "95p 0",
+            "",
+            "",
+            "1h7y 0",
+            "19 0 125 0 1g 0 2te 0 sm 0 1l6 5 1q 0 14 0 g 0 av 0 2dh 0 3 0 w 1 5 0 t 0 cf 0 k 0 34 0 14lt 1 12 0 b 0 4q 0 2u9 0 8v 0",
+        ],
+    ),
+    (
+        "Dep Deprecated",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "95 0 10q 0 1s4 0 2 0 1m2 1 1qe 5 je 1 jh07 0",
+        ],
+    ),
+    (
+        "Dia Diacritic",
+        [
+            "1h5u 5 f3 a 1rd 1 125 0",
+            "2wq 0 bt 1 5 4 kw 2 t5t m 37 1 ml2 0 g28 6 nay 6 c6 3",
+            "28l 0 vmc 2 loy 1 5 0 h4d 1",
+            "1p4 7 16 9 oa 0 254 1 q1 0 3 a mn 2 1a4k 5 2 15 2 8 1jc 3 5d 0 16 0 vi8 3 2 6 2 1 64i 19 3 m 3u0 0",
+            "1hj1 2 7d4 e xy3 1p",
+            "",
+            "2t6 0 1ru 0 mk 0 au 0 c 1 ro3 0 12 0 jd8 2 5 0 lr 0 r 4 15a 0 43 2 2 1 e 1 28v 1 o 0 d05 0 2f0 1 nsy 1",
+            "2m 0 2 0 20 0 7 0 5 0 3 1 e0 4e 2 7 6 5 i 1 5 0 a 1 72 4 5u 0 1k g 2 q 2 0 2 1 2 0 3r 7 5 1 3r 1 5 1 4 2 1w q 2k a 1n a z 1 3j 7 16 9 h r 1q 0 h 0 4 3 t 0 23 0 h 0 33 0 h 0 33 0 h 0 1c 2 1p 0 h 0 8 0 3c 0 33 0 h 0 33 0 h 0 32 1 h 0 3h 0 34 0 d 5 2 0 30 0 e 4 24 1 s 0 2 0 2 0 5 1 1v 2 2 1 1r 0 35 0 2 1 15 1 5 4 q 6 2 0 b 1 jm 2 qd 1 v 0 45 a a 0 9o 2 85 0 l 7 3 0 1d e 3 a 2x 0 g 0 13 8 1j 1 1n 0 c 1 1v 1 1t 5 2b o 5 0 7 0 3 2 1f 1q 2i b 12 a ce 0 2 2 c 2 e 2 e 2 e 1 2k1 2 8u 0 e3 5 2y 3 2o 0 n77 0 d 1 2 0 t 1 2b 1 f x 2v 2 32 1 d 0 12 0 48 0 s h 1m 3 11 0 2o 0 d 0 11 0 46 2 1u 3 1g 0 2t 4 a 2 3l 1 fn5 0 le f 7j 0 2 0 1c 0 1a 1 1w 0 l9 0 ww 5 2 15 2 8 hq 2 5 0 4m 1 fw 5 13 0 r 4 b4 2 1z a 1e 3 5d 0 16 0 21 1 3d 1 1r 0 25 0 a 2 2x 1 4z 1 29 1 h 0 p 6 4 4 2i 2 2 1 e 1 2o 0 4 0 3g 1 70 1 3j 0 3b 1 38 0 7i 1 77 1 5 0 4d 0 2c 0 j 0 2a 0 bq 0 77 0 2 1 2a 0 bu 1 o 0 44t e 8uy 0 1xd 4 1o 6 fp 1 f7 g 29 1 cn3 3 2 6 2 1 64i 19 3 m f5 2 4 5 9 7 3 6 v 3 2v7 1p 5f 6 ag 0 1q 3 lb 1 kh 6 32 2 2 2",
+        ],
+    ),
+    (
+        "EBase Emoji_Modifier_Base",
+        [
+            "2rw5 1 2 1",
+            "2qui 3 2 0 w 0 2 0 vy 0 e 0 v 0 3j 0 i 2",
+            "2rrg 0 2z 0",
+            "2s3n 2 17 6",
+            "2s53 1",
+            "",
+            "",
+            "7j1 0 64 0 h 3 2iyg 0 1p 2 3 0 3 2 3a 1 3 a m i 4 0 5 2 2 2 8 0 2 0 p 0 5m 1 5 0 m 0 5 1 4v 2 4 4 2c 0 h 2 a 0 c 0 g0 0 3 0 9 7 7 0 a 9 3 2 1l 0 1q 1 2 1 2 0 i 2 2 c 6e 2 17 8",
+        ],
+    ),
+    (
+        "EComp Emoji_Component",
+        [
+            "6bx 0 5y 0 17os 0 1dpd 3 gw31 2n",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "z 0 7 0 6 9 6ac 0 5y 0 17os 0 1c5z p e4 4 14h 3 gw31 2n",
+        ],
+    ),
+    (
+        "EMod Emoji_Modifier",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2qrf 4",
+        ],
+    ),
+    (
+        "EPres Emoji_Presentation",
+        [
+            "2rcp 0 gk 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o",
+            "2rbp 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 4h 3 5 2 6 2 e 5",
+            "2rbq 1 10 1 eo 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6",
+            "2rbx 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2rbw 0 pl 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "",
+            "2s21 0 6 0 1b 0 8 0 m 0 3 0 a 0",
+            "6xm 1 5q 3 4 0 3 0 ei 1 m 1 1f b 18 0 k 0 e 0 9 1 i 1 6 1 9 0 6 0 m 0 8 1 2 0 5 0 3 0 8 0 5 1 t 0 10 0 2 0 5 2 2 0 1q 2 p 0 f 0 nw 1 1g 0 5 0 2hf3 0 5n 0 5b 0 3 9 24 p 2 0 p 0 l 0 3 4 2 2 m 1 4v w d 8 2 1x 2 l d 16 5 4 d g 4 0 4 1y 2 0 2 56 3 1q e 3 2 n j 0 r 1 e 0 2f 2c 1d 1x 7 0 4 2 3 2 5 3 c 1 8 8 6c b 5 0 7w 1a 2 9 2 54 35 c 4 9 6 1j 8 e 3 a 7 8",
+        ],
+    ),
+    (
+        "Emoji Emoji",
+        [
+            "7kv 0 v 0 2jqz 0 gk 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o",
+            "2rbp 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 4h 3 5 2 6 2 e 5",
+            "7mv 0 2jov 1 10 1 eo 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6",
+            "2rbx 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6",
+            "2rbw 0 pl 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1",
+            "",
+            "2s21 0 6 0 1b 0 8 0 m 0 3 0 a 0",
+            "z 0 7 0 6 9 34 0 5 0 68e 0 d 0 61 0 n 0 2j 5 g 1 a8 1 d 0 4n 0 q a 5 2 5k 0 6g 1 b 0 a 0 1n 3 2 4 a 0 3 0 3 1 3 0 5 0 3 0 2 1 3 0 4 0 4 1 9 2 6 0 2 0 6 b c 1 3 0 2 1 2 0 j 0 3 1 j 5 2 0 2 1 4 1 6 0 3 1 5 1 c 1 6 1 3 0 6 1 2 0 2 1 l 1 6 5 2 3 3 0 5 0 3 0 3 5 2 0 3 0 2 0 2 0 7 0 4 0 7 0 b 1 g 0 3 0 5 0 2 0 5 2 2 0 c 1 1d 2 a 0 f 0 f 0 ad 1 cw 2 k 1 1g 0 5 0 yj 0 d 0 gq 0 2 0 2fzf 0 5n 0 4h 1 d 1 f 0 3 9 24 p 2 1 o 0 l 0 3 8 m 1 4v x 3 33 3 1 2 2 3 2a 3 2 2 7a 2 1q c 5 2 n 8 1 3 7 d 0 3 3 3 0 5 1 e 1 3 0 9 1 a 0 6 2 d 2 9 2 3 0 2 0 5 0 7 0 4 0 7 2d 1d 1x 6 7 3 2 5 9 4 0 2 1 4 0 3 9 6c b 5 0 7w 1a 2 9 2 54 35 c 4 9 6 1j 8 e 3 a 7 8",
+        ],
+    ),
+    (
+        "Ext Extender",
+        [
+            "",
+            "20o3 0 me1 1",
+            "28l 0",
+            "1g1t 1",
+            "",
+            "",
+            "229 0 3u 0 1f0z 0 s 0 5 0 y0 0 bf 1 15i4 0",
+            "53 0 ex 1 of 0 ca 0 hj 0 3u 0 2i 0 kx 0 3k 0 1tw 0 1l 0 h0 0 b3 0 1x 0 3uy 0 18 4 2w 1 2m 2 lxz 0 16f 0 qr 0 n 0 3u 0 31 0 m 1 gos 0 1ld 1 158 0 s 0 5 0 y0 0 86 0 39 1 dv 2 y8 0 fxm 1 wt 1 2 0 me1 1 xe 0 np 2",
+        ],
+    ),
+    (
+        "ExtPict Extended_Pictographic",
+        [
+            "4p 0 5 0 68e 0 d 0 61 0 n 0 2j 5 g 1 a8 1 d 0 2o 0 1z 0 q a 5 2 5k 0 6g 1 b 0 a 0 1n 3 2 5 2 b 2 35 b 39 3 a 2 0 2 0 7 0 4 0 7 0 b 1 g 0 3 0 5 0 2 0 5 2 2 0 c 4 1a 2 a 0 f 0 f 0 ad 1 cw 2 k 1 1g 0 5 0 yj 0 d 0 gq 0 2 0 2fzb 73 e 2 w 0 1p 5 d 1 f 0 3 9 j 1k s e b 0 l 0 3 8 2 3 a c1 6 8t 9 7d 1d 3j 39 b 2e 16 d 3 1l 7 b 5 15 7 v 29 d 1a 2 9 2 1bq",
+            "",
+            "2s5c 73",
+            "",
+            "",
+            "",
+            "",
+            "4p 0 5 0 68e 0 d 0 61 0 n 0 2j 5 g 1 a8 1 d 0 2o 0 1z 0 q a 5 2 5k 0 6g 1 b 0 a 0 1n 3 2 5 2 b 2 35 b 39 3 a 2 0 2 0 7 0 4 0 7 0 b 1 g 0 3 0 5 0 2 0 5 2 2 0 c 4 1a 2 a 0 f 0 f 0 ad 1 cw 2 k 1 1g 0 5 0 yj 0 d 0 gq 0 2 0 2fzb 73 e 2 w 0 1p 5 d 1 f 0 3 9 j 1k s e b 0 l 0 3 8 2 3 a c1 6 8t 9 7d 1d 3j 39 b 2e 16 d 3 1l 7 b 5 15 7 v 29 d 1a 2 9 2 c8 75 sd",
+        ],
+    ),
+    (
+        "Gr_Base Grapheme_Base",
+        [
+            "128 0 14 0 2v 0 en 1 hj 0 em 0 2d0 0 t4 16 3 2 2yj 2 n o 5 e gc 4 kh 0 lvg 4 1j4 0 9 1 91 0 j7a 1 j 0 jc z d 9 cn 13 9 l c 8 dn 2 3n 0 11t 0 6e 1a a 0 3 0 cs 1 45 0 jn 5 2 1 2 10 5 1 2 0 2 0 8 9 8n i 3 3 fns 2i 503 4 esv j 3j 6 4xl 1v vv 0 156 0 64 3 ad 2 t 4 3 3 4 0 2 3 p a e 9 8 1 11 o 2p d",
+            "2gn 0 en 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 2h5 0 cl 0 2xb 0 1i 0 gg 0 xc 0 n23 5 3 4 dz 0 bt 1 juh m vd 0 gp 0 ko 7 3 15 9 3 2 3 4g 1 117 1d e 0 fnq 5 1h 8 2j 1 4r3 5 861 2 i 3 9eh 18 b 6 3 9 5 1 a9 17 5 9 6 0 18s 0 qe 1o tr 0 12h 0 11 0 6e b 82 2 1c 0 1e 0 a 0 16 5 4 1 b 5 4 7 3 2 1d 2b t 3 5 2 6 2 e 5",
+            "1q6 9 u5 0 61f 0 jd 2 o9 4 5iv 9 g8h c 1jf 3 17 1 oi 3 h0x 0 2jo 15 4 0 3 1 73 r ak 0 3r 0 i4 0 6 1 wv 6 3 0 3 7 2 1 2 n 2 4 2 1 5 0 2 3 2 2 a 9 193 0 fuo 1 5c2 de 17 8 jr9 2 2m 2 1q 0 10p 1 10 1 c4 1 2j 0 2u 0 5 1 17 1 7 2 u 0 4p 0 f 3 g i 8 6 a 2 e 6 16 42 2 1i 12 9 xr2 6 hlv 3t6",
+            "17h 0 gj u 13 0 j 1 pg 0 3k 0 20g 0 8 0 a 0 l 0 t4 0 1d 1 11e 0 29b 0 1c 0 dw a mg0 2 1j5 1 f 1 2 0 2 4 p 2 gjy 0 am f 3k 0 1b 1 1gx a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu h 5 3 6g 1 3 0 18k 0 3r 6 oa f 441 2q bjy 26 2 9 dnr 3 2 6 2 1 81 3 5ym 37 fa 1 2l2 u oi t 10z 6 2 3 2 1 2 e 2xr 2 7l 0 ax 0 2b 0 4v 1 19 3 b 3 9 2 i 2 7 7 9 6 xyg 1 386 3",
+            "2k3 0 1fm4 1 1q8 9 s9 e 2 z 9 1 2 0 2 m 446 0 i 5 ooc 0 z 0 6ln j 2fm 5 7a 1p v7 r 5 9 3j7 0 48 2 5 4 2i 0 ik 2 g 1 11 2 c 2 2 0 f 1 b 1 d 0 f 1 116p 0 i6v 38f",
+            "9h8 3 ds 0 3tvl h9",
+            "2io 0 7 1 2 1 216 0 v 0 sn 0 2 0 6 1 a 1 1c 0 17 0 20 1 46 1 1i5 2 2pn 1 nae 2 d 2 af 0 31 0 i68 1f 1fx 11 9 n 9 1 8j 2 l8 0 39 0 7s 0 1f 9 2 0 3 0 2 11 2 0 2 1 g 0 2 1 4 0 2 2 2 1 ke 0 q j 1n 0 f3 0 hv x f 9 nc 0 467 32y 5qe t d 2 4 9 2dj 1l hj 1 5qm 0 cg1 6x 7 c3 j7 0 7 0 40z t 3 a 5 0 3oz 9 5 1 co 0 6 0 1b 0 8 0 m 0 3 0 a 0 6a 10",
+            "w 2m y c 2 gh 35 7 3 5 5 6 2 0 2 j 2 67 8 4l 2 11 3 1d 3 2 1b 0 2 0 3 0 3 0 a q 5 5 i 9 c 0 2 19 m f 2 2s 9 0 7 1 3 0 5 v 3 0 2 t u 2g c 0 f 16 a 6 4 n 5 0 a 0 4 0 8 e 2 o 6 0 2 a 6 u i 15 1m 1i 2 0 2 3 9 3 2 2 8 9 3 s 2 1 2 7 3 1 3 l 2 6 2 0 4 3 4 0 2 1 7 1 3 1 2 0 e 1 2 2 5 n 6 0 2 5 5 1 3 l 2 6 2 1 2 1 2 1 5 2 p 3 2 0 8 9 3 2 2 0 d 0 2 8 2 2 2 l 2 6 2 1 2 4 4 3 9 0 2 1 4 0 g 1 5 b 8 0 9 1 2 7 3 1 3 l 2 6 2 1 2 4 4 0 3 0 7 1 3 1 g 1 2 2 5 h c 0 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 6 0 2 1 4 2 2 2 4 0 m k 7 2 2 7 2 2 2 m 2 f 4 0 4 3 k 2 3 0 3 1 5 9 8 9 2 a 2 2 2 m 2 9 2 4 4 1 3 0 2 1 p 1 2 1 5 9 2 2 f a 2 2 2 14 3 0 2 1 6 2 2 2 2 1 5 2 2 9 5 p 3 1 2 h 4 n 2 8 2 0 3 6 a 1 7 6 8 9 3 2 d 1b 2 1 c 7 9 c 12 1 2 0 2 4 2 n 2 0 2 9 2 1 a 0 3 4 2 0 a 9 3 3 x n 3 q 2 0 2 0 2 d 2 z j 0 6 0 3 4 1e 7 2 5 2 c 12 18 5 0 7 0 3 1 3 o 3 3 4 f 5 c 2 1 3 5 2 e 2 13 2 0 6 0 3 ag 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 6 s 4 p 7 2d 3 5 3 ik 4 2g 8 h e i 4 1 a h f c 2 2 g 1f 3 0 8 7 2 1 c 8 4 9 7 9 7 a 6 9 7 2g 8 4 3 x 2 0 6 1x b u 5 3 3 2 5 1 2 5 8 0 4 15 3 4 c 17 5 p 7 a 4 1k 3 1 4 1j 2 0 a 0 2 1 9 5 e 9 7 9 7 d 2f 1b b 3 4 7 2 s a b 3 v 5 1 7 1j 2 0 3 2 2 0 e 1b 9 1 6 e 4 1p 6 16 3 a c 0 e 0 8 3 2 5 2 2 3 0 6 5b 1t 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 e 2 d 3 5 2 i 3 2 2 8 2 a 6 n 8 1c h 1 3 q 2 c 4 w 1s 3v 5 ih n a m 1eb 3 v 2 9j 4 1 6 18 2 0 6 0 3 1j 8 1 g m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 y 2l z p 2 2g d 5x r 1l 7 f 2 2d 5 2s 6 16 2 2l 2 2d a 1b 2 mlo 4 1i a 9n l 1a 5 0 b v 3 27 3 5 9 5p 3 1 2 0 2 7 m f 2 2 2 3 2 o 3 4 5 9 7 1j 9 1v b b p c 2 11 9 o c 0 d t 7 1b 2 1 5 1 3 1 2 c 2 a 5 6 2 o 2 14 7 1 3 1 c 2 2 7 2 0 3 9 3 v 2 1e 2 0 4 1 3 4 3 0 2 0 p g 3 7 c 5 3 5 3 5 a 6 2 6 2 1n 5 38 2 1 2 3 4 9 7 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 0 2 n 2 4 2 0 2 1 2 1 2 3g h cc 3 1h 8 0 x f h 9 n y 2 i 2 3 5 4 2 3q 5 4c 3 u 4 5 3 5 3 5 3 2 4 6 2 6 e 1 3 b 2 p 2 i 2 1 2 e 3 d z 3e 6 2 5 18 4 2f 2 c 4 0 1c 18 3o s 4 1c h q 5 z a t 6 11 b t 2 10 5 d 17 4d 3 9 7 z 5 z 5 13 9 1f c b 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m 2 1z 9 8 1d i 2 1 6 w 4 q 6 0 1t 1j 5 j 3 1a g 3 2 2 2 s b 8 8 8 8 1r x 10 7 b a 1h 4 s 3 q 6 p 8 3 d 6 29 20 1k 1e e 1e 8 15 d 9 7 11 9 n 9 1 5t u 2 15 4 0 3 1 h 2 1o 13 9 l c 8 n h 5 3 13 r l m a 0 2 1h g 6 5 t 2 1 3 0 d 1c 5 1 3 1 2 3 f o 8 9 a z 6 0 a h 9 y 2 2 c 1f a 0 2 7 5 1 2 f 2 j c h 2 r 4 1 5 5 2 1 1s 6 2 0 2 3 2 e 2 a 7 1a 2 2 e 9 9 1 2 7 3 1 3 l 2 6 2 1 2 4 4 0 2 0 2 3 3 1 3 1 4 0 d 6 t 9 2 0 3 0 2 11 2 0 2 1 g 0 2 1 4 0 2 2 2 1 14 1j 9 1 4 0 2 k 2 0 2 2 v 1b 2 1 7 0 2 1 2 0 3 0 3 3 9 9 4n 1a 2 1 7 3 3 0 3 q 11 1e 9 1 2 0 3 3 c 9 7 c k 16 2 0 2 1 9 1 7 9 7 j t q 4 0 2 1 5 0 a m 56 1a a 0 3 0 2t 2a d 7 3 0 3 7 2 1 2 n 2 4 2 1 7 3 2 2 a 9 1z 7 3 15 9 3 2 3 s 0 b 13 7 1 5 7 a 0 7 1 4 19 e 0 3 8 e 20 8 9 53 x f 9 7 8 2 11 f 0 2 5 b s 4 v q 0 8 0 3 0 24 6 2 1 2 11 m 0 a 9 7 5 2 1 2 10 5 1 2 0 2 0 8 9 8n i 3 3 a e 2 z 9 1 4 m 2f 0 g 1d e pm 2v 32 2 4 c 5f 219 2q e tr i 5 q 32y 6 g6 5a2 t d 2 4 9 1c7 fs 8 u 2 9 5 28 2 9 7 t 8 0 b 1b 8 e b 9 2 6 2 k 6 i c1 1l 5j 2i 2u 22 6 1j c c 1t 3 t 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 3 0 3 0 31d 6x 7 c3 4d 37 1p 6t b 12 3 1n 6 2 n 1 8 t 5 1o m 1t 4 0 3f j d j d 2e a o 3s 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 83 3 fl 1k 3 1f 7 2 d 2 6 vp u 7 5 7a 1p 43 18 b 6 3 9 5 1 8x t j 17 5 9 6 0 cx r 5 9 5z t 3 a 5 0 dd 6 2 3 2 1 2 e 2 5g 3 8 1d 1v 8 0 5 9 5 1 lu 1v 25 1o 5f 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 1h 1 7j 17 5 2r d e 3 e 2 e 2 10 b 4t 1l s e 17 5 8 8 1 f 5 4b rb 5 g 4 c 4 3a 5 2m 7 b 5 0 g b 5 1j 9 9 7 13 9 t 3 b 5 1 1r 9f d d 3 c 4 9 6 1j 8 e 3 a 7 8 8 42 2 2t sn wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Gr_Ext Grapheme_Extend",
+        [
+            "1kt 0 5y 0 8b 0 ee 0 v0b 0 js5 3 f3 a hl 0 aa 0 83 0 r5 8 2 1 ct 1 p4 1 4 0 2 0 9o 1",
+            "2wq 0 2gr 0 s5k 0 m53 3 3 1 5 0 gvj 0 mht 6 c6 3",
+            "28l 0 fg 0 2m6 1 rxo 0 k8v 1 mb 0 1gh 0 b 1 2 0 5 0 h41 0",
+            "1p4 7 17 8 oa 0 260 0 63 0 j6 d mk 0 1bpk 3 6j 0 3 1 26 0 11ku 19 3 m 3u0 0",
+            "2xa 0 1elr 2 n6 0 2in 1 1h 4 6 0 2 0 45a 0 7 e y0q 0 v1 3",
+            "",
+            "1p3 0 tl 0 7 1 2 1 216 0 v 0 sn 0 2 0 6 1 2u 0 20 1 rxc 0 31 0 jop 4 b3 0 jo 0 39 0 7s 0 2z 0 3 5 2 0 3 0 2 2 5 2 2 0 f 1 k4 0 2w 0 f3 0 16s 0 p 0 czo b 4 2 2wx 1 j91 0 7 0 41t 1",
+            "lc 33 7o 6 7c 18 2 0 2 1 2 1 2 0 21 a 1d k h 0 2u 6 3 5 3 1 2 3 10 0 v q 2k a 1n 8 a 0 p 3 2 8 2 2 2 4 18 2 1o 8 17 n 2 v 1k 0 2 0 5 7 5 0 4 6 b 1 u 0 1n 0 2 0 3 3 9 0 a 0 b 1 r 0 3 1 1m 0 5 1 5 1 3 2 4 0 v 1 4 0 c 1 1m 0 5 4 2 1 5 0 l 1 n 5 2 0 1n 0 2 1 2 3 9 0 8 2 b 1 v 0 1o 0 2 0 d 0 a 0 15 0 4 0 1k 0 2 2 6 2 2 3 8 1 c 1 u 0 1n 0 3 1 2 0 4 2 2 3 8 1 c 1 t 1 1m 1 2 0 3 3 9 0 a 0 b 1 u 0 21 0 5 0 3 2 2 0 9 0 2a 0 3 6 d 7 2r 0 3 8 c 6 22 1 s 0 2 0 2 0 1k d 2 4 2 1 6 a 2 z a 0 2v 3 2 5 2 1 3 1 q 1 5 2 h 3 e 0 3 1 7 0 g 0 jk 2 qb 3 t 2 u 1 v 1 1t 1 2 6 9 0 3 a a 0 1a 2 2 0 3a 1 z 0 3b 2 5 1 a 0 7 2 64 1 3 0 1n 0 2 6 2 0 2 0 3 7 7 9 3 0 1d u 1e 3 1d 9 5 2 13 8 d 1 x 3 3 5 1l 0 2 1 4 0 2 4 1l 7 3 1 49 2 2 c 2 6 5 0 7 0 4 1 5j 1r el 0 5g w 2db 2 3y 0 2p v ff 5 2y 1 n9x 3 2 9 x 1 29 1 7l 0 4 0 5 0 q 1 6 0 48 1 r h e 0 13 7 q a 2 0 19 2 1d 0 3 3 3 1 3 0 11 0 1w 5 3 1 3 1 d 0 9 0 1c 0 1g 0 2 2 3 1 6 1 2 0 17 1 9 0 6n 0 3 0 5 0 fn5 0 ki f h f a7 1 gu 0 6b 0 46 4 1af 2 2 1 6 3 15 2 5 0 4m 1 fy 3 1u 4 8u 1 28 3 1z a 1e 3 3g 0 1j e 16 0 3 1 b 2 1e 3 3 1 8 0 1q 2 11 4 2 7 1r 0 d 1 1h 8 2 0 9 3 3 0 2o 2 3 3 7 0 3 0 4e 0 4 7 m 1 1m 1 2 0 2 0 d 0 a 0 f 6 4 4 1w 0 3 5 2 0 3 0 2 2 5 2 2 0 f 1 2e 7 3 2 2 0 o 0 2a 0 3 5 2 0 3 0 2 1 2 1 6k 0 3 3 7 1 2 1 s 1 2e 7 3 0 2 1 2z 0 2 0 3 7 2u 0 2 0 3 3 2 4 78 8 2 1 6u 0 b 3 5 0 41 3 3 1 5 0 x 9 15 5 3 3 9 0 a 5 3 2 1b c 2 1 bb 6 2 5 2 0 2b l 3 6 2 1 2 1 3f 5 4 0 2 1 2 6 2 0 21 1 4 0 2 0 9o 1 c 1 1h 4 6 2 o 0 44m 0 7 e 8uh b 4 2 1xd 4 1o 6 t5 0 1s 3 2a 0 c 1 f58 1 3mq 19 3 m f3 4 4 5 9 7 3 6 v 3 45 2 1j0 1i 5 1d 9 0 f 0 n 4 2 e 11t 6 2 g 3 6 2 1 2 4 2t 0 4h 6 ag 0 1q 3 e5 3 73 1 kh 6 32 6 gzbq 2n 3l 6n",
+        ],
+    ),
+    (
+        "Hex Hex_Digit",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1c 9 8 5 r 5 1eay 9 8 5 r 5",
+        ],
+    ),
+    (
+        "IDC ID_Continue",
+        [
+            "128 0 14 0 2v 0 em 0 5y 0 8b 0 ee 0 2gk 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 1 j79 1 jv 13 9 9 cn s b 0 9 w dw 2 3n 0 aa 0 83 0 jg 0 6e 1m gz 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n m fnu 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 reo 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 7 2 1 4g 1 gqo 5 5 0 1c 8 2k 0 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 0 aa 1l 18y 0",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 4jf 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 1 jkn 15 2 1 4 1 73 k ar 0 3r 1 i9 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 8 d 9 193 0 fuc 0 c 1 5c2 de 17 8 lwo 9 xr2 6 hlv 3t6",
+            "1o0 n 2 5 a 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 j2 0 2t1 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu l 6j 5 25 0 1a6 6 oa f 441 2o bk0 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m 33u u oi u 10y 6 2 3 2 1 2 e 11pc 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 2in g 2 14 4 4 e 9 446 0 h l onx 0 z 0 91s 5 7a 1p y 0 u9 15 15j4 0 i6v 38f",
+            "6bw 1 3ce 0 14ru 0 2paj h9",
+            "1p3 0 3xu 1 ri9 2 d 2 ijo 1f 1fx 11 4 4 2 m 8t 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 7 e 1 ku j yl w g 9 o1 0 45i 32y 5qe 1l 2dj 18 4 9 686 0 cmp 9 4wn 16",
+            "1c 9 8 p 5 0 2 p 1c 0 b 0 2 0 3 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 i 38 2 1 3 3 2 0 7 4 2 0 2 j 2 2a 2 3u 2 4 3 4l 2 11 3 0 7 14 9 18 2 0 2 1 2 1 2 0 9 q 5 3 u a 6 21 5 2t 2 7 3 9 2 i 3 0 h 1m 3 2s f 1h 5 0 3 0 3 19 j r 5 a 6 n 2 5 9 22 2 3k 3 9 2 i 2 7 3 1 3 l 2 6 2 0 4 3 3 8 3 1 3 3 9 0 5 1 2 4 3 b b 0 2 0 3 2 2 5 5 1 3 l 2 6 2 1 2 1 2 1 3 0 2 4 5 1 3 2 4 0 8 3 2 0 8 f c 2 2 8 2 2 2 l 2 6 2 1 2 4 3 9 2 2 2 2 3 0 g 3 3 9 a 6 2 2 2 7 3 1 3 l 2 6 2 1 2 4 3 8 3 1 3 2 8 2 5 1 2 4 3 9 2 0 h 1 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 5 4 4 2 2 3 3 0 7 0 f 9 h c 2 2 2 m 2 f 3 8 2 2 2 3 8 1 2 2 3 0 3 3 3 9 h 3 2 7 2 2 2 m 2 9 2 4 3 8 2 2 2 3 8 1 7 1 2 3 3 9 2 2 d c 2 2 2 1e 2 2 2 4 6 3 8 4 3 9 b 5 2 2 2 h 4 n 2 8 2 0 3 6 4 0 5 5 2 0 2 7 7 9 3 1 e 1l 6 e 2 9 14 1 2 0 2 4 2 n 2 0 2 m 3 4 2 0 2 6 2 9 3 3 x 0 o 1 7 9 c 0 2 0 2 0 5 9 2 z 5 j 2 h 2 z a 0 1m 21 7 25 3 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 3 2 a 8 f f h 2d 3 5 4 h7 3 g 2 p 6 22 4 a 8 l a l c j d c 2 2 2 1 d 2b 4 0 5 1 3 9 y 2 2 a 7 2g 8 16 6 1x b u 2 b 5 b b 13 3 4 c 17 5 p 7 a 12 r 5 1q 2 s 3 a 7 9 e 0 9 d 2 f 1e 24 4 9 i 8 d 37 d 1j 9 9 4 1c 3 a 6 16 3 2 h 2 2 12 6 et 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 g 1 1e 1 k 0 t 0 e 0 h c 1g c 5 0 4 b i 0 5 0 3 9 2 0 3 5 7 0 2 0 2 0 2 f 3 3 6 4 5 0 i 14 22g 6c 7 8 d 11 2 0 6 0 3 1j 8 0 g n a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 2 v ee 2 q e 2 4 3 4 5 2d 3 6 2 2m 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 r l 1b 5 9 2 36 12 8 3 2u 3 1u 3 1 2 0 2 7 m 1h 5 0 k 1f d 1x b 9 7 n 4 0 2 1c 3 z d s 4 1s f a 7 u 2 1i a d 3 9 7 m 4 20 p 2 3 f 3 4 b 5 3 5 3 5 a 6 2 6 2 16 2 d 7 3e 2 1 3 9 7 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 b 2 c 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 b 5 f h f 4 1 p 2 x 4 2 3q k 9 8 p 5 0 2 p b 2h 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e 1y 1g 3t 0 3n s 4 1c g 0 w v e t 6 16 6 t 3 z 5 7 2 4 17 4d 3 9 7 z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 3 2 1 6 7 2 2 2 s 3 2 5 0 x s 4 s 10 7 2 t q 1h b l b i e h 33 20 1k 1e e 1e e 13 9 9 7 11 4 4 2 m 6z 15 2 1 4 1 h 2 1k w b 0 9 w w l 17 k s m a 1y w f a 1n 8 0 e o 8 9 7 1g 2 9 5 3 9 z 3 0 a 1w 5 3 2 c 2 0 10 h 2 10 7 3 1r 6 2 0 2 3 2 e 2 9 8 1m 6 9 7 3 2 7 3 1 3 l 2 6 2 1 2 4 2 9 3 1 3 2 3 0 7 0 6 6 3 6 4 4 c 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 7 e 1 u 22 6 9 5 3 v 1x 2 0 9 9 4n 1h 3 8 o 5 z 1s 4 0 c 9 13 1k 8 9 7 j t q 3 e 5 9 7 6 56 1m 2u 21 m 7 3 0 3 7 2 1 2 t 2 1 3 8 d 9 1z 7 3 19 3 7 2 1 s 1q 9 0 9 21 4 0 j 20 5k w g 9 7 8 2 18 2 8 g 9 p t 3 l 2 d 22 6 2 1 2 17 4 0 2 1 2 8 9 9 7 5 2 1 2 10 2 1 2 5 8 9 8n m a g 2 14 4 4 e a 2e 0 28 pl 2v 32 i 5f 219 2o g tr h l b 32y 6 g6 5a2 1l 1c7 fs 8 u 2 9 7 26 2 9 7 t 3 4 c 1i a 3 d 9 a k 6 i c1 18 4 9 5j 1r 3l 22 5 1k 8 g 1t 1 2 1 c 1 f 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 4 1 382 9 ef 19 3 m f3 4 4 5 9 7 3 6 v 3 45 2 cc 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 3 1d e9 1i 5 1d 9 0 f 0 n 4 2 e up u 7 5 5y 6 2 g 3 6 2 1 2 4 6 1p y 0 35 18 4 d 3 9 5 0 8y u i 1l d3 15 5z 16 di 6 2 3 2 1 2 e 2 5g c 6 16 23 5 9 x3 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 2lx 9 sn wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f f9e9 6n",
+        ],
+    ),
+    (
+        "IDS ID_Start",
+        [
+            "128 0 14 0 2v 0 3nt 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 0 j7a 1 jv z d9 s b 0 9 l e7 0 15i 0 6e 17 he 0 jn 5 2 1 2 v f 0 94 i fny 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 2ti 1 7 0 reo 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 12 h 0 2 0 4h 1 gqo 5 49 0 4r3 5 861 2 i 3 9eh 18 b 6 h 0 aa 17 19c 0",
+            "1q6 9 u5 0 793 4 5iv 9 g8h c 1jf 3 17 1 oi 1 jkn 15 7 1 73 k ar 0 m1 1 wv 6 3 0 3 7 2 1 2 n g 0 2 0 19r 0 l6r de 17 8 1jnz 6 hlv 3t6",
+            "1o0 n 2 5 13 0 j 1 pg 0 3k 0 20g 0 i 0 tp 0 3c3 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu h 6o 1 3 0 1cb 6 oa f 441 2o bk0 26 do2 3 2 6 2 1 81 3 926 u oi t 10z 6 2 3 2 1 2 e 11pc 1 386 3",
+            "1i67 1 2iq 0 2 c 2 x 458 0 i 5 ooc 0 z 0 91s 5 7a 1p v7 r 15ji 0 i6v 38f",
+            "43qo h9",
+            "5mx 1 ri9 2 d 2 ijo 1f 1g7 r a m 8t 2 xo 9 2 0 3 0 2 11 2 0 q 0 2 0 1kd w 4u8 32y 5qe t 2eb 18 68j 0 hjl t 3 0",
+            "1t p 7 p 1c 0 b 0 5 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 3m 4 2 1 3 3 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 3 0 7 14 20 q 5 3 1a 16 10 1 2 2q 2 0 g 1 8 1 b 2 3 0 h 0 2 t u 2g c 0 p w a 1 5 0 6 l 5 0 a 0 4 0 o o 8 a 6 n 2 5 i 15 1n 1h 4 0 j 0 8 9 g f 5 7 3 1 3 l 2 6 2 0 4 3 4 0 h 0 e 1 2 2 f 1 b 0 9 5 5 1 3 l 2 6 2 1 2 1 2 1 w 3 2 0 k 2 h 8 2 2 2 l 2 6 2 1 2 4 4 0 j 0 g 1 o 0 c 7 3 1 3 l 2 6 2 1 2 4 4 0 v 1 2 2 g 0 i 0 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b n 0 1h 7 2 2 2 m 2 f 4 0 r 2 3 0 3 1 v 0 5 7 2 2 2 m 2 9 2 4 4 0 w 1 2 1 g 1 i 8 2 2 2 14 3 0 h 0 6 2 9 2 p 5 6 h 4 n 2 8 2 0 3 6 1n 1b 2 1 d 6 1n 1 2 0 2 4 2 n 2 0 2 9 2 1 a 0 3 4 2 0 m 3 x 0 1s 7 2 z s 4 38 16 l 0 h 5 5 3 4 0 4 1 8 2 5 c d 0 i 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 12 f h 2d 3 5 4 h7 3 g 2 p 6 22 4 a 8 h e i f h f c 2 2 g 1f 10 0 5 0 1w 2g 8 14 2 0 6 1x b u 1e t 3 4 c 17 5 p 1j m a 1g 2b 0 2m 1a i 7 1j t e 1 b 17 r z 16 2 b z 3 a 6 16 3 2 16 3 2 5 2 1 4 0 6 5b 1t 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 39 0 e 0 h c 2u 0 5 0 3 9 2 0 3 5 7 0 2 0 2 0 2 f 3 3 6 4 5 0 i 14 22g 6c 7 3 4 1 d 11 2 0 6 0 3 1j 8 0 h m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 fb 2 q 8 8 4 3 4 5 2d 5 4 2 2h 2 3 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 f b 1 l 1a h u 3 27 14 8 3 2u 3 1u 3 1 2 0 2 7 m f 2 2 2 3 2 m u 1f f 1d 1r 5 4 0 2 1 c r b m q s 8 1a t 0 h 4 2 9 b 4 2 14 o 2 2 7 l m 4 0 4 1d 2 0 4 1 3 4 3 0 2 0 p 2 3 a 8 2 d 5 3 5 3 5 a 6 2 6 2 16 2 d 7 36 u 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 0 2 9 2 c 2 4 2 0 2 1 2 1 2 2z y a2 j 1r 3 1h 15 b 39 4 2 3q 11 p 7 p c 2g 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e 1y 1g 7g s 4 1c 1c v e t 6 11 b t 3 z 5 7 2 4 17 4d j z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 0 g 3 2 2 2 s 17 s 4 s 10 7 2 r s 1h b l b i e h 33 20 1k 1e e 1e e z 13 r a m 6z 15 7 1 h 2 1o s b 0 9 l 17 h 1b k s m d 1g 1m 1 3 0 e 18 x o r z u 0 3 0 9 y 4 0 d 1b f 3 m 0 2 0 10 h 2 o k 1 1s 6 2 0 2 3 2 e 2 9 8 1a 13 7 3 1 3 l 2 6 2 1 2 4 4 0 j 0 d 4 v 9 2 0 3 0 2 11 2 0 q 0 2 0 19 1g j 3 l 2 v 1b l 1 2 0 55 1a 16 3 11 1b l 0 1o 16 e 0 20 q 12 6 56 17 39 1r w 7 3 0 3 7 2 1 2 n g 0 2 0 2n 7 3 12 h 0 2 0 t 0 b 13 8 0 m 0 c 19 k 0 j 20 5k w w 8 2 10 i 0 1e t 35 6 2 1 2 11 m 0 q 5 2 1 2 v f 0 94 i g 0 2 c 2 x 3h 0 28 pl 2v 32 i 5f 219 2o g tr i 5 q 32y 6 g6 5a2 t 1cz fs 8 u i 26 i t j 1b h 3 w k 6 i c1 18 5w 1r 3l 22 6 0 1v c 1t 1 2 0 t 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 4mf 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 u 7 5 7a 1p 43 18 b 6 h 0 8y t j 17 dh r 6d t 3 0 ds 6 2 3 2 1 2 e 2 5g 1o 1v 8 0 xh 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 3et wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "IDSB IDS_Binary_Operator",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9h8 1 du 0",
+            "",
+            "9gw 1 3 9 du 0",
+        ],
+    ),
+    (
+        "IDST IDS_Trinary_Operator",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9gy 1",
+        ],
+    ),
+    (
+        "Ideo Ideographic",
+        [
+            "vl7 4 19tq 4",
+            "25f6 5",
+            "fcm 9 g8h c 152g 0 5cf de 17 8 1jnz 6 hlv 3t6",
+            "vlp 2 2uhr 1 386 3",
+            "3tbt 0 i6v 38f",
+            "43qo h9",
+            "26f3 0",
+            "9hi 1 q 8 f 2 qu 533 1t g73 hkx a5 3 2x n4b 0 s 4qf 9 yd 16 9 76w az f7p wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+    (
+        "Join_C Join_Control",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "6bw 1",
+        ],
+    ),
+    (
+        "LOE Logical_Order_Exception",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2tc 4 3g 4 25t 2 3 0 smz 1 3 0 2 1",
+        ],
+    ),
+    (
+        "Lower Lowercase",
+        [
+            "128 0 14 0 288 16 3 2 trk 0 a 0 138n v",
+            "x4r 0 2 0 2 0 4 0 pv 1",
+            "x54 0 2 0 18 0 oi 0",
+            "8rj 0 ode 0 g 0 2 0 2 0 2 0 2 0 iim a 2 e 2 6 2 1 ck 0 3 2 2 15 2 8 16iu 9 2 j",
+            "3cs 0 tti 2 ol 0 1osc 5 7a 1p",
+            "",
+            "5my 0 rib 0 e 0 k2d l",
+            "2p p 1c 0 b 0 5 0 11 n 2 7 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 2 3 0 2 0 3 0 4 1 5 0 3 0 4 2 3 0 3 0 2 0 2 0 3 0 2 1 2 0 3 0 4 0 2 0 3 1 3 2 7 0 3 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 6 3 0 3 1 2 0 5 0 2 0 2 0 2 0 2 1w 2 z 8 1 v 4 2p 0 18 0 2 0 4 0 3 3 j 0 s y 2 1 4 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 0 3 0 3 1 1g 1b 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 1d 14 288 16 2 3 l5 5 1oj 8 2 0 3a 5b 1u 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 8 9 5 b 7 9 7 9 5 b 7 9 7 9 d 3 7 9 7 9 7 9 4 2 1 7 0 4 2 2 1 9 3 3 1 9 7 b 2 2 1 3e 0 e 0 h c 32 0 4 1 4 0 s 0 5 0 5 0 3 1 9 3 5 0 y f 5 0 ng p 1fr 1b 2 0 4 1 2 0 2 0 2 0 5 0 2 1 2 7 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 8 0 2 0 5 0 d 11 2 0 6 0 nx0 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 2 3q 0 2 0 2 0 2 0 2 0 2 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 9 2 0 2 0 3 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 0 6 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 5 0 2 0 3 0 4 0 2 0 2 0 2 0 2 0 2 0 n 2 2 0 2 2 mu 16 2 d 7 27 fnl 6 d 4 tm p y6 13 3t z 4c a 2 e 2 6 2 1 ck 0 3 2 2 15 2 8 zq 1e 3i l 27v v gw1 v k2j p r 6 2 h r p r 3 2 0 2 6 2 a r p r p r p r p r p r p r p r p r r t o 2 5 r o 2 5 r o 2 5 r o 2 5 r o 2 5 2 0 1f9 9 2 j 7 5 7a 1p 1px x",
+        ],
+    ),
+    (
+        "Math Math",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1h8u 1",
+            "17 0 h 2 w 0 u 0 2 0 1a 0 5 0 12 0 w 0 k9 2 3 0 r 1 3 2 eo 2 55a 0 s 2 c 0 4 0 e 0 f 3 m 4 c 4 1u c 5 0 4 1 5 4 j 0 5 0 3 9 2 0 3 5 7 0 4 1 3 1 2 2 2 5 4 d 2 0 1x n 2 5 2 1 5 1 5 v 2 0 7 1 f 7f 9 3 l 1 2j 0 v q 2 0 p 0 c 6 ce 1 d 9 5 5 5 1 3 1 4 4 f 0 2 0 3 5 c 7 6 1 1m 0 2 0 u 3 a 2 9d 1r 75 e7 1d k 3 5 1525 0 mw 5 2 0 4j 0 h 2 u 0 2 0 u 0 2 0 3o 0 7 3 2oy 1 1375 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 83 3 1d 4ch 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 1h 1",
+        ],
+    ),
+    (
+        "NChar Noncharacter_Code_Point",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "1e4w v en 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1 1ekf 1",
+        ],
+    ),
+    (
+        "Pat_Syn Pattern_Syntax",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "x e b 6 r 3 2 0 r 3 z 6 2 0 2 1 2 0 2 1 5 0 5 0 4 0 o 0 w 0 655 n 9 e 3 i 2 9 8i jz 4h hh v vf e9 3j aq 2 5 o g 0 14i6 1 7a 1",
+        ],
+    ),
+    (
+        "Pat_WS Pattern_White_Space",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9 4 j 0 2t 0 689 1 p 1",
+        ],
+    ),
+    (
+        "QMark Quotation_Mark",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "y 0 5 0 3o 0 g 0 671 7 q 1 2rs 0 cq 3 e 2 14pu 3 5a 0 5 0 2j 1",
+        ],
+    ),
+    (
+        "RI Regional_Indicator",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2qcm p",
+        ],
+    ),
+    (
+        "Radical Radical",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "96o p 2 2g d 5x",
+        ],
+    ),
+    (
+        "SD Soft_Dotted",
+        [
+            "",
+            "",
+            "",
+            "2mmy 0",
+            "2mvg 1 r 0",
+            "",
+            "",
+            "2x 1 5h 0 7u 0 v 0 1h 0 l 0 8x 0 2r 0 2 0 4y2 0 1g 0 e 0 4 0 3p 0 4e 0 bq 0 5z 1 27n 0 2bom 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1f 1 1on 0 8i 1 r 0",
+        ],
+    ),
+    (
+        "STerm Sentence_Terminal",
+        [
+            "17i 0 ex 0 2 0 4 1 1fyv 4 332 1 fq8 0",
+            "",
+            "1jk4 0 2 0",
+            "17h 0 480 1 3px 1 18he 3",
+            "1kqr 1",
+            "4pg 1",
+            "5e6 1 1c 0 x1 0 2j9 2 15av 0 3 1 4am 1 hp5 1",
+            "x 0 d 0 h 0 11m 0 44 2 51 0 18 2 6v 0 1q 0 2 0 4 1 86 1 1d1 1 lz 0 5 1 li 0 5j 1 4e 1 1a 0 6 0 8r 1 9v 3 4j 1 b 1 3 1 u 2 58 1 1u 1 px 0 o 1 a 2 2i8 2 8j 0 e 0 n 1 by 0 n3x 0 7j 1 6c 0 4 0 an 1 2f 1 2o 0 49 1 44 2 41 1 6y 0 g87 0 3 1 1o 0 4 1 4q 0 d 0 h 0 1u 0 25x 1 zi 4 19 3 5a 1 3a 3 3k 2 3m 1 7 0 h 1 2h 1 2 1 31 0 8b 1 3a 1 ae 1 6 e 2y 1 6y 2 ee 0 2 0 70 1 2g 1 bp 1 j9 1 23 1 eui 1 3q 0 1u 1 c 0 fe 1 89 0 fev 0 5wp 0",
+        ],
+    ),
+    (
+        "Term Terminal_Punctuation",
+        [
+            "17i 0 7xq 0 2 0 18g7 4 332 1 fq7 1",
+            "4fh 0 4pu 0",
+            "1il6 0 yy 0 2 0",
+            "17h 0 480 1 3px 1 18he 3",
+            "1kqr 1",
+            "",
+            "1me 0 3rs 1 1c 0 x1 0 2j9 2 15av 0 3 1 4am 1 hp5 1",
+            "x 0 b 0 2 0 c 1 4 0 n3 0 9 0 ea 0 1m 0 21 0 f 0 2 2 51 0 18 a 2 0 6k 1 1j 5 2 7 w 0 7a 1 z9 1 4t 0 5 5 8o 1 ly 7 li 0 3h 2 20 1 4e 2 4 0 14 3 3 1 8r 1 9v 3 4j 1 b 1 2 2 u 2 58 4 1r 1 px 0 o 1 a 2 2i8 2 8j 0 e 0 5 0 b 0 2 1 4 1 bx 1 n3w 1 7i 2 6c 4 an 1 2f 1 2o 0 48 2 44 2 3k 0 h 1 6y 0 g87 0 3 1 1m 2 2 3 4q 0 b 0 2 0 c 1 4 0 1u 0 3 0 u3 0 1d 0 w7 0 5k 0 8n 1 49 5 1x 5 2i 3 qh 4 19 3 5a 6 35 3 3k 2 3m 1 7 0 h 1 2h 4 31 0 8b 1 3a 2 d 1 9z 3 4 e 2y 1 6y 2 ee 0 2 0 70 1 2g 1 5 1 bj 2 1a 0 hy 1 23 1 10s 4 dtm 1 3q 0 1u 2 b 0 fe 1 88 1 fev 0 5wo 3",
+        ],
+    ),
+    (
+        "UIdeo Unified_Ideograph",
+        [
+            "vl7 4",
+            "",
+            "fcm 9 g8h c 2uhn 6 hlv 3t6",
+            "vlp 2 2uhr 1 386 3",
+            "3tbt 0 i6v 38f",
+            "43qo h9",
+            "",
+            "a9s 533 1t g73 hsf 1 2 0 2 1 b 0 2 0 2 1 3 2 1fpz wyn x 37d 7 65 3 4g1 f 5rk g h9 3hf 3t6 6 38f",
+        ],
+    ),
+    (
+        "Upper Uppercase",
+        [
+            "5n4 16 3 2 rg9 0 137s v",
+            "x4q 0 2 0 2 0 4 0 2 2",
+            "x53 0 2 0 18 0",
+            "8q7 0 oep 0 g 0 6 0 2 0 ihk a 2 e 2 6 2 1",
+            "",
+            "",
+            "5mx 0 ria 1 e 0 2 0 k1g l",
+            "1t p 2u m 2 6 y 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 4 1 2 0 2 1 2 2 3 3 2 1 2 2 4 1 2 1 2 0 2 0 2 1 2 0 3 0 2 1 2 2 2 0 2 1 4 0 8 0 3 0 3 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 0 3 0 2 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 8 1 2 1 3 0 2 3 2 0 2 0 2 0 2 0 82 0 2 0 4 0 9 0 7 0 2 2 2 0 2 1 2 g 2 8 10 0 3 2 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 6 0 3 0 2 1 3 1e 1d 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 1 2 0 2 0 2 0 2 0 2 0 2 0 3 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3 11 28a 11 2 0 6 0 k3 2d 1p0 0 7 16 3 2 8x 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 a 7 9 5 b 7 9 7 9 5 c 0 2 0 2 0 2 0 9 7 21 3 d 3 d 3 d 4 c 3 7b 0 5 0 4 2 3 2 3 0 4 4 7 0 2 0 2 0 2 3 3 3 b 1 6 0 r f k 0 mr p 1f5 1b 1d 0 2 2 3 0 2 0 2 0 2 3 2 0 3 0 9 2 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 9 0 2 0 5 0 nym 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 k 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 3s 0 2 0 2 0 2 0 2 0 2 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 b 0 2 0 2 1 2 0 2 0 2 0 2 0 5 0 2 0 3 0 2 0 4 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 4 2 4 2 0 2 0 2 0 2 0 2 0 2 0 2 0 2 3 2 0 2 1 4 0 6 0 2 0 2 0 2 0 p 0 h7w p xy 13 3t z 4d a 2 e 2 6 2 1 1d7 1e 4e l 27v v gw1 v k2p p r p r p r 0 2 1 3 0 3 1 3 3 2 7 r p r 1 2 3 3 7 2 6 s 1 2 3 2 4 2 0 4 6 s p r p r p r p r p r p v o y o y o y o y o y 0 3ee x 1lb p 7 p 7 p",
+        ],
+    ),
+    (
+        "VS Variation_Selector",
+        [
+            "",
+            "",
+            "",
+            "4r3 0",
+            "",
+            "",
+            "",
+            "4qz 2 2 0 19f5 f i9yp 6n",
+        ],
+    ),
+    (
+        "WSpace White_Space space",
+        [
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "9 4 j 0 2t 0 r 0 4bk 0 1vk a u 1 6 0 1c 0 335 0",
+        ],
+    ),
+    (
+        "XIDC XID_Continue",
+        [
+            "128 0 14 0 2v 0 em 0 5y 0 8b 0 ee 0 2gk 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 1 j79 1 jv 13 9 9 cn s b 0 9 w dw 2 3n 0 aa 0 83 0 jg 0 6e 1m gz 0 jn 5 2 1 2 10 2 1 2 5 8 9 8n m fnu 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 e 0 2tc 0 reo 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 19 3 7 2 1 4g 1 gqo 5 5 0 1c 8 2k 0 4r3 5 861 2 i 3 9eh 18 4 d 3 9 5 0 aa 1l 18y 0",
+            "1q6 9 i6 0 bz 0 3h 0 2m6 1 4jf 4 5iv 9 g8h c 1jf 3 17 1 1i 0 n0 1 jkn 15 2 1 4 1 73 k ar 0 3r 1 i9 1 wv 6 3 0 3 7 2 1 2 t 2 1 3 8 d 9 193 0 fuc 0 c 1 5c2 de 17 8 lwo 9 xr2 6 hlv 3t6",
+            "1o0 n 2 5 a 7 m 0 j a oa 0 x 0 3k 0 20g 0 8 0 a 0 6o 0 j6 d 3i 0 j2 0 2t1 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu l 6j 5 25 0 1a6 6 oa f 441 2o bk0 26 2 9 dnr 3 2 6 2 1 81 3 5we 19 3 m 33u u oi u 10y 6 2 3 2 1 2 e 11pc 1 386 3",
+            "2k3 0 d7 0 1elr 2 n4 2 2in g 2 14 4 4 e 9 446 0 h l onx 0 z 0 91s 5 7a 1p y 0 u9 15 15j4 0 i6v 38f",
+            "6bw 1 3ce 0 14ru 0 2paj h9",
+            "1p3 0 3xu 1 ri9 2 d 2 ijo 1f 1fx 11 4 4 2 m 8t 2 1k 0 w4 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 7 e 1 ku j yl w g 9 o1 0 45i 32y 5qe 1l 2dj 18 4 9 686 0 cmp 9 4wn 16",
+            "1c 9 8 p 5 0 2 p 1c 0 b 0 2 0 3 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 i 38 2 1 4 2 2 0 7 4 2 0 2 j 2 2a 2 3u 2 4 3 4l 2 11 3 0 7 14 9 18 2 0 2 1 2 1 2 0 9 q 5 3 u a 6 21 5 2t 2 7 3 9 2 i 3 0 h 1m 3 2s f 1h 5 0 3 0 3 19 j r 5 a 6 n 2 5 9 22 2 3k 3 9 2 i 2 7 3 1 3 l 2 6 2 0 4 3 3 8 3 1 3 3 9 0 5 1 2 4 3 b b 0 2 0 3 2 2 5 5 1 3 l 2 6 2 1 2 1 2 1 3 0 2 4 5 1 3 2 4 0 8 3 2 0 8 f c 2 2 8 2 2 2 l 2 6 2 1 2 4 3 9 2 2 2 2 3 0 g 3 3 9 a 6 2 2 2 7 3 1 3 l 2 6 2 1 2 4 3 8 3 1 3 2 8 2 5 1 2 4 3 9 2 0 h 1 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b 5 4 4 2 2 3 3 0 7 0 f 9 h c 2 2 2 m 2 f 3 8 2 2 2 3 8 1 2 2 3 0 3 3 3 9 h 3 2 7 2 2 2 m 2 9 2 4 3 8 2 2 2 3 8 1 7 1 2 3 3 9 2 2 d c 2 2 2 1e 2 2 2 4 6 3 8 4 3 9 b 5 2 2 2 h 4 n 2 8 2 0 3 6 4 0 5 5 2 0 2 7 7 9 3 1 e 1l 6 e 2 9 14 1 2 0 2 4 2 n 2 0 2 m 3 4 2 0 2 6 2 9 3 3 x 0 o 1 7 9 c 0 2 0 2 0 5 9 2 z 5 j 2 h 2 z a 0 1m 21 7 25 3 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 3 2 a 8 f f h 2d 3 5 4 h7 3 g 2 p 6 22 4 a 8 l a l c j d c 2 2 2 1 d 2b 4 0 5 1 3 9 y 2 2 a 7 2g 8 16 6 1x b u 2 b 5 b b 13 3 4 c 17 5 p 7 a 12 r 5 1q 2 s 3 a 7 9 e 0 9 d 2 f 1e 24 4 9 i 8 d 37 d 1j 9 9 4 1c 3 a 6 16 3 2 h 2 2 12 6 et 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 g 1 1e 1 k 0 t 0 e 0 h c 1g c 5 0 4 b i 0 5 0 3 9 2 0 3 5 7 0 2 0 2 0 2 f 3 3 6 4 5 0 i 14 22g 6c 7 8 d 11 2 0 6 0 3 1j 8 0 g n a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 2 v ee 2 q e 2 4 3 4 5 2d 3 1 3 2 2 2m 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 r l 1b 5 9 2 36 12 8 3 2u 3 1u 3 1 2 0 2 7 m 1h 5 0 k 1f d 1x b 9 7 n 4 0 2 1c 3 z d s 4 1s f a 7 u 2 1i a d 3 9 7 m 4 20 p 2 3 f 3 4 b 5 3 5 3 5 a 6 2 6 2 16 2 d 7 3e 2 1 3 9 7 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 b 2 c 2 4 2 0 2 1 2 1 2 2z y 3u 7 61 j 1r 3 1h 15 9 7 f h f 4 1 p 2 y 0 2 0 4 0 2 0 2 0 2 0 2 3h k 9 8 p 5 0 2 p b 2h 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e 1y 1g 3t 0 3n s 4 1c g 0 w v e t 6 16 6 t 3 z 5 7 2 4 17 4d 3 9 7 z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 3 2 1 6 7 2 2 2 s 3 2 5 0 x s 4 s 10 7 2 t q 1h b l b i e h 33 20 1k 1e e 1e e 13 9 9 7 11 4 4 2 m 6z 15 2 1 4 1 h 2 1k w b 0 9 w w l 17 k s m a 1y w f a 1n 8 0 e o 8 9 7 1g 2 9 5 3 9 z 3 0 a 1w 5 3 2 c 2 0 10 h 2 10 7 3 1r 6 2 0 2 3 2 e 2 9 8 1m 6 9 7 3 2 7 3 1 3 l 2 6 2 1 2 4 2 9 3 1 3 2 3 0 7 0 6 6 3 6 4 4 c 9 2 0 3 0 2 11 2 9 2 0 3 0 2 3 2 7 e 1 u 22 6 9 5 3 v 1x 2 0 9 9 4n 1h 3 8 o 5 z 1s 4 0 c 9 13 1k 8 9 7 j t q 3 e 5 9 7 6 56 1m 2u 21 m 7 3 0 3 7 2 1 2 t 2 1 3 8 d 9 1z 7 3 19 3 7 2 1 s 1q 9 0 9 21 4 0 j 20 5k w g 9 7 8 2 18 2 8 g 9 p t 3 l 2 d 22 6 2 1 2 17 4 0 2 1 2 8 9 9 7 5 2 1 2 10 2 1 2 5 8 9 8n m a g 2 14 4 4 e a 2e 0 28 pl 2v 32 i 5f 219 2o g tr h l b 32y 6 g6 5a2 1l 1c7 fs 8 u 2 9 7 26 2 9 7 t 3 4 c 1i a 3 d 9 a k 6 i c1 18 4 9 5j 1r 3l 22 5 1k 8 g 1t 1 2 1 c 1 f 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 4 1 382 9 ef 19 3 m f3 4 4 5 9 7 3 6 v 3 45 2 cc 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 3 1d e9 1i 5 1d 9 0 f 0 n 4 2 e up u 7 5 5y 6 2 g 3 6 2 1 2 4 6 1p y 0 35 18 4 d 3 9 5 0 8y u i 1l d3 15 5z 16 di 6 2 3 2 1 2 e 2 5g c 6 16 23 5 9 x3 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 2lx 9 sn wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f f9e9 6n",
+        ],
+    ),
+    (
+        "XIDS XID_Start",
+        [
+            "128 0 14 0 2v 0 3nt 0 t4 16 3 2 41c 0 lvg 4 1j4 0 9 1 91 0 j7a 1 jv z d9 s b 0 9 l e7 0 15i 0 6e 17 he 0 jn 5 2 1 2 v f 0 94 i fny 1r 50u 4",
+            "2va 0 3 0 3 0 2 5 5 0 8 0 8 1 3 0 2ti 1 7 0 reo 5 3 4 ps 1 juh m vd 0 gp 0 ko 7 3 12 h 0 2 0 4h 1 gqo 5 49 0 4r3 5 861 2 i 3 9eh 18 b 6 h 0 aa 17 19c 0",
+            "1q6 9 u5 0 793 4 5iv 9 g8h c 1jf 3 17 1 oi 1 jkn 15 7 1 73 k ar 0 m1 1 wv 6 3 0 3 7 2 1 2 n g 0 2 0 19r 0 l6r de 17 8 1jnz 6 hlv 3t6",
+            "1o0 n 2 5 13 0 j 1 pg 0 3k 0 20g 0 i 0 tp 0 3c3 0 1c 0 mu6 2 1j5 1 f 1 2 0 2 4 p 2 igs a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 ck 5 2 15 2 8 1iu h 6o 1 3 0 1cb 6 oa f 441 2o bk0 26 do2 3 2 6 2 1 81 3 926 u oi t 10z 6 2 3 2 1 2 e 11pc 1 386 3",
+            "1i67 1 2iq 0 2 c 2 x 458 0 i 5 ooc 0 z 0 91s 5 7a 1p v7 r 15ji 0 i6v 38f",
+            "43qo h9",
+            "5mx 1 ri9 2 d 2 ijo 1f 1g7 r a m 8t 2 xo 9 2 0 3 0 2 11 2 0 q 0 2 0 1kd w 4u8 32y 5qe t 2eb 18 68j 0 hjl t 3 0",
+            "1t p 7 p 1c 0 b 0 5 0 6 m 2 u 2 cp 5 b f 4 8 0 2 0 3m 4 2 1 4 2 2 0 7 0 2 2 2 0 2 j 2 2a 2 3u 9 4l 2 11 3 0 7 14 20 q 5 3 1a 16 10 1 2 2q 2 0 g 1 8 1 b 2 3 0 h 0 2 t u 2g c 0 p w a 1 5 0 6 l 5 0 a 0 4 0 o o 8 a 6 n 2 5 i 15 1n 1h 4 0 j 0 8 9 g f 5 7 3 1 3 l 2 6 2 0 4 3 4 0 h 0 e 1 2 2 f 1 b 0 9 5 5 1 3 l 2 6 2 1 2 1 2 1 w 3 2 0 k 2 h 8 2 2 2 l 2 6 2 1 2 4 4 0 j 0 g 1 o 0 c 7 3 1 3 l 2 6 2 1 2 4 4 0 v 1 2 2 g 0 i 0 2 5 4 2 2 3 4 1 2 0 2 1 4 1 4 2 4 b n 0 1h 7 2 2 2 m 2 f 4 0 r 2 3 0 3 1 v 0 5 7 2 2 2 m 2 9 2 4 4 0 w 1 2 1 g 1 i 8 2 2 2 14 3 0 h 0 6 2 9 2 p 5 6 h 4 n 2 8 2 0 3 6 1n 1b 2 0 e 6 1n 1 2 0 2 4 2 n 2 0 2 9 2 0 b 0 3 4 2 0 m 3 x 0 1s 7 2 z s 4 38 16 l 0 h 5 5 3 4 0 4 1 8 2 5 c d 0 i 11 2 0 6 0 3 16 2 98 2 3 3 6 2 0 2 3 3 14 2 3 3 w 2 3 3 6 2 0 2 3 3 e 2 1k 2 3 3 1u 12 f h 2d 3 5 4 h7 3 g 2 p 6 22 4 a 8 h e i f h f c 2 2 g 1f 10 0 5 0 1w 2g 8 14 2 0 6 1x b u 1e t 3 4 c 17 5 p 1j m a 1g 2b 0 2m 1a i 7 1j t e 1 b 17 r z 16 2 b z 3 a 6 16 3 2 16 3 2 5 2 1 4 0 6 5b 1t 7p 3 5 3 11 3 5 3 7 2 0 2 0 2 0 2 u 3 1g 2 6 2 0 4 2 2 6 4 3 3 5 5 c 6 2 2 6 39 0 e 0 h c 2u 0 5 0 3 9 2 0 3 5 7 0 2 0 2 0 2 f 3 3 6 4 5 0 i 14 22g 6c 7 3 4 1 d 11 2 0 6 0 3 1j 8 0 h m a 6 2 6 2 6 2 6 2 6 2 6 2 6 2 6 fb 2 q 8 8 4 3 4 5 2d 7 2 2 2h 2 3 6 16 2 2l i v 1d f e9 533 1t h3g 1w 19 3 7g 4 f b 1 l 1a h u 3 27 14 8 3 2u 3 1u 3 1 2 0 2 7 m f 2 2 2 3 2 m u 1f f 1d 1r 5 4 0 2 1 c r b m q s 8 1a t 0 h 4 2 9 b 4 2 14 o 2 2 7 l m 4 0 4 1d 2 0 4 1 3 4 3 0 2 0 p 2 3 a 8 2 d 5 3 5 3 5 a 6 2 6 2 16 2 d 7 36 u 8mb d m 5 1c 6it a5 3 2x 13 6 d 4 6 0 2 9 2 c 2 4 2 0 2 1 2 1 2 2z y 3u 7 61 j 1r 3 1h 15 9 3c 0 2 0 4 0 2 0 2 0 2 0 2 3h 11 p 7 p c 1j 3 u 4 5 3 5 3 5 3 2 10 b 2 p 2 i 2 1 2 e 3 d z 3e 1y 1g 7g s 4 1c 1c v e t 6 11 b t 3 z 5 7 2 4 17 4d j z 5 z 5 13 9 1f d a 2 e 2 6 2 1 2 a 2 e 2 6 2 1 4 1f d 8m a l b 7 p 5 2 15 2 8 1y 5 3 0 2 17 2 1 4 0 3 m b m a u 1u i 2 1 b l b p 1z 1j 7 1 1t 0 g 3 2 2 2 s 17 s 4 s 10 7 2 r s 1h b l b i e h 33 20 1k 1e e 1e e z 13 r a m 6z 15 7 1 h 2 1o s b 0 9 l 17 h 1b k s m d 1g 1m 1 3 0 e 18 x o r z u 0 3 0 9 y 4 0 d 1b f 3 m 0 2 0 10 h 2 o k 1 1s 6 2 0 2 3 2 e 2 9 8 1a 13 7 3 1 3 l 2 6 2 1 2 4 4 0 j 0 d 4 v 9 2 0 3 0 2 11 2 0 q 0 2 0 19 1g j 3 l 2 v 1b l 1 2 0 55 1a 16 3 11 1b l 0 1o 16 e 0 20 q 12 6 56 17 39 1r w 7 3 0 3 7 2 1 2 n g 0 2 0 2n 7 3 12 h 0 2 0 t 0 b 13 8 0 m 0 c 19 k 0 j 20 5k w w 8 2 10 i 0 1e t 35 6 2 1 2 11 m 0 q 5 2 1 2 v f 0 94 i g 0 2 c 2 x 3h 0 28 pl 2v 32 i 5f 219 2o g tr i 5 q 32y 6 g6 5a2 t 1cz fs 8 u i 26 i t j 1b h 3 w k 6 i c1 18 5w 1r 3l 22 6 0 1v c 1t 1 2 0 t 4qf 9 yd 16 9 6w8 3 2 6 2 1 2 82 g 0 u 2 3 0 f 3 9 az 1s5 2y 6 c 4 8 8 9 4mf 2c 2 1y 2 1 3 0 3 1 3 3 2 b 2 0 2 6 2 1s 2 3 3 7 2 6 2 r 2 3 2 4 2 0 4 6 2 9f 3 o 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 u 2 o 2 7 1f9 u 7 5 7a 1p 43 18 b 6 h 0 8y t j 17 dh r 6d t 3 0 ds 6 2 3 2 1 2 e 2 5g 1o 1v 8 0 xh 3 2 q 2 1 2 0 3 0 2 9 2 3 2 0 2 0 7 0 5 0 2 0 2 0 2 2 2 1 2 0 3 0 2 0 2 0 2 0 2 0 2 1 2 0 3 3 2 6 2 3 2 3 2 0 2 9 2 g 6 2 2 4 2 g 3et wyn x 37d 7 65 3 4g1 f 5rk g h9 1wj f1 15v 3t6 6 38f",
+        ],
+    ),
+];
+
+// The multi-code-point sequences of each property of strings, for the last
+// 2 versions in `UNICODE_VERSIONS`: first those in the earliest, then those
+// added in each later one. Each sequence is its code points in base 36,
+// separated by dots. Basic_Emoji's single code points aren't listed, as
+// they're Emoji_Presentation minus Regional_Indicator
+pub(super) static STRING_PROPERTY_SEQUENCES: &[(&str, [&str; 2])] = &[
+    (
+        "Basic_Emoji",
+        [
+            "4p.1e6n 4u.1e6n 6d8.1e6n 6dl.1e6n 6jm.1e6n 6k9.1e6n 6ms.1e6n 6mt.1e6n 6mu.1e6n 6mv.1e6n 6mw.1e6n 6mx.1e6n 6nd.1e6n 6ne.1e6n 6y0.1e6n 72n.1e6n 73h.1e6n 73i.1e6n 73j.1e6n 73l.1e6n 73m.1e6n 73s.1e6n 73t.1e6n 73u.1e6n 79e.1e6n 7fu.1e6n 7fv.1e6n 7g6.1e6n 7gg.1e6n 7i3.1e6n 7i4.1e6n 7i8.1e6n 7i9.1e6n 7ia.1e6n 7ib.1e6n 7ic.1e6n 7im.1e6n 7ip.1e6n 7iw.1e6n 7j1.1e6n 7j4.1e6n 7j6.1e6n 7j7.1e6n 7ja.1e6n 7je.1e6n 7ji.1e6n 7jj.1e6n 7js.1e6n 7jt.1e6n 7ju.1e6n 7k0.1e6n 7k2.1e6n 7kv.1e6n 7kw.1e6n 7kz.1e6n 7l1.1e6n 7l2.1e6n 7l4.1e6n 7ln.1e6n 7lq.1e6n 7ma.1e6n 7mc.1e6n 7md.1e6n 7me.1e6n 7mf.1e6n 7mh.1e6n 7mj.1e6n 7mk.1e6n 7mo.1e6n 7mv.1e6n 7n4.1e6n 7n5.1e6n 7ns.1e6n 7nz.1e6n 7o1.1e6n 7o3.1e6n 7op.1e6n 7ow.1e6n 7ox.1e6n 7p0.1e6n 7p3.1e6n 7p4.1e6n 7p5.1e6n 7pe.1e6n 7pk.1e6n 7pl.1e6n 7po.1e6n 7pp.1e6n 7pr.1e6n 7pu.1e6n 7pw.1e6n 7py.1e6n 7q5.1e6n 7q9.1e6n 7qr.1e6n 7qs.1e6n 7r8.1e6n 7rb.1e6n 7s3.1e6n 7s4.1e6n 7tt.1e6n 850.1e6n 851.1e6n 8hx.1e6n 8hy.1e6n 8hz.1e6n 9io.1e6n 9j1.1e6n 9zr.1e6n 9zt.1e6n 2q9c.1e6n 2q9d.1e6n 2q9q.1e6n 2q9r.1e6n 2qde.1e6n 2qev.1e6n 2qld.1e6n 2qlg.1e6n 2qlh.1e6n 2qli.1e6n 2qlj.1e6n 2qlk.1e6n 2qll.1e6n 2qlm.1e6n 2qln.1e6n 2qlo.1e6n 2qly.1e6n 2qnx.1e6n 2qom.1e6n 2qon.1e6n 2qop.1e6n 2qoq.1e6n 2qor.1e6n 2qou.1e6n 2qov.1e6n 2qq3.1e6n 2qq4.1e6n 2qq5.1e6n 2qq6.1e6n 2qqc.1e6n 2qqd.1e6n 2qqe.1e6n 2qqf.1e6n 2qqg.1e6n 2qqh.1e6n 2qqi.1e6n 2qqj.1e6n 2qqk.1e6n 2qql.1e6n 2qqm.1e6n 2qqn.1e6n 2qr7.1e6n 2qr9.1e6n 2qrb.1e6n 2qtb.1e6n 2qtd.1e6n 2qyl.1e6n 2r0p.1e6n 2r0q.1e6n 2r1r.1e6n 2r1s.1e6n 2r1v.1e6n 2r1w.1e6n 2r1x.1e6n 2r1y.1e6n 2r1z.1e6n 2r20.1e6n 2r21.1e6n 2r2f.1e6n 2r2i.1e6n 2r2j.1e6n 2r2k.1e6n 2r2l.1e6n 2r2o.1e6n 2r39.1e6n 2r3c.1e6n 2r3l.1e6n 2r3m.1e6n 2r3w.1e6n 2r42.1e6n 2r43.1e6n 2r44.1e6n 2r4h.1e6n 2r4i.1e6n 2r4j.1e6n 2r4s.1e6n 2r4t.1e6n 2r4u.1e6n 2r4x.1e6n 2r4z.1e6n 2r54.1e6n 2r5b.1e6n 2r5f.1e6n 2r5m.1e6n 2rbf.1e6n 2rbh.1e6n 2rbi.1e6n 2rbj.1e6n 2rc0.1e6n 2rc1.1e6n 2rc2.1e6n 2rc3.1e6n 2rc4.1e6n 2rc5.1e6n 2rc9.1e6n 2rcg.1e6n 2rcj.1e6n",
+            "",
+        ],
+    ),
+    (
+        "Emoji_Keycap_Sequence",
+        [
+            "z.1e6n.6hv 16.1e6n.6hv 1c.1e6n.6hv 1d.1e6n.6hv 1e.1e6n.6hv 1f.1e6n.6hv 1g.1e6n.6hv 1h.1e6n.6hv 1i.1e6n.6hv 1j.1e6n.6hv 1k.1e6n.6hv 1l.1e6n.6hv",
+            "",
+        ],
+    ),
+    (
+        "RGI_Emoji_Flag_Sequence",
+        [
+            "2qcm.2qco 2qcm.2qcp 2qcm.2qcq 2qcm.2qcr 2qcm.2qcs 2qcm.2qcu 2qcm.2qcx 2qcm.2qcy 2qcm.2qd0 2qcm.2qd2 2qcm.2qd3 2qcm.2qd4 2qcm.2qd5 2qcm.2qd6 2qcm.2qd8 2qcm.2qd9 2qcm.2qdb 2qcn.2qcm 2qcn.2qcn 2qcn.2qcp 2qcn.2qcq 2qcn.2qcr 2qcn.2qcs 2qcn.2qct 2qcn.2qcu 2qcn.2qcv 2qcn.2qcx 2qcn.2qcy 2qcn.2qcz 2qcn.2qd0 2qcn.2qd2 2qcn.2qd3 2qcn.2qd4 2qcn.2qd5 2qcn.2qd7 2qcn.2qd8 2qcn.2qda 2qcn.2qdb 2qco.2qcm 2qco.2qco 2qco.2qcp 2qco.2qcr 2qco.2qcs 2qco.2qct 2qco.2qcu 2qco.2qcw 2qco.2qcx 2qco.2qcy 2qco.2qcz 2qco.2qd0 2qco.2qd1 2qco.2qd3 2qco.2qd6 2qco.2qd7 2qco.2qd8 2qco.2qd9 2qco.2qda 2qco.2qdb 2qcp.2qcq 2qcp.2qcs 2qcp.2qcv 2qcp.2qcw 2qcp.2qcy 2qcp.2qd0 2qcp.2qdb 2qcq.2qcm 2qcq.2qco 2qcq.2qcq 2qcq.2qcs 2qcq.2qct 2qcq.2qd3 2qcq.2qd4 2qcq.2qd5 2qcq.2qd6 2qcr.2qcu 2qcr.2qcv 2qcr.2qcw 2qcr.2qcy 2qcr.2qd0 2qcr.2qd3 2qcs.2qcm 2qcs.2qcn 2qcs.2qcp 2qcs.2qcq 2qcs.2qcr 2qcs.2qcs 2qcs.2qct 2qcs.2qcu 2qcs.2qcx 2qcs.2qcy 2qcs.2qcz 2qcs.2qd1 2qcs.2qd2 2qcs.2qd3 2qcs.2qd4 2qcs.2qd5 2qcs.2qd6 2qcs.2qd8 2qcs.2qda 2qct.2qcw 2qct.2qcy 2qct.2qcz 2qct.2qd3 2qct.2qd5 2qct.2qd6 2qcu.2qco 2qcu.2qcp 2qcu.2qcq 2qcu.2qcx 2qcu.2qcy 2qcu.2qcz 2qcu.2qd0 2qcu.2qd2 2qcu.2qd3 2qcu.2qd4 2qcu.2qd5 2qcv.2qcq 2qcv.2qcy 2qcv.2qd0 2qcv.2qd1 2qcw.2qcq 2qcw.2qcs 2qcw.2qct 2qcw.2qcu 2qcw.2qcy 2qcw.2qcz 2qcw.2qd1 2qcw.2qd3 2qcw.2qd8 2qcw.2qda 2qcw.2qdb 2qcx.2qcm 2qcx.2qcn 2qcx.2qco 2qcx.2qcu 2qcx.2qcw 2qcx.2qd3 2qcx.2qd4 2qcx.2qd5 2qcx.2qd6 2qcx.2qd7 2qcx.2qda 2qcy.2qcm 2qcy.2qco 2qcy.2qcp 2qcy.2qcq 2qcy.2qcr 2qcy.2qcs 2qcy.2qct 2qcy.2qcw 2qcy.2qcx 2qcy.2qcy 2qcy.2qcz 2qcy.2qd0 2qcy.2qd1 2qcy.2qd2 2qcy.2qd3 2qcy.2qd4 2qcy.2qd5 2qcy.2qd6 2qcy.2qd7 2qcy.2qd8 2qcy.2qd9 2qcy.2qda 2qcy.2qdb 2qcz.2qcm 2qcz.2qco 2qcz.2qcq 2qcz.2qcr 2qcz.2qcs 2qcz.2qcu 2qcz.2qcx 2qcz.2qd0 2qcz.2qd1 2qcz.2qd3 2qcz.2qd6 2qcz.2qdb 2qd0.2qcy 2qd1.2qcm 2qd1.2qcq 2qd1.2qcr 2qd1.2qcs 2qd1.2qct 2qd1.2qcw 2qd1.2qcx 2qd1.2qcy 2qd1.2qcz 2qd1.2qd3 2qd1.2qd4 2qd1.2qd5 2qd1.2qd8 2qd1.2qda 2qd2.2qcm 2qd3.2qcq 2qd3.2qd0 2qd3.2qd4 2qd3.2qd6 2qd3.2qd8 2qd4.2qcm 2qd4.2qcn 2qd4.2qco 2qd4.2qcp 2qd4.2qcq 2qd4.2qcs 2qd4.2qct 2qd4.2qcu 2qd4.2qcv 2qd4.2qcw 2qd4.2qcx 2qd4.2qcy 2qd4.2qcz 2qd4.2qd0 2qd4.2qd3 2qd4.2qd4 2qd4.2qd5 2qd4.2qd7 2qd4.2qd9 2qd4.2qda 2qd4.2qdb 2qd5.2qcm 2qd5.2qco 2qd5.2qcp 2qd5.2qcr 2qd5.2qcs 2qd5.2qct 2qd5.2qcv 2qd5.2qcw 2qd5.2qcx 2qd5.2qcy 2qd5.2qcz 2qd5.2qd0 2qd5.2qd3 2qd5.2qd5 2qd5.2qd7 2qd5.2qd8 2qd5.2qdb 2qd6.2qcm 2qd6.2qcs 2qd6.2qcy 2qd6.2qcz 2qd6.2qd4 2qd6.2qda 2qd6.2qdb 2qd7.2qcm 2qd7.2qco 2qd7.2qcq 2qd7.2qcs 2qd7.2qcu 2qd7.2qcz 2qd7.2qd6 2qd8.2qcr 2qd8.2qd4 2qd9.2qcw 2qda.2qcq 2qda.2qd5 2qdb.2qcm 2qdb.2qcy 2qdb.2qd8",
+            "2qco.2qd2",
+        ],
+    ),
+    (
+        "RGI_Emoji_Modifier_Sequence",
+        [
+            "7j1.2qrf 7j1.2qrg 7j1.2qrh 7j1.2qri 7j1.2qrj 7p5.2qrf 7p5.2qrg 7p5.2qrh 7p5.2qri 7p5.2qrj 7pm.2qrf 7pm.2qrg 7pm.2qrh 7pm.2qri 7pm.2qrj 7pn.2qrf 7pn.2qrg 7pn.2qrh 7pn.2qri 7pn.2qrj 7po.2qrf 7po.2qrg 7po.2qrh 7po.2qri 7po.2qrj 7pp.2qrf 7pp.2qrg 7pp.2qrh 7pp.2qri 7pp.2qrj 2qo5.2qrf 2qo5.2qrg 2qo5.2qrh 2qo5.2qri 2qo5.2qrj 2qpu.2qrf 2qpu.2qrg 2qpu.2qrh 2qpu.2qri 2qpu.2qrj 2qpv.2qrf 2qpv.2qrg 2qpv.2qrh 2qpv.2qri 2qpv.2qrj 2qpw.2qrf 2qpw.2qrg 2qpw.2qrh 2qpw.2qri 2qpw.2qrj 2qpz.2qrf 2qpz.2qrg 2qpz.2qrh 2qpz.2qri 2qpz.2qrj 2qq2.2qrf 2qq2.2qrg 2qq2.2qrh 2qq2.2qri 2qq2.2qrj 2qq3.2qrf 2qq3.2qrg 2qq3.2qrh 2qq3.2qri 2qq3.2qrj 2qq4.2qrf 2qq4.2qrg 2qq4.2qrh 2qq4.2qri 2qq4.2qrj 2qte.2qrf 2qte.2qrg 2qte.2qrh 2qte.2qri 2qte.2qrj 2qtf.2qrf 2qtf.2qrg 2qtf.2qrh 2qtf.2qri 2qtf.2qrj 2qti.2qrf 2qti.2qrg 2qti.2qrh 2qti.2qri 2qti.2qrj 2qtj.2qrf 2qtj.2qrg 2qtj.2qrh 2qtj.2qri 2qtj.2qrj 2qtk.2qrf 2qtk.2qrg 2qtk.2qrh 2qtk.2qri 2qtk.2qrj 2qtl.2qrf 2qtl.2qrg 2qtl.2qrh 2qtl.2qri 2qtl.2qrj 2qtm.2qrf 2qtm.2qrg 2qtm.2qrh 2qtm.2qri 2qtm.2qrj 2qtn.2qrf 2qtn.2qrg 2qtn.2qrh 2qtn.2qri 2qtn.2qrj 2qto.2qrf 2qto.2qrg 2qto.2qrh 2qto.2qri 2qto.2qrj 2qtp.2qrf 2qtp.2qrg 2qtp.2qrh 2qtp.2qri 2qtp.2qrj 2qtq.2qrf 2qtq.2qrg 2qtq.2qrh 2qtq.2qri 2qtq.2qrj 2qtr.2qrf 2qtr.2qrg 2qtr.2qrh 2qtr.2qri 2qtr.2qrj 2qts.2qrf 2qts.2qrg 2qts.2qrh 2qts.2qri 2qts.2qrj 2que.2qrf 2que.2qrg 2que.2qrh 2que.2qri 2que.2qrj 2quf.2qrf 2quf.2qrg 2quf.2qrh 2quf.2qri 2quf.2qrj 2qug.2qrf 2qug.2qrg 2qug.2qrh 2qug.2qri 2qug.2qrj 2quh.2qrf 2quh.2qrg 2quh.2qrh 2quh.2qri 2quh.2qrj 2quj.2qrf 2quj.2qrg 2quj.2qrh 2quj.2qri 2quj.2qrj 2quk.2qrf 2quk.2qrg 2quk.2qrh 2quk.2qri 2quk.2qrj 2qul.2qrf 2qul.2qrg 2qul.2qrh 2qul.2qri 2qul.2qrj 2qum.2qrf 2qum.2qrg 2qum.2qrh 2qum.2qri 2qum.2qrj 2quo.2qrf 2quo.2qrg 2quo.2qrh 2quo.2qri 2quo.2qrj 2qup.2qrf 2qup.2qrg 2qup.2qrh 2qup.2qri 2qup.2qrj 2quq.2qrf 2quq.2qrg 2quq.2qrh 2quq.2qri 2quq.2qrj 2qur.2qrf 2qur.2qrg 2qur.2qrh 2qur.2qri 2qur.2qrj 2qus.2qrf 2qus.2qrg 2qus.2qrh 2qus.2qri 2qus.2qrj 2qut.2qrf 2qut.2qrg 2qut.2qrh 2qut.2qri 2qut.2qrj 2quu.2qrf 2quu.2qrg 2quu.2qrh 2quu.2qri 2quu.2qrj 2quv.2qrf 2quv.2qrg 2quv.2qrh 2quv.2qri 2quv.2qrj 2quw.2qrf 2quw.2qrg 2quw.2qrh 2quw.2qri 2quw.2qrj 2qv0.2qrf 2qv0.2qrg 2qv0.2qrh 2qv0.2qri 2qv0.2qrj 2qv5.2qrf 2qv5.2qrg 2qv5.2qrh 2qv5.2qri 2qv5.2qrj 2qv6.2qrf 2qv6.2qrg 2qv6.2qrh 2qv6.2qri 2qv6.2qrj 2qv7.2qrf 2qv7.2qrg 2qv7.2qrh 2qv7.2qri 2qv7.2qrj 2qv9.2qrf 2qv9.2qrg 2qv9.2qrh 2qv9.2qri 2qv9.2qrj 2qva.2qrf 2qva.2qrg 2qva.2qrh 2qva.2qri 2qva.2qrj 2qvb.2qrf 2qvb.2qrg 2qvb.2qrh 2qvb.2qri 2qvb.2qrj 2qvj.2qrf 2qvj.2qrg 2qvj.2qrh 2qvj.2qri 2qvj.2qrj 2qvl.2qrf 2qvl.2qrg 2qvl.2qrh 2qvl.2qri 2qvl.2qrj 2qwa.2qrf 2qwa.2qrg 2qwa.2qrh 2qwa.2qri 2qwa.2qrj 2r1w.2qrf 2r1w.2qrg 2r1w.2qrh 2r1w.2qri 2r1w.2qrj 2r1x.2qrf 2r1x.2qrg 2r1x.2qrh 2r1x.2qri 2r1x.2qrj 2r22.2qrf 2r22.2qrg 2r22.2qrh 2r22.2qri 2r22.2qrj 2r2o.2qrf 2r2o.2qrg 2r2o.2qrh 2r2o.2qri 2r2o.2qrj 2r2t.2qrf 2r2t.2qrg 2r2t.2qrh 2r2t.2qri 2r2t.2qrj 2r2u.2qrf 2r2u.2qrg 2r2u.2qrh 2r2u.2qri 2r2u.2qrj 2r7p.2qrf 2r7p.2qrg 2r7p.2qrh 2r7p.2qri 2r7p.2qrj 2r7q.2qrf 2r7q.2qrg 2r7q.2qrh 2r7q.2qri 2r7q.2qrj 2r7r.2qrf 2r7r.2qrg 2r7r.2qrh 2r7r.2qri 2r7r.2qrj 2r7v.2qrf 2r7v.2qrg 2r7v.2qrh 2r7v.2qri 2r7v.2qrj 2r7w.2qrf 2r7w.2qrg 2r7w.2qrh 2r7w.2qri 2r7w.2qrj 2r7x.2qrf 2r7x.2qrg 2r7x.2qrh 2r7x.2qri 2r7x.2qrj 2r7y.2qrf 2r7y.2qrg 2r7y.2qrh 2r7y.2qri 2r7y.2qrj 2r7z.2qrf 2r7z.2qrg 2r7z.2qrh 2r7z.2qri 2r7z.2qrj 2rab.2qrf 2rab.2qrg 2rab.2qrh 2rab.2qri 2rab.2qrj 2ras.2qrf 2ras.2qrg 2ras.2qrh 2ras.2qri 2ras.2qrj 2rat.2qrf 2rat.2qrg 2rat.2qrh 2rat.2qri 2rat.2qrj 2rau.2qrf 2rau.2qrg 2rau.2qrh 2rau.2qri 2rau.2qrj 2rb4.2qrf 2rb4.2qrg 2rb4.2qrh 2rb4.2qri 2rb4.2qrj 2rbg.2qrf 2rbg.2qrg 2rbg.2qrh 2rbg.2qri 2rbg.2qrj 2rrg.2qrf 2rrg.2qrg 2rrg.2qrh 2rrg.2qri 2rrg.2qrj 2rrj.2qrf 2rrj.2qrg 2rrj.2qrh 2rrj.2qri 2rrj.2qrj 2rrs.2qrf 2rrs.2qrg 2rrs.2qrh 2rrs.2qri 2rrs.2qrj 2rrt.2qrf 2rrt.2qrg 2rrt.2qrh 2rrt.2qri 2rrt.2qrj 2rru.2qrf 2rru.2qrg 2rru.2qrh 2rru.2qri 2rru.2qrj 2rrv.2qrf 2rrv.2qrg 2rrv.2qrh 2rrv.2qri 2rrv.2qrj 2rrw.2qrf 2rrw.2qrg 2rrw.2qrh 2rrw.2qri 2rrw.2qrj 2rrx.2qrf 2rrx.2qrg 2rrx.2qrh 2rrx.2qri 2rrx.2qrj 2rry.2qrf 2rry.2qrg 2rry.2qrh 2rry.2qri 2rry.2qrj 2rrz.2qrf 2rrz.2qrg 2rrz.2qrh 2rrz.2qri 2rrz.2qrj 2rs6.2qrf 2rs6.2qrg 2rs6.2qrh 2rs6.2qri 2rs6.2qrj 2rsg.2qrf 2rsg.2qrg 2rsg.2qrh 2rsg.2qri 2rsg.2qrj 2rsh.2qrf 2rsh.2qrg 2rsh.2qrh 2rsh.2qri 2rsh.2qrj 2rsi.2qrf 2rsi.2qrg 2rsi.2qrh 2rsi.2qri 2rsi.2qrj 2rsj.2qrf 2rsj.2qrg 2rsj.2qrh 2rsj.2qri 2rsj.2qrj 2rsk.2qrf 2rsk.2qrg 2rsk.2qrh 2rsk.2qri 2rsk.2qrj 2rsl.2qrf 2rsl.2qrg 2rsl.2qrh 2rsl.2qri 2rsl.2qrj 2rsm.2qrf 2rsm.2qrg 2rsm.2qrh 2rsm.2qri 2rsm.2qrj 2rsn.2qrf 2rsn.2qrg 2rsn.2qrh 2rsn.2qri 2rsn.2qrj 2rso.2qrf 2rso.2qrg 2rso.2qrh 2rso.2qri 2rso.2qrj 2rsp.2qrf 2rsp.2qrg 2rsp.2qrh 2rsp.2qri 2rsp.2qrj 2rst.2qrf 2rst.2qrg 2rst.2qrh 2rst.2qri 2rst.2qrj 2rsu.2qrf 2rsu.2qrg 2rsu.2qrh 2rsu.2qri 2rsu.2qrj 2ruf.2qrf 2ruf.2qrg 2ruf.2qrh 2ruf.2qri 2ruf.2qrj 2rw5.2qrf 2rw5.2qrg 2rw5.2qrh 2rw5.2qri 2rw5.2qrj 2rw6.2qrf 2rw6.2qrg 2rw6.2qrh 2rw6.2qri 2rw6.2qrj 2rw8.2qrf 2rw8.2qrg 2rw8.2qrh 2rw8.2qri 2rw8.2qrj 2rw9.2qrf 2rw9.2qrg 2rw9.2qrh 2rw9.2qri 2rw9.2qrj 2rwb.2qrf 2rwb.2qrg 2rwb.2qrh 2rwb.2qri 2rwb.2qrj 2rwt.2qrf 2rwt.2qrg 2rwt.2qrh 2rwt.2qri 2rwt.2qrj 2rwu.2qrf 2rwu.2qrg 2rwu.2qrh 2rwu.2qri 2rwu.2qrj 2rwv.2qrf 2rwv.2qrg 2rwv.2qrh 2rwv.2qri 2rwv.2qrj 2rwx.2qrf 2rwx.2qrg 2rwx.2qrh 2rwx.2qri 2rwx.2qrj 2rwy.2qrf 2rwy.2qrg 2rwy.2qrh 2rwy.2qri 2rwy.2qrj 2rwz.2qrf 2rwz.2qrg 2rwz.2qrh 2rwz.2qri 2rwz.2qrj 2rx0.2qrf 2rx0.2qrg 2rx0.2qrh 2rx0.2qri 2rx0.2qrj 2rx1.2qrf 2rx1.2qrg 2rx1.2qrh 2rx1.2qri 2rx1.2qrj 2rx2.2qrf 2rx2.2qrg 2rx2.2qrh 2rx2.2qri 2rx2.2qrj 2rx3.2qrf 2rx3.2qrg 2rx3.2qrh 2rx3.2qri 2rx3.2qrj 2rx4.2qrf 2rx4.2qrg 2rx4.2qrh 2rx4.2qri 2rx4.2qrj 2rx5.2qrf 2rx5.2qrg 2rx5.2qrh 2rx5.2qri 2rx5.2qrj 2rx6.2qrf 2rx6.2qrg 2rx6.2qrh 2rx6.2qri 2rx6.2qrj 2rx7.2qrf 2rx7.2qrg 2rx7.2qrh 2rx7.2qri 2rx7.2qrj 2rx8.2qrf 2rx8.2qrg 2rx8.2qrh 2rx8.2qri 2rx8.2qrj 2rx9.2qrf 2rx9.2qrg 2rx9.2qrh 2rx9.2qri 2rx9.2qrj 2s3n.2qrf 2s3n.2qrg 2s3n.2qrh 2s3n.2qri 2s3n.2qrj 2s3o.2qrf 2s3o.2qrg 2s3o.2qrh 2s3o.2qri 2s3o.2qrj 2s3p.2qrf 2s3p.2qrg 2s3p.2qrh 2s3p.2qri 2s3p.2qrj 2s4w.2qrf 2s4w.2qrg 2s4w.2qrh 2s4w.2qri 2s4w.2qrj 2s4x.2qrf 2s4x.2qrg 2s4x.2qrh 2s4x.2qri 2s4x.2qrj 2s4y.2qrf 2s4y.2qrg 2s4y.2qrh 2s4y.2qri 2s4y.2qrj 2s4z.2qrf 2s4z.2qrg 2s4z.2qrh 2s4z.2qri 2s4z.2qrj 2s50.2qrf 2s50.2qrg 2s50.2qrh 2s50.2qri 2s50.2qrj 2s51.2qrf 2s51.2qrg 2s51.2qrh 2s51.2qri 2s51.2qrj 2s52.2qrf 2s52.2qrg 2s52.2qrh 2s52.2qri 2s52.2qrj 2s53.2qrf 2s53.2qrg 2s53.2qrh 2s53.2qri 2s53.2qrj 2s54.2qrf 2s54.2qrg 2s54.2qrh 2s54.2qri 2s54.2qrj",
+            "",
+        ],
+    ),
+    (
+        "RGI_Emoji_Tag_Sequence",
+        [
+            "2qr8.jo13.jo0y.jo11.jo1a.jo13.jo1r 2qr8.jo13.jo0y.jo1f.jo0z.jo1g.jo1r 2qr8.jo13.jo0y.jo1j.jo18.jo1f.jo1r",
+            "",
+        ],
+    ),
+    (
+        "RGI_Emoji_ZWJ_Sequence",
+        [
+            "7o3.1e6n.6bx.2qw5 7p5.1e6n.6bx.7k0.1e6n 7p5.1e6n.6bx.7k2.1e6n 7p5.2qrf.6bx.7k0.1e6n 7p5.2qrf.6bx.7k2.1e6n 7p5.2qrg.6bx.7k0.1e6n 7p5.2qrg.6bx.7k2.1e6n 7p5.2qrh.6bx.7k0.1e6n 7p5.2qrh.6bx.7k2.1e6n 7p5.2qri.6bx.7k0.1e6n 7p5.2qri.6bx.7k2.1e6n 7p5.2qrj.6bx.7k0.1e6n 7p5.2qrj.6bx.7k2.1e6n 7s4.1e6n.6bx.2qzp 7s4.1e6n.6bx.2s1l 2qmc.6bx.2rjf 2qmj.6bx.2rjd 2qpv.6bx.7k0.1e6n 2qpv.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.6bx.7k2.1e6n 2qpv.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.6bx.7tt.1e6n 2qpv.2qrf.6bx.7k0.1e6n 2qpv.2qrf.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.2qrf.6bx.7k2.1e6n 2qpv.2qrf.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.2qrf.6bx.7tt.1e6n 2qpv.2qrg.6bx.7k0.1e6n 2qpv.2qrg.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.2qrg.6bx.7k2.1e6n 2qpv.2qrg.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.2qrg.6bx.7tt.1e6n 2qpv.2qrh.6bx.7k0.1e6n 2qpv.2qrh.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.2qrh.6bx.7k2.1e6n 2qpv.2qrh.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.2qrh.6bx.7tt.1e6n 2qpv.2qri.6bx.7k0.1e6n 2qpv.2qri.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.2qri.6bx.7k2.1e6n 2qpv.2qri.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.2qri.6bx.7tt.1e6n 2qpv.2qrj.6bx.7k0.1e6n 2qpv.2qrj.6bx.7k0.1e6n.6bx.7tt.1e6n 2qpv.2qrj.6bx.7k2.1e6n 2qpv.2qrj.6bx.7k2.1e6n.6bx.7tt.1e6n 2qpv.2qrj.6bx.7tt.1e6n 2qpw.6bx.7k0.1e6n 2qpw.6bx.7k2.1e6n 2qpw.2qrf.6bx.7k0.1e6n 2qpw.2qrf.6bx.7k2.1e6n 2qpw.2qrg.6bx.7k0.1e6n 2qpw.2qrg.6bx.7k2.1e6n 2qpw.2qrh.6bx.7k0.1e6n 2qpw.2qrh.6bx.7k2.1e6n 2qpw.2qri.6bx.7k0.1e6n 2qpw.2qri.6bx.7k2.1e6n 2qpw.2qrj.6bx.7k0.1e6n 2qpw.2qrj.6bx.7k2.1e6n 2qq2.6bx.7k0.1e6n 2qq2.6bx.7k2.1e6n 2qq2.2qrf.6bx.7k0.1e6n 2qq2.2qrf.6bx.7k2.1e6n 2qq2.2qrg.6bx.7k0.1e6n 2qq2.2qrg.6bx.7k2.1e6n 2qq2.2qrh.6bx.7k0.1e6n 2qq2.2qrh.6bx.7k2.1e6n 2qq2.2qri.6bx.7k0.1e6n 2qq2.2qri.6bx.7k2.1e6n 2qq2.2qrj.6bx.7k0.1e6n 2qq2.2qrj.6bx.7k2.1e6n 2qq3.1e6n.6bx.7k0.1e6n 2qq3.1e6n.6bx.7k2.1e6n 2qq3.2qrf.6bx.7k0.1e6n 2qq3.2qrf.6bx.7k2.1e6n 2qq3.2qrg.6bx.7k0.1e6n 2qq3.2qrg.6bx.7k2.1e6n 2qq3.2qrh.6bx.7k0.1e6n 2qq3.2qrh.6bx.7k2.1e6n 2qq3.2qri.6bx.7k0.1e6n 2qq3.2qri.6bx.7k2.1e6n 2qq3.2qrj.6bx.7k0.1e6n 2qq3.2qrj.6bx.7k2.1e6n 2qq4.1e6n.6bx.7k0.1e6n 2qq4.1e6n.6bx.7k2.1e6n 2qq4.2qrf.6bx.7k0.1e6n 2qq4.2qrf.6bx.7k2.1e6n 2qq4.2qrg.6bx.7k0.1e6n 2qq4.2qrg.6bx.7k2.1e6n 2qq4.2qrh.6bx.7k0.1e6n 2qq4.2qrh.6bx.7k2.1e6n 2qq4.2qri.6bx.7k0.1e6n 2qq4.2qri.6bx.7k2.1e6n 2qq4.2qrj.6bx.7k0.1e6n 2qq4.2qrj.6bx.7k2.1e6n 2qr7.1e6n.6bx.7mv.1e6n 2qr7.1e6n.6bx.2qko 2qr8.6bx.7j4.1e6n 2qrs.6bx.8ij 2qs5.6bx.2rwa 2qsm.6bx.8ij 2qsm.6bx.2qzp 2qt7.6bx.7r8.1e6n 2qtd.1e6n.6bx.2r54.1e6n 2qug.6bx.7md.1e6n 2qug.6bx.7me.1e6n 2qug.6bx.7pk.1e6n 2qug.6bx.7s4.1e6n.6bx.2qug 2qug.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug 2qug.6bx.2qm6 2qug.6bx.2qnn 2qug.6bx.2qnw 2qug.6bx.2qoj 2qug.6bx.2qp0 2qug.6bx.2qp4 2qug.6bx.2qqz 2qug.6bx.2qr1 2qug.6bx.2que 2qug.6bx.2que.6bx.2que 2qug.6bx.2quf 2qug.6bx.2quf.6bx.2que 2qug.6bx.2quf.6bx.2quf 2qug.6bx.2qug.6bx.2que 2qug.6bx.2qug.6bx.2que.6bx.2que 2qug.6bx.2qug.6bx.2quf 2qug.6bx.2qug.6bx.2quf.6bx.2que 2qug.6bx.2qug.6bx.2quf.6bx.2quf 2qug.6bx.2quh.6bx.2que 2qug.6bx.2quh.6bx.2que.6bx.2que 2qug.6bx.2quh.6bx.2quf 2qug.6bx.2quh.6bx.2quf.6bx.2que 2qug.6bx.2quh.6bx.2quf.6bx.2quf 2qug.6bx.2qwr 2qug.6bx.2qws 2qug.6bx.2qzr 2qug.6bx.2qzw 2qug.6bx.2r9c 2qug.6bx.2r9u 2qug.6bx.2rvz 2qug.6bx.2rvz.6bx.7tt.1e6n 2qug.6bx.2rw0 2qug.6bx.2rw1 2qug.6bx.2rw2 2qug.6bx.2rw3 2qug.6bx.2rwc 2qug.6bx.2rwc.6bx.7tt.1e6n 2qug.6bx.2rwd 2qug.6bx.2rwd.6bx.7tt.1e6n 2qug.2qrf.6bx.7md.1e6n 2qug.2qrf.6bx.7me.1e6n 2qug.2qrf.6bx.7pk.1e6n 2qug.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrf 2qug.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrg 2qug.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrh 2qug.2qrf.6bx.7s4.1e6n.6bx.2qug.2qri 2qug.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrj 2qug.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2qug.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2qug.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2qug.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2qug.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2qug.2qrf.6bx.2qm6 2qug.2qrf.6bx.2qnn 2qug.2qrf.6bx.2qnw 2qug.2qrf.6bx.2qoj 2qug.2qrf.6bx.2qp0 2qug.2qrf.6bx.2qp4 2qug.2qrf.6bx.2qqz 2qug.2qrf.6bx.2qr1 2qug.2qrf.6bx.2qwr 2qug.2qrf.6bx.2qws 2qug.2qrf.6bx.2qzr 2qug.2qrf.6bx.2qzw 2qug.2qrf.6bx.2r9c 2qug.2qrf.6bx.2r9u 2qug.2qrf.6bx.2rrx.6bx.2qug.2qrg 2qug.2qrf.6bx.2rrx.6bx.2qug.2qrh 2qug.2qrf.6bx.2rrx.6bx.2qug.2qri 2qug.2qrf.6bx.2rrx.6bx.2qug.2qrj 2qug.2qrf.6bx.2rvz 2qug.2qrf.6bx.2rvz.6bx.7tt.1e6n 2qug.2qrf.6bx.2rw0 2qug.2qrf.6bx.2rw1 2qug.2qrf.6bx.2rw2 2qug.2qrf.6bx.2rw3 2qug.2qrf.6bx.2rwc 2qug.2qrf.6bx.2rwc.6bx.7tt.1e6n 2qug.2qrf.6bx.2rwd 2qug.2qrf.6bx.2rwd.6bx.7tt.1e6n 2qug.2qrg.6bx.7md.1e6n 2qug.2qrg.6bx.7me.1e6n 2qug.2qrg.6bx.7pk.1e6n 2qug.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrf 2qug.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrg 2qug.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrh 2qug.2qrg.6bx.7s4.1e6n.6bx.2qug.2qri 2qug.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrj 2qug.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2qug.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2qug.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2qug.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2qug.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2qug.2qrg.6bx.2qm6 2qug.2qrg.6bx.2qnn 2qug.2qrg.6bx.2qnw 2qug.2qrg.6bx.2qoj 2qug.2qrg.6bx.2qp0 2qug.2qrg.6bx.2qp4 2qug.2qrg.6bx.2qqz 2qug.2qrg.6bx.2qr1 2qug.2qrg.6bx.2qwr 2qug.2qrg.6bx.2qws 2qug.2qrg.6bx.2qzr 2qug.2qrg.6bx.2qzw 2qug.2qrg.6bx.2r9c 2qug.2qrg.6bx.2r9u 2qug.2qrg.6bx.2rrx.6bx.2qug.2qrf 2qug.2qrg.6bx.2rrx.6bx.2qug.2qrh 2qug.2qrg.6bx.2rrx.6bx.2qug.2qri 2qug.2qrg.6bx.2rrx.6bx.2qug.2qrj 2qug.2qrg.6bx.2rvz 2qug.2qrg.6bx.2rvz.6bx.7tt.1e6n 2qug.2qrg.6bx.2rw0 2qug.2qrg.6bx.2rw1 2qug.2qrg.6bx.2rw2 2qug.2qrg.6bx.2rw3 2qug.2qrg.6bx.2rwc 2qug.2qrg.6bx.2rwc.6bx.7tt.1e6n 2qug.2qrg.6bx.2rwd 2qug.2qrg.6bx.2rwd.6bx.7tt.1e6n 2qug.2qrh.6bx.7md.1e6n 2qug.2qrh.6bx.7me.1e6n 2qug.2qrh.6bx.7pk.1e6n 2qug.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrf 2qug.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrg 2qug.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrh 2qug.2qrh.6bx.7s4.1e6n.6bx.2qug.2qri 2qug.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrj 2qug.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2qug.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2qug.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2qug.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2qug.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2qug.2qrh.6bx.2qm6 2qug.2qrh.6bx.2qnn 2qug.2qrh.6bx.2qnw 2qug.2qrh.6bx.2qoj 2qug.2qrh.6bx.2qp0 2qug.2qrh.6bx.2qp4 2qug.2qrh.6bx.2qqz 2qug.2qrh.6bx.2qr1 2qug.2qrh.6bx.2qwr 2qug.2qrh.6bx.2qws 2qug.2qrh.6bx.2qzr 2qug.2qrh.6bx.2qzw 2qug.2qrh.6bx.2r9c 2qug.2qrh.6bx.2r9u 2qug.2qrh.6bx.2rrx.6bx.2qug.2qrf 2qug.2qrh.6bx.2rrx.6bx.2qug.2qrg 2qug.2qrh.6bx.2rrx.6bx.2qug.2qri 2qug.2qrh.6bx.2rrx.6bx.2qug.2qrj 2qug.2qrh.6bx.2rvz 2qug.2qrh.6bx.2rvz.6bx.7tt.1e6n 2qug.2qrh.6bx.2rw0 2qug.2qrh.6bx.2rw1 2qug.2qrh.6bx.2rw2 2qug.2qrh.6bx.2rw3 2qug.2qrh.6bx.2rwc 2qug.2qrh.6bx.2rwc.6bx.7tt.1e6n 2qug.2qrh.6bx.2rwd 2qug.2qrh.6bx.2rwd.6bx.7tt.1e6n 2qug.2qri.6bx.7md.1e6n 2qug.2qri.6bx.7me.1e6n 2qug.2qri.6bx.7pk.1e6n 2qug.2qri.6bx.7s4.1e6n.6bx.2qug.2qrf 2qug.2qri.6bx.7s4.1e6n.6bx.2qug.2qrg 2qug.2qri.6bx.7s4.1e6n.6bx.2qug.2qrh 2qug.2qri.6bx.7s4.1e6n.6bx.2qug.2qri 2qug.2qri.6bx.7s4.1e6n.6bx.2qug.2qrj 2qug.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2qug.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2qug.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2qug.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2qug.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2qug.2qri.6bx.2qm6 2qug.2qri.6bx.2qnn 2qug.2qri.6bx.2qnw 2qug.2qri.6bx.2qoj 2qug.2qri.6bx.2qp0 2qug.2qri.6bx.2qp4 2qug.2qri.6bx.2qqz 2qug.2qri.6bx.2qr1 2qug.2qri.6bx.2qwr 2qug.2qri.6bx.2qws 2qug.2qri.6bx.2qzr 2qug.2qri.6bx.2qzw 2qug.2qri.6bx.2r9c 2qug.2qri.6bx.2r9u 2qug.2qri.6bx.2rrx.6bx.2qug.2qrf 2qug.2qri.6bx.2rrx.6bx.2qug.2qrg 2qug.2qri.6bx.2rrx.6bx.2qug.2qrh 2qug.2qri.6bx.2rrx.6bx.2qug.2qrj 2qug.2qri.6bx.2rvz 2qug.2qri.6bx.2rvz.6bx.7tt.1e6n 2qug.2qri.6bx.2rw0 2qug.2qri.6bx.2rw1 2qug.2qri.6bx.2rw2 2qug.2qri.6bx.2rw3 2qug.2qri.6bx.2rwc 2qug.2qri.6bx.2rwc.6bx.7tt.1e6n 2qug.2qri.6bx.2rwd 2qug.2qri.6bx.2rwd.6bx.7tt.1e6n 2qug.2qrj.6bx.7md.1e6n 2qug.2qrj.6bx.7me.1e6n 2qug.2qrj.6bx.7pk.1e6n 2qug.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrf 2qug.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrg 2qug.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrh 2qug.2qrj.6bx.7s4.1e6n.6bx.2qug.2qri 2qug.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrj 2qug.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2qug.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2qug.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2qug.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2qug.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2qug.2qrj.6bx.2qm6 2qug.2qrj.6bx.2qnn 2qug.2qrj.6bx.2qnw 2qug.2qrj.6bx.2qoj 2qug.2qrj.6bx.2qp0 2qug.2qrj.6bx.2qp4 2qug.2qrj.6bx.2qqz 2qug.2qrj.6bx.2qr1 2qug.2qrj.6bx.2qwr 2qug.2qrj.6bx.2qws 2qug.2qrj.6bx.2qzr 2qug.2qrj.6bx.2qzw 2qug.2qrj.6bx.2r9c 2qug.2qrj.6bx.2r9u 2qug.2qrj.6bx.2rrx.6bx.2qug.2qrf 2qug.2qrj.6bx.2rrx.6bx.2qug.2qrg 2qug.2qrj.6bx.2rrx.6bx.2qug.2qrh 2qug.2qrj.6bx.2rrx.6bx.2qug.2qri 2qug.2qrj.6bx.2rvz 2qug.2qrj.6bx.2rvz.6bx.7tt.1e6n 2qug.2qrj.6bx.2rw0 2qug.2qrj.6bx.2rw1 2qug.2qrj.6bx.2rw2 2qug.2qrj.6bx.2rw3 2qug.2qrj.6bx.2rwc 2qug.2qrj.6bx.2rwc.6bx.7tt.1e6n 2qug.2qrj.6bx.2rwd 2qug.2qrj.6bx.2rwd.6bx.7tt.1e6n 2quh.6bx.7md.1e6n 2quh.6bx.7me.1e6n 2quh.6bx.7pk.1e6n 2quh.6bx.7s4.1e6n.6bx.2qug 2quh.6bx.7s4.1e6n.6bx.2quh 2quh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug 2quh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh 2quh.6bx.2qm6 2quh.6bx.2qnn 2quh.6bx.2qnw 2quh.6bx.2qoj 2quh.6bx.2qp0 2quh.6bx.2qp4 2quh.6bx.2qqz 2quh.6bx.2qr1 2quh.6bx.2que 2quh.6bx.2que.6bx.2que 2quh.6bx.2quf 2quh.6bx.2quf.6bx.2que 2quh.6bx.2quf.6bx.2quf 2quh.6bx.2quh.6bx.2que 2quh.6bx.2quh.6bx.2que.6bx.2que 2quh.6bx.2quh.6bx.2quf 2quh.6bx.2quh.6bx.2quf.6bx.2que 2quh.6bx.2quh.6bx.2quf.6bx.2quf 2quh.6bx.2qwr 2quh.6bx.2qws 2quh.6bx.2qzr 2quh.6bx.2qzw 2quh.6bx.2r9c 2quh.6bx.2r9u 2quh.6bx.2rvz 2quh.6bx.2rvz.6bx.7tt.1e6n 2quh.6bx.2rw0 2quh.6bx.2rw1 2quh.6bx.2rw2 2quh.6bx.2rw3 2quh.6bx.2rwc 2quh.6bx.2rwc.6bx.7tt.1e6n 2quh.6bx.2rwd 2quh.6bx.2rwd.6bx.7tt.1e6n 2quh.2qrf.6bx.7md.1e6n 2quh.2qrf.6bx.7me.1e6n 2quh.2qrf.6bx.7pk.1e6n 2quh.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrf 2quh.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrg 2quh.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrh 2quh.2qrf.6bx.7s4.1e6n.6bx.2qug.2qri 2quh.2qrf.6bx.7s4.1e6n.6bx.2qug.2qrj 2quh.2qrf.6bx.7s4.1e6n.6bx.2quh.2qrf 2quh.2qrf.6bx.7s4.1e6n.6bx.2quh.2qrg 2quh.2qrf.6bx.7s4.1e6n.6bx.2quh.2qrh 2quh.2qrf.6bx.7s4.1e6n.6bx.2quh.2qri 2quh.2qrf.6bx.7s4.1e6n.6bx.2quh.2qrj 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrf 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrg 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrh 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qri 2quh.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrj 2quh.2qrf.6bx.2qm6 2quh.2qrf.6bx.2qnn 2quh.2qrf.6bx.2qnw 2quh.2qrf.6bx.2qoj 2quh.2qrf.6bx.2qp0 2quh.2qrf.6bx.2qp4 2quh.2qrf.6bx.2qqz 2quh.2qrf.6bx.2qr1 2quh.2qrf.6bx.2qwr 2quh.2qrf.6bx.2qws 2quh.2qrf.6bx.2qzr 2quh.2qrf.6bx.2qzw 2quh.2qrf.6bx.2r9c 2quh.2qrf.6bx.2r9u 2quh.2qrf.6bx.2rrx.6bx.2qug.2qrg 2quh.2qrf.6bx.2rrx.6bx.2qug.2qrh 2quh.2qrf.6bx.2rrx.6bx.2qug.2qri 2quh.2qrf.6bx.2rrx.6bx.2qug.2qrj 2quh.2qrf.6bx.2rrx.6bx.2quh.2qrg 2quh.2qrf.6bx.2rrx.6bx.2quh.2qrh 2quh.2qrf.6bx.2rrx.6bx.2quh.2qri 2quh.2qrf.6bx.2rrx.6bx.2quh.2qrj 2quh.2qrf.6bx.2rvz 2quh.2qrf.6bx.2rvz.6bx.7tt.1e6n 2quh.2qrf.6bx.2rw0 2quh.2qrf.6bx.2rw1 2quh.2qrf.6bx.2rw2 2quh.2qrf.6bx.2rw3 2quh.2qrf.6bx.2rwc 2quh.2qrf.6bx.2rwc.6bx.7tt.1e6n 2quh.2qrf.6bx.2rwd 2quh.2qrf.6bx.2rwd.6bx.7tt.1e6n 2quh.2qrg.6bx.7md.1e6n 2quh.2qrg.6bx.7me.1e6n 2quh.2qrg.6bx.7pk.1e6n 2quh.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrf 2quh.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrg 2quh.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrh 2quh.2qrg.6bx.7s4.1e6n.6bx.2qug.2qri 2quh.2qrg.6bx.7s4.1e6n.6bx.2qug.2qrj 2quh.2qrg.6bx.7s4.1e6n.6bx.2quh.2qrf 2quh.2qrg.6bx.7s4.1e6n.6bx.2quh.2qrg 2quh.2qrg.6bx.7s4.1e6n.6bx.2quh.2qrh 2quh.2qrg.6bx.7s4.1e6n.6bx.2quh.2qri 2quh.2qrg.6bx.7s4.1e6n.6bx.2quh.2qrj 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrf 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrg 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrh 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qri 2quh.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrj 2quh.2qrg.6bx.2qm6 2quh.2qrg.6bx.2qnn 2quh.2qrg.6bx.2qnw 2quh.2qrg.6bx.2qoj 2quh.2qrg.6bx.2qp0 2quh.2qrg.6bx.2qp4 2quh.2qrg.6bx.2qqz 2quh.2qrg.6bx.2qr1 2quh.2qrg.6bx.2qwr 2quh.2qrg.6bx.2qws 2quh.2qrg.6bx.2qzr 2quh.2qrg.6bx.2qzw 2quh.2qrg.6bx.2r9c 2quh.2qrg.6bx.2r9u 2quh.2qrg.6bx.2rrx.6bx.2qug.2qrf 2quh.2qrg.6bx.2rrx.6bx.2qug.2qrh 2quh.2qrg.6bx.2rrx.6bx.2qug.2qri 2quh.2qrg.6bx.2rrx.6bx.2qug.2qrj 2quh.2qrg.6bx.2rrx.6bx.2quh.2qrf 2quh.2qrg.6bx.2rrx.6bx.2quh.2qrh 2quh.2qrg.6bx.2rrx.6bx.2quh.2qri 2quh.2qrg.6bx.2rrx.6bx.2quh.2qrj 2quh.2qrg.6bx.2rvz 2quh.2qrg.6bx.2rvz.6bx.7tt.1e6n 2quh.2qrg.6bx.2rw0 2quh.2qrg.6bx.2rw1 2quh.2qrg.6bx.2rw2 2quh.2qrg.6bx.2rw3 2quh.2qrg.6bx.2rwc 2quh.2qrg.6bx.2rwc.6bx.7tt.1e6n 2quh.2qrg.6bx.2rwd 2quh.2qrg.6bx.2rwd.6bx.7tt.1e6n 2quh.2qrh.6bx.7md.1e6n 2quh.2qrh.6bx.7me.1e6n 2quh.2qrh.6bx.7pk.1e6n 2quh.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrf 2quh.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrg 2quh.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrh 2quh.2qrh.6bx.7s4.1e6n.6bx.2qug.2qri 2quh.2qrh.6bx.7s4.1e6n.6bx.2qug.2qrj 2quh.2qrh.6bx.7s4.1e6n.6bx.2quh.2qrf 2quh.2qrh.6bx.7s4.1e6n.6bx.2quh.2qrg 2quh.2qrh.6bx.7s4.1e6n.6bx.2quh.2qrh 2quh.2qrh.6bx.7s4.1e6n.6bx.2quh.2qri 2quh.2qrh.6bx.7s4.1e6n.6bx.2quh.2qrj 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrf 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrg 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrh 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qri 2quh.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrj 2quh.2qrh.6bx.2qm6 2quh.2qrh.6bx.2qnn 2quh.2qrh.6bx.2qnw 2quh.2qrh.6bx.2qoj 2quh.2qrh.6bx.2qp0 2quh.2qrh.6bx.2qp4 2quh.2qrh.6bx.2qqz 2quh.2qrh.6bx.2qr1 2quh.2qrh.6bx.2qwr 2quh.2qrh.6bx.2qws 2quh.2qrh.6bx.2qzr 2quh.2qrh.6bx.2qzw 2quh.2qrh.6bx.2r9c 2quh.2qrh.6bx.2r9u 2quh.2qrh.6bx.2rrx.6bx.2qug.2qrf 2quh.2qrh.6bx.2rrx.6bx.2qug.2qrg 2quh.2qrh.6bx.2rrx.6bx.2qug.2qri 2quh.2qrh.6bx.2rrx.6bx.2qug.2qrj 2quh.2qrh.6bx.2rrx.6bx.2quh.2qrf 2quh.2qrh.6bx.2rrx.6bx.2quh.2qrg 2quh.2qrh.6bx.2rrx.6bx.2quh.2qri 2quh.2qrh.6bx.2rrx.6bx.2quh.2qrj 2quh.2qrh.6bx.2rvz 2quh.2qrh.6bx.2rvz.6bx.7tt.1e6n 2quh.2qrh.6bx.2rw0 2quh.2qrh.6bx.2rw1 2quh.2qrh.6bx.2rw2 2quh.2qrh.6bx.2rw3 2quh.2qrh.6bx.2rwc 2quh.2qrh.6bx.2rwc.6bx.7tt.1e6n 2quh.2qrh.6bx.2rwd 2quh.2qrh.6bx.2rwd.6bx.7tt.1e6n 2quh.2qri.6bx.7md.1e6n 2quh.2qri.6bx.7me.1e6n 2quh.2qri.6bx.7pk.1e6n 2quh.2qri.6bx.7s4.1e6n.6bx.2qug.2qrf 2quh.2qri.6bx.7s4.1e6n.6bx.2qug.2qrg 2quh.2qri.6bx.7s4.1e6n.6bx.2qug.2qrh 2quh.2qri.6bx.7s4.1e6n.6bx.2qug.2qri 2quh.2qri.6bx.7s4.1e6n.6bx.2qug.2qrj 2quh.2qri.6bx.7s4.1e6n.6bx.2quh.2qrf 2quh.2qri.6bx.7s4.1e6n.6bx.2quh.2qrg 2quh.2qri.6bx.7s4.1e6n.6bx.2quh.2qrh 2quh.2qri.6bx.7s4.1e6n.6bx.2quh.2qri 2quh.2qri.6bx.7s4.1e6n.6bx.2quh.2qrj 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrf 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrg 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrh 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qri 2quh.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrj 2quh.2qri.6bx.2qm6 2quh.2qri.6bx.2qnn 2quh.2qri.6bx.2qnw 2quh.2qri.6bx.2qoj 2quh.2qri.6bx.2qp0 2quh.2qri.6bx.2qp4 2quh.2qri.6bx.2qqz 2quh.2qri.6bx.2qr1 2quh.2qri.6bx.2qwr 2quh.2qri.6bx.2qws 2quh.2qri.6bx.2qzr 2quh.2qri.6bx.2qzw 2quh.2qri.6bx.2r9c 2quh.2qri.6bx.2r9u 2quh.2qri.6bx.2rrx.6bx.2qug.2qrf 2quh.2qri.6bx.2rrx.6bx.2qug.2qrg 2quh.2qri.6bx.2rrx.6bx.2qug.2qrh 2quh.2qri.6bx.2rrx.6bx.2qug.2qrj 2quh.2qri.6bx.2rrx.6bx.2quh.2qrf 2quh.2qri.6bx.2rrx.6bx.2quh.2qrg 2quh.2qri.6bx.2rrx.6bx.2quh.2qrh 2quh.2qri.6bx.2rrx.6bx.2quh.2qrj 2quh.2qri.6bx.2rvz 2quh.2qri.6bx.2rvz.6bx.7tt.1e6n 2quh.2qri.6bx.2rw0 2quh.2qri.6bx.2rw1 2quh.2qri.6bx.2rw2 2quh.2qri.6bx.2rw3 2quh.2qri.6bx.2rwc 2quh.2qri.6bx.2rwc.6bx.7tt.1e6n 2quh.2qri.6bx.2rwd 2quh.2qri.6bx.2rwd.6bx.7tt.1e6n 2quh.2qrj.6bx.7md.1e6n 2quh.2qrj.6bx.7me.1e6n 2quh.2qrj.6bx.7pk.1e6n 2quh.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrf 2quh.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrg 2quh.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrh 2quh.2qrj.6bx.7s4.1e6n.6bx.2qug.2qri 2quh.2qrj.6bx.7s4.1e6n.6bx.2qug.2qrj 2quh.2qrj.6bx.7s4.1e6n.6bx.2quh.2qrf 2quh.2qrj.6bx.7s4.1e6n.6bx.2quh.2qrg 2quh.2qrj.6bx.7s4.1e6n.6bx.2quh.2qrh 2quh.2qrj.6bx.7s4.1e6n.6bx.2quh.2qri 2quh.2qrj.6bx.7s4.1e6n.6bx.2quh.2qrj 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrf 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrg 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrh 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qri 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2qug.2qrj 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrf 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrg 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrh 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qri 2quh.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2quh.2qrj 2quh.2qrj.6bx.2qm6 2quh.2qrj.6bx.2qnn 2quh.2qrj.6bx.2qnw 2quh.2qrj.6bx.2qoj 2quh.2qrj.6bx.2qp0 2quh.2qrj.6bx.2qp4 2quh.2qrj.6bx.2qqz 2quh.2qrj.6bx.2qr1 2quh.2qrj.6bx.2qwr 2quh.2qrj.6bx.2qws 2quh.2qrj.6bx.2qzr 2quh.2qrj.6bx.2qzw 2quh.2qrj.6bx.2r9c 2quh.2qrj.6bx.2r9u 2quh.2qrj.6bx.2rrx.6bx.2qug.2qrf 2quh.2qrj.6bx.2rrx.6bx.2qug.2qrg 2quh.2qrj.6bx.2rrx.6bx.2qug.2qrh 2quh.2qrj.6bx.2rrx.6bx.2qug.2qri 2quh.2qrj.6bx.2rrx.6bx.2quh.2qrf 2quh.2qrj.6bx.2rrx.6bx.2quh.2qrg 2quh.2qrj.6bx.2rrx.6bx.2quh.2qrh 2quh.2qrj.6bx.2rrx.6bx.2quh.2qri 2quh.2qrj.6bx.2rvz 2quh.2qrj.6bx.2rvz.6bx.7tt.1e6n 2quh.2qrj.6bx.2rw0 2quh.2qrj.6bx.2rw1 2quh.2qrj.6bx.2rw2 2quh.2qrj.6bx.2rw3 2quh.2qrj.6bx.2rwc 2quh.2qrj.6bx.2rwc.6bx.7tt.1e6n 2quh.2qrj.6bx.2rwd 2quh.2qrj.6bx.2rwd.6bx.7tt.1e6n 2qum.6bx.7k0.1e6n 2qum.6bx.7k2.1e6n 2qum.2qrf.6bx.7k0.1e6n 2qum.2qrf.6bx.7k2.1e6n 2qum.2qrg.6bx.7k0.1e6n 2qum.2qrg.6bx.7k2.1e6n 2qum.2qrh.6bx.7k0.1e6n 2qum.2qrh.6bx.7k2.1e6n 2qum.2qri.6bx.7k0.1e6n 2qum.2qri.6bx.7k2.1e6n 2qum.2qrj.6bx.7k0.1e6n 2qum.2qrj.6bx.7k2.1e6n 2qun.6bx.7k0.1e6n 2qun.6bx.7k2.1e6n 2quo.6bx.7k0.1e6n 2quo.6bx.7k2.1e6n 2quo.2qrf.6bx.7k0.1e6n 2quo.2qrf.6bx.7k2.1e6n 2quo.2qrg.6bx.7k0.1e6n 2quo.2qrg.6bx.7k2.1e6n 2quo.2qrh.6bx.7k0.1e6n 2quo.2qrh.6bx.7k2.1e6n 2quo.2qri.6bx.7k0.1e6n 2quo.2qri.6bx.7k2.1e6n 2quo.2qrj.6bx.7k0.1e6n 2quo.2qrj.6bx.7k2.1e6n 2qup.6bx.7k0.1e6n 2qup.6bx.7k2.1e6n 2qup.2qrf.6bx.7k0.1e6n 2qup.2qrf.6bx.7k2.1e6n 2qup.2qrg.6bx.7k0.1e6n 2qup.2qrg.6bx.7k2.1e6n 2qup.2qrh.6bx.7k0.1e6n 2qup.2qrh.6bx.7k2.1e6n 2qup.2qri.6bx.7k0.1e6n 2qup.2qri.6bx.7k2.1e6n 2qup.2qrj.6bx.7k0.1e6n 2qup.2qrj.6bx.7k2.1e6n 2qur.6bx.7k0.1e6n 2qur.6bx.7k2.1e6n 2qur.2qrf.6bx.7k0.1e6n 2qur.2qrf.6bx.7k2.1e6n 2qur.2qrg.6bx.7k0.1e6n 2qur.2qrg.6bx.7k2.1e6n 2qur.2qrh.6bx.7k0.1e6n 2qur.2qrh.6bx.7k2.1e6n 2qur.2qri.6bx.7k0.1e6n 2qur.2qri.6bx.7k2.1e6n 2qur.2qrj.6bx.7k0.1e6n 2qur.2qrj.6bx.7k2.1e6n 2quv.6bx.7k0.1e6n 2quv.6bx.7k2.1e6n 2quv.2qrf.6bx.7k0.1e6n 2quv.2qrf.6bx.7k2.1e6n 2quv.2qrg.6bx.7k0.1e6n 2quv.2qrg.6bx.7k2.1e6n 2quv.2qrh.6bx.7k0.1e6n 2quv.2qrh.6bx.7k2.1e6n 2quv.2qri.6bx.7k0.1e6n 2quv.2qri.6bx.7k2.1e6n 2quv.2qrj.6bx.7k0.1e6n 2quv.2qrj.6bx.7k2.1e6n 2qv5.6bx.7k0.1e6n 2qv5.6bx.7k2.1e6n 2qv5.2qrf.6bx.7k0.1e6n 2qv5.2qrf.6bx.7k2.1e6n 2qv5.2qrg.6bx.7k0.1e6n 2qv5.2qrg.6bx.7k2.1e6n 2qv5.2qrh.6bx.7k0.1e6n 2qv5.2qrh.6bx.7k2.1e6n 2qv5.2qri.6bx.7k0.1e6n 2qv5.2qri.6bx.7k2.1e6n 2qv5.2qrj.6bx.7k0.1e6n 2qv5.2qrj.6bx.7k2.1e6n 2qv6.6bx.7k0.1e6n 2qv6.6bx.7k2.1e6n 2qv6.2qrf.6bx.7k0.1e6n 2qv6.2qrf.6bx.7k2.1e6n 2qv6.2qrg.6bx.7k0.1e6n 2qv6.2qrg.6bx.7k2.1e6n 2qv6.2qrh.6bx.7k0.1e6n 2qv6.2qrh.6bx.7k2.1e6n 2qv6.2qri.6bx.7k0.1e6n 2qv6.2qri.6bx.7k2.1e6n 2qv6.2qrj.6bx.7k0.1e6n 2qv6.2qrj.6bx.7k2.1e6n 2qva.6bx.7k0.1e6n 2qva.6bx.7k2.1e6n 2qva.2qrf.6bx.7k0.1e6n 2qva.2qrf.6bx.7k2.1e6n 2qva.2qrg.6bx.7k0.1e6n 2qva.2qrg.6bx.7k2.1e6n 2qva.2qrh.6bx.7k0.1e6n 2qva.2qrh.6bx.7k2.1e6n 2qva.2qri.6bx.7k0.1e6n 2qva.2qri.6bx.7k2.1e6n 2qva.2qrj.6bx.7k0.1e6n 2qva.2qrj.6bx.7k2.1e6n 2qvb.6bx.7k0.1e6n 2qvb.6bx.7k2.1e6n 2qvb.2qrf.6bx.7k0.1e6n 2qvb.2qrf.6bx.7k2.1e6n 2qvb.2qrg.6bx.7k0.1e6n 2qvb.2qrg.6bx.7k2.1e6n 2qvb.2qrh.6bx.7k0.1e6n 2qvb.2qrh.6bx.7k2.1e6n 2qvb.2qri.6bx.7k0.1e6n 2qvb.2qri.6bx.7k2.1e6n 2qvb.2qrj.6bx.7k0.1e6n 2qvb.2qrj.6bx.7k2.1e6n 2r1x.1e6n.6bx.7k0.1e6n 2r1x.1e6n.6bx.7k2.1e6n 2r1x.2qrf.6bx.7k0.1e6n 2r1x.2qrf.6bx.7k2.1e6n 2r1x.2qrg.6bx.7k0.1e6n 2r1x.2qrg.6bx.7k2.1e6n 2r1x.2qrh.6bx.7k0.1e6n 2r1x.2qrh.6bx.7k2.1e6n 2r1x.2qri.6bx.7k0.1e6n 2r1x.2qri.6bx.7k2.1e6n 2r1x.2qrj.6bx.7k0.1e6n 2r1x.2qrj.6bx.7k2.1e6n 2r72.6bx.2qw8 2r79.6bx.2qwb 2r7a.6bx.2qln.1e6n 2r7m.6bx.6ms.1e6n 2r7m.6bx.6mt.1e6n 2r7p.6bx.7k0.1e6n 2r7p.6bx.7k2.1e6n 2r7p.2qrf.6bx.7k0.1e6n 2r7p.2qrf.6bx.7k2.1e6n 2r7p.2qrg.6bx.7k0.1e6n 2r7p.2qrg.6bx.7k2.1e6n 2r7p.2qrh.6bx.7k0.1e6n 2r7p.2qrh.6bx.7k2.1e6n 2r7p.2qri.6bx.7k0.1e6n 2r7p.2qri.6bx.7k2.1e6n 2r7p.2qrj.6bx.7k0.1e6n 2r7p.2qrj.6bx.7k2.1e6n 2r7q.6bx.7k0.1e6n 2r7q.6bx.7k2.1e6n 2r7q.2qrf.6bx.7k0.1e6n 2r7q.2qrf.6bx.7k2.1e6n 2r7q.2qrg.6bx.7k0.1e6n 2r7q.2qrg.6bx.7k2.1e6n 2r7q.2qrh.6bx.7k0.1e6n 2r7q.2qrh.6bx.7k2.1e6n 2r7q.2qri.6bx.7k0.1e6n 2r7q.2qri.6bx.7k2.1e6n 2r7q.2qrj.6bx.7k0.1e6n 2r7q.2qrj.6bx.7k2.1e6n 2r7r.6bx.7k0.1e6n 2r7r.6bx.7k2.1e6n 2r7r.2qrf.6bx.7k0.1e6n 2r7r.2qrf.6bx.7k2.1e6n 2r7r.2qrg.6bx.7k0.1e6n 2r7r.2qrg.6bx.7k2.1e6n 2r7r.2qrh.6bx.7k0.1e6n 2r7r.2qrh.6bx.7k2.1e6n 2r7r.2qri.6bx.7k0.1e6n 2r7r.2qri.6bx.7k2.1e6n 2r7r.2qrj.6bx.7k0.1e6n 2r7r.2qrj.6bx.7k2.1e6n 2r7v.6bx.7k0.1e6n 2r7v.6bx.7k2.1e6n 2r7v.2qrf.6bx.7k0.1e6n 2r7v.2qrf.6bx.7k2.1e6n 2r7v.2qrg.6bx.7k0.1e6n 2r7v.2qrg.6bx.7k2.1e6n 2r7v.2qrh.6bx.7k0.1e6n 2r7v.2qrh.6bx.7k2.1e6n 2r7v.2qri.6bx.7k0.1e6n 2r7v.2qri.6bx.7k2.1e6n 2r7v.2qrj.6bx.7k0.1e6n 2r7v.2qrj.6bx.7k2.1e6n 2r7x.6bx.7k0.1e6n 2r7x.6bx.7k2.1e6n 2r7x.2qrf.6bx.7k0.1e6n 2r7x.2qrf.6bx.7k2.1e6n 2r7x.2qrg.6bx.7k0.1e6n 2r7x.2qrg.6bx.7k2.1e6n 2r7x.2qrh.6bx.7k0.1e6n 2r7x.2qrh.6bx.7k2.1e6n 2r7x.2qri.6bx.7k0.1e6n 2r7x.2qri.6bx.7k2.1e6n 2r7x.2qrj.6bx.7k0.1e6n 2r7x.2qrj.6bx.7k2.1e6n 2r7y.6bx.7k0.1e6n 2r7y.6bx.7k2.1e6n 2r7y.2qrf.6bx.7k0.1e6n 2r7y.2qrf.6bx.7k2.1e6n 2r7y.2qrg.6bx.7k0.1e6n 2r7y.2qrg.6bx.7k2.1e6n 2r7y.2qrh.6bx.7k0.1e6n 2r7y.2qrh.6bx.7k2.1e6n 2r7y.2qri.6bx.7k0.1e6n 2r7y.2qri.6bx.7k2.1e6n 2r7y.2qrj.6bx.7k0.1e6n 2r7y.2qrj.6bx.7k2.1e6n 2rab.6bx.7k0.1e6n 2rab.6bx.7k2.1e6n 2rab.2qrf.6bx.7k0.1e6n 2rab.2qrf.6bx.7k2.1e6n 2rab.2qrg.6bx.7k0.1e6n 2rab.2qrg.6bx.7k2.1e6n 2rab.2qrh.6bx.7k0.1e6n 2rab.2qrh.6bx.7k2.1e6n 2rab.2qri.6bx.7k0.1e6n 2rab.2qri.6bx.7k2.1e6n 2rab.2qrj.6bx.7k0.1e6n 2rab.2qrj.6bx.7k2.1e6n 2ras.6bx.7k0.1e6n 2ras.6bx.7k2.1e6n 2ras.2qrf.6bx.7k0.1e6n 2ras.2qrf.6bx.7k2.1e6n 2ras.2qrg.6bx.7k0.1e6n 2ras.2qrg.6bx.7k2.1e6n 2ras.2qrh.6bx.7k0.1e6n 2ras.2qrh.6bx.7k2.1e6n 2ras.2qri.6bx.7k0.1e6n 2ras.2qri.6bx.7k2.1e6n 2ras.2qrj.6bx.7k0.1e6n 2ras.2qrj.6bx.7k2.1e6n 2rat.6bx.7k0.1e6n 2rat.6bx.7k2.1e6n 2rat.2qrf.6bx.7k0.1e6n 2rat.2qrf.6bx.7k2.1e6n 2rat.2qrg.6bx.7k0.1e6n 2rat.2qrg.6bx.7k2.1e6n 2rat.2qrh.6bx.7k0.1e6n 2rat.2qrh.6bx.7k2.1e6n 2rat.2qri.6bx.7k0.1e6n 2rat.2qri.6bx.7k2.1e6n 2rat.2qrj.6bx.7k0.1e6n 2rat.2qrj.6bx.7k2.1e6n 2rau.6bx.7k0.1e6n 2rau.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.6bx.7k2.1e6n 2rau.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.6bx.7tt.1e6n 2rau.2qrf.6bx.7k0.1e6n 2rau.2qrf.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.2qrf.6bx.7k2.1e6n 2rau.2qrf.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.2qrf.6bx.7tt.1e6n 2rau.2qrg.6bx.7k0.1e6n 2rau.2qrg.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.2qrg.6bx.7k2.1e6n 2rau.2qrg.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.2qrg.6bx.7tt.1e6n 2rau.2qrh.6bx.7k0.1e6n 2rau.2qrh.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.2qrh.6bx.7k2.1e6n 2rau.2qrh.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.2qrh.6bx.7tt.1e6n 2rau.2qri.6bx.7k0.1e6n 2rau.2qri.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.2qri.6bx.7k2.1e6n 2rau.2qri.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.2qri.6bx.7tt.1e6n 2rau.2qrj.6bx.7k0.1e6n 2rau.2qrj.6bx.7k0.1e6n.6bx.7tt.1e6n 2rau.2qrj.6bx.7k2.1e6n 2rau.2qrj.6bx.7k2.1e6n.6bx.7tt.1e6n 2rau.2qrj.6bx.7tt.1e6n 2rs6.6bx.7k0.1e6n 2rs6.6bx.7k2.1e6n 2rs6.2qrf.6bx.7k0.1e6n 2rs6.2qrf.6bx.7k2.1e6n 2rs6.2qrg.6bx.7k0.1e6n 2rs6.2qrg.6bx.7k2.1e6n 2rs6.2qrh.6bx.7k0.1e6n 2rs6.2qrh.6bx.7k2.1e6n 2rs6.2qri.6bx.7k0.1e6n 2rs6.2qri.6bx.7k2.1e6n 2rs6.2qrj.6bx.7k0.1e6n 2rs6.2qrj.6bx.7k2.1e6n 2rsl.6bx.7k0.1e6n 2rsl.6bx.7k2.1e6n 2rsl.2qrf.6bx.7k0.1e6n 2rsl.2qrf.6bx.7k2.1e6n 2rsl.2qrg.6bx.7k0.1e6n 2rsl.2qrg.6bx.7k2.1e6n 2rsl.2qrh.6bx.7k0.1e6n 2rsl.2qrh.6bx.7k2.1e6n 2rsl.2qri.6bx.7k0.1e6n 2rsl.2qri.6bx.7k2.1e6n 2rsl.2qrj.6bx.7k0.1e6n 2rsl.2qrj.6bx.7k2.1e6n 2rsn.6bx.7k0.1e6n 2rsn.6bx.7k2.1e6n 2rsn.2qrf.6bx.7k0.1e6n 2rsn.2qrf.6bx.7k2.1e6n 2rsn.2qrg.6bx.7k0.1e6n 2rsn.2qrg.6bx.7k2.1e6n 2rsn.2qrh.6bx.7k0.1e6n 2rsn.2qrh.6bx.7k2.1e6n 2rsn.2qri.6bx.7k0.1e6n 2rsn.2qri.6bx.7k2.1e6n 2rsn.2qrj.6bx.7k0.1e6n 2rsn.2qrj.6bx.7k2.1e6n 2rso.6bx.7k0.1e6n 2rso.6bx.7k2.1e6n 2rso.2qrf.6bx.7k0.1e6n 2rso.2qrf.6bx.7k2.1e6n 2rso.2qrg.6bx.7k0.1e6n 2rso.2qrg.6bx.7k2.1e6n 2rso.2qrh.6bx.7k0.1e6n 2rso.2qrh.6bx.7k2.1e6n 2rso.2qri.6bx.7k0.1e6n 2rso.2qri.6bx.7k2.1e6n 2rso.2qrj.6bx.7k0.1e6n 2rso.2qrj.6bx.7k2.1e6n 2rsp.6bx.7k0.1e6n 2rsp.6bx.7k2.1e6n 2rsp.2qrf.6bx.7k0.1e6n 2rsp.2qrf.6bx.7k2.1e6n 2rsp.2qrg.6bx.7k0.1e6n 2rsp.2qrg.6bx.7k2.1e6n 2rsp.2qrh.6bx.7k0.1e6n 2rsp.2qrh.6bx.7k2.1e6n 2rsp.2qri.6bx.7k0.1e6n 2rsp.2qri.6bx.7k2.1e6n 2rsp.2qrj.6bx.7k0.1e6n 2rsp.2qrj.6bx.7k2.1e6n 2rss.6bx.7k0.1e6n 2rss.6bx.7k2.1e6n 2rst.6bx.7k0.1e6n 2rst.6bx.7k2.1e6n 2rst.2qrf.6bx.7k0.1e6n 2rst.2qrf.6bx.7k2.1e6n 2rst.2qrg.6bx.7k0.1e6n 2rst.2qrg.6bx.7k2.1e6n 2rst.2qrh.6bx.7k0.1e6n 2rst.2qrh.6bx.7k2.1e6n 2rst.2qri.6bx.7k0.1e6n 2rst.2qri.6bx.7k2.1e6n 2rst.2qrj.6bx.7k0.1e6n 2rst.2qrj.6bx.7k2.1e6n 2rsu.6bx.7k0.1e6n 2rsu.6bx.7k2.1e6n 2rsu.2qrf.6bx.7k0.1e6n 2rsu.2qrf.6bx.7k2.1e6n 2rsu.2qrg.6bx.7k0.1e6n 2rsu.2qrg.6bx.7k2.1e6n 2rsu.2qrh.6bx.7k0.1e6n 2rsu.2qrh.6bx.7k2.1e6n 2rsu.2qri.6bx.7k0.1e6n 2rsu.2qri.6bx.7k2.1e6n 2rsu.2qrj.6bx.7k0.1e6n 2rsu.2qrj.6bx.7k2.1e6n 2rw8.6bx.7k0.1e6n 2rw8.6bx.7k2.1e6n 2rw8.2qrf.6bx.7k0.1e6n 2rw8.2qrf.6bx.7k2.1e6n 2rw8.2qrg.6bx.7k0.1e6n 2rw8.2qrg.6bx.7k2.1e6n 2rw8.2qrh.6bx.7k0.1e6n 2rw8.2qrh.6bx.7k2.1e6n 2rw8.2qri.6bx.7k0.1e6n 2rw8.2qri.6bx.7k2.1e6n 2rw8.2qrj.6bx.7k0.1e6n 2rw8.2qrj.6bx.7k2.1e6n 2rw9.6bx.7k0.1e6n 2rw9.6bx.7k2.1e6n 2rw9.2qrf.6bx.7k0.1e6n 2rw9.2qrf.6bx.7k2.1e6n 2rw9.2qrg.6bx.7k0.1e6n 2rw9.2qrg.6bx.7k2.1e6n 2rw9.2qrh.6bx.7k0.1e6n 2rw9.2qrh.6bx.7k2.1e6n 2rw9.2qri.6bx.7k0.1e6n 2rw9.2qri.6bx.7k2.1e6n 2rw9.2qrj.6bx.7k0.1e6n 2rw9.2qrj.6bx.7k2.1e6n 2rwt.6bx.7k0.1e6n 2rwt.6bx.7k2.1e6n 2rwt.2qrf.6bx.7k0.1e6n 2rwt.2qrf.6bx.7k2.1e6n 2rwt.2qrg.6bx.7k0.1e6n 2rwt.2qrg.6bx.7k2.1e6n 2rwt.2qrh.6bx.7k0.1e6n 2rwt.2qrh.6bx.7k2.1e6n 2rwt.2qri.6bx.7k0.1e6n 2rwt.2qri.6bx.7k2.1e6n 2rwt.2qrj.6bx.7k0.1e6n 2rwt.2qrj.6bx.7k2.1e6n 2rwu.6bx.7k0.1e6n 2rwu.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.6bx.7k2.1e6n 2rwu.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.6bx.7tt.1e6n 2rwu.2qrf.6bx.7k0.1e6n 2rwu.2qrf.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.2qrf.6bx.7k2.1e6n 2rwu.2qrf.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.2qrf.6bx.7tt.1e6n 2rwu.2qrg.6bx.7k0.1e6n 2rwu.2qrg.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.2qrg.6bx.7k2.1e6n 2rwu.2qrg.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.2qrg.6bx.7tt.1e6n 2rwu.2qrh.6bx.7k0.1e6n 2rwu.2qrh.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.2qrh.6bx.7k2.1e6n 2rwu.2qrh.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.2qrh.6bx.7tt.1e6n 2rwu.2qri.6bx.7k0.1e6n 2rwu.2qri.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.2qri.6bx.7k2.1e6n 2rwu.2qri.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.2qri.6bx.7tt.1e6n 2rwu.2qrj.6bx.7k0.1e6n 2rwu.2qrj.6bx.7k0.1e6n.6bx.7tt.1e6n 2rwu.2qrj.6bx.7k2.1e6n 2rwu.2qrj.6bx.7k2.1e6n.6bx.7tt.1e6n 2rwu.2qrj.6bx.7tt.1e6n 2rwv.6bx.7k0.1e6n 2rwv.6bx.7k2.1e6n 2rwv.2qrf.6bx.7k0.1e6n 2rwv.2qrf.6bx.7k2.1e6n 2rwv.2qrg.6bx.7k0.1e6n 2rwv.2qrg.6bx.7k2.1e6n 2rwv.2qrh.6bx.7k0.1e6n 2rwv.2qrh.6bx.7k2.1e6n 2rwv.2qri.6bx.7k0.1e6n 2rwv.2qri.6bx.7k2.1e6n 2rwv.2qrj.6bx.7k0.1e6n 2rwv.2qrj.6bx.7k2.1e6n 2rwx.6bx.7md.1e6n 2rwx.6bx.7me.1e6n 2rwx.6bx.7pk.1e6n 2rwx.6bx.2qm6 2rwx.6bx.2qnn 2rwx.6bx.2qnw 2rwx.6bx.2qo4 2rwx.6bx.2qoj 2rwx.6bx.2qp0 2rwx.6bx.2qp4 2rwx.6bx.2qqz 2rwx.6bx.2qr1 2rwx.6bx.2qwr 2rwx.6bx.2qws 2rwx.6bx.2qzr 2rwx.6bx.2qzw 2rwx.6bx.2r9c 2rwx.6bx.2r9u 2rwx.6bx.2rrx.6bx.2rwx 2rwx.6bx.2rvz 2rwx.6bx.2rvz.6bx.7tt.1e6n 2rwx.6bx.2rw0 2rwx.6bx.2rw1 2rwx.6bx.2rw2 2rwx.6bx.2rw3 2rwx.6bx.2rwc 2rwx.6bx.2rwc.6bx.7tt.1e6n 2rwx.6bx.2rwd 2rwx.6bx.2rwd.6bx.7tt.1e6n 2rwx.6bx.2rwx.6bx.2rwy 2rwx.6bx.2rwx.6bx.2rwy.6bx.2rwy 2rwx.6bx.2rwy 2rwx.6bx.2rwy.6bx.2rwy 2rwx.2qrf.6bx.7md.1e6n 2rwx.2qrf.6bx.7me.1e6n 2rwx.2qrf.6bx.7pk.1e6n 2rwx.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrg 2rwx.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrh 2rwx.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qri 2rwx.2qrf.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrj 2rwx.2qrf.6bx.7s4.1e6n.6bx.2rwx.2qrg 2rwx.2qrf.6bx.7s4.1e6n.6bx.2rwx.2qrh 2rwx.2qrf.6bx.7s4.1e6n.6bx.2rwx.2qri 2rwx.2qrf.6bx.7s4.1e6n.6bx.2rwx.2qrj 2rwx.2qrf.6bx.2qm6 2rwx.2qrf.6bx.2qnn 2rwx.2qrf.6bx.2qnw 2rwx.2qrf.6bx.2qo4 2rwx.2qrf.6bx.2qoj 2rwx.2qrf.6bx.2qp0 2rwx.2qrf.6bx.2qp4 2rwx.2qrf.6bx.2qqz 2rwx.2qrf.6bx.2qr1 2rwx.2qrf.6bx.2qwr 2rwx.2qrf.6bx.2qws 2rwx.2qrf.6bx.2qzr 2rwx.2qrf.6bx.2qzw 2rwx.2qrf.6bx.2r9c 2rwx.2qrf.6bx.2r9u 2rwx.2qrf.6bx.2rrx.6bx.2rwx.2qrf 2rwx.2qrf.6bx.2rrx.6bx.2rwx.2qrg 2rwx.2qrf.6bx.2rrx.6bx.2rwx.2qrh 2rwx.2qrf.6bx.2rrx.6bx.2rwx.2qri 2rwx.2qrf.6bx.2rrx.6bx.2rwx.2qrj 2rwx.2qrf.6bx.2rvz 2rwx.2qrf.6bx.2rvz.6bx.7tt.1e6n 2rwx.2qrf.6bx.2rw0 2rwx.2qrf.6bx.2rw1 2rwx.2qrf.6bx.2rw2 2rwx.2qrf.6bx.2rw3 2rwx.2qrf.6bx.2rwc 2rwx.2qrf.6bx.2rwc.6bx.7tt.1e6n 2rwx.2qrf.6bx.2rwd 2rwx.2qrf.6bx.2rwd.6bx.7tt.1e6n 2rwx.2qrg.6bx.7md.1e6n 2rwx.2qrg.6bx.7me.1e6n 2rwx.2qrg.6bx.7pk.1e6n 2rwx.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrf 2rwx.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrh 2rwx.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qri 2rwx.2qrg.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrj 2rwx.2qrg.6bx.7s4.1e6n.6bx.2rwx.2qrf 2rwx.2qrg.6bx.7s4.1e6n.6bx.2rwx.2qrh 2rwx.2qrg.6bx.7s4.1e6n.6bx.2rwx.2qri 2rwx.2qrg.6bx.7s4.1e6n.6bx.2rwx.2qrj 2rwx.2qrg.6bx.2qm6 2rwx.2qrg.6bx.2qnn 2rwx.2qrg.6bx.2qnw 2rwx.2qrg.6bx.2qo4 2rwx.2qrg.6bx.2qoj 2rwx.2qrg.6bx.2qp0 2rwx.2qrg.6bx.2qp4 2rwx.2qrg.6bx.2qqz 2rwx.2qrg.6bx.2qr1 2rwx.2qrg.6bx.2qwr 2rwx.2qrg.6bx.2qws 2rwx.2qrg.6bx.2qzr 2rwx.2qrg.6bx.2qzw 2rwx.2qrg.6bx.2r9c 2rwx.2qrg.6bx.2r9u 2rwx.2qrg.6bx.2rrx.6bx.2rwx.2qrf 2rwx.2qrg.6bx.2rrx.6bx.2rwx.2qrg 2rwx.2qrg.6bx.2rrx.6bx.2rwx.2qrh 2rwx.2qrg.6bx.2rrx.6bx.2rwx.2qri 2rwx.2qrg.6bx.2rrx.6bx.2rwx.2qrj 2rwx.2qrg.6bx.2rvz 2rwx.2qrg.6bx.2rvz.6bx.7tt.1e6n 2rwx.2qrg.6bx.2rw0 2rwx.2qrg.6bx.2rw1 2rwx.2qrg.6bx.2rw2 2rwx.2qrg.6bx.2rw3 2rwx.2qrg.6bx.2rwc 2rwx.2qrg.6bx.2rwc.6bx.7tt.1e6n 2rwx.2qrg.6bx.2rwd 2rwx.2qrg.6bx.2rwd.6bx.7tt.1e6n 2rwx.2qrh.6bx.7md.1e6n 2rwx.2qrh.6bx.7me.1e6n 2rwx.2qrh.6bx.7pk.1e6n 2rwx.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrf 2rwx.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrg 2rwx.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qri 2rwx.2qrh.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrj 2rwx.2qrh.6bx.7s4.1e6n.6bx.2rwx.2qrf 2rwx.2qrh.6bx.7s4.1e6n.6bx.2rwx.2qrg 2rwx.2qrh.6bx.7s4.1e6n.6bx.2rwx.2qri 2rwx.2qrh.6bx.7s4.1e6n.6bx.2rwx.2qrj 2rwx.2qrh.6bx.2qm6 2rwx.2qrh.6bx.2qnn 2rwx.2qrh.6bx.2qnw 2rwx.2qrh.6bx.2qo4 2rwx.2qrh.6bx.2qoj 2rwx.2qrh.6bx.2qp0 2rwx.2qrh.6bx.2qp4 2rwx.2qrh.6bx.2qqz 2rwx.2qrh.6bx.2qr1 2rwx.2qrh.6bx.2qwr 2rwx.2qrh.6bx.2qws 2rwx.2qrh.6bx.2qzr 2rwx.2qrh.6bx.2qzw 2rwx.2qrh.6bx.2r9c 2rwx.2qrh.6bx.2r9u 2rwx.2qrh.6bx.2rrx.6bx.2rwx.2qrf 2rwx.2qrh.6bx.2rrx.6bx.2rwx.2qrg 2rwx.2qrh.6bx.2rrx.6bx.2rwx.2qrh 2rwx.2qrh.6bx.2rrx.6bx.2rwx.2qri 2rwx.2qrh.6bx.2rrx.6bx.2rwx.2qrj 2rwx.2qrh.6bx.2rvz 2rwx.2qrh.6bx.2rvz.6bx.7tt.1e6n 2rwx.2qrh.6bx.2rw0 2rwx.2qrh.6bx.2rw1 2rwx.2qrh.6bx.2rw2 2rwx.2qrh.6bx.2rw3 2rwx.2qrh.6bx.2rwc 2rwx.2qrh.6bx.2rwc.6bx.7tt.1e6n 2rwx.2qrh.6bx.2rwd 2rwx.2qrh.6bx.2rwd.6bx.7tt.1e6n 2rwx.2qri.6bx.7md.1e6n 2rwx.2qri.6bx.7me.1e6n 2rwx.2qri.6bx.7pk.1e6n 2rwx.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrf 2rwx.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrg 2rwx.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrh 2rwx.2qri.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrj 2rwx.2qri.6bx.7s4.1e6n.6bx.2rwx.2qrf 2rwx.2qri.6bx.7s4.1e6n.6bx.2rwx.2qrg 2rwx.2qri.6bx.7s4.1e6n.6bx.2rwx.2qrh 2rwx.2qri.6bx.7s4.1e6n.6bx.2rwx.2qrj 2rwx.2qri.6bx.2qm6 2rwx.2qri.6bx.2qnn 2rwx.2qri.6bx.2qnw 2rwx.2qri.6bx.2qo4 2rwx.2qri.6bx.2qoj 2rwx.2qri.6bx.2qp0 2rwx.2qri.6bx.2qp4 2rwx.2qri.6bx.2qqz 2rwx.2qri.6bx.2qr1 2rwx.2qri.6bx.2qwr 2rwx.2qri.6bx.2qws 2rwx.2qri.6bx.2qzr 2rwx.2qri.6bx.2qzw 2rwx.2qri.6bx.2r9c 2rwx.2qri.6bx.2r9u 2rwx.2qri.6bx.2rrx.6bx.2rwx.2qrf 2rwx.2qri.6bx.2rrx.6bx.2rwx.2qrg 2rwx.2qri.6bx.2rrx.6bx.2rwx.2qrh 2rwx.2qri.6bx.2rrx.6bx.2rwx.2qri 2rwx.2qri.6bx.2rrx.6bx.2rwx.2qrj 2rwx.2qri.6bx.2rvz 2rwx.2qri.6bx.2rvz.6bx.7tt.1e6n 2rwx.2qri.6bx.2rw0 2rwx.2qri.6bx.2rw1 2rwx.2qri.6bx.2rw2 2rwx.2qri.6bx.2rw3 2rwx.2qri.6bx.2rwc 2rwx.2qri.6bx.2rwc.6bx.7tt.1e6n 2rwx.2qri.6bx.2rwd 2rwx.2qri.6bx.2rwd.6bx.7tt.1e6n 2rwx.2qrj.6bx.7md.1e6n 2rwx.2qrj.6bx.7me.1e6n 2rwx.2qrj.6bx.7pk.1e6n 2rwx.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrf 2rwx.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrg 2rwx.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qrh 2rwx.2qrj.6bx.7s4.1e6n.6bx.2qvf.6bx.2rwx.2qri 2rwx.2qrj.6bx.7s4.1e6n.6bx.2rwx.2qrf 2rwx.2qrj.6bx.7s4.1e6n.6bx.2rwx.2qrg 2rwx.2qrj.6bx.7s4.1e6n.6bx.2rwx.2qrh 2rwx.2qrj.6bx.7s4.1e6n.6bx.2rwx.2qri 2rwx.2qrj.6bx.2qm6 2rwx.2qrj.6bx.2qnn 2rwx.2qrj.6bx.2qnw 2rwx.2qrj.6bx.2qo4 2rwx.2qrj.6bx.2qoj 2rwx.2qrj.6bx.2qp0 2rwx.2qrj.6bx.2qp4 2rwx.2qrj.6bx.2qqz 2rwx.2qrj.6bx.2qr1 2rwx.2qrj.6bx.2qwr 2rwx.2qrj.6bx.2qws 2rwx.2qrj.6bx.2qzr 2rwx.2qrj.6bx.2qzw 2rwx.2qrj.6bx.2r9c 2rwx.2qrj.6bx.2r9u 2rwx.2qrj.6bx.2rrx.6bx.2rwx.2qrf 2rwx.2qrj.6bx.2rrx.6bx.2rwx.2qrg 2rwx.2qrj.6bx.2rrx.6bx.2rwx.2qrh 2rwx.2qrj.6bx.2rrx.6bx.2rwx.2qri 2rwx.2qrj.6bx.2rrx.6bx.2rwx.2qrj 2rwx.2qrj.6bx.2rvz 2rwx.2qrj.6bx.2rvz.6bx.7tt.1e6n 2rwx.2qrj.6bx.2rw0 2rwx.2qrj.6bx.2rw1 2rwx.2qrj.6bx.2rw2 2rwx.2qrj.6bx.2rw3 2rwx.2qrj.6bx.2rwc 2rwx.2qrj.6bx.2rwc.6bx.7tt.1e6n 2rwx.2qrj.6bx.2rwd 2rwx.2qrj.6bx.2rwd.6bx.7tt.1e6n 2rx0.6bx.7k0.1e6n 2rx0.6bx.7k2.1e6n 2rx0.2qrf.6bx.7k0.1e6n 2rx0.2qrf.6bx.7k2.1e6n 2rx0.2qrg.6bx.7k0.1e6n 2rx0.2qrg.6bx.7k2.1e6n 2rx0.2qrh.6bx.7k0.1e6n 2rx0.2qrh.6bx.7k2.1e6n 2rx0.2qri.6bx.7k0.1e6n 2rx0.2qri.6bx.7k2.1e6n 2rx0.2qrj.6bx.7k0.1e6n 2rx0.2qrj.6bx.7k2.1e6n 2rx2.6bx.7k0.1e6n 2rx2.6bx.7k2.1e6n 2rx2.2qrf.6bx.7k0.1e6n 2rx2.2qrf.6bx.7k2.1e6n 2rx2.2qrg.6bx.7k0.1e6n 2rx2.2qrg.6bx.7k2.1e6n 2rx2.2qrh.6bx.7k0.1e6n 2rx2.2qrh.6bx.7k2.1e6n 2rx2.2qri.6bx.7k0.1e6n 2rx2.2qri.6bx.7k2.1e6n 2rx2.2qrj.6bx.7k0.1e6n 2rx2.2qrj.6bx.7k2.1e6n 2rx3.6bx.7k0.1e6n 2rx3.6bx.7k2.1e6n 2rx3.2qrf.6bx.7k0.1e6n 2rx3.2qrf.6bx.7k2.1e6n 2rx3.2qrg.6bx.7k0.1e6n 2rx3.2qrg.6bx.7k2.1e6n 2rx3.2qrh.6bx.7k0.1e6n 2rx3.2qrh.6bx.7k2.1e6n 2rx3.2qri.6bx.7k0.1e6n 2rx3.2qri.6bx.7k2.1e6n 2rx3.2qrj.6bx.7k0.1e6n 2rx3.2qrj.6bx.7k2.1e6n 2rx4.6bx.7k0.1e6n 2rx4.6bx.7k2.1e6n 2rx4.2qrf.6bx.7k0.1e6n 2rx4.2qrf.6bx.7k2.1e6n 2rx4.2qrg.6bx.7k0.1e6n 2rx4.2qrg.6bx.7k2.1e6n 2rx4.2qrh.6bx.7k0.1e6n 2rx4.2qrh.6bx.7k2.1e6n 2rx4.2qri.6bx.7k0.1e6n 2rx4.2qri.6bx.7k2.1e6n 2rx4.2qrj.6bx.7k0.1e6n 2rx4.2qrj.6bx.7k2.1e6n 2rx5.6bx.7k0.1e6n 2rx5.6bx.7k2.1e6n 2rx5.2qrf.6bx.7k0.1e6n 2rx5.2qrf.6bx.7k2.1e6n 2rx5.2qrg.6bx.7k0.1e6n 2rx5.2qrg.6bx.7k2.1e6n 2rx5.2qrh.6bx.7k0.1e6n 2rx5.2qrh.6bx.7k2.1e6n 2rx5.2qri.6bx.7k0.1e6n 2rx5.2qri.6bx.7k2.1e6n 2rx5.2qrj.6bx.7k0.1e6n 2rx5.2qrj.6bx.7k2.1e6n 2rx6.6bx.7k0.1e6n 2rx6.6bx.7k2.1e6n 2rx6.2qrf.6bx.7k0.1e6n 2rx6.2qrf.6bx.7k2.1e6n 2rx6.2qrg.6bx.7k0.1e6n 2rx6.2qrg.6bx.7k2.1e6n 2rx6.2qrh.6bx.7k0.1e6n 2rx6.2qrh.6bx.7k2.1e6n 2rx6.2qri.6bx.7k0.1e6n 2rx6.2qri.6bx.7k2.1e6n 2rx6.2qrj.6bx.7k0.1e6n 2rx6.2qrj.6bx.7k2.1e6n 2rx7.6bx.7k0.1e6n 2rx7.6bx.7k2.1e6n 2rx7.2qrf.6bx.7k0.1e6n 2rx7.2qrf.6bx.7k2.1e6n 2rx7.2qrg.6bx.7k0.1e6n 2rx7.2qrg.6bx.7k2.1e6n 2rx7.2qrh.6bx.7k0.1e6n 2rx7.2qrh.6bx.7k2.1e6n 2rx7.2qri.6bx.7k0.1e6n 2rx7.2qri.6bx.7k2.1e6n 2rx7.2qrj.6bx.7k0.1e6n 2rx7.2qrj.6bx.7k2.1e6n 2rx8.6bx.7k0.1e6n 2rx8.6bx.7k2.1e6n 2rx8.2qrf.6bx.7k0.1e6n 2rx8.2qrf.6bx.7k2.1e6n 2rx8.2qrg.6bx.7k0.1e6n 2rx8.2qrg.6bx.7k2.1e6n 2rx8.2qrh.6bx.7k0.1e6n 2rx8.2qrh.6bx.7k2.1e6n 2rx8.2qri.6bx.7k0.1e6n 2rx8.2qri.6bx.7k2.1e6n 2rx8.2qrj.6bx.7k0.1e6n 2rx8.2qrj.6bx.7k2.1e6n 2rx9.6bx.7k0.1e6n 2rx9.6bx.7k2.1e6n 2rx9.2qrf.6bx.7k0.1e6n 2rx9.2qrf.6bx.7k2.1e6n 2rx9.2qrg.6bx.7k0.1e6n 2rx9.2qrg.6bx.7k2.1e6n 2rx9.2qrh.6bx.7k0.1e6n 2rx9.2qrh.6bx.7k2.1e6n 2rx9.2qri.6bx.7k0.1e6n 2rx9.2qri.6bx.7k2.1e6n 2rx9.2qrj.6bx.7k0.1e6n 2rx9.2qrj.6bx.7k2.1e6n 2rxa.6bx.7k0.1e6n 2rxa.6bx.7k2.1e6n 2rxb.6bx.7k0.1e6n 2rxb.6bx.7k2.1e6n 2s4x.2qrf.6bx.2s4y.2qrg 2s4x.2qrf.6bx.2s4y.2qrh 2s4x.2qrf.6bx.2s4y.2qri 2s4x.2qrf.6bx.2s4y.2qrj 2s4x.2qrg.6bx.2s4y.2qrf 2s4x.2qrg.6bx.2s4y.2qrh 2s4x.2qrg.6bx.2s4y.2qri 2s4x.2qrg.6bx.2s4y.2qrj 2s4x.2qrh.6bx.2s4y.2qrf 2s4x.2qrh.6bx.2s4y.2qrg 2s4x.2qrh.6bx.2s4y.2qri 2s4x.2qrh.6bx.2s4y.2qrj 2s4x.2qri.6bx.2s4y.2qrf 2s4x.2qri.6bx.2s4y.2qrg 2s4x.2qri.6bx.2s4y.2qrh 2s4x.2qri.6bx.2s4y.2qrj 2s4x.2qrj.6bx.2s4y.2qrf 2s4x.2qrj.6bx.2s4y.2qrg 2s4x.2qrj.6bx.2s4y.2qrh 2s4x.2qrj.6bx.2s4y.2qri",
+            "",
+        ],
+    ),
+];
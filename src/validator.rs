@@ -1,14 +1,16 @@
-use std::{collections::HashSet, ops::Range, rc::Rc};
+use std::{collections::HashSet, ops, ops::Range, rc::Rc, sync::Arc};
 
 use derive_builder::Builder;
+use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use squalid::OptionExt;
 
 use crate::{
     ecma_versions::LATEST_ECMA_VERSION,
+    group_specifiers::{GroupSpecifiers, GroupSpecifiersAsES2018, GroupSpecifiersAsES2025},
     reader::CodePoint,
-    regexp_syntax_error::{self, new_reg_exp_syntax_error},
+    regexp_syntax_error::{self, new_reg_exp_syntax_error, RegExpSyntaxErrorKind},
     unicode::{
         combine_surrogate_pair, digit_to_int, is_decimal_digit, is_hex_digit, is_id_continue,
         is_id_start, is_latin_letter, is_lead_surrogate, is_line_terminator, is_octal_digit,
@@ -117,19 +119,19 @@ static CLASS_SET_RESERVED_PUNCTUATOR: Lazy<HashSet<CodePoint>> = Lazy::new(|| {
     .collect()
 });
 
-fn is_syntax_character(cp: CodePoint) -> bool {
+pub(crate) fn is_syntax_character(cp: CodePoint) -> bool {
     SYNTAX_CHARACTER.contains(&cp)
 }
 
-fn is_class_set_reserved_double_punctuator_character(cp: Option<CodePoint>) -> bool {
+pub(crate) fn is_class_set_reserved_double_punctuator_character(cp: Option<CodePoint>) -> bool {
     cp.matches(|cp| CLASS_SET_RESERVED_DOUBLE_PUNCTUATOR_CHARACTER.contains(&cp))
 }
 
-fn is_class_set_syntax_character(cp: CodePoint) -> bool {
+pub(crate) fn is_class_set_syntax_character(cp: CodePoint) -> bool {
     CLASS_SET_SYNTAX_CHARACTER.contains(&cp)
 }
 
-fn is_class_set_reserved_punctuator(cp: Option<CodePoint>) -> bool {
+pub(crate) fn is_class_set_reserved_punctuator(cp: Option<CodePoint>) -> bool {
     cp.matches(|cp| CLASS_SET_RESERVED_PUNCTUATOR.contains(&cp))
 }
 
@@ -185,7 +187,14 @@ pub struct RegExpFlags {
     pub unicode_sets: bool,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Default)]
+pub struct RegExpModifierFlags {
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub dot_all: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AssertionKind {
     Lookahead,
@@ -195,7 +204,7 @@ pub enum AssertionKind {
     Word,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum CharacterKind {
     Any,
@@ -205,7 +214,7 @@ pub enum CharacterKind {
     Property,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash, Serialize)]
 #[serde(untagged)]
 pub enum CapturingGroupKey {
     Index(usize),
@@ -228,6 +237,9 @@ impl From<Wtf16> for CapturingGroupKey {
 pub trait Options {
     fn strict(&self) -> Option<bool>;
     fn ecma_version(&self) -> Option<EcmaVersion>;
+    fn tolerant(&self) -> Option<bool> {
+        None
+    }
     fn on_literal_enter(&self, start: usize) {}
     fn on_literal_leave(&self, start: usize, end: usize) {}
     fn on_reg_exp_flags(&self, start: usize, end: usize, flags: RegExpFlags) {}
@@ -239,6 +251,10 @@ pub trait Options {
     fn on_alternative_leave(&self, start: usize, end: usize, index: usize) {}
     fn on_group_enter(&self, start: usize) {}
     fn on_group_leave(&self, start: usize, end: usize) {}
+    fn on_modifiers_enter(&self, start: usize) {}
+    fn on_modifiers_leave(&self, start: usize, end: usize) {}
+    fn on_add_modifiers(&self, start: usize, end: usize, flags: RegExpModifierFlags) {}
+    fn on_remove_modifiers(&self, start: usize, end: usize, flags: RegExpModifierFlags) {}
     fn on_capturing_group_enter(&self, start: usize, name: Option<&Wtf16>) {}
     fn on_capturing_group_leave(&self, start: usize, end: usize, name: Option<&Wtf16>) {}
     fn on_quantifier(&self, start: usize, end: usize, min: u32, max: u32, greedy: bool) {}
@@ -286,6 +302,7 @@ pub trait Options {
     fn on_class_string_disjunction_leave(&self, start: usize, end: usize) {}
     fn on_string_alternative_enter(&self, start: usize, index: usize) {}
     fn on_string_alternative_leave(&self, start: usize, end: usize, index: usize) {}
+    fn on_error(&self, start: usize, end: usize, error: &RegExpSyntaxError) {}
 }
 
 struct NoopOptions;
@@ -317,6 +334,8 @@ struct Mode {
 #[builder(default, setter(strip_option))]
 struct RaiseContext {
     index: Option<usize>,
+    start: Option<usize>,
+    end: Option<usize>,
     unicode: Option<bool>,
     unicode_sets: Option<bool>,
 }
@@ -327,8 +346,86 @@ pub fn bmp_char_to_utf_16(ch: char) -> u16 {
     buffer[0]
 }
 
+/// Where each capturing group's opening parenthesis is, from the reader's
+/// position to its end, in group number order. The reader is left where it
+/// started
+pub(crate) fn capturing_paren_starts(reader: &mut Reader) -> Vec<usize> {
+    let start = reader.index();
+    let mut in_class = false;
+    let mut escaped = false;
+    let mut starts: Vec<usize> = Default::default();
+
+    while let Some(cp) = reader.current_code_point() {
+        if escaped {
+            escaped = false;
+        } else if cp == REVERSE_SOLIDUS {
+            escaped = true;
+        } else if cp == LEFT_SQUARE_BRACKET {
+            in_class = true;
+        } else if cp == RIGHT_SQUARE_BRACKET {
+            in_class = false;
+        } else if cp == LEFT_PARENTHESIS
+            && !in_class
+            && (reader.next_code_point() != Some(QUESTION_MARK)
+                || (reader.next_code_point2() == Some(LESS_THAN_SIGN)
+                    && reader.next_code_point3() != Some(EQUALS_SIGN)
+                    && reader.next_code_point3() != Some(EXCLAMATION_MARK)))
+        {
+            starts.push(reader.index());
+        }
+        reader.advance();
+    }
+
+    reader.rewind(start);
+    starts
+}
+
+/// An empty span underlines nothing, so it's widened to the character at it,
+/// or the last one before it at the end of the source
+fn non_empty_span(src_ctx: &RegExpValidatorSourceContext, span: Range<usize>) -> Range<usize> {
+    if !span.is_empty() {
+        return span;
+    }
+    let source = &src_ctx.source;
+    let is_pair = |lead: usize| {
+        lead + 1 < src_ctx.end
+            && is_lead_surrogate(source[lead].into())
+            && is_trail_surrogate(source[lead + 1].into())
+    };
+    let start = span.start;
+    if start < src_ctx.end {
+        return start..start + if is_pair(start) { 2 } else { 1 };
+    }
+    let end = src_ctx.end;
+    if end >= src_ctx.start + 2 && is_pair(end - 2) {
+        end - 2..end
+    } else if end > src_ctx.start {
+        end - 1..end
+    } else {
+        span
+    }
+}
+
+/// A validator's options, which are either its own or shared with
+/// validators on other threads
+enum ValidatorOptions<'a> {
+    Local(Rc<dyn Options + 'a>),
+    Shared(Arc<dyn Options + Send + Sync + 'a>),
+}
+
+impl<'a> ops::Deref for ValidatorOptions<'a> {
+    type Target = dyn Options + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Local(options) => &**options,
+            Self::Shared(options) => &**options,
+        }
+    }
+}
+
 pub struct RegExpValidator<'a> {
-    _options: Rc<dyn Options + 'a>,
+    _options: ValidatorOptions<'a>,
     _reader: Reader,
     _unicode_mode: bool,
     _unicode_sets_mode: bool,
@@ -338,15 +435,38 @@ pub struct RegExpValidator<'a> {
     _last_str_value: Wtf16,
     _last_assertion_is_quantifiable: bool,
     _num_capturing_parens: usize,
-    _group_names: HashSet<Wtf16>,
-    _backreference_names: HashSet<Wtf16>,
+    _group_specifiers: Box<dyn GroupSpecifiers>,
+    /// Each `\k` name, with where it's first referenced
+    _backreference_names: IndexMap<Wtf16, Range<usize>>,
+    _disjunction_depth: usize,
+    _diagnostics: Vec<RegExpSyntaxError>,
     _src_ctx: Option<RegExpValidatorSourceContext>,
 }
 
 impl<'a> RegExpValidator<'a> {
     pub fn new(options: Option<Rc<dyn Options + 'a>>) -> Self {
+        Self::with_options(ValidatorOptions::Local(
+            options.unwrap_or_else(|| Rc::new(NoopOptions)),
+        ))
+    }
+
+    /// Uses options that are shared with validators on other threads. Only
+    /// a validator used directly takes these: a
+    /// [`RegExpParser`](crate::RegExpParser) always validates with its own
+    /// per-thread state, so these callbacks never run while parsing
+    pub fn new_shared(options: Arc<dyn Options + Send + Sync + 'a>) -> Self {
+        Self::with_options(ValidatorOptions::Shared(options))
+    }
+
+    fn with_options(options: ValidatorOptions<'a>) -> Self {
+        let _group_specifiers: Box<dyn GroupSpecifiers> =
+            if options.ecma_version().unwrap_or(LATEST_ECMA_VERSION) >= EcmaVersion::_2025 {
+                Box::<GroupSpecifiersAsES2025>::default()
+            } else {
+                Box::<GroupSpecifiersAsES2018>::default()
+            };
         Self {
-            _options: options.unwrap_or_else(|| Rc::new(NoopOptions)),
+            _options: options,
             _reader: Default::default(),
             _unicode_mode: Default::default(),
             _unicode_sets_mode: Default::default(),
@@ -356,8 +476,10 @@ impl<'a> RegExpValidator<'a> {
             _last_str_value: Default::default(),
             _last_assertion_is_quantifiable: Default::default(),
             _num_capturing_parens: Default::default(),
-            _group_names: Default::default(),
+            _group_specifiers,
             _backreference_names: Default::default(),
+            _disjunction_depth: Default::default(),
+            _diagnostics: Default::default(),
             _src_ctx: Default::default(),
         }
     }
@@ -376,6 +498,7 @@ impl<'a> RegExpValidator<'a> {
             end,
             kind: RegExpValidatorSourceContextKind::Literal,
         });
+        self._diagnostics.clear();
         self._unicode_sets_mode = false;
         self._unicode_mode = false;
         self._n_flag = false;
@@ -397,10 +520,10 @@ impl<'a> RegExpValidator<'a> {
                 }),
             )?;
         } else if start >= end {
-            self.raise("Empty", None)?;
+            self.raise(RegExpSyntaxErrorKind::Empty, None)?;
         } else {
             let c = char::try_from(self.current_code_point().unwrap()).unwrap();
-            self.raise(&format!("Unexpected character '{c}'"), None)?;
+            self.raise(RegExpSyntaxErrorKind::UnexpectedCharacter(c), None)?;
         }
         self.on_literal_leave(start, end);
         Ok(())
@@ -420,6 +543,7 @@ impl<'a> RegExpValidator<'a> {
             end,
             kind: RegExpValidatorSourceContextKind::Flags,
         });
+        self._diagnostics.clear();
         self.validate_flags_internal(source, start, end)
     }
 
@@ -438,9 +562,16 @@ impl<'a> RegExpValidator<'a> {
             end,
             kind: RegExpValidatorSourceContextKind::Pattern,
         });
+        self._diagnostics.clear();
         self.validate_pattern_internal(source, start, end, flags)
     }
 
+    /// Returns the errors that were recovered from during the last
+    /// validation, which only happens when [`Options::tolerant`] is enabled
+    pub fn take_diagnostics(&mut self) -> Vec<RegExpSyntaxError> {
+        std::mem::take(&mut self._diagnostics)
+    }
+
     fn validate_pattern_internal(
         &mut self,
         source: &[u16],
@@ -454,14 +585,16 @@ impl<'a> RegExpValidator<'a> {
         self._n_flag = mode.n_flag;
         self._unicode_sets_mode = mode.unicode_sets_mode;
         self.reset(source, start, end);
+        let num_diagnostics = self._diagnostics.len();
         self.consume_pattern()?;
 
         if !self._n_flag
             && self.ecma_version() >= EcmaVersion::_2018
-            && !self._group_names.is_empty()
+            && !self._group_specifiers.is_empty()
         {
             self._n_flag = true;
             self.rewind(start);
+            self._diagnostics.truncate(num_diagnostics);
             self.consume_pattern()?;
         }
 
@@ -483,17 +616,26 @@ impl<'a> RegExpValidator<'a> {
         let mut dot_all = false;
         let mut has_indices = false;
         let mut unicode_sets = false;
-        for &flag in &source[start..end] {
+        for (flag_index, &flag) in source[start..end].iter().enumerate() {
             let flag: CodePoint = flag.into();
+            let flag_index = start + flag_index;
 
             if existing_flags.contains(&flag) {
                 // TODO: technically probably should handle the failure of char::try_from()
                 // here (and below, and in validate_literal()) which I believe would fail
                 // if this is part of a surrogate pair?
-                self.raise(
-                    &format!("Duplicated flag '{}'", char::try_from(flag).unwrap()),
-                    Some(RaiseContextBuilder::default().index(start).build().unwrap()),
+                self.raise_recoverable(
+                    RegExpSyntaxErrorKind::DuplicatedFlag(char::try_from(flag).unwrap()),
+                    Some(
+                        RaiseContextBuilder::default()
+                            .index(start)
+                            .start(flag_index)
+                            .end(flag_index + 1)
+                            .build()
+                            .unwrap(),
+                    ),
                 )?;
+                continue;
             }
             existing_flags.insert(flag);
 
@@ -514,9 +656,16 @@ impl<'a> RegExpValidator<'a> {
             } else if flag == LATIN_SMALL_LETTER_V && self.ecma_version() >= EcmaVersion::_2024 {
                 unicode_sets = true;
             } else {
-                self.raise(
-                    &format!("Invalid flag '{}'", char::try_from(flag).unwrap()),
-                    Some(RaiseContextBuilder::default().index(start).build().unwrap()),
+                self.raise_recoverable(
+                    RegExpSyntaxErrorKind::InvalidFlag(char::try_from(flag).unwrap()),
+                    Some(
+                        RaiseContextBuilder::default()
+                            .index(start)
+                            .start(flag_index)
+                            .end(flag_index + 1)
+                            .build()
+                            .unwrap(),
+                    ),
                 )?;
             }
         }
@@ -545,6 +694,10 @@ impl<'a> RegExpValidator<'a> {
         self._options.ecma_version().unwrap_or(LATEST_ECMA_VERSION)
     }
 
+    fn tolerant(&self) -> bool {
+        self._options.tolerant().unwrap_or_default()
+    }
+
     fn on_literal_enter(&mut self, start: usize) {
         self._options.on_literal_enter(start);
     }
@@ -589,6 +742,22 @@ impl<'a> RegExpValidator<'a> {
         self._options.on_group_leave(start, end);
     }
 
+    fn on_modifiers_enter(&mut self, start: usize) {
+        self._options.on_modifiers_enter(start);
+    }
+
+    fn on_modifiers_leave(&mut self, start: usize, end: usize) {
+        self._options.on_modifiers_leave(start, end);
+    }
+
+    fn on_add_modifiers(&mut self, start: usize, end: usize, flags: RegExpModifierFlags) {
+        self._options.on_add_modifiers(start, end, flags);
+    }
+
+    fn on_remove_modifiers(&mut self, start: usize, end: usize, flags: RegExpModifierFlags) {
+        self._options.on_remove_modifiers(start, end, flags);
+    }
+
     fn on_capturing_group_enter(&mut self, start: usize, name: Option<&Wtf16>) {
         self._options.on_capturing_group_enter(start, name);
     }
@@ -720,8 +889,12 @@ impl<'a> RegExpValidator<'a> {
         self._options.on_string_alternative_leave(start, end, index);
     }
 
+    fn on_error(&mut self, start: usize, end: usize, error: &RegExpSyntaxError) {
+        self._options.on_error(start, end, error);
+    }
+
     fn _parse_flags_option_to_mode(
-        &self,
+        &mut self,
         flags: Option<ValidatePatternFlags>,
         source_end: usize,
     ) -> Result<Mode, RegExpSyntaxError> {
@@ -735,10 +908,12 @@ impl<'a> RegExpValidator<'a> {
         }
 
         if unicode && unicode_sets {
-            self.raise(
-                "Invalid regular expression flags",
+            self.raise_recoverable(
+                RegExpSyntaxErrorKind::InvalidRegularExpressionFlags,
                 Some(RaiseContext {
                     index: Some(source_end + 1),
+                    start: self._src_ctx.as_ref().map(|src_ctx| src_ctx.start),
+                    end: Some(source_end),
                     unicode: Some(unicode),
                     unicode_sets: Some(unicode_sets),
                 }),
@@ -770,14 +945,6 @@ impl<'a> RegExpValidator<'a> {
         self._reader.next_code_point()
     }
 
-    fn next_code_point2(&self) -> Option<CodePoint> {
-        self._reader.next_code_point2()
-    }
-
-    fn next_code_point3(&self) -> Option<CodePoint> {
-        self._reader.next_code_point3()
-    }
-
     fn reset(&mut self, source: &[u16], start: usize, end: usize) {
         self._reader.reset(source, start, end, self._unicode_mode);
     }
@@ -802,9 +969,21 @@ impl<'a> RegExpValidator<'a> {
         self._reader.eat3(cp1, cp2, cp3)
     }
 
-    fn raise(&self, message: &str, context: Option<RaiseContext>) -> Result<(), RegExpSyntaxError> {
+    fn raise(
+        &self,
+        kind: RegExpSyntaxErrorKind,
+        context: Option<RaiseContext>,
+    ) -> Result<(), RegExpSyntaxError> {
+        let index = context
+            .and_then(|context| context.index)
+            .unwrap_or(self.index());
+        let start = context.and_then(|context| context.start).unwrap_or(index);
+        let end = context
+            .and_then(|context| context.end)
+            .unwrap_or_else(|| start.max(index).max(self.index()));
+        let src_ctx = self._src_ctx.as_ref().unwrap();
         Err(new_reg_exp_syntax_error(
-            self._src_ctx.as_ref().unwrap(),
+            src_ctx,
             regexp_syntax_error::Flags {
                 unicode: context
                     .and_then(|context| context.unicode)
@@ -813,13 +992,26 @@ impl<'a> RegExpValidator<'a> {
                     .and_then(|context| context.unicode_sets)
                     .unwrap_or(self._unicode_sets_mode),
             },
-            context
-                .and_then(|context| context.index)
-                .unwrap_or(self.index()),
-            message,
+            index,
+            non_empty_span(src_ctx, start..end),
+            kind,
         ))
     }
 
+    fn raise_recoverable(
+        &mut self,
+        kind: RegExpSyntaxErrorKind,
+        context: Option<RaiseContext>,
+    ) -> Result<(), RegExpSyntaxError> {
+        match self.raise(kind, context) {
+            Err(error) if self.tolerant() => {
+                self._diagnostics.push(error);
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
     fn eat_reg_exp_body(&mut self) -> Result<bool, RegExpSyntaxError> {
         let start = self.index();
         let mut in_class = false;
@@ -831,12 +1023,14 @@ impl<'a> RegExpValidator<'a> {
                 None => true,
                 Some(cp) => is_line_terminator(cp),
             } {
-                let kind = if in_class {
-                    "character class"
-                } else {
-                    "regular expression"
-                };
-                self.raise(&format!("Unterminated {kind}"), None)?;
+                self.raise(
+                    if in_class {
+                        RegExpSyntaxErrorKind::UnterminatedCharacterClass
+                    } else {
+                        RegExpSyntaxErrorKind::UnterminatedRegularExpression
+                    },
+                    None,
+                )?;
             }
             let cp = cp.unwrap();
             if escaped {
@@ -859,70 +1053,57 @@ impl<'a> RegExpValidator<'a> {
     fn consume_pattern(&mut self) -> Result<(), RegExpSyntaxError> {
         let start = self.index();
         self._num_capturing_parens = self.count_capturing_parens();
-        self._group_names.clear();
+        self._group_specifiers.clear();
         self._backreference_names.clear();
 
         self.on_pattern_enter(start);
         self.consume_disjunction()?;
+        self.raise_unexpected_character()?;
+        for (name, span) in self._backreference_names.clone() {
+            if !self._group_specifiers.has_in_pattern(&name) {
+                self.raise_recoverable(
+                    RegExpSyntaxErrorKind::InvalidNamedCaptureReferenced { name },
+                    Some(
+                        RaiseContextBuilder::default()
+                            .start(span.start)
+                            .end(span.end)
+                            .build()
+                            .unwrap(),
+                    ),
+                )?;
+            }
+        }
+        self.on_pattern_leave(start, self.index());
+        Ok(())
+    }
 
-        let cp = self.current_code_point();
-        if let Some(cp) = cp {
+    fn raise_unexpected_character(&self) -> Result<(), RegExpSyntaxError> {
+        if let Some(cp) = self.current_code_point() {
             if cp == RIGHT_PARENTHESIS {
-                self.raise("Unmatched ')'", None)?;
+                self.raise(RegExpSyntaxErrorKind::UnmatchedRightParenthesis, None)?;
             }
             if cp == REVERSE_SOLIDUS {
-                self.raise("\\ at end of pattern", None)?;
+                self.raise(RegExpSyntaxErrorKind::BackslashAtEndOfPattern, None)?;
             }
             if cp == RIGHT_SQUARE_BRACKET || cp == RIGHT_CURLY_BRACKET {
-                self.raise("Lone quantifier brackets", None)?;
+                self.raise(RegExpSyntaxErrorKind::LoneQuantifierBrackets, None)?;
             }
             let c = char::try_from(cp).unwrap();
-            self.raise(&format!("Unexpected character '{c}'"), None)?;
-        }
-        for name in &self._backreference_names {
-            if !self._group_names.contains(name) {
-                self.raise("Invalid named capture referenced", None)?;
-            }
+            self.raise(RegExpSyntaxErrorKind::UnexpectedCharacter(c), None)?;
         }
-        self.on_pattern_leave(start, self.index());
         Ok(())
     }
 
     fn count_capturing_parens(&mut self) -> usize {
-        let start = self.index();
-        let mut in_class = false;
-        let mut escaped = false;
-        let mut count = 0;
-
-        while let Some(cp) = self.current_code_point() {
-            if escaped {
-                escaped = false;
-            } else if cp == REVERSE_SOLIDUS {
-                escaped = true;
-            } else if cp == LEFT_SQUARE_BRACKET {
-                in_class = true;
-            } else if cp == RIGHT_SQUARE_BRACKET {
-                in_class = false;
-            } else if cp == LEFT_PARENTHESIS
-                && !in_class
-                && (self.next_code_point() != Some(QUESTION_MARK)
-                    || (self.next_code_point2() == Some(LESS_THAN_SIGN)
-                        && self.next_code_point3() != Some(EQUALS_SIGN)
-                        && self.next_code_point3() != Some(EXCLAMATION_MARK)))
-            {
-                count += 1;
-            }
-            self.advance();
-        }
-
-        self.rewind(start);
-        count
+        capturing_paren_starts(&mut self._reader).len()
     }
 
     fn consume_disjunction(&mut self) -> Result<(), RegExpSyntaxError> {
         let start = self.index();
         let mut i = 0;
 
+        self._group_specifiers.enter_disjunction();
+        self._disjunction_depth += 1;
         self.on_disjunction_enter(start);
         while {
             self.consume_alternative(i)?;
@@ -930,25 +1111,121 @@ impl<'a> RegExpValidator<'a> {
             self.eat(VERTICAL_LINE)
         } {}
 
+        self.raise_lone_quantifier()?;
+        self.on_disjunction_leave(start, self.index());
+        self._disjunction_depth -= 1;
+        self._group_specifiers.leave_disjunction();
+        Ok(())
+    }
+
+    fn raise_lone_quantifier(&mut self) -> Result<(), RegExpSyntaxError> {
+        let quantifier_start = self.index();
         if self.consume_quantifier(Some(true))? {
-            self.raise("Nothing to repeat", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::NothingToRepeat,
+                Some(
+                    RaiseContextBuilder::default()
+                        .start(quantifier_start)
+                        .build()
+                        .unwrap(),
+                ),
+            )?;
         }
         if self.eat(LEFT_CURLY_BRACKET) {
-            self.raise("Lone quantifier brackets", None)?;
+            self.raise(RegExpSyntaxErrorKind::LoneQuantifierBrackets, None)?;
         }
-        self.on_disjunction_leave(start, self.index());
         Ok(())
     }
 
     fn consume_alternative(&mut self, i: usize) -> Result<(), RegExpSyntaxError> {
         let start = self.index();
 
+        self._group_specifiers.enter_alternative(i);
         self.on_alternative_enter(start, i);
-        while self.current_code_point().is_some() && self.consume_term()? {}
+        while self.current_code_point().is_some() {
+            let term_start = self.index();
+            let branch = self._group_specifiers.current_branch();
+            let disjunction_depth = self._disjunction_depth;
+            let error = match self.consume_term() {
+                Ok(true) => continue,
+                Ok(false) => {
+                    let cp = self.current_code_point();
+                    if !self.tolerant()
+                        || cp == Some(VERTICAL_LINE)
+                        || cp == Some(RIGHT_PARENTHESIS) && disjunction_depth > 1
+                    {
+                        break;
+                    }
+                    // Report whatever consume_disjunction() or consume_pattern()
+                    // would have complained about once this alternative ended
+                    self.raise_lone_quantifier()
+                        .and_then(|()| self.raise_unexpected_character())
+                        .unwrap_err()
+                }
+                Err(error) if self.tolerant() => error,
+                Err(error) => return Err(error),
+            };
+            self._group_specifiers.restore_branch(branch);
+            self._disjunction_depth = disjunction_depth;
+            self.recover(term_start, error);
+        }
         self.on_alternative_leave(start, self.index(), i);
         Ok(())
     }
 
+    fn recover(&mut self, start: usize, error: RegExpSyntaxError) {
+        let end = self.find_sync_point(start, self.index().max(error.span.end));
+        self.rewind(end);
+        self.on_error(start, end, &error);
+        self._diagnostics.push(error);
+    }
+
+    /// Finds where to resume after an error in the term starting at `start`:
+    /// the first `|` or unmatched `)` at or after `error_end`, or the end of
+    /// the character class containing the error
+    fn find_sync_point(&mut self, start: usize, error_end: usize) -> usize {
+        self.rewind(start);
+        let mut depth = 0;
+        let mut class_depth = 0;
+        let mut escaped = false;
+
+        while let Some(cp) = self.current_code_point() {
+            let index = self.index();
+            if escaped {
+                escaped = false;
+            } else if cp == REVERSE_SOLIDUS {
+                escaped = true;
+            } else if class_depth > 0 {
+                if cp == RIGHT_SQUARE_BRACKET {
+                    class_depth -= 1;
+                    if class_depth == 0 && depth == 0 && index >= error_end {
+                        self.advance();
+                        return self.index();
+                    }
+                } else if cp == LEFT_SQUARE_BRACKET && self._unicode_sets_mode {
+                    class_depth += 1;
+                }
+            } else if cp == LEFT_SQUARE_BRACKET {
+                class_depth = 1;
+            } else if cp == LEFT_PARENTHESIS {
+                depth += 1;
+            } else if cp == RIGHT_PARENTHESIS || cp == VERTICAL_LINE {
+                if depth == 0 && index >= error_end {
+                    if index == start {
+                        self.advance();
+                        return self.index();
+                    }
+                    return index;
+                }
+                if cp == RIGHT_PARENTHESIS && depth > 0 {
+                    depth -= 1;
+                }
+            }
+            self.advance();
+        }
+        self.index()
+    }
+
     fn consume_term(&mut self) -> Result<bool, RegExpSyntaxError> {
         if self._unicode_mode || self.strict() {
             return Ok(self.consume_assertion()?
@@ -1000,7 +1277,10 @@ impl<'a> RegExpValidator<'a> {
                 self.on_lookaround_assertion_enter(start, kind, negate);
                 self.consume_disjunction()?;
                 if !self.eat(RIGHT_PARENTHESIS) {
-                    self.raise("Unterminated group", None)?;
+                    self.raise(
+                        RegExpSyntaxErrorKind::UnterminatedGroup,
+                        Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                    )?;
                 }
                 self._last_assertion_is_quantifiable = !lookbehind && !self.strict();
                 self.on_lookaround_assertion_leave(start, self.index(), kind, negate);
@@ -1057,14 +1337,20 @@ impl<'a> RegExpValidator<'a> {
                 }
                 if self.eat(RIGHT_CURLY_BRACKET) {
                     if !no_error && max < min {
-                        self.raise("numbers out of order in {} quantifier", None)?;
+                        self.raise(
+                            RegExpSyntaxErrorKind::NumbersOutOfOrderInQuantifier,
+                            Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                        )?;
                     }
                     self._last_range = min..max;
                     return Ok(true);
                 }
             }
             if !no_error && (self._unicode_mode || self.strict()) {
-                self.raise("Incomplete quantifier", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::IncompleteQuantifier,
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
             }
             self.rewind(start);
         }
@@ -1101,11 +1387,39 @@ impl<'a> RegExpValidator<'a> {
 
     fn consume_uncapturing_group(&mut self) -> Result<bool, RegExpSyntaxError> {
         let start = self.index();
-        if self.eat3(LEFT_PARENTHESIS, QUESTION_MARK, COLON) {
+        if self.eat2(LEFT_PARENTHESIS, QUESTION_MARK) {
+            let modifiers_start = self.index();
+            if self.ecma_version() >= EcmaVersion::_2025 {
+                while self
+                    .current_code_point()
+                    .matches(|cp| is_latin_letter(cp) || cp == HYPHEN_MINUS)
+                {
+                    self.advance();
+                }
+            }
+            if self.current_code_point() != Some(COLON) {
+                self.rewind(start);
+                return Ok(false);
+            }
+            let modifiers_end = self.index();
+            self.rewind(modifiers_start);
+
             self.on_group_enter(start);
+            if modifiers_end != modifiers_start {
+                self.consume_modifiers()?;
+            }
+            if !self.eat(COLON) {
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidGroup,
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
+            }
             self.consume_disjunction()?;
             if !self.eat(RIGHT_PARENTHESIS) {
-                self.raise("Unterminated group", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::UnterminatedGroup,
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
             }
             self.on_group_leave(start, self.index());
             return Ok(true);
@@ -1113,6 +1427,96 @@ impl<'a> RegExpValidator<'a> {
         Ok(false)
     }
 
+    fn consume_modifiers(&mut self) -> Result<(), RegExpSyntaxError> {
+        let start = self.index();
+        let add_modifiers = self.eat_modifiers()?;
+        let add_modifiers_end = self.index();
+        let has_hyphen = self.eat(HYPHEN_MINUS);
+
+        self.on_modifiers_enter(start);
+        self.on_add_modifiers(start, add_modifiers_end, add_modifiers);
+
+        if has_hyphen {
+            let remove_modifiers_start = self.index();
+            let remove_modifiers = self.eat_modifiers()?;
+            if remove_modifiers_start == self.index() && add_modifiers_end == start {
+                self.raise(RegExpSyntaxErrorKind::InvalidEmptyFlags, None)?;
+            }
+            for (flag, added, removed) in [
+                (
+                    LATIN_SMALL_LETTER_I,
+                    add_modifiers.ignore_case,
+                    remove_modifiers.ignore_case,
+                ),
+                (
+                    LATIN_SMALL_LETTER_M,
+                    add_modifiers.multiline,
+                    remove_modifiers.multiline,
+                ),
+                (
+                    LATIN_SMALL_LETTER_S,
+                    add_modifiers.dot_all,
+                    remove_modifiers.dot_all,
+                ),
+            ] {
+                if added && removed {
+                    self.raise(
+                        RegExpSyntaxErrorKind::DuplicatedFlag(char::try_from(flag).unwrap()),
+                        None,
+                    )?;
+                }
+            }
+            self.on_remove_modifiers(remove_modifiers_start, self.index(), remove_modifiers);
+        }
+
+        self.on_modifiers_leave(start, self.index());
+        Ok(())
+    }
+
+    fn eat_modifiers(&mut self) -> Result<RegExpModifierFlags, RegExpSyntaxError> {
+        let start = self.index();
+        let mut flags = RegExpModifierFlags::default();
+        while let Some(cp) = self.current_code_point().filter(|&cp| is_latin_letter(cp)) {
+            if !matches!(
+                cp,
+                LATIN_SMALL_LETTER_I | LATIN_SMALL_LETTER_M | LATIN_SMALL_LETTER_S
+            ) {
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidFlag(char::try_from(cp).unwrap()),
+                    Some(
+                        RaiseContextBuilder::default()
+                            .index(start)
+                            .start(self.index())
+                            .end(self.index() + 1)
+                            .build()
+                            .unwrap(),
+                    ),
+                )?;
+            }
+            let flag = match cp {
+                LATIN_SMALL_LETTER_I => &mut flags.ignore_case,
+                LATIN_SMALL_LETTER_M => &mut flags.multiline,
+                _ => &mut flags.dot_all,
+            };
+            if *flag {
+                self.raise(
+                    RegExpSyntaxErrorKind::DuplicatedFlag(char::try_from(cp).unwrap()),
+                    Some(
+                        RaiseContextBuilder::default()
+                            .index(start)
+                            .start(self.index())
+                            .end(self.index() + 1)
+                            .build()
+                            .unwrap(),
+                    ),
+                )?;
+            }
+            *flag = true;
+            self.advance();
+        }
+        Ok(flags)
+    }
+
     fn consume_capturing_group(&mut self) -> Result<bool, RegExpSyntaxError> {
         let start = self.index();
         if self.eat(LEFT_PARENTHESIS) {
@@ -1122,13 +1526,19 @@ impl<'a> RegExpValidator<'a> {
                     name = Some(self._last_str_value.clone());
                 }
             } else if self.current_code_point() == Some(QUESTION_MARK) {
-                self.raise("Invalid group", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidGroup,
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
             }
 
             self.on_capturing_group_enter(start, name.as_ref());
             self.consume_disjunction()?;
             if !self.eat(RIGHT_PARENTHESIS) {
-                self.raise("Unterminated group", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::UnterminatedGroup,
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
             }
             self.on_capturing_group_leave(start, self.index(), name.as_ref());
 
@@ -1162,8 +1572,12 @@ impl<'a> RegExpValidator<'a> {
     }
 
     fn consume_invalid_braced_quantifier(&mut self) -> Result<bool, RegExpSyntaxError> {
+        let start = self.index();
         if self.eat_braced_quantifier(true)? {
-            self.raise("Nothing to repeat", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::NothingToRepeat,
+                Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+            )?;
         }
         Ok(false)
     }
@@ -1205,15 +1619,32 @@ impl<'a> RegExpValidator<'a> {
     }
 
     fn consume_group_specifier(&mut self) -> Result<bool, RegExpSyntaxError> {
+        // Called after the opening parenthesis
+        let group_start = self.index() - 1;
         if self.eat(QUESTION_MARK) {
+            let start = self.index();
             if self.eat_group_name()? {
-                if !self._group_names.contains(&self._last_str_value) {
-                    self._group_names.insert(self._last_str_value.clone());
+                if !self._group_specifiers.has_in_scope(&self._last_str_value) {
+                    self._group_specifiers
+                        .add_to_scope(self._last_str_value.clone());
                     return Ok(true);
                 }
-                self.raise("Duplicate capture group name", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::DuplicateCaptureGroupName {
+                        name: self._last_str_value.clone(),
+                    },
+                    Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                )?;
             }
-            self.raise("Invalid group", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidGroup,
+                Some(
+                    RaiseContextBuilder::default()
+                        .start(group_start)
+                        .build()
+                        .unwrap(),
+                ),
+            )?;
         }
         Ok(false)
     }
@@ -1227,7 +1658,7 @@ impl<'a> RegExpValidator<'a> {
             return Ok(true);
         }
         if self.strict() || self._unicode_mode {
-            self.raise("Invalid escape", None)?;
+            self.raise(RegExpSyntaxErrorKind::InvalidEscape, None)?;
         }
         Ok(false)
     }
@@ -1241,7 +1672,7 @@ impl<'a> RegExpValidator<'a> {
                 return Ok(true);
             }
             if self.strict() || self._unicode_mode {
-                self.raise("Invalid escape", None)?;
+                self.raise(RegExpSyntaxErrorKind::InvalidEscape, None)?;
             }
             self.rewind(start);
         }
@@ -1303,14 +1734,29 @@ impl<'a> RegExpValidator<'a> {
                 Default::default();
             if self.eat(LEFT_CURLY_BRACKET)
                 && {
-                    result = self.eat_unicode_property_value_expression()?;
+                    result =
+                        self.eat_unicode_property_value_expression()
+                            .map_err(|mut error| {
+                                error.span.start = start - 1;
+                                error
+                            })?;
                     result.is_some()
                 }
                 && self.eat(RIGHT_CURLY_BRACKET)
             {
                 let result = result.unwrap();
                 if negate && result.strings == Some(true) {
-                    self.raise("Invalid property name", None)?;
+                    self.raise(
+                        RegExpSyntaxErrorKind::NegatedPropertyOfStrings {
+                            name: result.key.clone(),
+                        },
+                        Some(
+                            RaiseContextBuilder::default()
+                                .start(start - 1)
+                                .build()
+                                .unwrap(),
+                        ),
+                    )?;
                 }
 
                 self.on_unicode_property_character_set(
@@ -1327,7 +1773,18 @@ impl<'a> RegExpValidator<'a> {
                     may_contain_strings: result.strings,
                 }));
             }
-            self.raise("Invalid property name", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidPropertyName {
+                    name: result.as_ref().map(|result| result.key.clone()),
+                    value: result.and_then(|result| result.value),
+                },
+                Some(
+                    RaiseContextBuilder::default()
+                        .start(start - 1)
+                        .build()
+                        .unwrap(),
+                ),
+            )?;
         }
 
         Ok(None)
@@ -1354,11 +1811,22 @@ impl<'a> RegExpValidator<'a> {
         if self.eat(LATIN_SMALL_LETTER_K) {
             if self.eat_group_name()? {
                 let group_name = &self._last_str_value;
-                self._backreference_names.insert(group_name.clone());
+                let span = start - 1..self.index();
+                self._backreference_names
+                    .entry(group_name.clone())
+                    .or_insert(span);
                 self.on_backreference(start - 1, self.index(), &group_name.clone().into());
                 return Ok(true);
             }
-            self.raise("Invalid named reference", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidNamedReference,
+                Some(
+                    RaiseContextBuilder::default()
+                        .start(start - 1)
+                        .build()
+                        .unwrap(),
+                ),
+            )?;
         }
         Ok(false)
     }
@@ -1373,12 +1841,18 @@ impl<'a> RegExpValidator<'a> {
             let result = self.consume_class_contents()?;
             if !self.eat(RIGHT_SQUARE_BRACKET) {
                 if self.current_code_point().is_none() {
-                    self.raise("Unterminated character class", None)?;
+                    self.raise(RegExpSyntaxErrorKind::UnterminatedCharacterClass, None)?;
                 }
-                self.raise("Invalid character in character class", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidCharacterInCharacterClass,
+                    None,
+                )?;
             }
             if negate && result.may_contain_strings == Some(true) {
-                self.raise("Negated character class may contain strings", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::NegatedCharacterClassMayContainStrings,
+                    None,
+                )?;
             }
 
             self.on_character_class_leave(start, self.index(), negate);
@@ -1417,14 +1891,22 @@ impl<'a> RegExpValidator<'a> {
 
             if min.is_none() || max.is_none() {
                 if strict {
-                    self.raise("Invalid character class", None)?;
+                    self.raise(RegExpSyntaxErrorKind::InvalidCharacterClass, None)?;
                 }
                 continue;
             }
             let min = min.unwrap();
             let max = max.unwrap();
             if min > max {
-                self.raise("Range out of order in character class", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::RangeOutOfOrderInCharacterClass,
+                    Some(
+                        RaiseContextBuilder::default()
+                            .start(range_start)
+                            .build()
+                            .unwrap(),
+                    ),
+                )?;
             }
 
             self.on_character_class_range(range_start, self.index(), min, max);
@@ -1454,7 +1936,7 @@ impl<'a> RegExpValidator<'a> {
                 return Ok(true);
             }
             if self.strict() || self._unicode_mode {
-                self.raise("Invalid escape", None)?;
+                self.raise(RegExpSyntaxErrorKind::InvalidEscape, None)?;
             }
             self.rewind(start);
         }
@@ -1517,13 +1999,19 @@ impl<'a> RegExpValidator<'a> {
             let cp = self.current_code_point();
             if cp == Some(REVERSE_SOLIDUS) {
                 self.advance();
-                self.raise("Invalid escape", None)?;
+                self.raise(RegExpSyntaxErrorKind::InvalidEscape, None)?;
             }
             if cp == self.next_code_point() && is_class_set_reserved_double_punctuator_character(cp)
             {
-                self.raise("Invalid set operation in character class", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidSetOperationInCharacterClass,
+                    None,
+                )?;
             }
-            self.raise("Invalid character in character class", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidCharacterInCharacterClass,
+                None,
+            )?;
         }
 
         if self.eat2(AMPERSAND, AMPERSAND) {
@@ -1544,7 +2032,10 @@ impl<'a> RegExpValidator<'a> {
                 });
             }
 
-            self.raise("Invalid character in character class", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidCharacterInCharacterClass,
+                None,
+            )?;
         }
         if self.eat2(HYPHEN_MINUS, HYPHEN_MINUS) {
             while self.consume_class_set_operand()?.is_some() {
@@ -1557,7 +2048,10 @@ impl<'a> RegExpValidator<'a> {
                     may_contain_strings,
                 });
             }
-            self.raise("Invalid character in character class", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidCharacterInCharacterClass,
+                None,
+            )?;
         }
         self.consume_class_union_right(UnicodeSetsConsumeResult {
             may_contain_strings,
@@ -1601,12 +2095,15 @@ impl<'a> RegExpValidator<'a> {
                 let max = self._last_int_value;
 
                 if min.is_none() || max.is_none() {
-                    self.raise("Invalid character class", None)?;
+                    self.raise(RegExpSyntaxErrorKind::InvalidCharacterClass, None)?;
                 }
                 let min = min.unwrap();
                 let max = max.unwrap();
                 if min > max {
-                    self.raise("Range out of order in character class", None)?;
+                    self.raise(
+                        RegExpSyntaxErrorKind::RangeOutOfOrderInCharacterClass,
+                        Some(RaiseContextBuilder::default().start(start).build().unwrap()),
+                    )?;
                 }
                 self.on_character_class_range(start, self.index(), min, max);
                 return Ok(true);
@@ -1643,10 +2140,13 @@ impl<'a> RegExpValidator<'a> {
             self.on_character_class_enter(start, negate, true);
             let result = self.consume_class_contents()?;
             if !self.eat(RIGHT_SQUARE_BRACKET) {
-                self.raise("Unterminated character class", None)?;
+                self.raise(RegExpSyntaxErrorKind::UnterminatedCharacterClass, None)?;
             }
             if negate && result.may_contain_strings == Some(true) {
-                self.raise("Negated character class may contain strings", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::NegatedCharacterClassMayContainStrings,
+                    None,
+                )?;
             }
             self.on_character_class_leave(start, self.index(), negate);
 
@@ -1686,7 +2186,10 @@ impl<'a> RegExpValidator<'a> {
                     may_contain_strings: Some(may_contain_strings),
                 }));
             }
-            self.raise("Unterminated class string disjunction", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::UnterminatedClassStringDisjunction,
+                None,
+            )?;
         }
         Ok(None)
     }
@@ -1745,7 +2248,7 @@ impl<'a> RegExpValidator<'a> {
             if self.eat_reg_exp_identifier_name()? && self.eat(GREATER_THAN_SIGN) {
                 return Ok(true);
             }
-            self.raise("Invalid capture group name", None)?;
+            self.raise(RegExpSyntaxErrorKind::InvalidCaptureGroupName, None)?;
         }
         Ok(false)
     }
@@ -1901,7 +2404,7 @@ impl<'a> RegExpValidator<'a> {
                 return Ok(true);
             }
             if self.strict() || u_flag {
-                self.raise("Invalid unicode escape", None)?;
+                self.raise(RegExpSyntaxErrorKind::InvalidUnicodeEscape, None)?;
             }
             self.rewind(start);
         }
@@ -2008,7 +2511,19 @@ impl<'a> RegExpValidator<'a> {
                         strings: None,
                     }));
                 }
-                self.raise("Invalid property name", None)?;
+                self.raise(
+                    RegExpSyntaxErrorKind::InvalidPropertyName {
+                        name: Some(key),
+                        value: Some(value),
+                    },
+                    Some(
+                        RaiseContextBuilder::default()
+                            .start(start)
+                            .end(self.unicode_property_value_expression_end())
+                            .build()
+                            .unwrap(),
+                    ),
+                )?;
             }
         }
         self.rewind(start);
@@ -2045,11 +2560,31 @@ impl<'a> RegExpValidator<'a> {
                     strings: Some(true),
                 }));
             }
-            self.raise("Invalid property name", None)?;
+            self.raise(
+                RegExpSyntaxErrorKind::InvalidPropertyName {
+                    name: Some(name_or_value),
+                    value: None,
+                },
+                Some(
+                    RaiseContextBuilder::default()
+                        .start(start)
+                        .end(self.unicode_property_value_expression_end())
+                        .build()
+                        .unwrap(),
+                ),
+            )?;
         }
         Ok(None)
     }
 
+    fn unicode_property_value_expression_end(&self) -> usize {
+        if self.current_code_point() == Some(RIGHT_CURLY_BRACKET) {
+            self.index() + 1
+        } else {
+            self.index()
+        }
+    }
+
     fn eat_unicode_property_name(&mut self) -> bool {
         self._last_str_value = Default::default();
         while let Some(current_code_point) = self
@@ -2087,7 +2622,7 @@ impl<'a> RegExpValidator<'a> {
                 return Ok(true);
             }
             if self._unicode_mode || self.strict() {
-                self.raise("Invalid escape", None)?;
+                self.raise(RegExpSyntaxErrorKind::InvalidEscape, None)?;
             }
             self.rewind(start);
         }
@@ -2219,9 +2754,11 @@ mod tests {
                 "end": 2,
                 "flags": { "unicode": true, "unicodeSets": true },
                 "error": {
+                    "kind": "invalidRegularExpressionFlags",
                     "message":
                         "Invalid regular expression: /ab/uv: Invalid regular expression flags",
                     "index": 3,
+                    "span": { "start": 0, "end": 2 },
                 },
             },
             {
@@ -2230,9 +2767,11 @@ mod tests {
                 "end": 2,
                 "flags": { "unicode": true, "unicodeSets": false },
                 "error": {
+                    "kind": "unterminatedCharacterClass",
                     "message":
                         "Invalid regular expression: /[A/u: Unterminated character class",
                     "index": 2,
+                    "span": { "start": 1, "end": 2 },
                 },
             },
             {
@@ -2241,9 +2780,11 @@ mod tests {
                 "end": 4,
                 "flags": { "unicode": false, "unicodeSets": true },
                 "error": {
+                    "kind": "unterminatedCharacterClass",
                     "message":
                         "Invalid regular expression: /[[A]/v: Unterminated character class",
                     "index": 4,
+                    "span": { "start": 3, "end": 4 },
                 },
             },
             {
@@ -2252,9 +2793,49 @@ mod tests {
                 "end": 6,
                 "flags": { "unicode": false, "unicodeSets": true },
                 "error": {
+                    "kind": "unterminatedCharacterClass",
                     "message":
                         "Invalid regular expression: /[[A]/v: Unterminated character class",
                     "index": 6,
+                    "span": { "start": 5, "end": 6 },
+                },
+            },
+            {
+                "source": "\\p{Foo}",
+                "start": 0,
+                "end": 7,
+                "flags": { "unicode": true, "unicodeSets": false },
+                "error": {
+                    "kind": { "invalidPropertyName": { "name": "Foo", "value": null } },
+                    "message": "Invalid regular expression: /\\p{Foo}/u: Invalid property name",
+                    "index": 6,
+                    "span": { "start": 0, "end": 7 },
+                },
+            },
+            {
+                "source": "\\p{Script=Foo}",
+                "start": 0,
+                "end": 14,
+                "flags": { "unicode": true, "unicodeSets": false },
+                "error": {
+                    "kind": { "invalidPropertyName": { "name": "Script", "value": "Foo" } },
+                    "message":
+                        "Invalid regular expression: /\\p{Script=Foo}/u: Invalid property name",
+                    "index": 13,
+                    "span": { "start": 0, "end": 14 },
+                },
+            },
+            {
+                "source": "(?<b>x)\\k<a>",
+                "start": 0,
+                "end": 12,
+                "flags": { "unicode": true, "unicodeSets": false },
+                "error": {
+                    "kind": { "invalidNamedCaptureReferenced": { "name": "a" } },
+                    "message":
+                        "Invalid regular expression: /(?<b>x)\\k<a>/u: Invalid named capture referenced",
+                    "index": 12,
+                    "span": { "start": 7, "end": 12 },
                 },
             },
         ]))
@@ -2283,8 +2864,10 @@ mod tests {
                 "start": 0,
                 "end": 2,
                 "error": {
+                    "kind": { "invalidFlag": "a" },
                     "message": "Invalid regular expression: Invalid flag 'a'",
                     "index": 0,
+                    "span": { "start": 0, "end": 1 },
                 },
             },
             {
@@ -2292,8 +2875,10 @@ mod tests {
                 "start": 0,
                 "end": 2,
                 "error": {
+                    "kind": { "duplicatedFlag": "d" },
                     "message": "Invalid regular expression: Duplicated flag 'd'",
                     "index": 0,
+                    "span": { "start": 1, "end": 2 },
                 },
             },
             {
@@ -2301,8 +2886,10 @@ mod tests {
                 "start": 3,
                 "end": 5,
                 "error": {
+                    "kind": { "duplicatedFlag": "d" },
                     "message": "Invalid regular expression: Duplicated flag 'd'",
                     "index": 3,
+                    "span": { "start": 4, "end": 5 },
                 },
             },
         ]))
@@ -2331,9 +2918,11 @@ mod tests {
                 "start": 1,
                 "end": 4,
                 "error": {
+                    "kind": "unterminatedCharacterClass",
                     "message":
                         "Invalid regular expression: /[/: Unterminated character class",
                     "index": 4,
+                    "span": { "start": 3, "end": 4 },
                 },
             },
         ]))
@@ -1,7 +1,9 @@
 use id_arena::Id;
-use squalid::EverythingExt;
 
-use crate::{ast::Node, AllArenas};
+use crate::{
+    ast::{ChildField, NodeKind},
+    AllArenas, Node,
+};
 
 pub struct RegExpVisitor<'a, THandlers: Handlers> {
     _arena: &'a AllArenas,
@@ -16,198 +18,238 @@ impl<'a, THandlers: Handlers> RegExpVisitor<'a, THandlers> {
         }
     }
 
-    pub fn visit(&self, node: Id<Node>) {
-        match &*self._arena.node(node) {
-            Node::Alternative(_) => self.visit_alternative(node),
-            Node::Assertion(_) => self.visit_assertion(node),
-            Node::Backreference(_) => self.visit_backreference(node),
-            Node::CapturingGroup(_) => self.visit_capturing_group(node),
-            Node::Character(_) => self.visit_character(node),
-            Node::CharacterClass(_) => self.visit_character_class(node),
-            Node::CharacterClassRange(_) => self.visit_character_class_range(node),
-            Node::CharacterSet(_) => self.visit_character_set(node),
-            Node::ClassIntersection(_) => self.visit_class_intersection(node),
-            Node::ClassStringDisjunction(_) => self.visit_class_string_disjunction(node),
-            Node::ClassSubtraction(_) => self.visit_class_subtraction(node),
-            Node::ExpressionCharacterClass(_) => self.visit_expression_character_class(node),
-            Node::Flags(_) => self.visit_flags(node),
-            Node::Group(_) => self.visit_group(node),
-            Node::Pattern(_) => self.visit_pattern(node),
-            Node::Quantifier(_) => self.visit_quantifier(node),
-            Node::RegExpLiteral(_) => self.visit_reg_exp_literal(node),
-            Node::StringAlternative(_) => self.visit_string_alternative(node),
-        }
+    pub fn visit(&self, node: impl Into<Id<Node>>) {
+        ControlledRegExpVisitor::new(self._arena, &IgnoreControl(self._handlers)).visit(node);
     }
+}
 
-    fn visit_alternative(&self, node: Id<Node>) {
-        self._handlers.on_alternative_enter(node);
-        // TODO: should probably not hold a RefCell borrow while
-        // invoking the handlers in case someone wants to mutably
-        // borrow from the arena in them?
-        self._arena
-            .node(node)
-            .as_alternative()
-            .elements
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_alternative_leave(node);
-    }
+/// What a [`ControlHandlers`] method wants the visitor to do next
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VisitControl {
+    #[default]
+    Continue,
+    /// Don't visit the node's children. The node's `_leave` handler is
+    /// still called. Only meaningful when returned from an `_enter` handler
+    SkipChildren,
+    /// Stop visiting altogether, without calling any more handlers (not
+    /// even the `_leave` handlers of the nodes currently being visited)
+    Break,
+}
 
-    fn visit_assertion(&self, node: Id<Node>) {
-        self._handlers.on_assertion_enter(node);
-        if let Some(alternatives) = self._arena.node(node).as_assertion().alternatives.as_ref() {
-            alternatives.iter().for_each(|&node| self.visit(node));
+/// Like [`RegExpVisitor`], but the handlers can skip subtrees or stop the
+/// traversal early
+pub struct ControlledRegExpVisitor<'a, THandlers: ControlHandlers> {
+    _arena: &'a AllArenas,
+    _handlers: &'a THandlers,
+}
+
+impl<'a, THandlers: ControlHandlers> ControlledRegExpVisitor<'a, THandlers> {
+    pub fn new(arena: &'a AllArenas, handlers: &'a THandlers) -> Self {
+        Self {
+            _arena: arena,
+            _handlers: handlers,
         }
-        self._handlers.on_assertion_leave(node);
     }
 
-    fn visit_backreference(&self, node: Id<Node>) {
-        self._handlers.on_backreference_enter(node);
-        self._handlers.on_backreference_leave(node);
+    /// Returns [`VisitControl::Break`] if a handler stopped the traversal,
+    /// otherwise [`VisitControl::Continue`]
+    pub fn visit(&self, node: impl Into<Id<Node>>) -> VisitControl {
+        ContextRegExpVisitor::new(self._arena, &IgnoreContext(self._handlers)).visit(node)
     }
+}
 
-    fn visit_capturing_group(&self, node: Id<Node>) {
-        self._handlers.on_capturing_group_enter(node);
-        self._arena
-            .node(node)
-            .as_capturing_group()
-            .alternatives
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_capturing_group_leave(node);
-    }
+/// Where the node being visited sits in the tree
+#[derive(Clone, Debug, Default)]
+pub struct VisitContext {
+    _ancestors: Vec<Id<Node>>,
+    _fields: Vec<ChildField>,
+}
 
-    fn visit_character(&self, node: Id<Node>) {
-        self._handlers.on_character_enter(node);
-        self._handlers.on_character_leave(node);
+impl VisitContext {
+    /// From the node the visit started at down to the node's parent
+    pub fn ancestors(&self) -> &[Id<Node>] {
+        &self._ancestors
     }
 
-    fn visit_character_class(&self, node: Id<Node>) {
-        self._handlers.on_character_class_enter(node);
-        self._arena
-            .node(node)
-            .as_character_class()
-            .elements
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_character_class_leave(node);
+    pub fn parent(&self) -> Option<Id<Node>> {
+        self._ancestors.last().copied()
     }
 
-    fn visit_character_class_range(&self, node: Id<Node>) {
-        self._handlers.on_character_class_range_enter(node);
-        self._arena
-            .node(node)
-            .as_character_class_range()
-            .thrush(|node| {
-                self.visit_character(node.min);
-                self.visit_character(node.max);
-            });
-        self._handlers.on_character_class_range_leave(node);
+    /// 0 for the node the visit started at
+    pub fn depth(&self) -> usize {
+        self._ancestors.len()
     }
 
-    fn visit_character_set(&self, node: Id<Node>) {
-        self._handlers.on_character_set_enter(node);
-        self._handlers.on_character_set_leave(node);
+    /// The field of its parent that the node hangs off, e.g.
+    /// [`ChildField::Min`] for the start of a `CharacterClassRange`
+    pub fn field(&self) -> Option<ChildField> {
+        self._fields.last().copied()
     }
 
-    fn visit_class_intersection(&self, node: Id<Node>) {
-        self._handlers.on_class_intersection_enter(node);
-        self._arena
-            .node(node)
-            .as_class_intersection()
-            .thrush(|node| {
-                self.visit(node.left);
-                self.visit(node.right);
-            });
-        self._handlers.on_class_intersection_leave(node);
+    /// The field each of [`ancestors`](Self::ancestors) (after the first)
+    /// and the node itself hang off
+    pub fn fields(&self) -> &[ChildField] {
+        &self._fields
     }
+}
 
-    fn visit_class_string_disjunction(&self, node: Id<Node>) {
-        self._handlers.on_class_string_disjunction_enter(node);
-        self._arena
-            .node(node)
-            .as_class_string_disjunction()
-            .alternatives
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_class_string_disjunction_leave(node);
-    }
+/// Like [`ControlledRegExpVisitor`], but the handlers are also told where
+/// each node is
+pub struct ContextRegExpVisitor<'a, THandlers: ContextHandlers> {
+    _arena: &'a AllArenas,
+    _handlers: &'a THandlers,
+}
 
-    fn visit_class_subtraction(&self, node: Id<Node>) {
-        self._handlers.on_class_subtraction_enter(node);
-        self._arena
-            .node(node)
-            .as_class_subtraction()
-            .thrush(|node| {
-                self.visit(node.left);
-                self.visit(node.right);
-            });
-        self._handlers.on_class_subtraction_leave(node);
+impl<'a, THandlers: ContextHandlers> ContextRegExpVisitor<'a, THandlers> {
+    pub fn new(arena: &'a AllArenas, handlers: &'a THandlers) -> Self {
+        Self {
+            _arena: arena,
+            _handlers: handlers,
+        }
     }
 
-    fn visit_expression_character_class(&self, node: Id<Node>) {
-        self._handlers.on_expression_character_class_enter(node);
-        self.visit(
-            self._arena
-                .node(node)
-                .as_expression_character_class()
-                .expression,
-        );
-        self._handlers.on_expression_character_class_leave(node);
+    /// Returns [`VisitControl::Break`] if a handler stopped the traversal,
+    /// otherwise [`VisitControl::Continue`]
+    pub fn visit(&self, node: impl Into<Id<Node>>) -> VisitControl {
+        self.visit_node(node.into(), &mut Default::default())
     }
 
-    fn visit_flags(&self, node: Id<Node>) {
-        self._handlers.on_flags_enter(node);
-        self._handlers.on_flags_leave(node);
+    fn visit_node(&self, node: Id<Node>, context: &mut VisitContext) -> VisitControl {
+        let kind = self._arena.node(node).kind();
+        match enter(self._handlers, kind, node, context) {
+            VisitControl::Break => return VisitControl::Break,
+            VisitControl::SkipChildren => (),
+            VisitControl::Continue => {
+                // Not borrowed from the arena while the handlers run, so
+                // they're free to look at (or modify) other nodes
+                let children = self._arena.node(node).children_with_fields();
+                context._ancestors.push(node);
+                for (field, child) in children {
+                    context._fields.push(field);
+                    let control = self.visit_node(child, context);
+                    context._fields.pop();
+                    if control == VisitControl::Break {
+                        return VisitControl::Break;
+                    }
+                }
+                context._ancestors.pop();
+            }
+        }
+        match leave(self._handlers, kind, node, context) {
+            VisitControl::Break => VisitControl::Break,
+            _ => VisitControl::Continue,
+        }
     }
+}
 
-    fn visit_group(&self, node: Id<Node>) {
-        self._handlers.on_group_enter(node);
-        self._arena
-            .node(node)
-            .as_group()
-            .alternatives
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_group_leave(node);
-    }
+/// Lets a plain [`Handlers`] be driven by [`ControlledRegExpVisitor`]
+struct IgnoreControl<'a, THandlers: Handlers>(&'a THandlers);
 
-    fn visit_pattern(&self, node: Id<Node>) {
-        self._handlers.on_pattern_enter(node);
-        self._arena
-            .node(node)
-            .as_pattern()
-            .alternatives
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_pattern_leave(node);
-    }
+/// Lets a [`ControlHandlers`] be driven by [`ContextRegExpVisitor`]
+struct IgnoreContext<'a, THandlers: ControlHandlers>(&'a THandlers);
 
-    fn visit_quantifier(&self, node: Id<Node>) {
-        self._handlers.on_quantifier_enter(node);
-        self.visit(self._arena.node(node).as_quantifier().element);
-        self._handlers.on_quantifier_leave(node);
-    }
+macro_rules! handler_traits {
+    ($($kind:ident => $enter:ident, $leave:ident;)+) => {
+        /// The same handlers as [`Handlers`], each of which says how the
+        /// traversal should proceed
+        #[allow(unused_variables)]
+        pub trait ControlHandlers {
+            $(
+                fn $enter(&self, node: Id<Node>) -> VisitControl {
+                    VisitControl::Continue
+                }
+                fn $leave(&self, node: Id<Node>) -> VisitControl {
+                    VisitControl::Continue
+                }
+            )+
+        }
 
-    fn visit_reg_exp_literal(&self, node: Id<Node>) {
-        self._handlers.on_reg_exp_literal_enter(node);
-        self._arena.node(node).as_reg_exp_literal().thrush(|node| {
-            self.visit_pattern(node.pattern);
-            self.visit_flags(node.flags);
-        });
-        self._handlers.on_reg_exp_literal_leave(node);
-    }
+        /// The same handlers as [`ControlHandlers`], which are also given
+        /// the node's [`VisitContext`]
+        #[allow(unused_variables)]
+        pub trait ContextHandlers {
+            $(
+                fn $enter(&self, node: Id<Node>, context: &VisitContext) -> VisitControl {
+                    VisitControl::Continue
+                }
+                fn $leave(&self, node: Id<Node>, context: &VisitContext) -> VisitControl {
+                    VisitControl::Continue
+                }
+            )+
+        }
 
-    fn visit_string_alternative(&self, node: Id<Node>) {
-        self._handlers.on_string_alternative_enter(node);
-        self._arena
-            .node(node)
-            .as_string_alternative()
-            .elements
-            .iter()
-            .for_each(|&node| self.visit(node));
-        self._handlers.on_string_alternative_leave(node);
-    }
+        impl<'a, THandlers: Handlers> ControlHandlers for IgnoreControl<'a, THandlers> {
+            $(
+                fn $enter(&self, node: Id<Node>) -> VisitControl {
+                    self.0.$enter(node);
+                    VisitControl::Continue
+                }
+                fn $leave(&self, node: Id<Node>) -> VisitControl {
+                    self.0.$leave(node);
+                    VisitControl::Continue
+                }
+            )+
+        }
+
+        impl<'a, THandlers: ControlHandlers> ContextHandlers for IgnoreContext<'a, THandlers> {
+            $(
+                fn $enter(&self, node: Id<Node>, _context: &VisitContext) -> VisitControl {
+                    self.0.$enter(node)
+                }
+                fn $leave(&self, node: Id<Node>, _context: &VisitContext) -> VisitControl {
+                    self.0.$leave(node)
+                }
+            )+
+        }
+
+        fn enter(
+            handlers: &impl ContextHandlers,
+            kind: NodeKind,
+            node: Id<Node>,
+            context: &VisitContext,
+        ) -> VisitControl {
+            match kind {
+                $(NodeKind::$kind => handlers.$enter(node, context),)+
+            }
+        }
+
+        fn leave(
+            handlers: &impl ContextHandlers,
+            kind: NodeKind,
+            node: Id<Node>,
+            context: &VisitContext,
+        ) -> VisitControl {
+            match kind {
+                $(NodeKind::$kind => handlers.$leave(node, context),)+
+            }
+        }
+    };
+}
+
+handler_traits! {
+    Alternative => on_alternative_enter, on_alternative_leave;
+    Assertion => on_assertion_enter, on_assertion_leave;
+    Backreference => on_backreference_enter, on_backreference_leave;
+    CapturingGroup => on_capturing_group_enter, on_capturing_group_leave;
+    Character => on_character_enter, on_character_leave;
+    CharacterClass => on_character_class_enter, on_character_class_leave;
+    CharacterClassRange => on_character_class_range_enter, on_character_class_range_leave;
+    CharacterSet => on_character_set_enter, on_character_set_leave;
+    ClassIntersection => on_class_intersection_enter, on_class_intersection_leave;
+    ClassStringDisjunction => on_class_string_disjunction_enter, on_class_string_disjunction_leave;
+    ClassSubtraction => on_class_subtraction_enter, on_class_subtraction_leave;
+    Error => on_error_enter, on_error_leave;
+    ExpressionCharacterClass =>
+        on_expression_character_class_enter,
+        on_expression_character_class_leave;
+    Flags => on_flags_enter, on_flags_leave;
+    Group => on_group_enter, on_group_leave;
+    Modifiers => on_modifiers_enter, on_modifiers_leave;
+    ModifierFlags => on_modifier_flags_enter, on_modifier_flags_leave;
+    Pattern => on_pattern_enter, on_pattern_leave;
+    Quantifier => on_quantifier_enter, on_quantifier_leave;
+    RegExpLiteral => on_reg_exp_literal_enter, on_reg_exp_literal_leave;
+    StringAlternative => on_string_alternative_enter, on_string_alternative_leave;
 }
 
 #[allow(unused_variables)]
@@ -234,12 +276,18 @@ pub trait Handlers {
     fn on_class_string_disjunction_leave(&self, node: Id<Node /*ClassStringDisjunction*/>) {}
     fn on_class_subtraction_enter(&self, node: Id<Node /*ClassSubtraction*/>) {}
     fn on_class_subtraction_leave(&self, node: Id<Node /*ClassSubtraction*/>) {}
+    fn on_error_enter(&self, node: Id<Node /*Error*/>) {}
+    fn on_error_leave(&self, node: Id<Node /*Error*/>) {}
     fn on_expression_character_class_enter(&self, node: Id<Node /*ExpressionCharacterClass*/>) {}
     fn on_expression_character_class_leave(&self, node: Id<Node /*ExpressionCharacterClass*/>) {}
     fn on_flags_enter(&self, node: Id<Node /*Flags*/>) {}
     fn on_flags_leave(&self, node: Id<Node /*Flags*/>) {}
     fn on_group_enter(&self, node: Id<Node /*Group*/>) {}
     fn on_group_leave(&self, node: Id<Node /*Group*/>) {}
+    fn on_modifiers_enter(&self, node: Id<Node /*Modifiers*/>) {}
+    fn on_modifiers_leave(&self, node: Id<Node /*Modifiers*/>) {}
+    fn on_modifier_flags_enter(&self, node: Id<Node /*ModifierFlags*/>) {}
+    fn on_modifier_flags_leave(&self, node: Id<Node /*ModifierFlags*/>) {}
     fn on_pattern_enter(&self, node: Id<Node /*Pattern*/>) {}
     fn on_pattern_leave(&self, node: Id<Node /*Pattern*/>) {}
     fn on_quantifier_enter(&self, node: Id<Node /*Quantifier*/>) {}
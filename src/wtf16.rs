@@ -1,7 +1,11 @@
 use std::{mem, ops, string::FromUtf16Error};
 
-use serde::{Deserialize, Deserializer};
+#[cfg(feature = "serde_json")]
+use serde::ser;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_bytes::ByteBuf;
+#[cfg(feature = "serde_json")]
+use serde_json::value::RawValue;
 use wtf8::Wtf8;
 
 use crate::{
@@ -40,6 +44,35 @@ impl Wtf16 {
         }
         Some(first_unit)
     }
+
+    #[cfg(feature = "serde_json")]
+    fn to_json_string_literal(&self) -> String {
+        let mut literal = String::from('"');
+        for c in char::decode_utf16(self.0.iter().copied()) {
+            match c {
+                Ok('"') => literal.push_str("\\\""),
+                Ok('\\') => literal.push_str("\\\\"),
+                Ok(c) if c < ' ' => literal.push_str(&format!("\\u{:04x}", u32::from(c))),
+                Ok(c) => literal.push(c),
+                Err(error) => literal.push_str(&format!("\\u{:04x}", error.unpaired_surrogate())),
+            }
+        }
+        literal.push('"');
+        literal
+    }
+
+    /// Each lone surrogate written as `\uXXXX`, with backslashes doubled so
+    /// that those can't be confused with the source's own
+    #[cfg(not(feature = "serde_json"))]
+    fn to_escaped_string(&self) -> String {
+        char::decode_utf16(self.0.iter().copied())
+            .map(|c| match c {
+                Ok('\\') => "\\\\".to_owned(),
+                Ok(c) => c.to_string(),
+                Err(error) => format!("\\u{:04x}", error.unpaired_surrogate()),
+            })
+            .collect()
+    }
 }
 
 impl<'de> Deserialize<'de> for Wtf16 {
@@ -57,6 +90,29 @@ impl<'de> Deserialize<'de> for Wtf16 {
     }
 }
 
+/// Lone surrogates can't be passed to [`Serializer::serialize_str`]. With
+/// the (default) `serde_json` feature, a string containing them is written as
+/// a raw JSON string literal with `\uXXXX` escapes, which round-trips through
+/// serde_json but which other serializers write as a struct. Without it, each
+/// lone surrogate is written as the text `\uXXXX` (and each backslash
+/// doubled), which any serializer accepts but which reads back as that text
+impl Serialize for Wtf16 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match String::from_utf16(&self.0) {
+            Ok(string) => serializer.serialize_str(&string),
+            #[cfg(feature = "serde_json")]
+            Err(_) => RawValue::from_string(self.to_json_string_literal())
+                .map_err(ser::Error::custom)?
+                .serialize(serializer),
+            #[cfg(not(feature = "serde_json"))]
+            Err(_) => serializer.serialize_str(&self.to_escaped_string()),
+        }
+    }
+}
+
 impl ops::Deref for Wtf16 {
     type Target = Vec<u16>;
 